@@ -3,6 +3,8 @@ use std::path::PathBuf;
 fn main() {
     generate_core_bindings();
     generate_image_bindings();
+    generate_ttf_bindings();
+    generate_vulkan_bindings();
     generate_main_bindings();
 }
 
@@ -55,3 +57,41 @@ fn generate_image_bindings() {
         .write_to_file(manifest_dir.join("../src").join("image.rs"))
         .unwrap();
 }
+
+fn generate_ttf_bindings() {
+    let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    let core_include_path = manifest_dir.join("SDL").join("include");
+    let ttf_header_path = manifest_dir
+        .join("SDL_ttf")
+        .join("include")
+        .join("SDL3_ttf")
+        .join("SDL_ttf.h");
+    bindgen::Builder::default()
+        .use_core()
+        .raw_line("use crate::*;")
+        .clang_arg(format!("-I{}", core_include_path.to_str().unwrap()))
+        .header(ttf_header_path.to_str().unwrap())
+        .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+        .blocklist_type("SDL_.*")
+        .generate()
+        .unwrap()
+        .write_to_file(manifest_dir.join("../src").join("ttf.rs"))
+        .unwrap();
+}
+
+fn generate_vulkan_bindings() {
+    let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    let core_include_path = manifest_dir.join("SDL").join("include");
+    let vulkan_header_path = core_include_path.join("SDL3").join("SDL_vulkan.h");
+    bindgen::Builder::default()
+        .use_core()
+        .raw_line("use crate::*;")
+        .clang_arg(format!("-I{}", core_include_path.to_str().unwrap()))
+        .header(vulkan_header_path.to_str().unwrap())
+        .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+        .allowlist_file(".*SDL_vulkan.h")
+        .generate()
+        .unwrap()
+        .write_to_file(manifest_dir.join("../src").join("vulkan.rs"))
+        .unwrap();
+}
@@ -1,6 +1,23 @@
 fn main() {
+    // Build scripts are compiled for the host, so the target has to be read from the environment.
+    if std::env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("emscripten") {
+        // Emscripten builds SDL from its ports, which are selected with linker flags.
+        println!("cargo:rustc-link-arg=-sUSE_SDL=3");
+
+        #[cfg(feature = "image")]
+        println!("cargo:rustc-link-arg=-sUSE_SDL_IMAGE=3");
+
+        #[cfg(feature = "ttf")]
+        println!("cargo:rustc-link-arg=-sUSE_SDL_TTF=3");
+
+        return;
+    }
+
     println!("cargo:rustc-link-lib=SDL3");
 
     #[cfg(feature = "image")]
     println!("cargo:rustc-link-lib=SDL3_image");
+
+    #[cfg(feature = "ttf")]
+    println!("cargo:rustc-link-lib=SDL3_ttf");
 }
@@ -0,0 +1,15 @@
+//! The parts of Emscripten's `emscripten.h` needed to drive the main loop from the browser.
+
+use ::core::ffi::{c_int, c_void};
+
+pub type em_arg_callback_func = ::core::option::Option<unsafe extern "C" fn(arg: *mut c_void)>;
+
+unsafe extern "C" {
+    pub fn emscripten_set_main_loop_arg(
+        func: em_arg_callback_func,
+        arg: *mut c_void,
+        fps: c_int,
+        simulate_infinite_loop: bool,
+    );
+    pub fn emscripten_cancel_main_loop();
+}
@@ -3,12 +3,21 @@
 
 mod core;
 
+#[cfg(target_os = "emscripten")]
+pub mod emscripten;
+
 #[cfg(feature = "image")]
 pub mod image;
 
 #[cfg(feature = "main")]
 pub mod sdl_main;
 
+#[cfg(feature = "ttf")]
+pub mod ttf;
+
+#[cfg(feature = "vulkan")]
+pub mod vulkan;
+
 mod defines {
     pub const SDL_WINDOW_FULLSCREEN: u64 = 0x0000000000000001;
     pub const SDL_WINDOW_OPENGL: u64 = 0x0000000000000002;
@@ -35,6 +44,9 @@ mod defines {
     pub const SDL_WINDOW_METAL: u64 = 0x0000000020000000;
     pub const SDL_WINDOW_TRANSPARENT: u64 = 0x0000000040000000;
     pub const SDL_WINDOW_NOT_FOCUSABLE: u64 = 0x0000000080000000;
+
+    pub const SDL_AUDIO_DEVICE_DEFAULT_PLAYBACK: u32 = 0xFFFFFFFF;
+    pub const SDL_AUDIO_DEVICE_DEFAULT_RECORDING: u32 = 0xFFFFFFFE;
 }
 
 pub use core::*;
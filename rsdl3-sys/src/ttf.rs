@@ -0,0 +1,141 @@
+//! The parts of SDL_ttf's `SDL_ttf.h` used by `rsdl3::ttf`, written by hand in bindgen's style.
+//!
+//! Running `bindgen-wrapper` with the `SDL_ttf` submodule checked out replaces this file with the
+//! full generated bindings.
+
+use crate::*;
+
+pub const SDL_TTF_MAJOR_VERSION: u32 = 3;
+pub const SDL_TTF_MINOR_VERSION: u32 = 2;
+pub const SDL_TTF_MICRO_VERSION: u32 = 2;
+pub const TTF_STYLE_NORMAL: u32 = 0;
+pub const TTF_STYLE_BOLD: u32 = 1;
+pub const TTF_STYLE_ITALIC: u32 = 2;
+pub const TTF_STYLE_UNDERLINE: u32 = 4;
+pub const TTF_STYLE_STRIKETHROUGH: u32 = 8;
+unsafe extern "C" {
+    #[doc = " This function gets the version of the dynamically linked SDL_ttf library.\n\n \\returns SDL_ttf version.\n\n \\threadsafety It is safe to call this function from any thread.\n\n \\since This function is available since SDL_ttf 3.0.0."]
+    pub fn TTF_Version() -> ::core::ffi::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct TTF_Font {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    #[doc = " Initialize SDL_ttf.\n\n You must successfully call this function before it is safe to call any\n other function in this library.\n\n It is safe to call this more than once, and each successful TTF_Init() call\n should be paired with a matching TTF_Quit() call.\n\n \\returns true on success or false on failure; call SDL_GetError() for more\n          information.\n\n \\since This function is available since SDL_ttf 3.0.0.\n\n \\sa TTF_Quit"]
+    pub fn TTF_Init() -> bool;
+}
+unsafe extern "C" {
+    #[doc = " Create a font from a file, using a specified point size.\n\n Some .fon fonts will have several sizes embedded in the file, so the point\n size becomes the index of choosing which size. If the value is too high,\n the last indexed size will be the default.\n\n \\param file path to font file.\n \\param ptsize point size to use for the newly-opened font.\n \\returns a valid TTF_Font, or NULL on failure; call SDL_GetError() for more\n          information.\n\n \\threadsafety This function should be called on the thread that created\n               the font.\n\n \\since This function is available since SDL_ttf 3.0.0.\n\n \\sa TTF_CloseFont"]
+    pub fn TTF_OpenFont(file: *const ::core::ffi::c_char, ptsize: f32) -> *mut TTF_Font;
+}
+unsafe extern "C" {
+    #[doc = " Create a font from an SDL_IOStream, using a specified point size.\n\n Some .fon fonts will have several sizes embedded in the file, so the point\n size becomes the index of choosing which size. If the value is too high,\n the last indexed size will be the default.\n\n If `closeio` is true, `src` will be automatically closed once the font is\n closed. Otherwise you should keep `src` open until the font is closed.\n\n \\param src an SDL_IOStream to provide a font file's data.\n \\param closeio true to close `src` when the font is closed, false to leave\n                it open.\n \\param ptsize point size to use for the newly-opened font.\n \\returns a valid TTF_Font, or NULL on failure; call SDL_GetError() for more\n          information.\n\n \\threadsafety This function should be called on the thread that created\n               the font.\n\n \\since This function is available since SDL_ttf 3.0.0.\n\n \\sa TTF_CloseFont"]
+    pub fn TTF_OpenFontIO(src: *mut SDL_IOStream, closeio: bool, ptsize: f32) -> *mut TTF_Font;
+}
+unsafe extern "C" {
+    #[doc = " Set a font's size dynamically.\n\n This updates any TTF_Text objects using this font, and clears\n already-generated glyphs, if any, from the cache.\n\n \\param font the font to resize.\n \\param ptsize the new point size.\n \\returns true on success or false on failure; call SDL_GetError() for more\n          information.\n\n \\threadsafety This function should be called on the thread that created\n               the font.\n\n \\since This function is available since SDL_ttf 3.0.0.\n\n \\sa TTF_GetFontSize"]
+    pub fn TTF_SetFontSize(font: *mut TTF_Font, ptsize: f32) -> bool;
+}
+unsafe extern "C" {
+    #[doc = " Get the size of a font.\n\n \\param font the font to query.\n \\returns the size of the font, or 0.0f on failure; call SDL_GetError() for\n          more information.\n\n \\threadsafety This function should be called on the thread that created\n               the font.\n\n \\since This function is available since SDL_ttf 3.0.0.\n\n \\sa TTF_SetFontSize"]
+    pub fn TTF_GetFontSize(font: *mut TTF_Font) -> f32;
+}
+#[doc = " Font style flags for TTF_Font\n\n These are the flags which can be used to set the style of a font in\n SDL_ttf. A combination of these flags can be used with functions that set\n or query font style, such as TTF_SetFontStyle or TTF_GetFontStyle.\n\n \\since This datatype is available since SDL_ttf 3.0.0.\n\n \\sa TTF_SetFontStyle\n \\sa TTF_GetFontStyle"]
+pub type TTF_FontStyleFlags = Uint32;
+unsafe extern "C" {
+    #[doc = " Set a font's current style.\n\n This updates any TTF_Text objects using this font, and clears\n already-generated glyphs, if any, from the cache.\n\n \\param font the font to set a new style on.\n \\param style the new style values to set, OR'd together.\n\n \\threadsafety This function should be called on the thread that created\n               the font.\n\n \\since This function is available since SDL_ttf 3.0.0.\n\n \\sa TTF_GetFontStyle"]
+    pub fn TTF_SetFontStyle(font: *mut TTF_Font, style: TTF_FontStyleFlags);
+}
+unsafe extern "C" {
+    #[doc = " Query a font's current style.\n\n \\param font the font to query.\n \\returns the current font style, as a set of bit flags.\n\n \\threadsafety It is safe to call this function from any thread.\n\n \\since This function is available since SDL_ttf 3.0.0.\n\n \\sa TTF_SetFontStyle"]
+    pub fn TTF_GetFontStyle(font: *const TTF_Font) -> TTF_FontStyleFlags;
+}
+unsafe extern "C" {
+    #[doc = " Query the total height of a font.\n\n This is usually equal to point size.\n\n \\param font the font to query.\n \\returns the font's height.\n\n \\threadsafety It is safe to call this function from any thread.\n\n \\since This function is available since SDL_ttf 3.0.0."]
+    pub fn TTF_GetFontHeight(font: *const TTF_Font) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    #[doc = " Query the offset from the baseline to the top of a font.\n\n This is a positive value, relative to the baseline.\n\n \\param font the font to query.\n \\returns the font's ascent.\n\n \\threadsafety It is safe to call this function from any thread.\n\n \\since This function is available since SDL_ttf 3.0.0."]
+    pub fn TTF_GetFontAscent(font: *const TTF_Font) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    #[doc = " Query the offset from the baseline to the bottom of a font.\n\n This is a negative value, relative to the baseline.\n\n \\param font the font to query.\n \\returns the font's descent.\n\n \\threadsafety It is safe to call this function from any thread.\n\n \\since This function is available since SDL_ttf 3.0.0."]
+    pub fn TTF_GetFontDescent(font: *const TTF_Font) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    #[doc = " Query the spacing between lines of text for a font.\n\n \\param font the font to query.\n \\returns the font's recommended spacing.\n\n \\threadsafety It is safe to call this function from any thread.\n\n \\since This function is available since SDL_ttf 3.0.0."]
+    pub fn TTF_GetFontLineSkip(font: *const TTF_Font) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    #[doc = " Calculate the dimensions of a rendered string of UTF-8 text.\n\n This will report the width and height, in pixels, of the space that the\n specified string will take to fully render.\n\n \\param font the font to query.\n \\param text text to calculate, in UTF-8 encoding.\n \\param length the length of the text, in bytes, or 0 for null terminated\n              text.\n \\param w will be filled with width, in pixels, on return.\n \\param h will be filled with height, in pixels, on return.\n \\returns true on success or false on failure; call SDL_GetError() for more\n          information.\n\n \\threadsafety This function should be called on the thread that created\n               the font.\n\n \\since This function is available since SDL_ttf 3.0.0."]
+    pub fn TTF_GetStringSize(
+        font: *mut TTF_Font,
+        text: *const ::core::ffi::c_char,
+        length: usize,
+        w: *mut ::core::ffi::c_int,
+        h: *mut ::core::ffi::c_int,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[doc = " Calculate how much of a UTF-8 string will fit in a given width.\n\n This reports the number of characters that can be rendered before reaching\n `max_width`.\n\n This does not need to render the string to do this calculation.\n\n \\param font the font to query.\n \\param text text to calculate, in UTF-8 encoding.\n \\param length the length of the text, in bytes, or 0 for null terminated\n              text.\n \\param max_width maximum width, in pixels, available for the string, or 0\n                  for unbounded width.\n \\param measured_width a pointer filled in with the width, in pixels, of the\n                       string that will fit, may be NULL.\n \\param measured_length a pointer filled in with the length, in bytes, of\n                        the string that will fit, may be NULL.\n \\returns true on success or false on failure; call SDL_GetError() for more\n          information.\n\n \\threadsafety This function should be called on the thread that created\n               the font.\n\n \\since This function is available since SDL_ttf 3.0.0."]
+    pub fn TTF_MeasureString(
+        font: *mut TTF_Font,
+        text: *const ::core::ffi::c_char,
+        length: usize,
+        max_width: ::core::ffi::c_int,
+        measured_width: *mut ::core::ffi::c_int,
+        measured_length: *mut usize,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[doc = " Render UTF-8 text at fast quality to a new 8-bit surface.\n\n This function will allocate a new 8-bit, palettized surface. The surface's\n 0 pixel will be the colorkey, giving a transparent background. The 1 pixel\n will be set to the text color.\n\n \\param font the font to render with.\n \\param text text to render, in UTF-8 encoding.\n \\param length the length of the text, in bytes, or 0 for null terminated\n              text.\n \\param fg the foreground color for the text.\n \\returns a new 8-bit, palettized surface, or NULL if there was an error.\n\n \\threadsafety This function should be called on the thread that created\n               the font.\n\n \\since This function is available since SDL_ttf 3.0.0."]
+    pub fn TTF_RenderText_Solid(
+        font: *mut TTF_Font,
+        text: *const ::core::ffi::c_char,
+        length: usize,
+        fg: SDL_Color,
+    ) -> *mut SDL_Surface;
+}
+unsafe extern "C" {
+    #[doc = " Render UTF-8 text at high quality to a new 8-bit surface.\n\n This function will allocate a new 8-bit, palettized surface. The surface's\n 0 pixel will be the specified background color, while other pixels have\n varying degrees of the foreground color.\n\n \\param font the font to render with.\n \\param text text to render, in UTF-8 encoding.\n \\param length the length of the text, in bytes, or 0 for null terminated\n              text.\n \\param fg the foreground color for the text.\n \\param bg the background color for the text.\n \\returns a new 8-bit, palettized surface, or NULL if there was an error.\n\n \\threadsafety This function should be called on the thread that created\n               the font.\n\n \\since This function is available since SDL_ttf 3.0.0."]
+    pub fn TTF_RenderText_Shaded(
+        font: *mut TTF_Font,
+        text: *const ::core::ffi::c_char,
+        length: usize,
+        fg: SDL_Color,
+        bg: SDL_Color,
+    ) -> *mut SDL_Surface;
+}
+unsafe extern "C" {
+    #[doc = " Render UTF-8 text at high quality to a new ARGB surface.\n\n This function will allocate a new 32-bit, ARGB surface, using alpha\n blending to dither the font with the given color.\n\n \\param font the font to render with.\n \\param text text to render, in UTF-8 encoding.\n \\param length the length of the text, in bytes, or 0 for null terminated\n              text.\n \\param fg the foreground color for the text.\n \\returns a new 32-bit, ARGB surface, or NULL if there was an error.\n\n \\threadsafety This function should be called on the thread that created\n               the font.\n\n \\since This function is available since SDL_ttf 3.0.0."]
+    pub fn TTF_RenderText_Blended(
+        font: *mut TTF_Font,
+        text: *const ::core::ffi::c_char,
+        length: usize,
+        fg: SDL_Color,
+    ) -> *mut SDL_Surface;
+}
+unsafe extern "C" {
+    #[doc = " Render word-wrapped UTF-8 text at high quality to a new ARGB surface.\n\n This function will allocate a new 32-bit, ARGB surface, using alpha\n blending to dither the font with the given color.\n\n Text is wrapped to multiple lines on line endings and on word boundaries if\n it extends beyond `wrap_width` in pixels.\n\n If wrap_width is 0, this function will only wrap on newline characters.\n\n \\param font the font to render with.\n \\param text text to render, in UTF-8 encoding.\n \\param length the length of the text, in bytes, or 0 for null terminated\n              text.\n \\param fg the foreground color for the text.\n \\param wrap_width the maximum width of the text surface or 0 to wrap on\n                   newline characters.\n \\returns a new 32-bit, ARGB surface, or NULL if there was an error.\n\n \\threadsafety This function should be called on the thread that created\n               the font.\n\n \\since This function is available since SDL_ttf 3.0.0."]
+    pub fn TTF_RenderText_Blended_Wrapped(
+        font: *mut TTF_Font,
+        text: *const ::core::ffi::c_char,
+        length: usize,
+        fg: SDL_Color,
+        wrap_width: ::core::ffi::c_int,
+    ) -> *mut SDL_Surface;
+}
+unsafe extern "C" {
+    #[doc = " Dispose of a previously-created font.\n\n Call this when done with a font. This function will free any resources\n associated with it. It is safe to call this function on NULL, for example\n on the result of a failed call to TTF_OpenFont().\n\n The font is not valid after being passed to this function. String pointers\n from functions that return information on this font, such as\n TTF_GetFontFamilyName() and TTF_GetFontStyleName(), are no longer valid\n after this call, as is the font pointer.\n\n \\param font the font to dispose of.\n\n \\threadsafety This function should not be called while any other thread is\n               using the font.\n\n \\since This function is available since SDL_ttf 3.0.0.\n\n \\sa TTF_OpenFont\n \\sa TTF_OpenFontIO"]
+    pub fn TTF_CloseFont(font: *mut TTF_Font);
+}
+unsafe extern "C" {
+    #[doc = " Deinitialize SDL_ttf.\n\n You must call this when done with the library, to free internal resources.\n It is safe to call this when the library isn't initialized, as it will just\n return immediately.\n\n Once you have as many quit calls as you have had successful calls to\n TTF_Init, the library will actually deinitialize.\n\n Please note that this does not automatically close any fonts that are still\n open at the time of deinitialization, and it is possibly not safe to close\n them afterwards, as parts of the library will no longer be initialized to\n deal with it. A well-written program should call TTF_CloseFont() on any\n open fonts before calling this function!\n\n \\since This function is available since SDL_ttf 3.0.0."]
+    pub fn TTF_Quit();
+}
+unsafe extern "C" {
+    #[doc = " Check if SDL_ttf is initialized.\n\n This reports the number of times the library has been initialized by a call\n to TTF_Init(), without a paired deinitialization request from TTF_Quit().\n\n In short: if it's greater than zero, the library is currently initialized\n and ready to work. If zero, it is not initialized.\n\n Despite the return value being a signed integer, this function should not\n return a negative number.\n\n \\returns the current number of initialization calls, that need to\n          eventually be paired with this many calls to TTF_Quit().\n\n \\threadsafety It is safe to call this function from any thread.\n\n \\since This function is available since SDL_ttf 3.0.0.\n\n \\sa TTF_Init\n \\sa TTF_Quit"]
+    pub fn TTF_WasInit() -> ::core::ffi::c_int;
+}
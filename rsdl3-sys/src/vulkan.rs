@@ -0,0 +1,73 @@
+//! The parts of SDL's `SDL_vulkan.h` used by `rsdl3::video::vulkan`, written by hand in bindgen's
+//! style.
+//!
+//! Running `bindgen-wrapper` replaces this file with the full generated bindings.
+
+use crate::*;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkInstance_T {
+    _unused: [u8; 0],
+}
+pub type VkInstance = *mut VkInstance_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkPhysicalDevice_T {
+    _unused: [u8; 0],
+}
+pub type VkPhysicalDevice = *mut VkPhysicalDevice_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkSurfaceKHR_T {
+    _unused: [u8; 0],
+}
+pub type VkSurfaceKHR = *mut VkSurfaceKHR_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkAllocationCallbacks {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    #[doc = " Dynamically load the Vulkan loader library.\n\n This should be called after initializing the video driver, but before\n creating any Vulkan windows. If no Vulkan loader library is loaded, the\n default library will be loaded upon creation of the first Vulkan window.\n\n SDL keeps a counter of how many times this function has been successfully\n called, so it is safe to call this function multiple times, so long as it\n is eventually paired with an equivalent number of calls to\n SDL_Vulkan_UnloadLibrary. The `path` argument is ignored unless there is no\n library currently loaded, and and the library isn't actually unloaded until\n there have been an equivalent number of calls to SDL_Vulkan_UnloadLibrary.\n\n \\param path the platform dependent Vulkan loader library name or NULL.\n \\returns true on success or false on failure; call SDL_GetError() for more\n          information.\n\n \\threadsafety This function is not thread safe.\n\n \\since This function is available since SDL 3.2.0.\n\n \\sa SDL_Vulkan_GetVkGetInstanceProcAddr\n \\sa SDL_Vulkan_UnloadLibrary"]
+    pub fn SDL_Vulkan_LoadLibrary(path: *const ::core::ffi::c_char) -> bool;
+}
+unsafe extern "C" {
+    #[doc = " Get the address of the `vkGetInstanceProcAddr` function.\n\n This should be called after either calling SDL_Vulkan_LoadLibrary() or\n creating an SDL_Window with the `SDL_WINDOW_VULKAN` flag.\n\n The actual type of the returned function pointer is\n PFN_vkGetInstanceProcAddr, but that isn't available because the Vulkan\n headers are not included here. You should cast the return value of this\n function to that type.\n\n \\returns the function pointer for `vkGetInstanceProcAddr` or NULL on\n          failure; call SDL_GetError() for more information.\n\n \\since This function is available since SDL 3.2.0."]
+    pub fn SDL_Vulkan_GetVkGetInstanceProcAddr() -> SDL_FunctionPointer;
+}
+unsafe extern "C" {
+    #[doc = " Unload the Vulkan library previously loaded by SDL_Vulkan_LoadLibrary().\n\n SDL keeps a counter of how many times this function has been called, so it\n is safe to call this function multiple times, so long as it is paired with\n an equivalent number of calls to SDL_Vulkan_LoadLibrary. The library isn't\n actually unloaded until there have been an equivalent number of calls to\n SDL_Vulkan_UnloadLibrary.\n\n Once the library has actually been unloaded, if any Vulkan instances\n remain, they will likely crash the program. Clean up any existing Vulkan\n resources, and destroy appropriate windows, renderers and GPU devices\n before calling this function.\n\n \\threadsafety This function is not thread safe.\n\n \\since This function is available since SDL 3.2.0.\n\n \\sa SDL_Vulkan_LoadLibrary"]
+    pub fn SDL_Vulkan_UnloadLibrary();
+}
+unsafe extern "C" {
+    #[doc = " Get the Vulkan instance extensions needed for vkCreateInstance.\n\n This should be called after either calling SDL_Vulkan_LoadLibrary() or\n creating an SDL_Window with the `SDL_WINDOW_VULKAN` flag.\n\n On return, the variable pointed to by `count` will be set to the number of\n elements returned, suitable for using with\n VkInstanceCreateInfo::enabledExtensionCount, and the returned array can be\n used with VkInstanceCreateInfo::ppEnabledExtensionNames, for calling\n Vulkan's vkCreateInstance API.\n\n You should not free the returned array; it is owned by SDL.\n\n \\param count a pointer filled in with the number of extensions returned.\n \\returns an array of extension name strings on success, NULL on failure;\n          call SDL_GetError() for more information.\n\n \\since This function is available since SDL 3.2.0.\n\n \\sa SDL_Vulkan_CreateSurface"]
+    pub fn SDL_Vulkan_GetInstanceExtensions(
+        count: *mut Uint32,
+    ) -> *const *const ::core::ffi::c_char;
+}
+unsafe extern "C" {
+    #[doc = " Create a Vulkan rendering surface for a window.\n\n The `window` must have been created with the `SDL_WINDOW_VULKAN` flag and\n `instance` must have been created with extensions returned by\n SDL_Vulkan_GetInstanceExtensions() enabled.\n\n If `allocator` is NULL, Vulkan will use the system default allocator. This\n argument is passed directly to Vulkan and isn't used by SDL itself.\n\n \\param window the window to which to attach the Vulkan surface.\n \\param instance the Vulkan instance handle.\n \\param allocator a VkAllocationCallbacks struct, which lets the app set the\n                  allocator that creates the surface. Can be NULL.\n \\param surface a pointer to a VkSurfaceKHR handle to output the newly\n                created surface.\n \\returns true on success or false on failure; call SDL_GetError() for more\n          information.\n\n \\since This function is available since SDL 3.2.0.\n\n \\sa SDL_Vulkan_GetInstanceExtensions\n \\sa SDL_Vulkan_DestroySurface"]
+    pub fn SDL_Vulkan_CreateSurface(
+        window: *mut SDL_Window,
+        instance: VkInstance,
+        allocator: *const VkAllocationCallbacks,
+        surface: *mut VkSurfaceKHR,
+    ) -> bool;
+}
+unsafe extern "C" {
+    #[doc = " Destroy the Vulkan rendering surface of a window.\n\n This should be called before SDL_DestroyWindow, if SDL_Vulkan_CreateSurface\n was called after SDL_CreateWindow.\n\n The `instance` must have been created with extensions returned by\n SDL_Vulkan_GetInstanceExtensions() enabled and `surface` must have been\n created successfully by an SDL_Vulkan_CreateSurface() call.\n\n If `allocator` is NULL, Vulkan will use the system default allocator. This\n argument is passed directly to Vulkan and isn't used by SDL itself.\n\n \\param instance the Vulkan instance handle.\n \\param surface vkSurfaceKHR handle to destroy.\n \\param allocator a VkAllocationCallbacks struct, which lets the app set the\n                  allocator that destroys the surface. Can be NULL.\n\n \\since This function is available since SDL 3.2.0.\n\n \\sa SDL_Vulkan_GetInstanceExtensions\n \\sa SDL_Vulkan_CreateSurface"]
+    pub fn SDL_Vulkan_DestroySurface(
+        instance: VkInstance,
+        surface: VkSurfaceKHR,
+        allocator: *const VkAllocationCallbacks,
+    );
+}
+unsafe extern "C" {
+    #[doc = " Query support for presentation via a given physical device and queue\n family.\n\n The `instance` must have been created with extensions returned by\n SDL_Vulkan_GetInstanceExtensions() enabled.\n\n \\param instance the Vulkan instance handle.\n \\param physicalDevice a valid Vulkan physical device handle.\n \\param queueFamilyIndex a valid queue family index for the given physical\n                         device.\n \\returns true if supported, false if unsupported or an error occurred.\n\n \\since This function is available since SDL 3.2.0.\n\n \\sa SDL_Vulkan_GetInstanceExtensions"]
+    pub fn SDL_Vulkan_GetPresentationSupport(
+        instance: VkInstance,
+        physicalDevice: VkPhysicalDevice,
+        queueFamilyIndex: Uint32,
+    ) -> bool;
+}
@@ -0,0 +1,344 @@
+use crate::init::SdlDrop;
+use crate::sys;
+use crate::{init::Sdl, Error};
+use alloc::boxed::Box;
+use alloc::ffi::CString;
+use alloc::rc::Rc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::ffi::c_void;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr::NonNull;
+use core::time::Duration;
+
+/// A queue that collects the results of asynchronous I/O tasks.
+///
+/// Tasks are started with [`AsyncIO`] or [`AsyncIOQueue::load_file`], run in the background and
+/// are collected with [`AsyncIOQueue::poll`] or [`AsyncIOQueue::wait`]. Every task carries a `tag`
+/// chosen by the caller, which is handed back in its [`AsyncIOOutcome`].
+///
+/// Buffers are owned by the queue while their task is running, and returned in the outcome.
+/// Dropping the queue blocks until every pending task has finished.
+pub struct AsyncIOQueue {
+    _sdl: Rc<SdlDrop>,
+    ptr: NonNull<sys::SDL_AsyncIOQueue>,
+    pending: Cell<usize>,
+}
+
+/// Data attached to a running task, returned through the outcome's userdata.
+struct PendingTask {
+    buffer: Vec<u8>,
+    tag: u64,
+    /// SDL reports whole-file loads as reads.
+    load: bool,
+}
+
+impl AsyncIOQueue {
+    pub fn new(sdl: &Sdl) -> Result<Self, Error> {
+        let ptr = NonNull::new(unsafe { sys::SDL_CreateAsyncIOQueue() }).ok_or_else(Error::new)?;
+        Ok(Self {
+            _sdl: Rc::clone(&sdl.drop),
+            ptr,
+            pending: Cell::new(0),
+        })
+    }
+
+    /// Starts loading the entire contents of a file.
+    ///
+    /// The contents are returned in the [`AsyncIOOutcome::buffer`] of an [`AsyncIOTaskType::Load`]
+    /// outcome.
+    pub fn load_file(&self, file: &str, tag: u64) -> Result<(), Error> {
+        let file = CString::new(file)?;
+        let userdata = Box::into_raw(Box::new(PendingTask {
+            buffer: Vec::new(),
+            tag,
+            load: true,
+        }));
+        let result =
+            unsafe { sys::SDL_LoadFileAsync(file.as_ptr(), self.raw(), userdata as *mut c_void) };
+        self.submitted(result, userdata)
+    }
+
+    /// Returns the outcome of a finished task, if any, without blocking.
+    pub fn poll(&self) -> Option<AsyncIOOutcome> {
+        let mut outcome = MaybeUninit::uninit();
+        let result = unsafe { sys::SDL_GetAsyncIOResult(self.raw(), outcome.as_mut_ptr()) };
+        if !result {
+            return None;
+        }
+        Some(unsafe { self.finish(outcome.assume_init()) })
+    }
+
+    /// Blocks until a task finishes, or until `timeout` elapses, if it's not `None`.
+    ///
+    /// Returns `None` right away if no task is pending.
+    pub fn wait(&self, timeout: Option<Duration>) -> Option<AsyncIOOutcome> {
+        if self.pending.get() == 0 {
+            return None;
+        }
+        let timeout_ms = match timeout {
+            Some(timeout) => i32::try_from(timeout.as_millis()).unwrap_or(i32::MAX),
+            None => -1,
+        };
+        let mut outcome = MaybeUninit::uninit();
+        let result =
+            unsafe { sys::SDL_WaitAsyncIOResult(self.raw(), outcome.as_mut_ptr(), timeout_ms) };
+        if !result {
+            return None;
+        }
+        Some(unsafe { self.finish(outcome.assume_init()) })
+    }
+
+    /// Returns the number of tasks whose outcome hasn't been collected yet.
+    #[inline]
+    pub fn pending(&self) -> usize {
+        self.pending.get()
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_AsyncIOQueue {
+        self.ptr.as_ptr()
+    }
+
+    /// Tracks a task started with `userdata`, or reclaims `userdata` if the task didn't start.
+    fn submitted(&self, result: bool, userdata: *mut PendingTask) -> Result<(), Error> {
+        if !result {
+            drop(unsafe { Box::from_raw(userdata) });
+            return Err(Error::new());
+        }
+        self.pending.set(self.pending.get() + 1);
+        Ok(())
+    }
+
+    /// SAFETY: `outcome` must come from this queue, for a task started by this module.
+    unsafe fn finish(&self, outcome: sys::SDL_AsyncIOOutcome) -> AsyncIOOutcome {
+        self.pending.set(self.pending.get() - 1);
+        let task = unsafe { Box::from_raw(outcome.userdata as *mut PendingTask) };
+        let kind = if task.load {
+            AsyncIOTaskType::Load
+        } else {
+            AsyncIOTaskType::from_ll(outcome.type_)
+        };
+        let mut buffer = task.buffer;
+        let transferred = usize::try_from(outcome.bytes_transferred).unwrap_or(usize::MAX);
+        match kind {
+            AsyncIOTaskType::Read => buffer.truncate(transferred),
+            AsyncIOTaskType::Load => {
+                if !outcome.buffer.is_null() {
+                    let bytes = outcome.buffer as *const u8;
+                    buffer = unsafe { core::slice::from_raw_parts(bytes, transferred) }.to_vec();
+                    unsafe { sys::SDL_free(outcome.buffer) };
+                }
+            }
+            AsyncIOTaskType::Write | AsyncIOTaskType::Close => {}
+        }
+        AsyncIOOutcome {
+            kind,
+            result: AsyncIOResult::from_ll(outcome.result),
+            buffer,
+            offset: outcome.offset,
+            bytes_requested: outcome.bytes_requested,
+            bytes_transferred: outcome.bytes_transferred,
+            tag: task.tag,
+        }
+    }
+}
+
+impl Drop for AsyncIOQueue {
+    fn drop(&mut self) {
+        // Collect the remaining tasks so their buffers are freed before SDL drops their outcomes.
+        while self.pending.get() > 0 {
+            let _ = self.wait(None);
+        }
+        unsafe { sys::SDL_DestroyAsyncIOQueue(self.raw()) };
+    }
+}
+
+/// A file opened for asynchronous reads and writes, whose results go to an [`AsyncIOQueue`].
+///
+/// Dropping an `AsyncIO` starts closing the file; the close finishes after every task already
+/// started on it and is reported as an [`AsyncIOTaskType::Close`] outcome with a tag of `0`.
+pub struct AsyncIO<'q> {
+    queue: &'q AsyncIOQueue,
+    ptr: NonNull<sys::SDL_AsyncIO>,
+}
+
+impl<'q> AsyncIO<'q> {
+    /// Opens a file, whose tasks report to `queue`.
+    ///
+    /// `mode` is one of `"r"`, `"w"`, `"r+"` or `"w+"`. Opening the file is not asynchronous.
+    pub fn from_file(queue: &'q AsyncIOQueue, file: &str, mode: &str) -> Result<Self, Error> {
+        let file = CString::new(file)?;
+        let mode = CString::new(mode)?;
+        let ptr = unsafe { sys::SDL_AsyncIOFromFile(file.as_ptr(), mode.as_ptr()) };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self { queue, ptr })
+    }
+
+    /// Returns the size of the file in bytes.
+    pub fn size(&self) -> Result<u64, Error> {
+        let size = unsafe { sys::SDL_GetAsyncIOSize(self.ptr.as_ptr()) };
+        u64::try_from(size).map_err(|_| Error::new())
+    }
+
+    /// Starts reading up to `len` bytes from `offset`.
+    ///
+    /// The bytes are returned in the [`AsyncIOOutcome::buffer`] of an [`AsyncIOTaskType::Read`]
+    /// outcome.
+    pub fn read(&self, offset: u64, len: usize, tag: u64) -> Result<(), Error> {
+        let mut buffer = vec![0; len];
+        let ptr = buffer.as_mut_ptr();
+        let userdata = Box::into_raw(Box::new(PendingTask {
+            buffer,
+            tag,
+            load: false,
+        }));
+        // The vector's heap allocation doesn't move when the vector is boxed.
+        let result = unsafe {
+            sys::SDL_ReadAsyncIO(
+                self.ptr.as_ptr(),
+                ptr as *mut c_void,
+                offset,
+                len as u64,
+                self.queue.raw(),
+                userdata as *mut c_void,
+            )
+        };
+        self.queue.submitted(result, userdata)
+    }
+
+    /// Starts writing `data` at `offset`.
+    ///
+    /// `data` is handed back in the [`AsyncIOOutcome::buffer`] of an [`AsyncIOTaskType::Write`]
+    /// outcome, so it can be reused.
+    pub fn write(&self, offset: u64, mut data: Vec<u8>, tag: u64) -> Result<(), Error> {
+        let ptr = data.as_mut_ptr();
+        let len = data.len() as u64;
+        let userdata = Box::into_raw(Box::new(PendingTask {
+            buffer: data,
+            tag,
+            load: false,
+        }));
+        let result = unsafe {
+            sys::SDL_WriteAsyncIO(
+                self.ptr.as_ptr(),
+                ptr as *mut c_void,
+                offset,
+                len,
+                self.queue.raw(),
+                userdata as *mut c_void,
+            )
+        };
+        self.queue.submitted(result, userdata)
+    }
+
+    /// Starts closing the file, after every task already started on it.
+    ///
+    /// If `flush` is true, the close only completes once the data has reached physical storage,
+    /// which is slower but recommended for important files such as saves.
+    pub fn close(self, flush: bool, tag: u64) -> Result<(), Self> {
+        let this = ManuallyDrop::new(self);
+        match this.start_close(flush, tag) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(ManuallyDrop::into_inner(this)),
+        }
+    }
+
+    fn start_close(&self, flush: bool, tag: u64) -> Result<(), Error> {
+        let userdata = Box::into_raw(Box::new(PendingTask {
+            buffer: Vec::new(),
+            tag,
+            load: false,
+        }));
+        let result = unsafe {
+            sys::SDL_CloseAsyncIO(
+                self.ptr.as_ptr(),
+                flush,
+                self.queue.raw(),
+                userdata as *mut c_void,
+            )
+        };
+        self.queue.submitted(result, userdata)
+    }
+}
+
+impl Drop for AsyncIO<'_> {
+    fn drop(&mut self) {
+        // If the close can't be started, the file is leaked rather than freed under running tasks.
+        let _ = self.start_close(false, 0);
+    }
+}
+
+/// The kind of task an [`AsyncIOOutcome`] reports on.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AsyncIOTaskType {
+    Read,
+    Write,
+    Close,
+    /// A read started by [`AsyncIOQueue::load_file`].
+    Load,
+}
+
+impl AsyncIOTaskType {
+    fn from_ll(ll: sys::SDL_AsyncIOTaskType) -> Self {
+        match ll {
+            sys::SDL_AsyncIOTaskType_SDL_ASYNCIO_TASK_WRITE => Self::Write,
+            sys::SDL_AsyncIOTaskType_SDL_ASYNCIO_TASK_CLOSE => Self::Close,
+            _ => Self::Read,
+        }
+    }
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AsyncIOResult {
+    Complete = sys::SDL_AsyncIOResult_SDL_ASYNCIO_COMPLETE,
+    Failure = sys::SDL_AsyncIOResult_SDL_ASYNCIO_FAILURE,
+    Canceled = sys::SDL_AsyncIOResult_SDL_ASYNCIO_CANCELED,
+}
+
+impl AsyncIOResult {
+    /// Converts a raw `SDL_AsyncIOResult` into a `AsyncIOResult`.
+    pub fn try_from_ll(ll: sys::SDL_AsyncIOResult) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_AsyncIOResult_SDL_ASYNCIO_COMPLETE => Self::Complete,
+            sys::SDL_AsyncIOResult_SDL_ASYNCIO_FAILURE => Self::Failure,
+            sys::SDL_AsyncIOResult_SDL_ASYNCIO_CANCELED => Self::Canceled,
+            _ => return Err(Error::register(c"Unknown async IO result.")),
+        })
+    }
+
+    fn from_ll(ll: sys::SDL_AsyncIOResult) -> Self {
+        match ll {
+            sys::SDL_AsyncIOResult_SDL_ASYNCIO_COMPLETE => Self::Complete,
+            sys::SDL_AsyncIOResult_SDL_ASYNCIO_CANCELED => Self::Canceled,
+            _ => Self::Failure,
+        }
+    }
+}
+
+/// The result of a finished asynchronous I/O task.
+#[derive(Clone, Debug)]
+pub struct AsyncIOOutcome {
+    pub kind: AsyncIOTaskType,
+    pub result: AsyncIOResult,
+    /// The bytes read or loaded, the data that was written, or an empty buffer for closes.
+    pub buffer: Vec<u8>,
+    pub offset: u64,
+    pub bytes_requested: u64,
+    pub bytes_transferred: u64,
+    /// The tag the task was started with.
+    pub tag: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(AsyncIOResult: Complete, Failure, Canceled);
+    }
+}
@@ -0,0 +1,811 @@
+use crate::sys;
+use crate::AudioSubsystem;
+use crate::Error;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::c_void;
+use core::ffi::CStr;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+
+impl AudioSubsystem {
+    /// Returns the names of the audio drivers built into SDL, in the order they are normally
+    /// initialized by default.
+    pub fn audio_drivers(&self) -> Vec<String> {
+        let count = unsafe { sys::SDL_GetNumAudioDrivers() };
+        (0..count)
+            .filter_map(|index| {
+                let ptr = unsafe { sys::SDL_GetAudioDriver(index) };
+                if ptr.is_null() {
+                    return None;
+                }
+                Some(
+                    unsafe { CStr::from_ptr(ptr) }
+                        .to_string_lossy()
+                        .into_owned(),
+                )
+            })
+            .collect()
+    }
+
+    /// Get the name of the current audio driver.
+    ///
+    /// The names of drivers are all simple, low-ASCII identifiers, like "alsa", "coreaudio" or
+    /// "wasapi". These never have Unicode characters, and are not meant to be proper names.
+    pub fn current_audio_driver(&self) -> Result<String, Error> {
+        let ptr = unsafe { sys::SDL_GetCurrentAudioDriver() };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        Ok(unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned())
+    }
+
+    /// Returns a list of currently connected audio playback devices.
+    pub fn playback_devices(&self) -> Result<Vec<AudioDeviceId>, Error> {
+        unsafe {
+            let mut count = 0;
+            let ptr = sys::SDL_GetAudioPlaybackDevices(&raw mut count);
+            Self::collect_devices(ptr, count)
+        }
+    }
+
+    /// Returns a list of currently connected audio recording devices.
+    pub fn recording_devices(&self) -> Result<Vec<AudioDeviceId>, Error> {
+        unsafe {
+            let mut count = 0;
+            let ptr = sys::SDL_GetAudioRecordingDevices(&raw mut count);
+            Self::collect_devices(ptr, count)
+        }
+    }
+
+    /// Returns the human-readable name of an audio device.
+    pub fn audio_device_name(&self, id: AudioDeviceId) -> Result<String, Error> {
+        let ptr = unsafe { sys::SDL_GetAudioDeviceName(id) };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        Ok(unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned())
+    }
+
+    /// Returns the current format of an audio device and its buffer size, in sample frames.
+    ///
+    /// For an opened device, this reports the format the device is currently using. If the device
+    /// isn't yet opened, this reports the device's preferred format. [`AUDIO_DEVICE_DEFAULT_PLAYBACK`]
+    /// and [`AUDIO_DEVICE_DEFAULT_RECORDING`] are also accepted, which is useful for getting a
+    /// reasonable recommendation before opening the system-recommended default device.
+    pub fn audio_device_format(&self, id: AudioDeviceId) -> Result<(AudioSpec, u32), Error> {
+        let mut spec: MaybeUninit<sys::SDL_AudioSpec> = MaybeUninit::uninit();
+        let mut sample_frames = 0;
+        let result =
+            unsafe { sys::SDL_GetAudioDeviceFormat(id, spec.as_mut_ptr(), &raw mut sample_frames) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok((
+            AudioSpec(unsafe { spec.assume_init() }),
+            sample_frames.max(0) as u32,
+        ))
+    }
+
+    /// Equivalent to [`AudioDevice::open`].
+    pub fn open_audio_device(
+        &self,
+        id: AudioDeviceId,
+        spec: Option<&AudioSpec>,
+    ) -> Result<AudioDevice, Error> {
+        AudioDevice::open(self, id, spec)
+    }
+
+    /// Equivalent to [`AudioStream::new`].
+    pub fn create_audio_stream(
+        &self,
+        src_spec: &AudioSpec,
+        dst_spec: &AudioSpec,
+    ) -> Result<AudioStream, Error> {
+        AudioStream::new(self, src_spec, dst_spec)
+    }
+
+    /// Equivalent to [`AudioStream::open_device`].
+    pub fn open_audio_device_stream(
+        &self,
+        id: AudioDeviceId,
+        spec: Option<&AudioSpec>,
+    ) -> Result<AudioStream, Error> {
+        AudioStream::open_device(self, id, spec)
+    }
+
+    /// Equivalent to [`AudioStream::open_device_with_callback`].
+    pub fn open_audio_device_stream_with_callback<C: AudioStreamCallback + 'static>(
+        &self,
+        id: AudioDeviceId,
+        spec: Option<&AudioSpec>,
+        callback: C,
+    ) -> Result<AudioStream, Error> {
+        AudioStream::open_device_with_callback(self, id, spec, callback)
+    }
+
+    unsafe fn collect_devices(
+        ptr: *mut sys::SDL_AudioDeviceID,
+        count: i32,
+    ) -> Result<Vec<AudioDeviceId>, Error> {
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        let vec = match usize::try_from(count) {
+            Ok(len) => unsafe { core::slice::from_raw_parts(ptr, len) }.to_vec(),
+            Err(err) => {
+                unsafe { sys::SDL_free(ptr as *mut c_void) };
+                return Err(err.into());
+            }
+        };
+        unsafe { sys::SDL_free(ptr as *mut c_void) };
+        Ok(vec)
+    }
+}
+
+pub type AudioDeviceId = sys::SDL_AudioDeviceID;
+
+/// Requests the most reasonable default playback device when opening a device or a stream.
+pub const AUDIO_DEVICE_DEFAULT_PLAYBACK: AudioDeviceId = sys::SDL_AUDIO_DEVICE_DEFAULT_PLAYBACK;
+
+/// Requests the most reasonable default recording device when opening a device or a stream.
+pub const AUDIO_DEVICE_DEFAULT_RECORDING: AudioDeviceId = sys::SDL_AUDIO_DEVICE_DEFAULT_RECORDING;
+
+/// A logical audio device opened by the application.
+///
+/// Playback devices take data from bound [`AudioStream`]s, mix it, and send it to the hardware.
+/// Recording devices feed any bound streams with a copy of any incoming data.
+///
+/// The device is closed when this value is dropped; any streams still bound to it are unbound.
+pub struct AudioDevice {
+    subsystem: AudioSubsystem,
+    id: AudioDeviceId,
+}
+
+impl AudioDevice {
+    /// Opens a specific audio device.
+    ///
+    /// Pass [`AUDIO_DEVICE_DEFAULT_PLAYBACK`] or [`AUDIO_DEVICE_DEFAULT_RECORDING`] to let SDL
+    /// pick the most reasonable default, and follow it if the system default changes later.
+    ///
+    /// The requested `spec` is only a hint; bound audio streams will convert from whatever format
+    /// they are given. An opened device starts out with no streams bound.
+    pub fn open(
+        subsystem: &AudioSubsystem,
+        id: AudioDeviceId,
+        spec: Option<&AudioSpec>,
+    ) -> Result<Self, Error> {
+        let spec = spec.map(AudioSpec::raw).unwrap_or(core::ptr::null());
+        let id = unsafe { sys::SDL_OpenAudioDevice(id, spec) };
+        if id == 0 {
+            return Err(Error::new());
+        }
+        Ok(Self {
+            subsystem: subsystem.clone(),
+            id,
+        })
+    }
+
+    /// Returns the instance ID of this logical device.
+    #[inline]
+    pub fn id(&self) -> AudioDeviceId {
+        self.id
+    }
+
+    /// Returns true if this is a playback device, false if it is a recording device.
+    pub fn is_playback(&self) -> bool {
+        unsafe { sys::SDL_IsAudioDevicePlayback(self.id) }
+    }
+
+    /// Pauses audio playback on the device.
+    ///
+    /// Streams bound to a paused device do not progress: no data is consumed from playback
+    /// streams and no data is fed to recording streams.
+    pub fn pause(&self) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_PauseAudioDevice(self.id) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Resumes audio playback on the device.
+    pub fn resume(&self) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_ResumeAudioDevice(self.id) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns true if the device is paused.
+    pub fn is_paused(&self) -> bool {
+        unsafe { sys::SDL_AudioDevicePaused(self.id) }
+    }
+
+    /// Returns the gain of the device, where 1.0 means no change.
+    pub fn gain(&self) -> Result<f32, Error> {
+        let gain = unsafe { sys::SDL_GetAudioDeviceGain(self.id) };
+        if gain < 0.0 {
+            return Err(Error::new());
+        }
+        Ok(gain)
+    }
+
+    /// Changes the gain of the device, where 1.0 means no change and 0.0 is silence.
+    pub fn set_gain(&self, gain: f32) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_SetAudioDeviceGain(self.id, gain) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns the current format of the device and its buffer size, in sample frames.
+    pub fn format(&self) -> Result<(AudioSpec, u32), Error> {
+        self.subsystem.audio_device_format(self.id)
+    }
+
+    /// Binds an audio stream to this device.
+    ///
+    /// Audio data flows as soon as the stream is bound and the device is not paused. A stream can
+    /// only be bound to a single device at a time.
+    pub fn bind_stream(&self, stream: &AudioStreamRef) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_BindAudioStream(self.id, stream.raw()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+}
+
+impl Drop for AudioDevice {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_CloseAudioDevice(self.id) };
+    }
+}
+
+/// Defines a callback fired when data passes through an [`AudioStream`].
+///
+/// The callback runs on SDL's audio thread, with the stream's lock held.
+pub trait AudioStreamCallback: Send {
+    /// `additional_amount` is the number of bytes needed to satisfy the immediate request, and
+    /// `total_amount` the total number of bytes being requested.
+    fn callback(
+        &mut self,
+        stream: &mut AudioStreamRef,
+        additional_amount: usize,
+        total_amount: usize,
+    );
+}
+
+unsafe extern "C" fn audio_stream_callback_marshall<C: AudioStreamCallback>(
+    user_data: *mut c_void,
+    stream: *mut sys::SDL_AudioStream,
+    additional_amount: i32,
+    total_amount: i32,
+) {
+    let callback: &mut C = unsafe { &mut *(user_data as *mut C) };
+    let stream = unsafe { AudioStreamRef::from_mut_ptr(stream) };
+    callback.callback(
+        stream,
+        additional_amount.max(0) as usize,
+        total_amount.max(0) as usize,
+    );
+}
+
+/// An audio stream converts audio data between formats and queues it for playback or recording.
+///
+/// Most of the functionality lives in [`AudioStreamRef`], which this type dereferences to.
+pub struct AudioStream {
+    _subsystem: AudioSubsystem,
+    ptr: NonNull<sys::SDL_AudioStream>,
+    // Must be dropped after the stream is destroyed.
+    callback: Option<Box<dyn AudioStreamCallback>>,
+}
+
+impl AudioStream {
+    /// Creates a new audio stream converting from `src_spec` to `dst_spec`.
+    ///
+    /// The stream is not bound to any device; use [`AudioDevice::bind_stream`] to play it back or
+    /// read from it directly with [`AudioStreamRef::get_data`].
+    pub fn new(
+        subsystem: &AudioSubsystem,
+        src_spec: &AudioSpec,
+        dst_spec: &AudioSpec,
+    ) -> Result<Self, Error> {
+        let ptr = unsafe { sys::SDL_CreateAudioStream(src_spec.raw(), dst_spec.raw()) };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            _subsystem: subsystem.clone(),
+            ptr,
+            callback: None,
+        })
+    }
+
+    /// Opens an audio device and creates a stream bound to it in a single call.
+    ///
+    /// `spec` is the application's side of the stream: the input format for playback and the
+    /// output format for recording. If `None`, the system chooses the format, which can be read
+    /// back with [`AudioStreamRef::format`].
+    ///
+    /// The device starts out paused; call [`AudioStreamRef::resume_device`] to start audio
+    /// flowing. The device is closed when the stream is dropped.
+    pub fn open_device(
+        subsystem: &AudioSubsystem,
+        id: AudioDeviceId,
+        spec: Option<&AudioSpec>,
+    ) -> Result<Self, Error> {
+        let spec = spec.map(AudioSpec::raw).unwrap_or(core::ptr::null());
+        let ptr = unsafe { sys::SDL_OpenAudioDeviceStream(id, spec, None, core::ptr::null_mut()) };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            _subsystem: subsystem.clone(),
+            ptr,
+            callback: None,
+        })
+    }
+
+    /// Same as [`AudioStream::open_device`], but `callback` is fired whenever the device needs
+    /// more data (for playback) or has new data available (for recording).
+    ///
+    /// The callback starts firing once the device is resumed.
+    pub fn open_device_with_callback<C: AudioStreamCallback + 'static>(
+        subsystem: &AudioSubsystem,
+        id: AudioDeviceId,
+        spec: Option<&AudioSpec>,
+        callback: C,
+    ) -> Result<Self, Error> {
+        let spec = spec.map(AudioSpec::raw).unwrap_or(core::ptr::null());
+        let mut callback = Box::new(callback);
+        let user_data = &mut *callback as *mut C as *mut c_void;
+        let ptr = unsafe {
+            sys::SDL_OpenAudioDeviceStream(
+                id,
+                spec,
+                Some(audio_stream_callback_marshall::<C>),
+                user_data,
+            )
+        };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            _subsystem: subsystem.clone(),
+            ptr,
+            callback: Some(callback),
+        })
+    }
+
+    /// Sets a callback that runs when data is requested from the stream, giving it the chance to
+    /// add more data on demand. Passing `None` removes any previously set callback.
+    pub fn set_get_callback<C: AudioStreamCallback + 'static>(
+        &mut self,
+        callback: Option<C>,
+    ) -> Result<(), Error> {
+        self.set_callback(callback, sys::SDL_SetAudioStreamGetCallback)
+    }
+
+    /// Sets a callback that runs when data is added to the stream, giving it the chance to read
+    /// the data as it arrives. Passing `None` removes any previously set callback.
+    pub fn set_put_callback<C: AudioStreamCallback + 'static>(
+        &mut self,
+        callback: Option<C>,
+    ) -> Result<(), Error> {
+        self.set_callback(callback, sys::SDL_SetAudioStreamPutCallback)
+    }
+
+    fn set_callback<C: AudioStreamCallback + 'static>(
+        &mut self,
+        callback: Option<C>,
+        set: unsafe extern "C" fn(
+            *mut sys::SDL_AudioStream,
+            sys::SDL_AudioStreamCallback,
+            *mut c_void,
+        ) -> bool,
+    ) -> Result<(), Error> {
+        let mut callback = callback.map(Box::new);
+        let (marshall, user_data): (sys::SDL_AudioStreamCallback, *mut c_void) = match &mut callback
+        {
+            Some(callback) => (
+                Some(audio_stream_callback_marshall::<C>),
+                &mut **callback as *mut C as *mut c_void,
+            ),
+            None => (None, core::ptr::null_mut()),
+        };
+        let result = unsafe { set(self.ptr.as_ptr(), marshall, user_data) };
+        if !result {
+            return Err(Error::new());
+        }
+        // SDL no longer references the previous callback, so it is safe to drop it here.
+        self.callback = callback.map(|c| c as Box<dyn AudioStreamCallback>);
+        Ok(())
+    }
+}
+
+impl Drop for AudioStream {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_DestroyAudioStream(self.ptr.as_ptr()) };
+    }
+}
+
+impl Deref for AudioStream {
+    type Target = AudioStreamRef;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { AudioStreamRef::from_ptr(self.ptr.as_ptr()) }
+    }
+}
+
+impl DerefMut for AudioStream {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { AudioStreamRef::from_mut_ptr(self.ptr.as_ptr()) }
+    }
+}
+
+/// A zero-sized type that functions as a reference to an SDL audio stream.
+///
+/// This type is only exposed as a reference such that its' lifetime is bound to an owner.
+///
+/// Check out [`AudioStream`] for the owned version of this struct.
+pub struct AudioStreamRef {
+    _inner: PhantomData<*const ()>, // !Send + !Sync
+}
+
+impl AudioStreamRef {
+    pub(crate) unsafe fn from_ptr<'a>(ptr: *const sys::SDL_AudioStream) -> &'a Self {
+        &*(ptr as *const Self)
+    }
+
+    pub(crate) unsafe fn from_mut_ptr<'a>(ptr: *mut sys::SDL_AudioStream) -> &'a mut Self {
+        &mut *(ptr as *mut Self)
+    }
+
+    /// Returns the input and output formats of the stream.
+    pub fn format(&self) -> Result<(AudioSpec, AudioSpec), Error> {
+        let mut src: MaybeUninit<sys::SDL_AudioSpec> = MaybeUninit::uninit();
+        let mut dst: MaybeUninit<sys::SDL_AudioSpec> = MaybeUninit::uninit();
+        let result = unsafe {
+            sys::SDL_GetAudioStreamFormat(self.raw(), src.as_mut_ptr(), dst.as_mut_ptr())
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(unsafe { (AudioSpec(src.assume_init()), AudioSpec(dst.assume_init())) })
+    }
+
+    /// Changes the input and/or output format of the stream. `None` leaves that side unchanged.
+    ///
+    /// Future calls to [`AudioStreamRef::available`] and [`AudioStreamRef::get_data`] reflect the
+    /// new format, and future calls to [`AudioStreamRef::put_data`] must provide data in the new
+    /// input format. Data that was already queued is not affected.
+    pub fn set_format(
+        &mut self,
+        src_spec: Option<&AudioSpec>,
+        dst_spec: Option<&AudioSpec>,
+    ) -> Result<(), Error> {
+        let src_spec = src_spec.map(AudioSpec::raw).unwrap_or(core::ptr::null());
+        let dst_spec = dst_spec.map(AudioSpec::raw).unwrap_or(core::ptr::null());
+        let result = unsafe { sys::SDL_SetAudioStreamFormat(self.raw(), src_spec, dst_spec) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Adds data to the stream, in the stream's input format.
+    pub fn put_data(&mut self, data: &[u8]) -> Result<(), Error> {
+        let len = i32::try_from(data.len())?;
+        let result =
+            unsafe { sys::SDL_PutAudioStreamData(self.raw(), data.as_ptr() as *const _, len) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Adds 32-bit float samples to the stream. The stream's input format must be
+    /// [`AudioFormat::F32`].
+    pub fn put_samples_f32(&mut self, samples: &[f32]) -> Result<(), Error> {
+        // SAFETY: f32 has no invalid bit patterns and a stricter alignment than u8.
+        let bytes = unsafe {
+            core::slice::from_raw_parts(samples.as_ptr() as *const u8, size_of_val(samples))
+        };
+        self.put_data(bytes)
+    }
+
+    /// Reads converted data from the stream, in the stream's output format.
+    ///
+    /// Returns the number of bytes written to `buf`, which may be less than its length if not
+    /// enough data is available.
+    pub fn get_data(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        let len = i32::try_from(buf.len())?;
+        let result =
+            unsafe { sys::SDL_GetAudioStreamData(self.raw(), buf.as_mut_ptr() as *mut _, len) };
+        if result < 0 {
+            return Err(Error::new());
+        }
+        Ok(result as usize)
+    }
+
+    /// Returns the number of converted bytes available to read with [`AudioStreamRef::get_data`].
+    pub fn available(&self) -> Result<usize, Error> {
+        let result = unsafe { sys::SDL_GetAudioStreamAvailable(self.raw()) };
+        if result < 0 {
+            return Err(Error::new());
+        }
+        Ok(result as usize)
+    }
+
+    /// Returns the number of bytes currently queued, in the stream's input format.
+    ///
+    /// This is useful to keep a playback stream from running dry without queueing too far ahead.
+    pub fn queued(&self) -> Result<usize, Error> {
+        let result = unsafe { sys::SDL_GetAudioStreamQueued(self.raw()) };
+        if result < 0 {
+            return Err(Error::new());
+        }
+        Ok(result as usize)
+    }
+
+    /// Tells the stream that no more data is coming for now, so any buffered data is converted
+    /// and made available.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_FlushAudioStream(self.raw()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Drops any data currently queued in the stream without converting it.
+    pub fn clear(&mut self) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_ClearAudioStream(self.raw()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns the gain of the stream, where 1.0 means no change.
+    pub fn gain(&self) -> Result<f32, Error> {
+        let gain = unsafe { sys::SDL_GetAudioStreamGain(self.raw()) };
+        if gain < 0.0 {
+            return Err(Error::new());
+        }
+        Ok(gain)
+    }
+
+    /// Changes the gain of the stream, where 1.0 means no change and 0.0 is silence.
+    pub fn set_gain(&mut self, gain: f32) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_SetAudioStreamGain(self.raw(), gain) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns the frequency ratio of the stream, where 1.0 means normal speed.
+    pub fn frequency_ratio(&self) -> Result<f32, Error> {
+        let ratio = unsafe { sys::SDL_GetAudioStreamFrequencyRatio(self.raw()) };
+        if ratio == 0.0 {
+            return Err(Error::new());
+        }
+        Ok(ratio)
+    }
+
+    /// Changes the frequency ratio of the stream, speeding up or slowing down playback.
+    ///
+    /// The ratio must be between 0.01 and 100.
+    pub fn set_frequency_ratio(&mut self, ratio: f32) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_SetAudioStreamFrequencyRatio(self.raw(), ratio) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns the device the stream is bound to, or `None` if it is unbound.
+    pub fn device_id(&self) -> Option<AudioDeviceId> {
+        let id = unsafe { sys::SDL_GetAudioStreamDevice(self.raw()) };
+        (id != 0).then_some(id)
+    }
+
+    /// Unbinds the stream from its device, if any.
+    pub fn unbind(&mut self) {
+        unsafe { sys::SDL_UnbindAudioStream(self.raw()) }
+    }
+
+    /// Pauses the device the stream is bound to.
+    pub fn pause_device(&mut self) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_PauseAudioStreamDevice(self.raw()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Resumes the device the stream is bound to.
+    ///
+    /// Streams opened with [`AudioStream::open_device`] start out paused and need this call to
+    /// start playing.
+    pub fn resume_device(&mut self) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_ResumeAudioStreamDevice(self.raw()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns true if the device the stream is bound to is paused.
+    pub fn is_device_paused(&self) -> bool {
+        unsafe { sys::SDL_AudioStreamDevicePaused(self.raw()) }
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_AudioStream {
+        self as *const Self as *mut Self as *mut sys::SDL_AudioStream
+    }
+}
+
+/// Format specifier for audio data.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug)]
+pub struct AudioSpec(sys::SDL_AudioSpec);
+
+impl AudioSpec {
+    /// Creates an `AudioSpec`.
+    ///
+    /// * `channels` - number of channels: 1 for mono, 2 for stereo, etc.
+    /// * `freq` - sample rate, in sample frames per second.
+    pub fn new(format: AudioFormat, channels: u32, freq: u32) -> Result<Self, Error> {
+        let channels = i32::try_from(channels)?;
+        let freq = i32::try_from(freq)?;
+        Ok(Self(sys::SDL_AudioSpec {
+            format: format.to_ll(),
+            channels,
+            freq,
+        }))
+    }
+
+    #[inline]
+    pub fn format(&self) -> AudioFormat {
+        AudioFormat::from_ll(self.0.format)
+    }
+
+    #[inline]
+    pub fn channels(&self) -> u32 {
+        self.0.channels.max(0) as u32
+    }
+
+    #[inline]
+    pub fn freq(&self) -> u32 {
+        self.0.freq.max(0) as u32
+    }
+
+    /// Returns the size of a single sample frame, in bytes.
+    #[inline]
+    pub fn frame_size(&self) -> usize {
+        self.format().byte_size() * self.channels() as usize
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_AudioSpec {
+        self.0
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *const sys::SDL_AudioSpec {
+        self as *const Self as *const sys::SDL_AudioSpec
+    }
+}
+
+/// Audio sample format.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AudioFormat {
+    Unknown = sys::SDL_AudioFormat_SDL_AUDIO_UNKNOWN,
+    /// Unsigned 8-bit samples.
+    U8 = sys::SDL_AudioFormat_SDL_AUDIO_U8,
+    /// Signed 8-bit samples.
+    S8 = sys::SDL_AudioFormat_SDL_AUDIO_S8,
+    /// Signed 16-bit samples, little-endian.
+    S16Le = sys::SDL_AudioFormat_SDL_AUDIO_S16LE,
+    /// Signed 16-bit samples, big-endian.
+    S16Be = sys::SDL_AudioFormat_SDL_AUDIO_S16BE,
+    /// Signed 32-bit samples, little-endian.
+    S32Le = sys::SDL_AudioFormat_SDL_AUDIO_S32LE,
+    /// Signed 32-bit samples, big-endian.
+    S32Be = sys::SDL_AudioFormat_SDL_AUDIO_S32BE,
+    /// 32-bit floating point samples, little-endian.
+    F32Le = sys::SDL_AudioFormat_SDL_AUDIO_F32LE,
+    /// 32-bit floating point samples, big-endian.
+    F32Be = sys::SDL_AudioFormat_SDL_AUDIO_F32BE,
+}
+
+impl AudioFormat {
+    /// Converts a raw `SDL_AudioFormat` into a `AudioFormat`.
+    pub fn try_from_ll(ll: sys::SDL_AudioFormat) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_AudioFormat_SDL_AUDIO_UNKNOWN => Self::Unknown,
+            sys::SDL_AudioFormat_SDL_AUDIO_U8 => Self::U8,
+            sys::SDL_AudioFormat_SDL_AUDIO_S8 => Self::S8,
+            sys::SDL_AudioFormat_SDL_AUDIO_S16LE => Self::S16Le,
+            sys::SDL_AudioFormat_SDL_AUDIO_S16BE => Self::S16Be,
+            sys::SDL_AudioFormat_SDL_AUDIO_S32LE => Self::S32Le,
+            sys::SDL_AudioFormat_SDL_AUDIO_S32BE => Self::S32Be,
+            sys::SDL_AudioFormat_SDL_AUDIO_F32LE => Self::F32Le,
+            sys::SDL_AudioFormat_SDL_AUDIO_F32BE => Self::F32Be,
+            _ => return Err(Error::register(c"Unknown audio format.")),
+        })
+    }
+
+    /// Signed 16-bit samples, in native byte order.
+    #[cfg(target_endian = "little")]
+    pub const S16: Self = Self::S16Le;
+    /// Signed 16-bit samples, in native byte order.
+    #[cfg(target_endian = "big")]
+    pub const S16: Self = Self::S16Be;
+    /// Signed 32-bit samples, in native byte order.
+    #[cfg(target_endian = "little")]
+    pub const S32: Self = Self::S32Le;
+    /// Signed 32-bit samples, in native byte order.
+    #[cfg(target_endian = "big")]
+    pub const S32: Self = Self::S32Be;
+    /// 32-bit floating point samples, in native byte order.
+    #[cfg(target_endian = "little")]
+    pub const F32: Self = Self::F32Le;
+    /// 32-bit floating point samples, in native byte order.
+    #[cfg(target_endian = "big")]
+    pub const F32: Self = Self::F32Be;
+
+    /// Returns the size of a single sample, in bytes.
+    #[inline]
+    pub fn byte_size(&self) -> usize {
+        // The low byte of the format holds the bit size of a sample.
+        ((*self as u32 & 0xFF) / 8) as usize
+    }
+
+    /// Returns the human readable name of the format, such as "SDL_AUDIO_S16LE".
+    pub fn name(&self) -> String {
+        let ptr = unsafe { sys::SDL_GetAudioFormatName(self.to_ll()) };
+        unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_AudioFormat {
+        *self as u32
+    }
+
+    fn from_ll(ll: sys::SDL_AudioFormat) -> Self {
+        match ll {
+            sys::SDL_AudioFormat_SDL_AUDIO_U8 => Self::U8,
+            sys::SDL_AudioFormat_SDL_AUDIO_S8 => Self::S8,
+            sys::SDL_AudioFormat_SDL_AUDIO_S16LE => Self::S16Le,
+            sys::SDL_AudioFormat_SDL_AUDIO_S16BE => Self::S16Be,
+            sys::SDL_AudioFormat_SDL_AUDIO_S32LE => Self::S32Le,
+            sys::SDL_AudioFormat_SDL_AUDIO_S32BE => Self::S32Be,
+            sys::SDL_AudioFormat_SDL_AUDIO_F32LE => Self::F32Le,
+            sys::SDL_AudioFormat_SDL_AUDIO_F32BE => Self::F32Be,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(AudioFormat: Unknown, U8, S8, S16Le, S16Be, S32Le, S32Be, F32Le, F32Be);
+    }
+}
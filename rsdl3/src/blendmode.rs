@@ -1,21 +1,23 @@
 use crate::{sys, Error};
 
 /// A set of blend modes used in drawing operations.
-#[repr(u32)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum BlendMode {
-    Blend = sys::SDL_BLENDMODE_BLEND,
-    BlendPremultiplied = sys::SDL_BLENDMODE_BLEND_PREMULTIPLIED,
-    Add = sys::SDL_BLENDMODE_ADD,
-    AddPremultipled = sys::SDL_BLENDMODE_ADD_PREMULTIPLIED,
-    Mod = sys::SDL_BLENDMODE_MOD,
-    Mul = sys::SDL_BLENDMODE_MUL,
-    Invalid = sys::SDL_BLENDMODE_INVALID,
+    Blend,
+    BlendPremultiplied,
+    Add,
+    AddPremultipled,
+    Mod,
+    Mul,
+    Invalid,
+    /// A blend mode created with [`BlendMode::compose`].
+    Custom(sys::SDL_BlendMode),
 }
 
 impl BlendMode {
     /// Converts a [`sys::SDL_BlendMode`] into a [`BlendMode`].
-    /// Returns an [`Error`] if `value` is invalid.
+    /// Values that aren't one of the predefined blend modes are returned as
+    /// [`BlendMode::Custom`].
     pub fn try_from_ll(value: sys::SDL_BlendMode) -> Result<Option<Self>, Error> {
         match value {
             sys::SDL_BLENDMODE_BLEND => Ok(Some(Self::Blend)),
@@ -26,15 +28,30 @@ impl BlendMode {
             sys::SDL_BLENDMODE_MUL => Ok(Some(Self::Mul)),
             sys::SDL_BLENDMODE_INVALID => Ok(Some(Self::Invalid)),
             sys::SDL_BLENDMODE_NONE => Ok(None),
-            _ => Err(Error::register(c"Unknown blend mode")),
+            value => Ok(Some(Self::Custom(value))),
         }
     }
 
     /// Compose a custom blend mode for renderers.
     ///
-    /// The functions [`crate::render::Renderer::draw_blend_mode`] and [`crate::render::Texture::set_blend_mode`]
+    /// The functions [`crate::render::Renderer::set_draw_blend_mode`] and [`crate::render::Texture::set_blend_mode`]
     /// accept the `BlendMode` returned by this function if the renderer supports it.
     ///
+    /// For example, premultiplied alpha blending that also preserves the destination alpha:
+    ///
+    /// ```no_run
+    /// use rsdl3::blendmode::{BlendFactor, BlendMode, BlendOperation};
+    ///
+    /// let mode = BlendMode::compose(
+    ///     BlendFactor::One,
+    ///     BlendFactor::OneMinusSrcAlpha,
+    ///     BlendOperation::Add,
+    ///     BlendFactor::Zero,
+    ///     BlendFactor::One,
+    ///     BlendOperation::Add,
+    /// );
+    /// ```
+    ///
     /// A blend mode controls how the pixels from a drawing operation (source) get combined with the pixels from
     /// the render target (destination). First, the components of the source and destination pixels get multiplied
     /// with their blend factors. Then, the blend operation takes the two products and calculates the result that
@@ -62,7 +79,7 @@ impl BlendMode {
     /// are not used in the alpha calculation.
     ///
     /// Support for these blend modes varies for each renderer. To check if a specific `BlendMode` is supported, create
-    /// a renderer and pass it to either [`crate::render::Renderer::draw_blend_mode`] or
+    /// a renderer and pass it to either [`crate::render::Renderer::set_draw_blend_mode`] or
     /// [`crate::render::Texture::set_blend_mode`]. They will return an error if the blend mode is not supported.
     ///
     /// This list describes the support of custom blend modes for each renderer. All renderers support the four blend
@@ -80,15 +97,15 @@ impl BlendMode {
     ///
     /// Some renderers do not provide an alpha component for the default render target. The [`BlendFactor::DstAlpha`] and
     /// [`BlendFactor::OneMinusDstAlpha`] factors do not have an effect in this case.
-    pub fn compose_custom(
+    pub fn compose(
         src_color_factor: BlendFactor,
         dst_color_factor: BlendFactor,
         color_operation: BlendOperation,
         src_alpha_factor: BlendFactor,
         dst_alpha_factor: BlendFactor,
         alpha_operation: BlendOperation,
-    ) -> Result<Option<Self>, Error> {
-        Self::try_from_ll(unsafe {
+    ) -> Self {
+        Self::Custom(unsafe {
             sys::SDL_ComposeCustomBlendMode(
                 src_color_factor.to_ll(),
                 dst_color_factor.to_ll(),
@@ -103,7 +120,16 @@ impl BlendMode {
     /// Converts a [`BlendMode`] into [`sys::SDL_BlendMode`].
     #[inline]
     pub fn to_ll(&self) -> sys::SDL_BlendMode {
-        *self as u32
+        match self {
+            Self::Blend => sys::SDL_BLENDMODE_BLEND,
+            Self::BlendPremultiplied => sys::SDL_BLENDMODE_BLEND_PREMULTIPLIED,
+            Self::Add => sys::SDL_BLENDMODE_ADD,
+            Self::AddPremultipled => sys::SDL_BLENDMODE_ADD_PREMULTIPLIED,
+            Self::Mod => sys::SDL_BLENDMODE_MOD,
+            Self::Mul => sys::SDL_BLENDMODE_MUL,
+            Self::Invalid => sys::SDL_BLENDMODE_INVALID,
+            Self::Custom(value) => *value,
+        }
     }
 
     pub fn option_to_ll(mode: Option<BlendMode>) -> sys::SDL_BlendMode {
@@ -114,8 +140,9 @@ impl BlendMode {
     }
 }
 
+/// A factor the source or destination components are multiplied with. See [`BlendMode::compose`].
 #[repr(u32)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum BlendFactor {
     /// 0, 0, 0, 0
     Zero = sys::SDL_BlendFactor_SDL_BLENDFACTOR_ZERO,
@@ -140,15 +167,33 @@ pub enum BlendFactor {
 }
 
 impl BlendFactor {
-    /// Converts a [`BlendOperation`] into an integer.
+    /// Converts a raw `SDL_BlendFactor` into a `BlendFactor`.
+    pub fn try_from_ll(ll: sys::SDL_BlendFactor) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_BlendFactor_SDL_BLENDFACTOR_ZERO => Self::Zero,
+            sys::SDL_BlendFactor_SDL_BLENDFACTOR_ONE => Self::One,
+            sys::SDL_BlendFactor_SDL_BLENDFACTOR_SRC_COLOR => Self::SrcColor,
+            sys::SDL_BlendFactor_SDL_BLENDFACTOR_ONE_MINUS_SRC_COLOR => Self::OneMinusSrcColor,
+            sys::SDL_BlendFactor_SDL_BLENDFACTOR_SRC_ALPHA => Self::SrcAlpha,
+            sys::SDL_BlendFactor_SDL_BLENDFACTOR_ONE_MINUS_SRC_ALPHA => Self::OneMinusSrcAlpha,
+            sys::SDL_BlendFactor_SDL_BLENDFACTOR_DST_COLOR => Self::DstColor,
+            sys::SDL_BlendFactor_SDL_BLENDFACTOR_ONE_MINUS_DST_COLOR => Self::OneMinusDstColor,
+            sys::SDL_BlendFactor_SDL_BLENDFACTOR_DST_ALPHA => Self::DstAlpha,
+            sys::SDL_BlendFactor_SDL_BLENDFACTOR_ONE_MINUS_DST_ALPHA => Self::OneMinusDstAlpha,
+            _ => return Err(Error::register(c"Unknown blend factor.")),
+        })
+    }
+
+    /// Converts a [`BlendFactor`] into an integer.
     #[inline]
     pub fn to_ll(&self) -> u32 {
         *self as u32
     }
 }
 
+/// How the source and destination products are combined. See [`BlendMode::compose`].
 #[repr(u32)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum BlendOperation {
     /// dst + src: supported by all renderers
     Add = sys::SDL_BlendOperation_SDL_BLENDOPERATION_ADD,
@@ -163,9 +208,33 @@ pub enum BlendOperation {
 }
 
 impl BlendOperation {
+    /// Converts a raw `SDL_BlendOperation` into a `BlendOperation`.
+    pub fn try_from_ll(ll: sys::SDL_BlendOperation) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_BlendOperation_SDL_BLENDOPERATION_ADD => Self::Add,
+            sys::SDL_BlendOperation_SDL_BLENDOPERATION_SUBTRACT => Self::Subtract,
+            sys::SDL_BlendOperation_SDL_BLENDOPERATION_REV_SUBTRACT => Self::RevSubtract,
+            sys::SDL_BlendOperation_SDL_BLENDOPERATION_MINIMUM => Self::Minimum,
+            sys::SDL_BlendOperation_SDL_BLENDOPERATION_MAXIMUM => Self::Maximum,
+            _ => return Err(Error::register(c"Unknown blend operation.")),
+        })
+    }
+
     /// Converts a [`BlendOperation`] into an integer.
     #[inline]
     pub fn to_ll(&self) -> u32 {
         *self as u32
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(BlendFactor: Zero, One, SrcColor, OneMinusSrcColor, SrcAlpha, OneMinusSrcAlpha, DstColor, OneMinusDstColor, DstAlpha, OneMinusDstAlpha);
+        assert_round_trips!(BlendOperation: Add, Subtract, RevSubtract, Minimum, Maximum);
+    }
+}
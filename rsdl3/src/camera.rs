@@ -1,5 +1,6 @@
 use crate::pixels::Colorspace;
 use crate::pixels::PixelFormat;
+use crate::properties::PropertiesRef;
 use crate::surface::SurfaceRef;
 use crate::sys;
 use crate::CameraSubsystem;
@@ -9,9 +10,29 @@ use alloc::vec::Vec;
 use core::ffi::c_void;
 use core::ffi::CStr;
 use core::mem::MaybeUninit;
+use core::ops::Deref;
 use core::ptr::NonNull;
 
 impl CameraSubsystem {
+    /// Returns the names of the camera drivers built into SDL, in the order they are normally
+    /// initialized by default.
+    pub fn camera_drivers(&self) -> Vec<String> {
+        let count = unsafe { sys::SDL_GetNumCameraDrivers() };
+        (0..count)
+            .filter_map(|index| {
+                let ptr = unsafe { sys::SDL_GetCameraDriver(index) };
+                if ptr.is_null() {
+                    return None;
+                }
+                Some(
+                    unsafe { CStr::from_ptr(ptr) }
+                        .to_string_lossy()
+                        .into_owned(),
+                )
+            })
+            .collect()
+    }
+
     /// Returns a list of currently connected camera devices.
     pub fn cameras(&self) -> Result<Vec<CameraId>, Error> {
         unsafe {
@@ -112,6 +133,9 @@ pub type CameraId = sys::SDL_CameraID;
 pub struct Camera {
     subsystem: CameraSubsystem,
     ptr: NonNull<sys::SDL_Camera>,
+    // Remembered at open time so the camera can be matched again after it's unplugged.
+    device_name: String,
+    spec: Option<CameraSpec>,
 }
 
 impl Camera {
@@ -136,32 +160,82 @@ impl Camera {
     /// Note that the camera is not usable until the user approves its use! On some platforms, the operating
     /// system will prompt the user to permit access to the camera, and they can choose Yes or No at that
     /// point. Until they do, the camera will not be usable. The app should either wait for an [`Event`]
-    /// with payload [`EventPayload::Camera(CameraEvent::DeviceApproved)`] (or
-    /// [`EventPayload::Camera(CameraEvent::DeviceDenied)`]) event, or poll [`Camera::permission_state`]
+    /// with payload [`EventPayload::Camera(CameraEventPayload::DeviceApproved)`] (or
+    /// [`EventPayload::Camera(CameraEventPayload::DeviceDenied)`]) event, or poll [`Camera::permission_state`]
     /// occasionally until it returns [`CameraPermissionState::Approved`]. On platforms that don't require
     /// explicit user approval (and perhaps in places where the user previously permitted access), the
     /// approval event might come immediately, but it might come seconds, minutes, or hours later!
     ///
     /// [`Event`]: crate::events::Event
     /// [`EventPayload`]: crate::events::EventPayload
-    /// [`CameraEvent`]: crate::events::CameraEvent
+    /// [`CameraEventPayload`]: crate::events::CameraEventPayload
     pub fn open(
         subsystem: &CameraSubsystem,
         id: CameraId,
         spec: Option<CameraSpec>,
     ) -> Result<Self, Error> {
         // SAFETY: CameraSpec has repr(transparent) and can be treated like a sys::SDL_CameraSpec.
-        let spec = spec
+        let spec_ptr = spec
             .as_ref()
             .map(CameraSpec::raw)
             .unwrap_or(core::ptr::null());
-        let ptr = NonNull::new(unsafe { sys::SDL_OpenCamera(id, spec) }).ok_or(Error::new())?;
+        let ptr =
+            NonNull::new(unsafe { sys::SDL_OpenCamera(id, spec_ptr) }).ok_or_else(Error::new)?;
         Ok(Self {
             subsystem: subsystem.clone(),
             ptr,
+            device_name: subsystem.camera_name(id).unwrap_or_default(),
+            spec,
         })
     }
 
+    /// Opens the connected camera that best matches this one, for recovering from hot-plugging.
+    ///
+    /// A camera with the same name is preferred. Otherwise, if this camera was opened with a
+    /// spec, the first camera that natively supports its format and size is chosen. The new
+    /// camera is opened with the same spec as this one.
+    ///
+    /// This camera itself is never chosen, so this is typically called after receiving a
+    /// [`CameraEventPayload::Removed`] event for it, or a [`CameraEventPayload::Added`] event for
+    /// another device. Returns `Ok(None)` if no connected camera matches.
+    ///
+    /// [`CameraEventPayload::Removed`]: crate::events::CameraEventPayload::Removed
+    /// [`CameraEventPayload::Added`]: crate::events::CameraEventPayload::Added
+    pub fn reopen_preferred(&self) -> Result<Option<Camera>, Error> {
+        let current = self.id().ok();
+        let candidates: Vec<CameraId> = self
+            .subsystem
+            .cameras()?
+            .into_iter()
+            .filter(|id| Some(*id) != current)
+            .collect();
+        let by_name = candidates.iter().copied().find(|id| {
+            !self.device_name.is_empty()
+                && self
+                    .subsystem
+                    .camera_name(*id)
+                    .is_ok_and(|name| name == self.device_name)
+        });
+        let by_spec = || {
+            let spec = self.spec.as_ref()?;
+            candidates.iter().copied().find(|id| {
+                self.subsystem
+                    .camera_supported_formats(*id)
+                    .is_ok_and(|formats| formats.iter().any(|f| f.same_mode(spec)))
+            })
+        };
+        match by_name.or_else(by_spec) {
+            Some(id) => Camera::open(&self.subsystem, id, self.spec.clone()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the properties of the camera.
+    pub fn properties(&self) -> Result<PropertiesRef<'_>, Error> {
+        let id = unsafe { sys::SDL_GetCameraProperties(self.ptr.as_ptr()) };
+        unsafe { PropertiesRef::from_ll(id) }
+    }
+
     /// Returns the instance ID of an opened camera.
     pub fn id(&self) -> Result<CameraId, Error> {
         let result = unsafe { sys::SDL_GetCameraID(self.ptr.as_ptr()) };
@@ -198,8 +272,8 @@ impl Camera {
     /// is approved for use, and `Some(CameraPermissionState::Denied)` if the user denied access.
     ///
     /// Instead of polling with this function, you can wait for an [`Event`] with payload
-    /// [`EventPayload::Camera(CameraEvent::DeviceApproved)`] (or
-    /// [`EventPayload::Camera(CameraEvent::DeviceDenied)`]) event in the standard SDL event loop, which
+    /// [`EventPayload::Camera(CameraEventPayload::DeviceApproved)`] (or
+    /// [`EventPayload::Camera(CameraEventPayload::DeviceDenied)`]) event in the standard SDL event loop, which
     /// is guaranteed to be sent once when permission to use the camera is decided.
     ///
     /// If a camera is declined, there's nothing to be done but drop the `Camera` to dispose of it.
@@ -215,13 +289,13 @@ impl Camera {
     ///
     /// If the system is waiting for the user to approve access to the camera, as some platforms require,
     /// this will return false, but this isn't necessarily a fatal error; you should either wait for an
-    /// [`Event`] with payload [`EventPayload::Camera(CameraEvent::DeviceApproved)`] (or
-    /// [`EventPayload::Camera(CameraEvent::DeviceDenied)`]) event, or poll [`Camera::permission_state`]
+    /// [`Event`] with payload [`EventPayload::Camera(CameraEventPayload::DeviceApproved)`] (or
+    /// [`EventPayload::Camera(CameraEventPayload::DeviceDenied)`]) event, or poll [`Camera::permission_state`]
     /// occasionally until it returns [`CameraPermissionState::Approved`].
     ///
     /// [`Event`]: crate::events::Event
     /// [`EventPayload`]: crate::events::EventPayload
-    /// [`CameraEvent`]: crate::events::CameraEvent
+    /// [`CameraEventPayload`]: crate::events::CameraEventPayload
     pub fn format(&self) -> Option<CameraSpec> {
         let mut spec: MaybeUninit<sys::SDL_CameraSpec> = MaybeUninit::uninit();
         let result = unsafe { sys::SDL_GetCameraFormat(self.ptr.as_ptr(), spec.as_mut_ptr()) };
@@ -265,12 +339,12 @@ impl Camera {
     ///
     /// If the system is waiting for the user to approve access to the camera, as some platforms
     /// require, this will return `Ok(None)` (no frames available); you should either wait for an
-    /// [`EventPayload::Camera(CameraEvent::DeviceApproved)`] or
-    /// [`EventPayload::Camera(CameraEvent::DeviceDenied)`] event, or poll [`Camera::permission_state`]
+    /// [`EventPayload::Camera(CameraEventPayload::DeviceApproved)`] or
+    /// [`EventPayload::Camera(CameraEventPayload::DeviceDenied)`] event, or poll [`Camera::permission_state`]
     /// occasionally until it returns [`CameraPermissionState::Approved`].
     ///
     /// [`EventPayload`]: crate::events::EventPayload
-    /// [`CameraEvent`]: crate::events::CameraEvent
+    /// [`CameraEventPayload`]: crate::events::CameraEventPayload
     pub fn acquire_frame<'a>(&'a mut self) -> Result<Option<CameraFrame<'a>>, Error> {
         let mut timestamp = 0;
         unsafe {
@@ -278,12 +352,6 @@ impl Camera {
             if surface.is_null() {
                 return Ok(None);
             }
-            if timestamp == 0 {
-                return Err(Error::new());
-            }
-            if surface.is_null() {
-                return Ok(None);
-            }
             Ok(Some(CameraFrame {
                 camera: self,
                 surface: SurfaceRef::from_mut_ptr(surface),
@@ -304,8 +372,11 @@ impl Drop for Camera {
 /// A camera frame.
 ///
 /// The surface containing the contents of this frame can be obtained by
-/// accessing the `surface` field. The timestamp can be obtained by calling
-/// [`CameraFrame::timestamp`].
+/// accessing the `surface` field, or through [`Deref`]. The timestamp can be
+/// obtained by calling [`CameraFrame::timestamp`].
+///
+/// The surface is owned by the camera and isn't copied; it's handed back to the
+/// camera when the frame is dropped, so drop frames as soon as possible.
 pub struct CameraFrame<'a> {
     camera: &'a Camera,
     timestamp: u64,
@@ -313,12 +384,22 @@ pub struct CameraFrame<'a> {
 }
 
 impl CameraFrame<'_> {
+    /// Returns the time the frame was captured, in nanoseconds, not necessarily synchronized
+    /// with the timestamps of events.
     #[inline]
     pub fn timestamp(&self) -> u64 {
         self.timestamp
     }
 }
 
+impl Deref for CameraFrame<'_> {
+    type Target = SurfaceRef;
+
+    fn deref(&self) -> &Self::Target {
+        self.surface
+    }
+}
+
 impl Drop for CameraFrame<'_> {
     fn drop(&mut self) {
         unsafe {
@@ -383,6 +464,12 @@ impl CameraSpec {
         self.0.framerate_denominator as u32
     }
 
+    /// Returns the frame rate in frames per second.
+    #[inline]
+    pub fn framerate(&self) -> f32 {
+        self.0.framerate_numerator as f32 / self.0.framerate_denominator.max(1) as f32
+    }
+
     #[inline]
     pub fn colorspace(&self) -> Colorspace {
         Colorspace::from_ll(self.0.colorspace)
@@ -402,16 +489,32 @@ impl CameraSpec {
     fn from_ll(ll: sys::SDL_CameraSpec) -> Self {
         Self(ll)
     }
+
+    /// Returns true if both specs have the same pixel format and frame size.
+    fn same_mode(&self, other: &Self) -> bool {
+        self.0.format == other.0.format
+            && self.0.width == other.0.width
+            && self.0.height == other.0.height
+    }
 }
 
 #[repr(i32)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum CameraPermissionState {
     Denied = -1,
     Approved = 1,
 }
 
 impl CameraPermissionState {
+    /// Converts a raw value into a `CameraPermissionState`.
+    pub fn try_from_ll(ll: i32) -> Result<Self, Error> {
+        Ok(match ll {
+            -1 => Self::Denied,
+            1 => Self::Approved,
+            _ => return Err(Error::register(c"Unknown camera permission state.")),
+        })
+    }
+
     fn from_ll(ll: i32) -> Option<Self> {
         if ll == -1 {
             Some(Self::Denied)
@@ -424,7 +527,7 @@ impl CameraPermissionState {
 }
 
 #[repr(u32)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum CameraPosition {
     FrontFacing = sys::SDL_CameraPosition_SDL_CAMERA_POSITION_FRONT_FACING,
     BackFacing = sys::SDL_CameraPosition_SDL_CAMERA_POSITION_BACK_FACING,
@@ -432,8 +535,35 @@ pub enum CameraPosition {
 }
 
 impl CameraPosition {
+    /// Converts a raw `SDL_CameraPosition` into a `CameraPosition`.
+    pub fn try_from_ll(ll: sys::SDL_CameraPosition) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_CameraPosition_SDL_CAMERA_POSITION_FRONT_FACING => Self::FrontFacing,
+            sys::SDL_CameraPosition_SDL_CAMERA_POSITION_BACK_FACING => Self::BackFacing,
+            sys::SDL_CameraPosition_SDL_CAMERA_POSITION_UNKNOWN => Self::Unknown,
+            _ => return Err(Error::register(c"Unknown camera position.")),
+        })
+    }
+
     /// SAFETY: only call this if the value comes from SDL (guaranteed to be a variant).
     unsafe fn from_ll_unchecked(ll: sys::SDL_CameraPosition) -> Self {
         unsafe { core::mem::transmute(ll) }
     }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_CameraPosition {
+        *self as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(CameraPosition: FrontFacing, BackFacing, Unknown);
+        assert_round_trips!(CameraPermissionState: Denied, Approved);
+    }
 }
@@ -1,9 +1,13 @@
+use crate::events::Event;
 use crate::sys;
+use crate::video::WindowRef;
 use crate::Error;
 use crate::VideoSubsystem;
+use alloc::boxed::Box;
 use alloc::ffi::CString;
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::ffi::c_char;
 use core::ffi::c_void;
 use core::ffi::CStr;
 
@@ -38,6 +42,9 @@ impl VideoSubsystem {
         }
     }
 
+    /// Returns the clipboard's data in the given mime type, such as `"image/png"`.
+    ///
+    /// See [`VideoSubsystem::mime_types`] for the mime types that are available.
     pub fn clipboard_data(&self, mime_type: &str) -> Result<Vec<u8>, Error> {
         unsafe {
             let mime_type = CString::new(mime_type)
@@ -73,6 +80,49 @@ impl VideoSubsystem {
         Ok(())
     }
 
+    /// Offer data to other applications through the clipboard, in each of the given mime types.
+    ///
+    /// The data isn't copied up front: `provider` is asked for it whenever an application pastes
+    /// it, and is dropped once the clipboard is cleared or replaced.
+    ///
+    /// Returns an `Error` if `mime_types` is empty or any of them contains an interior nul byte.
+    pub fn set_clipboard_data<P: ClipboardDataProvider>(
+        &mut self,
+        provider: P,
+        mime_types: &[&str],
+    ) -> Result<(), Error> {
+        if mime_types.is_empty() {
+            return Err(Error::register(c"At least one mime type is required."));
+        }
+        let mime_types = mime_types
+            .iter()
+            .map(|mime_type| CString::new(*mime_type))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| Error::register(c"Invalid string format for mime_type."))?;
+        let mut pointers: Vec<*const c_char> = mime_types.iter().map(|m| m.as_ptr()).collect();
+        let provider = Box::into_raw(Box::new(provider));
+        let result = unsafe {
+            sys::SDL_SetClipboardData(
+                Some(clipboard_data_marshall::<P>),
+                Some(clipboard_cleanup_marshall::<P>),
+                provider as *mut c_void,
+                pointers.as_mut_ptr(),
+                pointers.len(),
+            )
+        };
+        // On failure SDL may already own the provider, so it's leaked rather than risking a
+        // double free.
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Put `data` into the clipboard as `mime_type`, such as `"image/png"`.
+    pub fn set_clipboard_bytes(&mut self, mime_type: &str, data: Vec<u8>) -> Result<(), Error> {
+        self.set_clipboard_data(ClipboardBytes(data), &[mime_type])
+    }
+
     /// Clear the clipboard data.
     pub fn clear_clipboard_data(&mut self) -> Result<(), Error> {
         let result = unsafe { sys::SDL_ClearClipboardData() };
@@ -93,6 +143,36 @@ impl VideoSubsystem {
         }
     }
 
+    /// Forwards the clipboard's text to `on_paste` if `event` is a paste shortcut aimed at
+    /// `window` while it's accepting text input.
+    ///
+    /// Text widgets usually only listen to text input events, which are not generated when the
+    /// user pastes text. Calling this for every event bridges that gap: `on_paste` is called the
+    /// same way a text input handler would be. Returns true if `on_paste` was called.
+    ///
+    /// See [`Event::is_paste_shortcut`] for the shortcuts that are recognized.
+    pub fn handle_paste_event<F: FnOnce(&str)>(
+        &self,
+        window: &WindowRef,
+        event: &Event,
+        on_paste: F,
+    ) -> bool {
+        if !event.is_paste_shortcut() {
+            return false;
+        }
+        if window.id().ok() != Some(unsafe { event.0.key.windowID }) {
+            return false;
+        }
+        if !unsafe { sys::SDL_TextInputActive(window.as_ptr() as *mut _) } {
+            return false;
+        }
+        let Some(text) = self.clipboard_text() else {
+            return false;
+        };
+        on_paste(&text);
+        true
+    }
+
     /// Retrieve the list of mime types available in the clipboard.
     pub fn mime_types(&self) -> Result<Vec<String>, Error> {
         let mut len = 0;
@@ -113,6 +193,51 @@ impl VideoSubsystem {
     }
 }
 
+/// Provides the data offered with [`VideoSubsystem::set_clipboard_data`].
+pub trait ClipboardDataProvider: Send + 'static {
+    /// Returns the data for `mime_type`, or `None` if it can't be provided.
+    ///
+    /// The data is copied by SDL before this is called again.
+    fn data(&mut self, mime_type: &str) -> Option<&[u8]>;
+}
+
+// Offers the same bytes for every mime type.
+struct ClipboardBytes(Vec<u8>);
+
+impl ClipboardDataProvider for ClipboardBytes {
+    fn data(&mut self, _mime_type: &str) -> Option<&[u8]> {
+        Some(&self.0)
+    }
+}
+
+unsafe extern "C" fn clipboard_data_marshall<P: ClipboardDataProvider>(
+    user_data: *mut c_void,
+    mime_type: *const c_char,
+    size: *mut usize,
+) -> *const c_void {
+    let provider: &mut P = unsafe { &mut *(user_data as *mut P) };
+    let data = if mime_type.is_null() {
+        None
+    } else {
+        let mime_type = unsafe { CStr::from_ptr(mime_type) }.to_string_lossy();
+        provider.data(&mime_type)
+    };
+    match data {
+        Some(data) => {
+            unsafe { *size = data.len() };
+            data.as_ptr() as *const c_void
+        }
+        None => {
+            unsafe { *size = 0 };
+            core::ptr::null()
+        }
+    }
+}
+
+unsafe extern "C" fn clipboard_cleanup_marshall<P: ClipboardDataProvider>(user_data: *mut c_void) {
+    drop(unsafe { Box::from_raw(user_data as *mut P) });
+}
+
 unsafe fn convert_sdl_heap_allocated_str_to_string(ptr: *mut i8) -> Option<String> {
     if ptr.is_null() {
         return None;
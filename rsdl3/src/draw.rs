@@ -0,0 +1,111 @@
+use crate::pixels::Color;
+use crate::rect::Rect;
+use crate::rect::RectF32;
+use crate::render::Renderer;
+use crate::render::Texture;
+use crate::surface::ScaleMode;
+use crate::surface::SurfaceRef;
+use crate::sys;
+use crate::Error;
+
+/// A target for simple 2D drawing, implemented by both [`Renderer`] and [`SurfaceRef`].
+///
+/// This allows UI or debug drawing code to render into a window through the GPU, or into an
+/// offscreen surface in software, with the same code path:
+///
+/// ```no_run
+/// use rsdl3::draw::Drawable;
+/// use rsdl3::pixels::Color;
+/// use rsdl3::rect::Rect;
+///
+/// fn draw_frame<D: Drawable + ?Sized>(target: &mut D) -> Result<(), rsdl3::Error> {
+///     target.clear(Color::new(0, 0, 0, 255))?;
+///     target.fill_rect(Some(Rect::new(10, 10, 100, 20)), Color::new(255, 0, 0, 255))
+/// }
+/// ```
+///
+/// The two implementations don't behave identically in every respect: a [`Renderer`] blends
+/// filled rectangles according to its draw blend mode, while a [`SurfaceRef`] writes the color
+/// as is.
+pub trait Drawable {
+    /// The type of image that can be drawn onto this target.
+    type Image: ?Sized;
+
+    /// Fills the entire target with `color`, ignoring any clip rectangle.
+    fn clear(&mut self, color: Color) -> Result<(), Error>;
+
+    /// Fills `rect` with `color`, or the entire target if `rect` is `None`.
+    fn fill_rect(&mut self, rect: Option<Rect>, color: Color) -> Result<(), Error>;
+
+    /// Copies the `src_rect` portion of `image` into `dest_rect`, scaling it if needed.
+    ///
+    /// A `None` `src_rect` copies the entire image, and a `None` `dest_rect` covers the entire
+    /// target.
+    fn draw_image(
+        &mut self,
+        image: &Self::Image,
+        src_rect: Option<Rect>,
+        dest_rect: Option<Rect>,
+    ) -> Result<(), Error>;
+}
+
+/// The draw color of the renderer is left set to the last color passed to `clear` or `fill_rect`.
+impl<T> Drawable for Renderer<T> {
+    type Image = Texture<T>;
+
+    fn clear(&mut self, color: Color) -> Result<(), Error> {
+        self.set_draw_color(color)?;
+        Renderer::clear(self)
+    }
+
+    fn fill_rect(&mut self, rect: Option<Rect>, color: Color) -> Result<(), Error> {
+        self.set_draw_color(color)?;
+        match rect {
+            Some(rect) => Renderer::fill_rect(self, RectF32::from(rect)),
+            None => {
+                let result = unsafe { sys::SDL_RenderFillRect(self.raw(), core::ptr::null()) };
+                if !result {
+                    return Err(Error::new());
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn draw_image(
+        &mut self,
+        image: &Texture<T>,
+        src_rect: Option<Rect>,
+        dest_rect: Option<Rect>,
+    ) -> Result<(), Error> {
+        self.render_texture(
+            image,
+            src_rect.map(RectF32::from),
+            dest_rect.map(RectF32::from),
+        )
+    }
+}
+
+/// Images are blitted with linear scaling, and with the blend mode, alpha and color modulation
+/// of the source surface.
+impl Drawable for SurfaceRef {
+    type Image = SurfaceRef;
+
+    fn clear(&mut self, color: Color) -> Result<(), Error> {
+        SurfaceRef::clear(self, color)
+    }
+
+    fn fill_rect(&mut self, rect: Option<Rect>, color: Color) -> Result<(), Error> {
+        let pixel = self.map_rgba((color.r(), color.g(), color.b(), color.a()));
+        SurfaceRef::fill_rect(self, rect, pixel)
+    }
+
+    fn draw_image(
+        &mut self,
+        image: &SurfaceRef,
+        src_rect: Option<Rect>,
+        dest_rect: Option<Rect>,
+    ) -> Result<(), Error> {
+        image.blit_scaled(src_rect, self, dest_rect, ScaleMode::Linear)
+    }
+}
@@ -1,10 +1,30 @@
+use crate::audio::AudioDeviceId;
+use crate::camera::CameraId;
+use crate::gamepad::{GamepadAxis, GamepadButton};
 use crate::init::EventsSubsystem;
+use crate::joystick::{JoystickHat, JoystickId, PowerInfo, PowerState};
+use crate::keyboard::{KeyboardId, Keycode, Keymod, Scancode};
+use crate::mouse::{MouseButton, MouseButtonFlags, MouseId, MouseWheelDirection};
+use crate::pen::{PenAxis, PenId, PenInputFlags};
+use crate::rect::PointF32;
+use crate::render::Renderer;
+use crate::sensor::{SensorId, SensorType};
 use crate::sys;
+use crate::touch::{FingerId, TouchId};
+use crate::video::DisplayOrientation;
 use crate::Error;
-use core::cell::RefMut;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::cell::{Cell, RefCell, RefMut, UnsafeCell};
 use core::ffi::c_void;
+use core::ffi::CStr;
 use core::marker::PhantomData;
 use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicU32, Ordering};
+use core::time::Duration;
 
 impl EventsSubsystem {
     /// Returns a mutably borrowed `EventPump`. Only a single instance of
@@ -23,6 +43,183 @@ impl EventsSubsystem {
     pub fn event_queue<'a>(&'a self) -> EventQueue<'a> {
         EventQueue(PhantomData)
     }
+
+    /// Reserves `count` consecutive event types for application-defined events, and returns the
+    /// first of them.
+    ///
+    /// Pass the types to [`Event::user`] or [`EventQueue::push_custom`]. Fails if `count` is 0
+    /// or there aren't enough event types left.
+    pub fn register_events(&self, count: u32) -> Result<u32, Error> {
+        let first = unsafe { sys::SDL_RegisterEvents(i32::try_from(count)?) };
+        if first == 0 {
+            return Err(Error::register(
+                c"Not enough user-defined event types left.",
+            ));
+        }
+        Ok(first)
+    }
+
+    /// Adds a callback that is called when an event is added to the event queue, and returns an
+    /// id that can be passed to [`EventsSubsystem::remove_event_watch`].
+    ///
+    /// Unlike [`EventQueue::add_event_watch`], the callback is owned by the subsystem: it stays
+    /// registered until it's removed or the last clone of the `EventsSubsystem` is dropped.
+    ///
+    /// Note: the callback may be called from any thread that pushes events. If it panics, the
+    /// process is aborted.
+    pub fn add_event_watch<F>(&self, watch: F) -> Result<EventWatchId, Error>
+    where
+        F: Fn(&Event) + Send + Sync + 'static,
+    {
+        let watch: Box<OwnedEventWatch> = Box::new(Box::new(watch));
+        let result =
+            unsafe { sys::SDL_AddEventWatch(Some(owned_event_watch_marshall), watch_data(&watch)) };
+        if !result {
+            return Err(Error::new());
+        }
+        let id = EventWatchId(self.callbacks.next_id.get());
+        self.callbacks.next_id.set(id.0 + 1);
+        self.callbacks.watches.borrow_mut().push((id, watch));
+        Ok(id)
+    }
+
+    /// Removes a callback added with [`EventsSubsystem::add_event_watch`]. Returns false if
+    /// there's no callback with that id.
+    pub fn remove_event_watch(&self, id: EventWatchId) -> bool {
+        let mut watches = self.callbacks.watches.borrow_mut();
+        let Some(index) = watches.iter().position(|(watch_id, _)| *watch_id == id) else {
+            return false;
+        };
+        let (_, watch) = watches.remove(index);
+        unsafe { sys::SDL_RemoveEventWatch(Some(owned_event_watch_marshall), watch_data(&watch)) };
+        true
+    }
+
+    /// Sets the event filter, replacing any previous one. See [`EventQueue::set_event_filter`]
+    /// for how its return value is used.
+    ///
+    /// The filter is owned by the subsystem and uninstalled when the last clone of the
+    /// `EventsSubsystem` is dropped, or by [`EventsSubsystem::clear_event_filter`].
+    ///
+    /// Replaced filters are only freed along with the subsystem, because a scope of
+    /// [`EventsSubsystem::with_event_filter`] may still reinstall them when it ends. Avoid setting
+    /// filters in a loop.
+    ///
+    /// Note: the filter may be called from any thread that pushes events. If it panics, the
+    /// process is aborted.
+    pub fn set_event_filter<F>(&self, filter: F)
+    where
+        F: Fn(&Event) -> bool + Send + Sync + 'static,
+    {
+        let filter: Box<OwnedEventFilter> = Box::new(Box::new(filter));
+        unsafe { sys::SDL_SetEventFilter(Some(owned_event_filter_marshall), filter_data(&filter)) };
+        self.callbacks.filters.borrow_mut().push(filter);
+    }
+
+    /// Installs `filter` as the event filter while `scope` runs, then restores the previous filter.
+    ///
+    /// Unlike [`EventsSubsystem::set_event_filter`], the filter doesn't need to be `'static`: it is
+    /// guaranteed to be removed before this function returns, even if `scope` panics. See
+    /// [`EventQueue::set_event_filter`] for how the return value of the filter is used.
+    ///
+    /// Scopes can only be opened on the thread that owns the `EventsSubsystem`, so nested scopes
+    /// always end in reverse order and never reinstall a filter that was already freed.
+    ///
+    /// Note: the filter may be called from any thread that pushes events. If `filter` panics, the
+    /// process is aborted.
+    pub fn with_event_filter<F, R>(&self, filter: F, scope: impl FnOnce() -> R) -> R
+    where
+        F: Fn(&Event) -> bool + Send + Sync,
+    {
+        let mut previous_callback: sys::SDL_EventFilter = None;
+        let mut previous_data: *mut c_void = core::ptr::null_mut();
+        let has_previous =
+            unsafe { sys::SDL_GetEventFilter(&raw mut previous_callback, &raw mut previous_data) };
+        let _guard = EventFilterGuard {
+            callback: if has_previous {
+                previous_callback
+            } else {
+                None
+            },
+            data: previous_data,
+        };
+        let callback: sys::SDL_EventFilter = Some(event_filter_closure_marshall::<F>);
+        unsafe { sys::SDL_SetEventFilter(callback, &filter as *const F as *mut _) };
+        scope()
+    }
+
+    /// Removes the event filter, whether it was set with [`EventsSubsystem::set_event_filter`]
+    /// or [`EventQueue::set_event_filter`].
+    pub fn clear_event_filter(&self) {
+        unsafe { sys::SDL_SetEventFilter(None, core::ptr::null_mut()) };
+    }
+}
+
+/// Identifies a callback added with [`EventsSubsystem::add_event_watch`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EventWatchId(u64);
+
+type OwnedEventWatch = Box<dyn Fn(&Event) + Send + Sync>;
+type OwnedEventFilter = Box<dyn Fn(&Event) -> bool + Send + Sync>;
+
+/// The callbacks owned by the [`EventsSubsystem`], shared by all of its clones.
+#[derive(Default)]
+pub(crate) struct EventCallbacks {
+    next_id: Cell<u64>,
+    // The closures are boxed twice so SDL gets a thin pointer that stays put when the `Vec`
+    // reallocates.
+    watches: RefCell<Vec<(EventWatchId, Box<OwnedEventWatch>)>>,
+    /// Every filter set with `EventsSubsystem::set_event_filter`, the latest last. SDL may still
+    /// use replaced ones: `EventsSubsystem::with_event_filter` restores the filter it replaced.
+    #[allow(clippy::vec_box)]
+    filters: RefCell<Vec<Box<OwnedEventFilter>>>,
+}
+
+impl Drop for EventCallbacks {
+    fn drop(&mut self) {
+        for (_, watch) in self.watches.get_mut().drain(..) {
+            unsafe {
+                sys::SDL_RemoveEventWatch(Some(owned_event_watch_marshall), watch_data(&watch))
+            };
+        }
+        let filters = self.filters.get_mut();
+        if !filters.is_empty() {
+            let mut callback: sys::SDL_EventFilter = None;
+            let mut data: *mut c_void = core::ptr::null_mut();
+            let has_filter = unsafe { sys::SDL_GetEventFilter(&raw mut callback, &raw mut data) };
+            // Leave alone a filter that isn't ours.
+            if has_filter && filters.iter().any(|filter| data == filter_data(filter)) {
+                unsafe { sys::SDL_SetEventFilter(None, core::ptr::null_mut()) };
+            }
+        }
+    }
+}
+
+fn watch_data(watch: &OwnedEventWatch) -> *mut c_void {
+    watch as *const OwnedEventWatch as *mut c_void
+}
+
+fn filter_data(filter: &OwnedEventFilter) -> *mut c_void {
+    filter as *const OwnedEventFilter as *mut c_void
+}
+
+unsafe extern "C" fn owned_event_watch_marshall(
+    user_data: *mut c_void,
+    event: *mut sys::SDL_Event,
+) -> bool {
+    let f: &OwnedEventWatch = unsafe { &*(user_data as *const _) };
+    let event = Event(unsafe { *event });
+    f(&event);
+    true
+}
+
+unsafe extern "C" fn owned_event_filter_marshall(
+    user_data: *mut c_void,
+    event: *mut sys::SDL_Event,
+) -> bool {
+    let f: &OwnedEventFilter = unsafe { &*(user_data as *const _) };
+    let event = Event(unsafe { *event });
+    f(&event)
 }
 
 /// A zero-sized type used for pumping and handling events.
@@ -59,10 +256,153 @@ impl EventPump {
         }
     }
 
+    /// Checks the event queue for events in the range of types from `min_type` to `max_type`,
+    /// inclusive, and returns the number of events added or retrieved.
+    ///
+    /// - [`EventAction::Add`] adds the events in `events` to the back of the queue, bypassing the
+    ///   event filter and event watches. The type range is ignored.
+    /// - [`EventAction::Peek`] copies up to `events.len()` matching events from the front of the
+    ///   queue into `events`, leaving them in the queue.
+    /// - [`EventAction::Get`] is the same as `Peek`, but removes the events from the queue.
+    ///
+    /// Use [`EventType::First`] and [`EventType::Last`] to match any event. This doesn't pump
+    /// events; call [`EventPump::pump_events`] first to get the pending OS events.
+    pub fn peep_events(
+        &mut self,
+        events: &mut [Event],
+        action: EventAction,
+        min_type: impl Into<u32>,
+        max_type: impl Into<u32>,
+    ) -> Result<usize, Error> {
+        let count = unsafe {
+            sys::SDL_PeepEvents(
+                // `Event` is a transparent wrapper of `SDL_Event`.
+                events.as_mut_ptr() as *mut sys::SDL_Event,
+                i32::try_from(events.len())?,
+                action.to_ll(),
+                min_type.into(),
+                max_type.into(),
+            )
+        };
+        if count < 0 {
+            return Err(Error::new());
+        }
+        Ok(count as usize)
+    }
+
     /// Returns an [`Iterator`] that yields [`Event`]s.
     pub fn poll_iter<'a>(&'a mut self) -> EventPollIter<'a> {
         EventPollIter(PhantomData)
     }
+
+    /// Returns an [`Iterator`] that sleeps until the next [`Event`] is available and yields it.
+    ///
+    /// The iterator only ends if waiting fails. Another thread can make it yield with
+    /// [`EventQueue::wake_up`], e.g. after finishing work the event loop should look at.
+    pub fn wait_iter<'a>(&'a mut self) -> EventWaitIter<'a> {
+        EventWaitIter(PhantomData)
+    }
+
+    /// Waits until the next event is available, or until `timeout` has elapsed, and removes it
+    /// from the queue.
+    ///
+    /// Returns `None` if the timeout elapsed without an event. The timeout has millisecond
+    /// precision and is rounded up.
+    pub fn wait_event_timeout(&mut self, timeout: Duration) -> Option<Event> {
+        wait_event_for(timeout)
+    }
+
+    /// Runs an event loop, calling `handler` for every event until it sets the [`ControlFlow`] to
+    /// [`ControlFlow::Exit`].
+    ///
+    /// The loop starts out in [`ControlFlow::Wait`]. The handler can change how the loop waits for
+    /// the next event at any time; the new value takes effect once the currently pending events have
+    /// been handled.
+    ///
+    /// See [`EventPump::run_event_loop_with_idle`] to get a chance to update and render between
+    /// batches of events.
+    pub fn run_event_loop(
+        &mut self,
+        handler: impl FnMut(Event, &mut ControlFlow),
+    ) -> Result<(), Error> {
+        self.run_event_loop_with_idle(handler, |_| {})
+    }
+
+    /// Same as [`EventPump::run_event_loop`], but `idle` is called every time the pending events
+    /// have been handled, right before the loop goes back to waiting.
+    ///
+    /// With [`ControlFlow::Poll`] this is called continuously, which makes it the natural place
+    /// to update and render a frame.
+    pub fn run_event_loop_with_idle(
+        &mut self,
+        mut handler: impl FnMut(Event, &mut ControlFlow),
+        mut idle: impl FnMut(&mut ControlFlow),
+    ) -> Result<(), Error> {
+        let mut control_flow = ControlFlow::Wait;
+        loop {
+            let first = match control_flow {
+                ControlFlow::Poll => self.poll_iter().next(),
+                ControlFlow::Wait => self.wait_event(true)?,
+                ControlFlow::WaitUntil(deadline) => wait_event_until(deadline),
+                ControlFlow::Exit => return Ok(()),
+            };
+            if let Some(event) = first {
+                handler(event, &mut control_flow);
+                while control_flow != ControlFlow::Exit {
+                    let Some(event) = self.poll_iter().next() else {
+                        break;
+                    };
+                    handler(event, &mut control_flow);
+                }
+                if control_flow == ControlFlow::Exit {
+                    return Ok(());
+                }
+            }
+            idle(&mut control_flow);
+        }
+    }
+}
+
+fn wait_event_until(deadline: Duration) -> Option<Event> {
+    let now = Duration::from_nanos(unsafe { sys::SDL_GetTicksNS() });
+    wait_event_for(deadline.saturating_sub(now))
+}
+
+fn wait_event_for(timeout: Duration) -> Option<Event> {
+    // Round up so the loop never wakes up right before the deadline.
+    let timeout_ms = timeout.as_nanos().div_ceil(1_000_000);
+    let timeout_ms = i32::try_from(timeout_ms).unwrap_or(i32::MAX);
+    let mut event: MaybeUninit<sys::SDL_Event> = MaybeUninit::uninit();
+    // A false result means the timeout elapsed (or an error happened), either way there is no event.
+    let result = unsafe { sys::SDL_WaitEventTimeout(event.as_mut_ptr(), timeout_ms) };
+    if !result {
+        return None;
+    }
+    Some(Event(unsafe { event.assume_init() }))
+}
+
+/// Controls how [`EventPump::run_event_loop`] waits for new events.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ControlFlow {
+    /// Doesn't wait: the loop keeps running even if no new events are available.
+    Poll,
+    /// Sleeps until a new event is available.
+    Wait,
+    /// Sleeps until a new event is available or the deadline is reached, whichever comes first.
+    ///
+    /// The deadline is measured on SDL's clock, the time since SDL was initialized; the same clock
+    /// used for event timestamps.
+    WaitUntil(Duration),
+    /// Leaves the event loop.
+    Exit,
+}
+
+impl ControlFlow {
+    /// Returns a [`ControlFlow::WaitUntil`] whose deadline is `timeout` from now.
+    pub fn wait_for(timeout: Duration) -> Self {
+        let now = Duration::from_nanos(unsafe { sys::SDL_GetTicksNS() });
+        Self::WaitUntil(now.saturating_add(timeout))
+    }
 }
 
 /// An [`Iterator`] that yields [`Event`]s.
@@ -88,40 +428,183 @@ impl Iterator for EventPollIter<'_> {
     }
 }
 
-/// Can be used to push [`Event`]s to SDL.
+/// An [`Iterator`] that waits for [`Event`]s, see [`EventPump::wait_iter`].
+pub struct EventWaitIter<'a>(PhantomData<&'a *const ()>);
+
+impl Iterator for EventWaitIter<'_> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut event = MaybeUninit::uninit();
+        // SAFETY: See `EventPollIter`.
+        let event = unsafe {
+            let result = sys::SDL_WaitEvent(event.as_mut_ptr());
+            if !result {
+                return None;
+            }
+            event.assume_init()
+        };
+        Some(Event(event))
+    }
+}
+
+// The event type pushed by `EventQueue::wake_up`, registered on first use. 0 if it isn't yet.
+static WAKE_UP_EVENT_TYPE: AtomicU32 = AtomicU32::new(0);
+
+fn wake_up_event_type() -> Result<u32, Error> {
+    let event_type = WAKE_UP_EVENT_TYPE.load(Ordering::Acquire);
+    if event_type != 0 {
+        return Ok(event_type);
+    }
+    let registered = unsafe { sys::SDL_RegisterEvents(1) };
+    if registered == 0 {
+        return Err(Error::new());
+    }
+    // If another thread registered one first, use theirs; the extra type just goes unused.
+    match WAKE_UP_EVENT_TYPE.compare_exchange(0, registered, Ordering::AcqRel, Ordering::Acquire) {
+        Ok(_) => Ok(registered),
+        Err(existing) => Ok(existing),
+    }
+}
+
+// The payloads of the events pushed by `EventQueue::push_custom`. Events are `Copy`, so a
+// payload can't be owned by its event; instead, the event stores the payload's id in `data1` and
+// the payload is removed from here when it's taken.
+static CUSTOM_PAYLOADS: CustomPayloads = CustomPayloads {
+    lock: UnsafeCell::new(0),
+    state: UnsafeCell::new(CustomPayloadState {
+        next_id: 0,
+        payloads: BTreeMap::new(),
+    }),
+};
+
+struct CustomPayloads {
+    lock: UnsafeCell<sys::SDL_SpinLock>,
+    state: UnsafeCell<CustomPayloadState>,
+}
+
+struct CustomPayloadState {
+    next_id: usize,
+    payloads: BTreeMap<usize, Box<dyn Any + Send>>,
+}
+
+// SAFETY: `state` is only accessed while holding `lock`.
+unsafe impl Sync for CustomPayloads {}
+
+impl CustomPayloads {
+    /// Runs `f` with the lock held. Payloads must be dropped after `f` returns, as their `Drop`
+    /// could push another custom event.
+    fn with<R>(&self, f: impl FnOnce(&mut CustomPayloadState) -> R) -> R {
+        unsafe { sys::SDL_LockSpinlock(self.lock.get()) };
+        let result = f(unsafe { &mut *self.state.get() });
+        unsafe { sys::SDL_UnlockSpinlock(self.lock.get()) };
+        result
+    }
+
+    /// Stored in `data2` to tell custom events apart from other user events.
+    fn marker(&self) -> *mut c_void {
+        self as *const Self as *mut c_void
+    }
+}
+
+/// A handle to SDL's event queue, obtained from [`EventsSubsystem::event_queue`].
 ///
-/// [`Event`]s pushed to this queue can be consumed by an [`EventPump`].
+/// Can be used to push [`Event`]s to SDL, which are then consumed by an [`EventPump`].
 ///
-// This can be shared between threads safely since SDL supports pushing events to the event queue
-// from multiple threads; SDL will synchronize things internally.
-//
-// That being said, its' use is still limited to scoped threads, since its'
-// lifetime is tied to the [`EventsSubsystem`]'s lifetime.
+/// `EventQueue` is `Send` and `Sync`: SDL synchronizes access to the queue internally, so events
+/// can be pushed from any thread. Its lifetime is tied to the [`EventsSubsystem`], which limits
+/// its use to scoped threads.
 pub struct EventQueue<'a>(PhantomData<&'a ()>);
 
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<EventQueue<'static>>();
+};
+
 impl EventQueue<'_> {
-    /// Check for the existence of a certain event type in the event queue.
+    /// Adds an event to the event queue.
     ///
-    /// If you need to check for a range of event types, use [`EventQueue::has_events`] instead.
-    pub fn has_event(&self, type_: u32) -> bool {
-        unsafe { sys::SDL_HasEvent(type_) }
+    /// The event passes through the event filter and event watches first. Returns an error if the
+    /// filter dropped it or the queue is full.
+    pub fn push_event(&self, mut event: Event) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_PushEvent(&raw mut event.0) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Pushes an application-defined event that carries `payload`, which the receiver takes back
+    /// with [`Event::take_custom`].
+    ///
+    /// `event_type` should come from [`EventsSubsystem::register_events`]. The payload is kept
+    /// until it's taken or dropped with [`Event::drop_custom`], so events that are never handled,
+    /// e.g. because they were flushed, leak it.
+    pub fn push_custom<T: Send + 'static>(
+        &self,
+        event_type: u32,
+        code: i32,
+        payload: Box<T>,
+    ) -> Result<(), Error> {
+        if event_type < sys::SDL_EventType_SDL_EVENT_USER {
+            return Err(Error::register(
+                c"Custom events need a user-defined event type.",
+            ));
+        }
+        let (id, replaced) = CUSTOM_PAYLOADS.with(|state| {
+            let id = state.next_id;
+            state.next_id = id.wrapping_add(1);
+            (id, state.payloads.insert(id, payload))
+        });
+        // Only possible once the ids wrap around, and dropped outside the lock like the others.
+        drop(replaced);
+        let mut event = Event::user(event_type, 0, code);
+        event.0.user.data1 = id as *mut c_void;
+        event.0.user.data2 = CUSTOM_PAYLOADS.marker();
+        let result = self.push_event(event);
+        if result.is_err() {
+            // Nobody will ever see the event, so the payload has to be dropped here.
+            let payload = CUSTOM_PAYLOADS.with(|state| state.payloads.remove(&id));
+            drop(payload);
+        }
+        result
+    }
+
+    /// Pushes an event that interrupts [`EventPump::wait_event`], [`EventPump::wait_iter`] and
+    /// the other waiting functions, so a thread can wake up the event loop.
+    ///
+    /// The event carries no data; [`Event::is_wake_up`] tells it apart from other events.
+    pub fn wake_up(&self) -> Result<(), Error> {
+        self.push_event(Event::user(wake_up_event_type()?, 0, 0))
     }
 
     /// Check for the existence of a certain event type in the event queue.
     ///
     /// If you need to check for a range of event types, use [`EventQueue::has_events`] instead.
-    pub fn has_events(&self, min_type: u32, max_type: u32) -> bool {
-        unsafe { sys::SDL_HasEvents(min_type, max_type) }
+    pub fn has_event(&self, event_type: impl Into<u32>) -> bool {
+        unsafe { sys::SDL_HasEvent(event_type.into()) }
+    }
+
+    /// Check for the existence of events in the range of types from `min_type` to `max_type`,
+    /// inclusive, in the event queue.
+    ///
+    /// Use [`EventType::First`] and [`EventType::Last`] to check for any event.
+    pub fn has_events(&self, min_type: impl Into<u32>, max_type: impl Into<u32>) -> bool {
+        unsafe { sys::SDL_HasEvents(min_type.into(), max_type.into()) }
     }
 
     /// Query the state of processing events by type.
-    pub fn event_enabled(&self, type_: u32) -> bool {
-        unsafe { sys::SDL_EventEnabled(type_) }
+    pub fn event_enabled(&self, event_type: impl Into<u32>) -> bool {
+        unsafe { sys::SDL_EventEnabled(event_type.into()) }
     }
 
     /// Set the state of processing events by type.
-    pub fn set_event_enabled(&self, type_: u32, enabled: bool) {
-        unsafe { sys::SDL_SetEventEnabled(type_, enabled) };
+    ///
+    /// Disabled events are dropped before they reach the event queue, event watches or the event
+    /// filter. This is useful to ignore high-frequency events, like [`EventType::MouseMotion`],
+    /// that the application doesn't handle.
+    pub fn set_event_enabled(&self, event_type: impl Into<u32>, enabled: bool) {
+        unsafe { sys::SDL_SetEventEnabled(event_type.into(), enabled) };
     }
 
     /// Clear events of a specific type from the event queue.
@@ -136,21 +619,21 @@ impl EventQueue<'_> {
     ///
     /// If you have user events with custom data that needs to be freed, you should use [`EventPump::peep_events`]
     /// to remove and clean up those events before calling this function.
-    pub fn flush_event(&self, type_: u32) {
-        unsafe { sys::SDL_FlushEvent(type_) }
+    pub fn flush_event(&self, event_type: impl Into<u32>) {
+        unsafe { sys::SDL_FlushEvent(event_type.into()) }
     }
 
     /// Clear events of a range of types from the event queue.
     ///
-    /// This will unconditionally remove any events from the queue that are in the range of `minType`
-    /// to `maxType`, inclusive. If you need to remove a single event type, use [`EventQueue::flush_event`] instead.
+    /// This will unconditionally remove any events from the queue that are in the range of `min_type`
+    /// to `max_type`, inclusive. If you need to remove a single event type, use [`EventQueue::flush_event`] instead.
     ///
     /// It's also normal to just ignore events you don't care about in your event loop without calling this function.
     ///
     /// This function only affects currently queued events. If you want to make sure that all pending OS events are
     /// flushed, you can call [`EventPump::pump_events`] on the main thread immediately before the flush call.
-    pub fn flush_events(&self, min_type: u32, max_type: u32) {
-        unsafe { sys::SDL_FlushEvents(min_type, max_type) }
+    pub fn flush_events(&self, min_type: impl Into<u32>, max_type: impl Into<u32>) {
+        unsafe { sys::SDL_FlushEvents(min_type.into(), max_type.into()) }
     }
 
     /// Add a callback to be triggered when an event is added to the event queue.
@@ -196,7 +679,7 @@ impl EventQueue<'_> {
     /// Note: Disabled events never make it to the event filter function; see [`EventSubsystem::set_event_enabled`].
     ///
     /// Note: Events pushed onto the queue with [`EventQueue::push_event`] get passed through the event filter, but
-    /// events pushed onto the queue with [`EventQueue::peep_events`] do not.
+    /// events pushed onto the queue with [`EventPump::peep_events`] do not.
     pub fn set_event_filter<T: EventFilterCallback>(&self, filter: &'static T) {
         let callback: sys::SDL_EventFilter = Some(event_filter_marshall::<T>);
         unsafe { sys::SDL_SetEventFilter(callback, filter as *const T as *mut _) };
@@ -210,6 +693,15 @@ impl EventQueue<'_> {
         let callback: sys::SDL_EventFilter = Some(event_filter_marshall::<T>);
         unsafe { sys::SDL_FilterEvents(callback, filter as *const T as *mut _) };
     }
+
+    /// Removes the events in the queue for which `keep` returns false.
+    ///
+    /// Like [`EventQueue::filter_events`], but takes a closure. It's only called on this thread,
+    /// before this function returns.
+    pub fn retain_events<F: FnMut(&Event) -> bool>(&self, mut keep: F) {
+        let callback: sys::SDL_EventFilter = Some(event_retain_marshall::<F>);
+        unsafe { sys::SDL_FilterEvents(callback, &raw mut keep as *mut _) };
+    }
 }
 
 /// Defines a filter
@@ -239,6 +731,38 @@ unsafe extern "C" fn event_filter_marshall<T: EventFilterCallback>(
     f.callback(event)
 }
 
+// Restores the event filter that was installed before `EventsSubsystem::with_event_filter`. The
+// subsystem isn't `Send`, so guards are dropped in reverse order on a single thread and the
+// filter they restore is still alive.
+struct EventFilterGuard {
+    callback: sys::SDL_EventFilter,
+    data: *mut c_void,
+}
+
+impl Drop for EventFilterGuard {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_SetEventFilter(self.callback, self.data) };
+    }
+}
+
+unsafe extern "C" fn event_filter_closure_marshall<F: Fn(&Event) -> bool + Send + Sync>(
+    user_data: *mut c_void,
+    event: *mut sys::SDL_Event,
+) -> bool {
+    let f: &F = unsafe { &*(user_data as *const _) };
+    let event = Event(unsafe { *event });
+    f(&event)
+}
+
+unsafe extern "C" fn event_retain_marshall<F: FnMut(&Event) -> bool>(
+    user_data: *mut c_void,
+    event: *mut sys::SDL_Event,
+) -> bool {
+    let f: &mut F = unsafe { &mut *(user_data as *mut _) };
+    let event = Event(unsafe { *event });
+    f(&event)
+}
+
 #[repr(u32)]
 #[derive(Copy, Clone, Debug)]
 pub enum EventAction {
@@ -251,11 +775,299 @@ pub enum EventAction {
 }
 
 impl EventAction {
+    /// Converts a raw `SDL_EventAction` into a `EventAction`.
+    pub fn try_from_ll(ll: sys::SDL_EventAction) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_EventAction_SDL_ADDEVENT => Self::Add,
+            sys::SDL_EventAction_SDL_GETEVENT => Self::Get,
+            sys::SDL_EventAction_SDL_PEEKEVENT => Self::Peek,
+            _ => return Err(Error::register(c"Unknown event action.")),
+        })
+    }
+
     pub fn to_ll(&self) -> sys::SDL_EventAction {
         *self as u32
     }
 }
 
+/// The types of events defined by SDL.
+///
+/// Application-defined events use types starting from [`EventType::User`]; those past it have
+/// no variant and are only available as raw values through [`Event::event_type`]. Functions that
+/// take an event type accept both an `EventType` and a raw `u32`, such as one returned by
+/// [`EventsSubsystem::register_events`].
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum EventType {
+    /// Lower bound of all event types, for use with range queries. No event has this type.
+    First = sys::SDL_EventType_SDL_EVENT_FIRST,
+    Quit = sys::SDL_EventType_SDL_EVENT_QUIT,
+    /// The application is being terminated by the OS.
+    Terminating = sys::SDL_EventType_SDL_EVENT_TERMINATING,
+    /// The application is low on memory; free memory if possible.
+    LowMemory = sys::SDL_EventType_SDL_EVENT_LOW_MEMORY,
+    WillEnterBackground = sys::SDL_EventType_SDL_EVENT_WILL_ENTER_BACKGROUND,
+    DidEnterBackground = sys::SDL_EventType_SDL_EVENT_DID_ENTER_BACKGROUND,
+    WillEnterForeground = sys::SDL_EventType_SDL_EVENT_WILL_ENTER_FOREGROUND,
+    DidEnterForeground = sys::SDL_EventType_SDL_EVENT_DID_ENTER_FOREGROUND,
+    LocaleChanged = sys::SDL_EventType_SDL_EVENT_LOCALE_CHANGED,
+    SystemThemeChanged = sys::SDL_EventType_SDL_EVENT_SYSTEM_THEME_CHANGED,
+    DisplayOrientation = sys::SDL_EventType_SDL_EVENT_DISPLAY_ORIENTATION,
+    DisplayAdded = sys::SDL_EventType_SDL_EVENT_DISPLAY_ADDED,
+    DisplayRemoved = sys::SDL_EventType_SDL_EVENT_DISPLAY_REMOVED,
+    DisplayMoved = sys::SDL_EventType_SDL_EVENT_DISPLAY_MOVED,
+    DisplayDesktopModeChanged = sys::SDL_EventType_SDL_EVENT_DISPLAY_DESKTOP_MODE_CHANGED,
+    DisplayCurrentModeChanged = sys::SDL_EventType_SDL_EVENT_DISPLAY_CURRENT_MODE_CHANGED,
+    DisplayContentScaleChanged = sys::SDL_EventType_SDL_EVENT_DISPLAY_CONTENT_SCALE_CHANGED,
+    WindowShown = sys::SDL_EventType_SDL_EVENT_WINDOW_SHOWN,
+    WindowHidden = sys::SDL_EventType_SDL_EVENT_WINDOW_HIDDEN,
+    WindowExposed = sys::SDL_EventType_SDL_EVENT_WINDOW_EXPOSED,
+    WindowMoved = sys::SDL_EventType_SDL_EVENT_WINDOW_MOVED,
+    WindowResized = sys::SDL_EventType_SDL_EVENT_WINDOW_RESIZED,
+    WindowPixelSizeChanged = sys::SDL_EventType_SDL_EVENT_WINDOW_PIXEL_SIZE_CHANGED,
+    WindowMetalViewResized = sys::SDL_EventType_SDL_EVENT_WINDOW_METAL_VIEW_RESIZED,
+    WindowMinimized = sys::SDL_EventType_SDL_EVENT_WINDOW_MINIMIZED,
+    WindowMaximized = sys::SDL_EventType_SDL_EVENT_WINDOW_MAXIMIZED,
+    WindowRestored = sys::SDL_EventType_SDL_EVENT_WINDOW_RESTORED,
+    WindowMouseEnter = sys::SDL_EventType_SDL_EVENT_WINDOW_MOUSE_ENTER,
+    WindowMouseLeave = sys::SDL_EventType_SDL_EVENT_WINDOW_MOUSE_LEAVE,
+    WindowFocusGained = sys::SDL_EventType_SDL_EVENT_WINDOW_FOCUS_GAINED,
+    WindowFocusLost = sys::SDL_EventType_SDL_EVENT_WINDOW_FOCUS_LOST,
+    /// The window manager requests that the window be closed.
+    WindowCloseRequested = sys::SDL_EventType_SDL_EVENT_WINDOW_CLOSE_REQUESTED,
+    WindowHitTest = sys::SDL_EventType_SDL_EVENT_WINDOW_HIT_TEST,
+    WindowIccProfChanged = sys::SDL_EventType_SDL_EVENT_WINDOW_ICCPROF_CHANGED,
+    WindowDisplayChanged = sys::SDL_EventType_SDL_EVENT_WINDOW_DISPLAY_CHANGED,
+    WindowDisplayScaleChanged = sys::SDL_EventType_SDL_EVENT_WINDOW_DISPLAY_SCALE_CHANGED,
+    WindowSafeAreaChanged = sys::SDL_EventType_SDL_EVENT_WINDOW_SAFE_AREA_CHANGED,
+    /// The window is completely covered by other windows.
+    WindowOccluded = sys::SDL_EventType_SDL_EVENT_WINDOW_OCCLUDED,
+    WindowEnterFullscreen = sys::SDL_EventType_SDL_EVENT_WINDOW_ENTER_FULLSCREEN,
+    WindowLeaveFullscreen = sys::SDL_EventType_SDL_EVENT_WINDOW_LEAVE_FULLSCREEN,
+    WindowDestroyed = sys::SDL_EventType_SDL_EVENT_WINDOW_DESTROYED,
+    WindowHdrStateChanged = sys::SDL_EventType_SDL_EVENT_WINDOW_HDR_STATE_CHANGED,
+    KeyDown = sys::SDL_EventType_SDL_EVENT_KEY_DOWN,
+    KeyUp = sys::SDL_EventType_SDL_EVENT_KEY_UP,
+    TextEditing = sys::SDL_EventType_SDL_EVENT_TEXT_EDITING,
+    TextInput = sys::SDL_EventType_SDL_EVENT_TEXT_INPUT,
+    /// The keymap changed, e.g. due to a system language or keyboard layout change.
+    KeymapChanged = sys::SDL_EventType_SDL_EVENT_KEYMAP_CHANGED,
+    KeyboardAdded = sys::SDL_EventType_SDL_EVENT_KEYBOARD_ADDED,
+    KeyboardRemoved = sys::SDL_EventType_SDL_EVENT_KEYBOARD_REMOVED,
+    TextEditingCandidates = sys::SDL_EventType_SDL_EVENT_TEXT_EDITING_CANDIDATES,
+    MouseMotion = sys::SDL_EventType_SDL_EVENT_MOUSE_MOTION,
+    MouseButtonDown = sys::SDL_EventType_SDL_EVENT_MOUSE_BUTTON_DOWN,
+    MouseButtonUp = sys::SDL_EventType_SDL_EVENT_MOUSE_BUTTON_UP,
+    MouseWheel = sys::SDL_EventType_SDL_EVENT_MOUSE_WHEEL,
+    MouseAdded = sys::SDL_EventType_SDL_EVENT_MOUSE_ADDED,
+    MouseRemoved = sys::SDL_EventType_SDL_EVENT_MOUSE_REMOVED,
+    JoystickAxisMotion = sys::SDL_EventType_SDL_EVENT_JOYSTICK_AXIS_MOTION,
+    JoystickBallMotion = sys::SDL_EventType_SDL_EVENT_JOYSTICK_BALL_MOTION,
+    JoystickHatMotion = sys::SDL_EventType_SDL_EVENT_JOYSTICK_HAT_MOTION,
+    JoystickButtonDown = sys::SDL_EventType_SDL_EVENT_JOYSTICK_BUTTON_DOWN,
+    JoystickButtonUp = sys::SDL_EventType_SDL_EVENT_JOYSTICK_BUTTON_UP,
+    JoystickAdded = sys::SDL_EventType_SDL_EVENT_JOYSTICK_ADDED,
+    JoystickRemoved = sys::SDL_EventType_SDL_EVENT_JOYSTICK_REMOVED,
+    JoystickBatteryUpdated = sys::SDL_EventType_SDL_EVENT_JOYSTICK_BATTERY_UPDATED,
+    /// Joystick update is complete.
+    JoystickUpdateComplete = sys::SDL_EventType_SDL_EVENT_JOYSTICK_UPDATE_COMPLETE,
+    GamepadAxisMotion = sys::SDL_EventType_SDL_EVENT_GAMEPAD_AXIS_MOTION,
+    GamepadButtonDown = sys::SDL_EventType_SDL_EVENT_GAMEPAD_BUTTON_DOWN,
+    GamepadButtonUp = sys::SDL_EventType_SDL_EVENT_GAMEPAD_BUTTON_UP,
+    GamepadAdded = sys::SDL_EventType_SDL_EVENT_GAMEPAD_ADDED,
+    GamepadRemoved = sys::SDL_EventType_SDL_EVENT_GAMEPAD_REMOVED,
+    GamepadRemapped = sys::SDL_EventType_SDL_EVENT_GAMEPAD_REMAPPED,
+    GamepadTouchpadDown = sys::SDL_EventType_SDL_EVENT_GAMEPAD_TOUCHPAD_DOWN,
+    GamepadTouchpadMotion = sys::SDL_EventType_SDL_EVENT_GAMEPAD_TOUCHPAD_MOTION,
+    GamepadTouchpadUp = sys::SDL_EventType_SDL_EVENT_GAMEPAD_TOUCHPAD_UP,
+    GamepadSensorUpdate = sys::SDL_EventType_SDL_EVENT_GAMEPAD_SENSOR_UPDATE,
+    /// Gamepad update is complete.
+    GamepadUpdateComplete = sys::SDL_EventType_SDL_EVENT_GAMEPAD_UPDATE_COMPLETE,
+    GamepadSteamHandleUpdated = sys::SDL_EventType_SDL_EVENT_GAMEPAD_STEAM_HANDLE_UPDATED,
+    FingerDown = sys::SDL_EventType_SDL_EVENT_FINGER_DOWN,
+    FingerUp = sys::SDL_EventType_SDL_EVENT_FINGER_UP,
+    FingerMotion = sys::SDL_EventType_SDL_EVENT_FINGER_MOTION,
+    FingerCanceled = sys::SDL_EventType_SDL_EVENT_FINGER_CANCELED,
+    ClipboardUpdate = sys::SDL_EventType_SDL_EVENT_CLIPBOARD_UPDATE,
+    DropFile = sys::SDL_EventType_SDL_EVENT_DROP_FILE,
+    DropText = sys::SDL_EventType_SDL_EVENT_DROP_TEXT,
+    DropBegin = sys::SDL_EventType_SDL_EVENT_DROP_BEGIN,
+    DropComplete = sys::SDL_EventType_SDL_EVENT_DROP_COMPLETE,
+    DropPosition = sys::SDL_EventType_SDL_EVENT_DROP_POSITION,
+    AudioDeviceAdded = sys::SDL_EventType_SDL_EVENT_AUDIO_DEVICE_ADDED,
+    AudioDeviceRemoved = sys::SDL_EventType_SDL_EVENT_AUDIO_DEVICE_REMOVED,
+    AudioDeviceFormatChanged = sys::SDL_EventType_SDL_EVENT_AUDIO_DEVICE_FORMAT_CHANGED,
+    SensorUpdate = sys::SDL_EventType_SDL_EVENT_SENSOR_UPDATE,
+    PenProximityIn = sys::SDL_EventType_SDL_EVENT_PEN_PROXIMITY_IN,
+    PenProximityOut = sys::SDL_EventType_SDL_EVENT_PEN_PROXIMITY_OUT,
+    PenDown = sys::SDL_EventType_SDL_EVENT_PEN_DOWN,
+    PenUp = sys::SDL_EventType_SDL_EVENT_PEN_UP,
+    PenButtonDown = sys::SDL_EventType_SDL_EVENT_PEN_BUTTON_DOWN,
+    PenButtonUp = sys::SDL_EventType_SDL_EVENT_PEN_BUTTON_UP,
+    PenMotion = sys::SDL_EventType_SDL_EVENT_PEN_MOTION,
+    PenAxis = sys::SDL_EventType_SDL_EVENT_PEN_AXIS,
+    CameraDeviceAdded = sys::SDL_EventType_SDL_EVENT_CAMERA_DEVICE_ADDED,
+    CameraDeviceRemoved = sys::SDL_EventType_SDL_EVENT_CAMERA_DEVICE_REMOVED,
+    CameraDeviceApproved = sys::SDL_EventType_SDL_EVENT_CAMERA_DEVICE_APPROVED,
+    CameraDeviceDenied = sys::SDL_EventType_SDL_EVENT_CAMERA_DEVICE_DENIED,
+    /// The render targets have been reset and their contents need to be updated.
+    RenderTargetsReset = sys::SDL_EventType_SDL_EVENT_RENDER_TARGETS_RESET,
+    /// The device has been reset and all textures need to be recreated.
+    RenderDeviceReset = sys::SDL_EventType_SDL_EVENT_RENDER_DEVICE_RESET,
+    /// The device has been lost and can't be recovered.
+    RenderDeviceLost = sys::SDL_EventType_SDL_EVENT_RENDER_DEVICE_LOST,
+    /// The first event type available for application-defined events.
+    User = sys::SDL_EventType_SDL_EVENT_USER,
+    /// Upper bound of all event types, for use with range queries. No event has this type.
+    Last = sys::SDL_EventType_SDL_EVENT_LAST,
+}
+
+impl From<EventType> for u32 {
+    #[inline]
+    fn from(value: EventType) -> Self {
+        value.to_ll()
+    }
+}
+
+impl EventType {
+    /// Converts a raw `SDL_EventType` into a `EventType`.
+    pub fn try_from_ll(ll: sys::SDL_EventType) -> Result<Self, Error> {
+        Self::from_ll(ll).ok_or_else(|| Error::register(c"Unknown event type."))
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_EventType {
+        *self as u32
+    }
+
+    /// Converts a raw event type, returning `None` for types that have no variant.
+    pub fn from_ll(ll: sys::SDL_EventType) -> Option<Self> {
+        Some(match ll {
+            sys::SDL_EventType_SDL_EVENT_FIRST => Self::First,
+            sys::SDL_EventType_SDL_EVENT_QUIT => Self::Quit,
+            sys::SDL_EventType_SDL_EVENT_TERMINATING => Self::Terminating,
+            sys::SDL_EventType_SDL_EVENT_LOW_MEMORY => Self::LowMemory,
+            sys::SDL_EventType_SDL_EVENT_WILL_ENTER_BACKGROUND => Self::WillEnterBackground,
+            sys::SDL_EventType_SDL_EVENT_DID_ENTER_BACKGROUND => Self::DidEnterBackground,
+            sys::SDL_EventType_SDL_EVENT_WILL_ENTER_FOREGROUND => Self::WillEnterForeground,
+            sys::SDL_EventType_SDL_EVENT_DID_ENTER_FOREGROUND => Self::DidEnterForeground,
+            sys::SDL_EventType_SDL_EVENT_LOCALE_CHANGED => Self::LocaleChanged,
+            sys::SDL_EventType_SDL_EVENT_SYSTEM_THEME_CHANGED => Self::SystemThemeChanged,
+            sys::SDL_EventType_SDL_EVENT_DISPLAY_ORIENTATION => Self::DisplayOrientation,
+            sys::SDL_EventType_SDL_EVENT_DISPLAY_ADDED => Self::DisplayAdded,
+            sys::SDL_EventType_SDL_EVENT_DISPLAY_REMOVED => Self::DisplayRemoved,
+            sys::SDL_EventType_SDL_EVENT_DISPLAY_MOVED => Self::DisplayMoved,
+            sys::SDL_EventType_SDL_EVENT_DISPLAY_DESKTOP_MODE_CHANGED => {
+                Self::DisplayDesktopModeChanged
+            }
+            sys::SDL_EventType_SDL_EVENT_DISPLAY_CURRENT_MODE_CHANGED => {
+                Self::DisplayCurrentModeChanged
+            }
+            sys::SDL_EventType_SDL_EVENT_DISPLAY_CONTENT_SCALE_CHANGED => {
+                Self::DisplayContentScaleChanged
+            }
+            sys::SDL_EventType_SDL_EVENT_WINDOW_SHOWN => Self::WindowShown,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_HIDDEN => Self::WindowHidden,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_EXPOSED => Self::WindowExposed,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_MOVED => Self::WindowMoved,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_RESIZED => Self::WindowResized,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_PIXEL_SIZE_CHANGED => Self::WindowPixelSizeChanged,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_METAL_VIEW_RESIZED => Self::WindowMetalViewResized,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_MINIMIZED => Self::WindowMinimized,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_MAXIMIZED => Self::WindowMaximized,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_RESTORED => Self::WindowRestored,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_MOUSE_ENTER => Self::WindowMouseEnter,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_MOUSE_LEAVE => Self::WindowMouseLeave,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_FOCUS_GAINED => Self::WindowFocusGained,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_FOCUS_LOST => Self::WindowFocusLost,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_CLOSE_REQUESTED => Self::WindowCloseRequested,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_HIT_TEST => Self::WindowHitTest,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_ICCPROF_CHANGED => Self::WindowIccProfChanged,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_DISPLAY_CHANGED => Self::WindowDisplayChanged,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_DISPLAY_SCALE_CHANGED => {
+                Self::WindowDisplayScaleChanged
+            }
+            sys::SDL_EventType_SDL_EVENT_WINDOW_SAFE_AREA_CHANGED => Self::WindowSafeAreaChanged,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_OCCLUDED => Self::WindowOccluded,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_ENTER_FULLSCREEN => Self::WindowEnterFullscreen,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_LEAVE_FULLSCREEN => Self::WindowLeaveFullscreen,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_DESTROYED => Self::WindowDestroyed,
+            sys::SDL_EventType_SDL_EVENT_WINDOW_HDR_STATE_CHANGED => Self::WindowHdrStateChanged,
+            sys::SDL_EventType_SDL_EVENT_KEY_DOWN => Self::KeyDown,
+            sys::SDL_EventType_SDL_EVENT_KEY_UP => Self::KeyUp,
+            sys::SDL_EventType_SDL_EVENT_TEXT_EDITING => Self::TextEditing,
+            sys::SDL_EventType_SDL_EVENT_TEXT_INPUT => Self::TextInput,
+            sys::SDL_EventType_SDL_EVENT_KEYMAP_CHANGED => Self::KeymapChanged,
+            sys::SDL_EventType_SDL_EVENT_KEYBOARD_ADDED => Self::KeyboardAdded,
+            sys::SDL_EventType_SDL_EVENT_KEYBOARD_REMOVED => Self::KeyboardRemoved,
+            sys::SDL_EventType_SDL_EVENT_TEXT_EDITING_CANDIDATES => Self::TextEditingCandidates,
+            sys::SDL_EventType_SDL_EVENT_MOUSE_MOTION => Self::MouseMotion,
+            sys::SDL_EventType_SDL_EVENT_MOUSE_BUTTON_DOWN => Self::MouseButtonDown,
+            sys::SDL_EventType_SDL_EVENT_MOUSE_BUTTON_UP => Self::MouseButtonUp,
+            sys::SDL_EventType_SDL_EVENT_MOUSE_WHEEL => Self::MouseWheel,
+            sys::SDL_EventType_SDL_EVENT_MOUSE_ADDED => Self::MouseAdded,
+            sys::SDL_EventType_SDL_EVENT_MOUSE_REMOVED => Self::MouseRemoved,
+            sys::SDL_EventType_SDL_EVENT_JOYSTICK_AXIS_MOTION => Self::JoystickAxisMotion,
+            sys::SDL_EventType_SDL_EVENT_JOYSTICK_BALL_MOTION => Self::JoystickBallMotion,
+            sys::SDL_EventType_SDL_EVENT_JOYSTICK_HAT_MOTION => Self::JoystickHatMotion,
+            sys::SDL_EventType_SDL_EVENT_JOYSTICK_BUTTON_DOWN => Self::JoystickButtonDown,
+            sys::SDL_EventType_SDL_EVENT_JOYSTICK_BUTTON_UP => Self::JoystickButtonUp,
+            sys::SDL_EventType_SDL_EVENT_JOYSTICK_ADDED => Self::JoystickAdded,
+            sys::SDL_EventType_SDL_EVENT_JOYSTICK_REMOVED => Self::JoystickRemoved,
+            sys::SDL_EventType_SDL_EVENT_JOYSTICK_BATTERY_UPDATED => Self::JoystickBatteryUpdated,
+            sys::SDL_EventType_SDL_EVENT_JOYSTICK_UPDATE_COMPLETE => Self::JoystickUpdateComplete,
+            sys::SDL_EventType_SDL_EVENT_GAMEPAD_AXIS_MOTION => Self::GamepadAxisMotion,
+            sys::SDL_EventType_SDL_EVENT_GAMEPAD_BUTTON_DOWN => Self::GamepadButtonDown,
+            sys::SDL_EventType_SDL_EVENT_GAMEPAD_BUTTON_UP => Self::GamepadButtonUp,
+            sys::SDL_EventType_SDL_EVENT_GAMEPAD_ADDED => Self::GamepadAdded,
+            sys::SDL_EventType_SDL_EVENT_GAMEPAD_REMOVED => Self::GamepadRemoved,
+            sys::SDL_EventType_SDL_EVENT_GAMEPAD_REMAPPED => Self::GamepadRemapped,
+            sys::SDL_EventType_SDL_EVENT_GAMEPAD_TOUCHPAD_DOWN => Self::GamepadTouchpadDown,
+            sys::SDL_EventType_SDL_EVENT_GAMEPAD_TOUCHPAD_MOTION => Self::GamepadTouchpadMotion,
+            sys::SDL_EventType_SDL_EVENT_GAMEPAD_TOUCHPAD_UP => Self::GamepadTouchpadUp,
+            sys::SDL_EventType_SDL_EVENT_GAMEPAD_SENSOR_UPDATE => Self::GamepadSensorUpdate,
+            sys::SDL_EventType_SDL_EVENT_GAMEPAD_UPDATE_COMPLETE => Self::GamepadUpdateComplete,
+            sys::SDL_EventType_SDL_EVENT_GAMEPAD_STEAM_HANDLE_UPDATED => {
+                Self::GamepadSteamHandleUpdated
+            }
+            sys::SDL_EventType_SDL_EVENT_FINGER_DOWN => Self::FingerDown,
+            sys::SDL_EventType_SDL_EVENT_FINGER_UP => Self::FingerUp,
+            sys::SDL_EventType_SDL_EVENT_FINGER_MOTION => Self::FingerMotion,
+            sys::SDL_EventType_SDL_EVENT_FINGER_CANCELED => Self::FingerCanceled,
+            sys::SDL_EventType_SDL_EVENT_CLIPBOARD_UPDATE => Self::ClipboardUpdate,
+            sys::SDL_EventType_SDL_EVENT_DROP_FILE => Self::DropFile,
+            sys::SDL_EventType_SDL_EVENT_DROP_TEXT => Self::DropText,
+            sys::SDL_EventType_SDL_EVENT_DROP_BEGIN => Self::DropBegin,
+            sys::SDL_EventType_SDL_EVENT_DROP_COMPLETE => Self::DropComplete,
+            sys::SDL_EventType_SDL_EVENT_DROP_POSITION => Self::DropPosition,
+            sys::SDL_EventType_SDL_EVENT_AUDIO_DEVICE_ADDED => Self::AudioDeviceAdded,
+            sys::SDL_EventType_SDL_EVENT_AUDIO_DEVICE_REMOVED => Self::AudioDeviceRemoved,
+            sys::SDL_EventType_SDL_EVENT_AUDIO_DEVICE_FORMAT_CHANGED => {
+                Self::AudioDeviceFormatChanged
+            }
+            sys::SDL_EventType_SDL_EVENT_SENSOR_UPDATE => Self::SensorUpdate,
+            sys::SDL_EventType_SDL_EVENT_PEN_PROXIMITY_IN => Self::PenProximityIn,
+            sys::SDL_EventType_SDL_EVENT_PEN_PROXIMITY_OUT => Self::PenProximityOut,
+            sys::SDL_EventType_SDL_EVENT_PEN_DOWN => Self::PenDown,
+            sys::SDL_EventType_SDL_EVENT_PEN_UP => Self::PenUp,
+            sys::SDL_EventType_SDL_EVENT_PEN_BUTTON_DOWN => Self::PenButtonDown,
+            sys::SDL_EventType_SDL_EVENT_PEN_BUTTON_UP => Self::PenButtonUp,
+            sys::SDL_EventType_SDL_EVENT_PEN_MOTION => Self::PenMotion,
+            sys::SDL_EventType_SDL_EVENT_PEN_AXIS => Self::PenAxis,
+            sys::SDL_EventType_SDL_EVENT_CAMERA_DEVICE_ADDED => Self::CameraDeviceAdded,
+            sys::SDL_EventType_SDL_EVENT_CAMERA_DEVICE_REMOVED => Self::CameraDeviceRemoved,
+            sys::SDL_EventType_SDL_EVENT_CAMERA_DEVICE_APPROVED => Self::CameraDeviceApproved,
+            sys::SDL_EventType_SDL_EVENT_CAMERA_DEVICE_DENIED => Self::CameraDeviceDenied,
+            sys::SDL_EventType_SDL_EVENT_RENDER_TARGETS_RESET => Self::RenderTargetsReset,
+            sys::SDL_EventType_SDL_EVENT_RENDER_DEVICE_RESET => Self::RenderDeviceReset,
+            sys::SDL_EventType_SDL_EVENT_RENDER_DEVICE_LOST => Self::RenderDeviceLost,
+            sys::SDL_EventType_SDL_EVENT_USER => Self::User,
+            sys::SDL_EventType_SDL_EVENT_LAST => Self::Last,
+            _ => return None,
+        })
+    }
+}
+
 /// A wrapper on top of [`sys::SDL_Event`].
 ///
 /// To read the contents of the event, convert this type into an [`EventPayload`] by calling
@@ -264,32 +1076,190 @@ impl EventAction {
 #[derive(Copy, Clone)]
 pub struct Event(pub(crate) sys::SDL_Event);
 
+/// An event of type [`EventType::First`] with no data, e.g. to fill a buffer for
+/// [`EventPump::peep_events`].
+impl Default for Event {
+    fn default() -> Self {
+        Self(unsafe { core::mem::zeroed() })
+    }
+}
+
 impl Event {
+    /// Creates an application-defined event, to be sent with [`EventQueue::push_event`].
+    ///
+    /// `event_type` should be [`EventType::User`] or a value past it.
+    pub fn user(event_type: u32, window_id: u32, code: i32) -> Self {
+        let mut event: sys::SDL_Event = unsafe { core::mem::zeroed() };
+        event.user.type_ = event_type;
+        event.user.windowID = window_id;
+        event.user.code = code;
+        Self(event)
+    }
+
     /// Event type id.
     #[inline]
     pub fn event_type(&self) -> u32 {
         unsafe { self.0.type_ }
     }
 
+    /// Returns the code of an application-defined event, or `None` if this is one of SDL's
+    /// events.
+    pub fn user_code(&self) -> Option<i32> {
+        if self.event_type() < sys::SDL_EventType_SDL_EVENT_USER {
+            return None;
+        }
+        Some(unsafe { self.0.user.code })
+    }
+
+    /// Takes the payload of an event pushed with [`EventQueue::push_custom`].
+    ///
+    /// Events are `Copy`, but a payload can only be taken once: this returns `None` for other
+    /// copies of the event, and if the payload isn't a `T`, in which case it's left in place.
+    pub fn take_custom<T: Any>(&self) -> Option<Box<T>> {
+        let id = self.custom_payload_id()?;
+        let payload = CUSTOM_PAYLOADS.with(|state| {
+            if !(**state.payloads.get(&id)?).is::<T>() {
+                return None;
+            }
+            state.payloads.remove(&id)
+        })?;
+        payload.downcast().ok()
+    }
+
+    /// Drops the payload of an event pushed with [`EventQueue::push_custom`] without taking it.
+    /// Returns false if there was no payload, or it was already taken.
+    pub fn drop_custom(&self) -> bool {
+        let Some(id) = self.custom_payload_id() else {
+            return false;
+        };
+        let payload = CUSTOM_PAYLOADS.with(|state| state.payloads.remove(&id));
+        payload.is_some()
+    }
+
+    fn custom_payload_id(&self) -> Option<usize> {
+        if self.event_type() < sys::SDL_EventType_SDL_EVENT_USER {
+            return None;
+        }
+        let user = unsafe { self.0.user };
+        (user.data2 == CUSTOM_PAYLOADS.marker()).then_some(user.data1 as usize)
+    }
+
+    /// Returns true if this event was pushed by [`EventQueue::wake_up`].
+    pub fn is_wake_up(&self) -> bool {
+        let wake_up = WAKE_UP_EVENT_TYPE.load(Ordering::Acquire);
+        wake_up != 0 && self.event_type() == wake_up
+    }
+
     /// Parses the raw SDL event and converts it into an `EventPayload` that can be safely
     /// accessed.
     pub fn payload(&self) -> EventPayload {
         EventPayload::from_ll(self.0)
     }
+
+    /// Returns true if this event is a key press of the paste shortcut: `Ctrl+V`, or `Cmd+V` on
+    /// Apple platforms.
+    ///
+    /// Key repeats are included, mirroring how text editors behave when the shortcut is held.
+    pub fn is_paste_shortcut(&self) -> bool {
+        unsafe {
+            if self.0.type_ != sys::SDL_EventType_SDL_EVENT_KEY_DOWN {
+                return false;
+            }
+            #[cfg(target_vendor = "apple")]
+            let shortcut_modifier = sys::SDL_KMOD_GUI;
+            #[cfg(not(target_vendor = "apple"))]
+            let shortcut_modifier = sys::SDL_KMOD_CTRL;
+            let modifiers = self.0.key.mod_ as u32;
+            self.0.key.key == sys::SDLK_V && modifiers & shortcut_modifier != 0
+        }
+    }
 }
 
 /// Payload of an SDL event.
 ///
 /// The contents of a raw [`sys::SDL_Event`] are transformed into this value.
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub enum EventPayload {
     Window(WindowEvent),
+    /// A display was connected, disconnected or changed, e.g. its refresh rate.
+    Display(DisplayEvent),
+    KeyDown(KeyboardEvent),
+    KeyUp(KeyboardEvent),
+    TextInput(TextInputEvent),
+    MouseMotion(MouseMotionEvent),
+    MouseButtonDown(MouseButtonEvent),
+    MouseButtonUp(MouseButtonEvent),
+    MouseWheel(MouseWheelEvent),
+    FingerDown(TouchFingerEvent),
+    FingerUp(TouchFingerEvent),
+    FingerMotion(TouchFingerEvent),
+    /// A finger touch was canceled, e.g. because the system took over the gesture.
+    FingerCanceled(TouchFingerEvent),
+    /// A pen became visible to the system, e.g. by hovering close enough to a tablet.
+    PenProximityIn(PenProximityEvent),
+    PenProximityOut(PenProximityEvent),
+    PenDown(PenTouchEvent),
+    PenUp(PenTouchEvent),
+    PenButtonDown(PenButtonEvent),
+    PenButtonUp(PenButtonEvent),
+    PenMotion(PenMotionEvent),
+    PenAxis(PenAxisEvent),
     Camera(CameraEvent),
+    /// An audio device was connected, disconnected or changed its format.
+    AudioDevice(AudioDeviceEvent),
+    /// Something is being dragged and dropped onto a window.
+    Drop(DropEvent),
+    Joystick(JoystickEvent),
+    Gamepad(GamepadEvent),
+    /// A sensor of an opened gamepad was updated, see [`crate::gamepad::Gamepad`].
+    GamepadSensor(GamepadSensorEvent),
+    Sensor(SensorEvent),
+    Render(RenderEvent),
+    /// The OS is terminating the application. Called `onDestroy()` on Android and
+    /// `applicationWillTerminate()` on iOS.
+    Terminating,
+    /// The OS is low on memory; free as much as possible.
+    LowMemory,
+    /// The application is about to enter the background. Rendering must stop until
+    /// [`EventPayload::DidEnterForeground`].
+    WillEnterBackground,
+    DidEnterBackground,
+    WillEnterForeground,
+    /// The application is interactive again.
+    DidEnterForeground,
     Quit,
     Unknown,
 }
 
 impl EventPayload {
+    /// Returns the id of the window the event is tied to, if any.
+    ///
+    /// Input events whose window is unknown, such as key presses while no window has focus, return
+    /// `None`. See [`crate::video::WindowManager`] to route events to windows.
+    pub fn window_id(&self) -> Option<u32> {
+        let id = match self {
+            Self::Window(event) => event.window_id,
+            Self::KeyDown(event) | Self::KeyUp(event) => event.window_id,
+            Self::TextInput(event) => event.window_id,
+            Self::MouseMotion(event) => event.window_id,
+            Self::MouseButtonDown(event) | Self::MouseButtonUp(event) => event.window_id,
+            Self::MouseWheel(event) => event.window_id,
+            Self::FingerDown(event)
+            | Self::FingerUp(event)
+            | Self::FingerMotion(event)
+            | Self::FingerCanceled(event) => event.window_id,
+            Self::PenProximityIn(event) | Self::PenProximityOut(event) => event.window_id,
+            Self::PenDown(event) | Self::PenUp(event) => event.window_id,
+            Self::PenButtonDown(event) | Self::PenButtonUp(event) => event.window_id,
+            Self::PenMotion(event) => event.window_id,
+            Self::PenAxis(event) => event.window_id,
+            Self::Render(event) => event.window_id,
+            Self::Drop(event) => event.window_id,
+            _ => 0,
+        };
+        (id != 0).then_some(id)
+    }
+
     /// Converts a [`sys::SDL_Event`] to an [`Event`].
     /// Returns [`Event::Unknown`] if `event` is not a valid [`sys::SDL_Event`].
     fn from_ll(event: sys::SDL_Event) -> Self {
@@ -442,6 +1412,343 @@ impl EventPayload {
                         window_id: event.window.windowID,
                     })
                 }
+                sys::SDL_EventType_SDL_EVENT_KEY_DOWN => {
+                    Self::KeyDown(KeyboardEvent::from_ll(&event.key))
+                }
+                sys::SDL_EventType_SDL_EVENT_KEY_UP => {
+                    Self::KeyUp(KeyboardEvent::from_ll(&event.key))
+                }
+                sys::SDL_EventType_SDL_EVENT_TEXT_INPUT => Self::TextInput(TextInputEvent {
+                    timestamp: event.text.timestamp,
+                    window_id: event.text.windowID,
+                    text: if event.text.text.is_null() {
+                        String::new()
+                    } else {
+                        CStr::from_ptr(event.text.text)
+                            .to_string_lossy()
+                            .into_owned()
+                    },
+                }),
+                sys::SDL_EventType_SDL_EVENT_MOUSE_MOTION => Self::MouseMotion(MouseMotionEvent {
+                    timestamp: event.motion.timestamp,
+                    window_id: event.motion.windowID,
+                    which: event.motion.which,
+                    state: MouseButtonFlags::from_ll(event.motion.state),
+                    x: event.motion.x,
+                    y: event.motion.y,
+                    xrel: event.motion.xrel,
+                    yrel: event.motion.yrel,
+                }),
+                sys::SDL_EventType_SDL_EVENT_MOUSE_BUTTON_DOWN => {
+                    Self::MouseButtonDown(MouseButtonEvent::from_ll(&event.button))
+                }
+                sys::SDL_EventType_SDL_EVENT_MOUSE_BUTTON_UP => {
+                    Self::MouseButtonUp(MouseButtonEvent::from_ll(&event.button))
+                }
+                sys::SDL_EventType_SDL_EVENT_MOUSE_WHEEL => Self::MouseWheel(MouseWheelEvent {
+                    timestamp: event.wheel.timestamp,
+                    window_id: event.wheel.windowID,
+                    which: event.wheel.which,
+                    x: event.wheel.x,
+                    y: event.wheel.y,
+                    direction: MouseWheelDirection::from_ll(event.wheel.direction),
+                    mouse_x: event.wheel.mouse_x,
+                    mouse_y: event.wheel.mouse_y,
+                }),
+                sys::SDL_EventType_SDL_EVENT_FINGER_DOWN => {
+                    Self::FingerDown(TouchFingerEvent::from_ll(&event.tfinger))
+                }
+                sys::SDL_EventType_SDL_EVENT_FINGER_UP => {
+                    Self::FingerUp(TouchFingerEvent::from_ll(&event.tfinger))
+                }
+                sys::SDL_EventType_SDL_EVENT_FINGER_MOTION => {
+                    Self::FingerMotion(TouchFingerEvent::from_ll(&event.tfinger))
+                }
+                sys::SDL_EventType_SDL_EVENT_FINGER_CANCELED => {
+                    Self::FingerCanceled(TouchFingerEvent::from_ll(&event.tfinger))
+                }
+                sys::SDL_EventType_SDL_EVENT_PEN_PROXIMITY_IN => {
+                    Self::PenProximityIn(PenProximityEvent::from_ll(&event.pproximity))
+                }
+                sys::SDL_EventType_SDL_EVENT_PEN_PROXIMITY_OUT => {
+                    Self::PenProximityOut(PenProximityEvent::from_ll(&event.pproximity))
+                }
+                sys::SDL_EventType_SDL_EVENT_PEN_DOWN => {
+                    Self::PenDown(PenTouchEvent::from_ll(&event.ptouch))
+                }
+                sys::SDL_EventType_SDL_EVENT_PEN_UP => {
+                    Self::PenUp(PenTouchEvent::from_ll(&event.ptouch))
+                }
+                sys::SDL_EventType_SDL_EVENT_PEN_BUTTON_DOWN => {
+                    Self::PenButtonDown(PenButtonEvent::from_ll(&event.pbutton))
+                }
+                sys::SDL_EventType_SDL_EVENT_PEN_BUTTON_UP => {
+                    Self::PenButtonUp(PenButtonEvent::from_ll(&event.pbutton))
+                }
+                sys::SDL_EventType_SDL_EVENT_PEN_MOTION => Self::PenMotion(PenMotionEvent {
+                    timestamp: event.pmotion.timestamp,
+                    window_id: event.pmotion.windowID,
+                    which: event.pmotion.which,
+                    pen_state: PenInputFlags::from_ll(event.pmotion.pen_state),
+                    x: event.pmotion.x,
+                    y: event.pmotion.y,
+                }),
+                sys::SDL_EventType_SDL_EVENT_PEN_AXIS => Self::PenAxis(PenAxisEvent {
+                    timestamp: event.paxis.timestamp,
+                    window_id: event.paxis.windowID,
+                    which: event.paxis.which,
+                    pen_state: PenInputFlags::from_ll(event.paxis.pen_state),
+                    x: event.paxis.x,
+                    y: event.paxis.y,
+                    axis: PenAxis::from_ll(event.paxis.axis),
+                    value: event.paxis.value,
+                }),
+                sys::SDL_EventType_SDL_EVENT_CAMERA_DEVICE_ADDED
+                | sys::SDL_EventType_SDL_EVENT_CAMERA_DEVICE_REMOVED
+                | sys::SDL_EventType_SDL_EVENT_CAMERA_DEVICE_APPROVED
+                | sys::SDL_EventType_SDL_EVENT_CAMERA_DEVICE_DENIED => {
+                    let payload = match event.type_ {
+                        sys::SDL_EventType_SDL_EVENT_CAMERA_DEVICE_ADDED => {
+                            CameraEventPayload::Added
+                        }
+                        sys::SDL_EventType_SDL_EVENT_CAMERA_DEVICE_REMOVED => {
+                            CameraEventPayload::Removed
+                        }
+                        sys::SDL_EventType_SDL_EVENT_CAMERA_DEVICE_APPROVED => {
+                            CameraEventPayload::DeviceApproved
+                        }
+                        _ => CameraEventPayload::DeviceDenied,
+                    };
+                    Self::Camera(CameraEvent {
+                        payload,
+                        timestamp: event.cdevice.timestamp,
+                        which: event.cdevice.which,
+                    })
+                }
+                sys::SDL_EventType_SDL_EVENT_AUDIO_DEVICE_ADDED
+                | sys::SDL_EventType_SDL_EVENT_AUDIO_DEVICE_REMOVED
+                | sys::SDL_EventType_SDL_EVENT_AUDIO_DEVICE_FORMAT_CHANGED => {
+                    let payload = match event.type_ {
+                        sys::SDL_EventType_SDL_EVENT_AUDIO_DEVICE_ADDED => {
+                            AudioDeviceEventPayload::Added
+                        }
+                        sys::SDL_EventType_SDL_EVENT_AUDIO_DEVICE_REMOVED => {
+                            AudioDeviceEventPayload::Removed
+                        }
+                        _ => AudioDeviceEventPayload::FormatChanged,
+                    };
+                    Self::AudioDevice(AudioDeviceEvent {
+                        payload,
+                        timestamp: event.adevice.timestamp,
+                        which: event.adevice.which,
+                        recording: event.adevice.recording,
+                    })
+                }
+                sys::SDL_EventType_SDL_EVENT_JOYSTICK_ADDED => Self::Joystick(JoystickEvent {
+                    payload: JoystickEventPayload::Added,
+                    timestamp: event.jdevice.timestamp,
+                    which: event.jdevice.which,
+                }),
+                sys::SDL_EventType_SDL_EVENT_JOYSTICK_REMOVED => Self::Joystick(JoystickEvent {
+                    payload: JoystickEventPayload::Removed,
+                    timestamp: event.jdevice.timestamp,
+                    which: event.jdevice.which,
+                }),
+                sys::SDL_EventType_SDL_EVENT_JOYSTICK_UPDATE_COMPLETE => {
+                    Self::Joystick(JoystickEvent {
+                        payload: JoystickEventPayload::UpdateComplete,
+                        timestamp: event.jdevice.timestamp,
+                        which: event.jdevice.which,
+                    })
+                }
+                sys::SDL_EventType_SDL_EVENT_JOYSTICK_AXIS_MOTION => {
+                    Self::Joystick(JoystickEvent {
+                        payload: JoystickEventPayload::AxisMotion {
+                            axis: event.jaxis.axis,
+                            value: event.jaxis.value,
+                        },
+                        timestamp: event.jaxis.timestamp,
+                        which: event.jaxis.which,
+                    })
+                }
+                sys::SDL_EventType_SDL_EVENT_JOYSTICK_BALL_MOTION => {
+                    Self::Joystick(JoystickEvent {
+                        payload: JoystickEventPayload::BallMotion {
+                            ball: event.jball.ball,
+                            xrel: event.jball.xrel,
+                            yrel: event.jball.yrel,
+                        },
+                        timestamp: event.jball.timestamp,
+                        which: event.jball.which,
+                    })
+                }
+                sys::SDL_EventType_SDL_EVENT_JOYSTICK_HAT_MOTION => Self::Joystick(JoystickEvent {
+                    payload: JoystickEventPayload::HatMotion {
+                        hat: event.jhat.hat,
+                        value: JoystickHat::from_ll(event.jhat.value),
+                    },
+                    timestamp: event.jhat.timestamp,
+                    which: event.jhat.which,
+                }),
+                sys::SDL_EventType_SDL_EVENT_JOYSTICK_BUTTON_DOWN
+                | sys::SDL_EventType_SDL_EVENT_JOYSTICK_BUTTON_UP => {
+                    Self::Joystick(JoystickEvent {
+                        payload: JoystickEventPayload::Button {
+                            button: event.jbutton.button,
+                            down: event.jbutton.down,
+                        },
+                        timestamp: event.jbutton.timestamp,
+                        which: event.jbutton.which,
+                    })
+                }
+                sys::SDL_EventType_SDL_EVENT_JOYSTICK_BATTERY_UPDATED => {
+                    Self::Joystick(JoystickEvent {
+                        payload: JoystickEventPayload::BatteryUpdated(
+                            PowerInfo::from_ll(event.jbattery.state, event.jbattery.percent)
+                                .unwrap_or(PowerInfo {
+                                    state: PowerState::Unknown,
+                                    percent: None,
+                                }),
+                        ),
+                        timestamp: event.jbattery.timestamp,
+                        which: event.jbattery.which,
+                    })
+                }
+                sys::SDL_EventType_SDL_EVENT_GAMEPAD_ADDED => Self::Gamepad(GamepadEvent {
+                    payload: GamepadEventPayload::Added,
+                    timestamp: event.gdevice.timestamp,
+                    which: event.gdevice.which,
+                }),
+                sys::SDL_EventType_SDL_EVENT_GAMEPAD_REMOVED => Self::Gamepad(GamepadEvent {
+                    payload: GamepadEventPayload::Removed,
+                    timestamp: event.gdevice.timestamp,
+                    which: event.gdevice.which,
+                }),
+                sys::SDL_EventType_SDL_EVENT_GAMEPAD_REMAPPED => Self::Gamepad(GamepadEvent {
+                    payload: GamepadEventPayload::Remapped,
+                    timestamp: event.gdevice.timestamp,
+                    which: event.gdevice.which,
+                }),
+                sys::SDL_EventType_SDL_EVENT_GAMEPAD_UPDATE_COMPLETE => {
+                    Self::Gamepad(GamepadEvent {
+                        payload: GamepadEventPayload::UpdateComplete,
+                        timestamp: event.gdevice.timestamp,
+                        which: event.gdevice.which,
+                    })
+                }
+                sys::SDL_EventType_SDL_EVENT_GAMEPAD_AXIS_MOTION => Self::Gamepad(GamepadEvent {
+                    payload: GamepadEventPayload::AxisMotion {
+                        axis: GamepadAxis::from_ll(event.gaxis.axis as i32),
+                        value: event.gaxis.value,
+                    },
+                    timestamp: event.gaxis.timestamp,
+                    which: event.gaxis.which,
+                }),
+                sys::SDL_EventType_SDL_EVENT_GAMEPAD_BUTTON_DOWN
+                | sys::SDL_EventType_SDL_EVENT_GAMEPAD_BUTTON_UP => Self::Gamepad(GamepadEvent {
+                    payload: GamepadEventPayload::Button {
+                        button: GamepadButton::from_ll(event.gbutton.button as i32),
+                        down: event.gbutton.down,
+                    },
+                    timestamp: event.gbutton.timestamp,
+                    which: event.gbutton.which,
+                }),
+                sys::SDL_EventType_SDL_EVENT_GAMEPAD_TOUCHPAD_DOWN
+                | sys::SDL_EventType_SDL_EVENT_GAMEPAD_TOUCHPAD_MOTION
+                | sys::SDL_EventType_SDL_EVENT_GAMEPAD_TOUCHPAD_UP => {
+                    let touchpad = event.gtouchpad.touchpad.max(0) as u32;
+                    let finger = event.gtouchpad.finger.max(0) as u32;
+                    let x = event.gtouchpad.x;
+                    let y = event.gtouchpad.y;
+                    let pressure = event.gtouchpad.pressure;
+                    let payload = match event.type_ {
+                        sys::SDL_EventType_SDL_EVENT_GAMEPAD_TOUCHPAD_DOWN => {
+                            GamepadEventPayload::TouchpadDown {
+                                touchpad,
+                                finger,
+                                x,
+                                y,
+                                pressure,
+                            }
+                        }
+                        sys::SDL_EventType_SDL_EVENT_GAMEPAD_TOUCHPAD_MOTION => {
+                            GamepadEventPayload::TouchpadMotion {
+                                touchpad,
+                                finger,
+                                x,
+                                y,
+                                pressure,
+                            }
+                        }
+                        _ => GamepadEventPayload::TouchpadUp {
+                            touchpad,
+                            finger,
+                            x,
+                            y,
+                            pressure,
+                        },
+                    };
+                    Self::Gamepad(GamepadEvent {
+                        payload,
+                        timestamp: event.gtouchpad.timestamp,
+                        which: event.gtouchpad.which,
+                    })
+                }
+                sys::SDL_EventType_SDL_EVENT_GAMEPAD_SENSOR_UPDATE => {
+                    Self::GamepadSensor(GamepadSensorEvent {
+                        timestamp: event.gsensor.timestamp,
+                        which: event.gsensor.which,
+                        sensor: SensorType::from_ll(event.gsensor.sensor),
+                        data: event.gsensor.data,
+                        sensor_timestamp: event.gsensor.sensor_timestamp,
+                    })
+                }
+                sys::SDL_EventType_SDL_EVENT_SENSOR_UPDATE => Self::Sensor(SensorEvent {
+                    timestamp: event.sensor.timestamp,
+                    which: event.sensor.which,
+                    data: event.sensor.data,
+                    sensor_timestamp: event.sensor.sensor_timestamp,
+                }),
+                sys::SDL_EventType_SDL_EVENT_RENDER_TARGETS_RESET
+                | sys::SDL_EventType_SDL_EVENT_RENDER_DEVICE_RESET
+                | sys::SDL_EventType_SDL_EVENT_RENDER_DEVICE_LOST => {
+                    let payload = match event.type_ {
+                        sys::SDL_EventType_SDL_EVENT_RENDER_TARGETS_RESET => {
+                            RenderEventPayload::TargetsReset
+                        }
+                        sys::SDL_EventType_SDL_EVENT_RENDER_DEVICE_RESET => {
+                            RenderEventPayload::DeviceReset
+                        }
+                        _ => RenderEventPayload::DeviceLost,
+                    };
+                    Self::Render(RenderEvent {
+                        payload,
+                        timestamp: event.render.timestamp,
+                        window_id: event.render.windowID,
+                    })
+                }
+                sys::SDL_EventType_SDL_EVENT_DISPLAY_ORIENTATION
+                | sys::SDL_EventType_SDL_EVENT_DISPLAY_ADDED
+                | sys::SDL_EventType_SDL_EVENT_DISPLAY_REMOVED
+                | sys::SDL_EventType_SDL_EVENT_DISPLAY_MOVED
+                | sys::SDL_EventType_SDL_EVENT_DISPLAY_DESKTOP_MODE_CHANGED
+                | sys::SDL_EventType_SDL_EVENT_DISPLAY_CURRENT_MODE_CHANGED
+                | sys::SDL_EventType_SDL_EVENT_DISPLAY_CONTENT_SCALE_CHANGED => {
+                    Self::Display(DisplayEvent::from_ll(&event.display))
+                }
+                sys::SDL_EventType_SDL_EVENT_DROP_BEGIN
+                | sys::SDL_EventType_SDL_EVENT_DROP_FILE
+                | sys::SDL_EventType_SDL_EVENT_DROP_TEXT
+                | sys::SDL_EventType_SDL_EVENT_DROP_POSITION
+                | sys::SDL_EventType_SDL_EVENT_DROP_COMPLETE => {
+                    Self::Drop(DropEvent::from_ll(&event.drop))
+                }
+                sys::SDL_EventType_SDL_EVENT_TERMINATING => Self::Terminating,
+                sys::SDL_EventType_SDL_EVENT_LOW_MEMORY => Self::LowMemory,
+                sys::SDL_EventType_SDL_EVENT_WILL_ENTER_BACKGROUND => Self::WillEnterBackground,
+                sys::SDL_EventType_SDL_EVENT_DID_ENTER_BACKGROUND => Self::DidEnterBackground,
+                sys::SDL_EventType_SDL_EVENT_WILL_ENTER_FOREGROUND => Self::WillEnterForeground,
+                sys::SDL_EventType_SDL_EVENT_DID_ENTER_FOREGROUND => Self::DidEnterForeground,
                 sys::SDL_EventType_SDL_EVENT_QUIT => Self::Quit,
                 _ => Self::Unknown,
             }
@@ -460,15 +1767,23 @@ pub struct WindowEvent {
 /// Payload of an event tied to a [`crate::video::Window`].
 #[derive(Copy, Clone, Debug)]
 pub enum WindowEventPayload {
-    Moved { x: i32, y: i32 },
+    Moved {
+        x: i32,
+        y: i32,
+    },
     Shown,
     Hidden,
     MouseEnter,
     MouseLeave,
     Unknown,
+    /// The window became visible again after being occluded, and should be redrawn.
     Exposed,
-    Resized { w: u32, h: u32 },
+    Resized {
+        w: u32,
+        h: u32,
+    },
     HitTest,
+    /// The window is fully covered by other windows. See [`crate::video::VisibilityTracker`].
     Occluded,
     Restored,
     Destroyed,
@@ -477,20 +1792,634 @@ pub enum WindowEventPayload {
     FocusLost,
     FocusGained,
     CloseRequested,
-    DisplayChanged { display_id: u32 },
+    DisplayChanged {
+        display_id: u32,
+    },
     IccProfileChanged,
     EnterFullscreen,
     LeaveFullscreen,
     SafeAreaChanged,
     HdrStateChanged,
     MetalViewResized,
-    PixelSizeChanged { w: u32, h: u32 },
+    PixelSizeChanged {
+        w: u32,
+        h: u32,
+    },
     DisplayScaleChanged,
 }
 
-/// Payload of an event tied to a [`crate::camera::Camera`].
+/// An event tied to a display.
+#[derive(Copy, Clone, Debug)]
+pub struct DisplayEvent {
+    pub payload: DisplayEventPayload,
+    pub timestamp: u64,
+    pub display_id: u32,
+}
+
+impl DisplayEvent {
+    fn from_ll(event: &sys::SDL_DisplayEvent) -> Self {
+        let payload = match event.type_ {
+            sys::SDL_EventType_SDL_EVENT_DISPLAY_ORIENTATION => DisplayEventPayload::Orientation(
+                DisplayOrientation::try_from_ll(event.data1.max(0) as u32)
+                    .unwrap_or(DisplayOrientation::Unknown),
+            ),
+            sys::SDL_EventType_SDL_EVENT_DISPLAY_ADDED => DisplayEventPayload::Added,
+            sys::SDL_EventType_SDL_EVENT_DISPLAY_REMOVED => DisplayEventPayload::Removed,
+            sys::SDL_EventType_SDL_EVENT_DISPLAY_MOVED => DisplayEventPayload::Moved,
+            sys::SDL_EventType_SDL_EVENT_DISPLAY_DESKTOP_MODE_CHANGED => {
+                DisplayEventPayload::DesktopModeChanged
+            }
+            sys::SDL_EventType_SDL_EVENT_DISPLAY_CURRENT_MODE_CHANGED => {
+                DisplayEventPayload::CurrentModeChanged
+            }
+            _ => DisplayEventPayload::ContentScaleChanged,
+        };
+        Self {
+            payload,
+            timestamp: event.timestamp,
+            display_id: event.displayID,
+        }
+    }
+}
+
+/// Payload of an event tied to a display.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DisplayEventPayload {
+    Orientation(DisplayOrientation),
+    Added,
+    Removed,
+    /// The display's position relative to the other displays changed.
+    Moved,
+    DesktopModeChanged,
+    /// The display's current mode, e.g. its resolution or refresh rate, changed.
+    CurrentModeChanged,
+    ContentScaleChanged,
+}
+
+/// A key was pressed or released.
+#[derive(Copy, Clone, Debug)]
+pub struct KeyboardEvent {
+    pub timestamp: u64,
+    /// The window with keyboard focus, if any.
+    pub window_id: u32,
+    /// The keyboard instance id, or 0 if unknown or virtual.
+    pub which: KeyboardId,
+    /// Physical key code.
+    pub scancode: Scancode,
+    /// Virtual key code, according to the current keyboard layout.
+    pub keycode: Keycode,
+    /// Modifiers held down when the event happened.
+    pub keymod: Keymod,
+    pub down: bool,
+    /// True if this is a key repeat.
+    pub repeat: bool,
+}
+
+impl KeyboardEvent {
+    fn from_ll(event: &sys::SDL_KeyboardEvent) -> Self {
+        Self {
+            timestamp: event.timestamp,
+            window_id: event.windowID,
+            which: event.which,
+            scancode: Scancode::from_ll(event.scancode),
+            keycode: Keycode::from_ll(event.key),
+            keymod: Keymod::from_ll(event.mod_),
+            down: event.down,
+            repeat: event.repeat,
+        }
+    }
+}
+
+/// Text was entered while text input was active.
+///
+/// The text is UTF-8 encoded and may contain more than one character, for instance when an input
+/// method commits a composition.
+#[derive(Clone, Debug)]
+pub struct TextInputEvent {
+    pub timestamp: u64,
+    /// The window with keyboard focus, if any.
+    pub window_id: u32,
+    pub text: String,
+}
+
+/// The mouse moved.
+///
+/// Coordinates are relative to the window and may be fractional on high-DPI displays.
+#[derive(Copy, Clone, Debug)]
+pub struct MouseMotionEvent {
+    pub timestamp: u64,
+    /// The window with mouse focus, if any.
+    pub window_id: u32,
+    /// The mouse instance id, or [`crate::mouse::TOUCH_MOUSE_ID`] for touch input.
+    pub which: MouseId,
+    /// Buttons held down during the motion.
+    pub state: MouseButtonFlags,
+    pub x: f32,
+    pub y: f32,
+    pub xrel: f32,
+    pub yrel: f32,
+}
+
+/// A mouse button was pressed or released.
+#[derive(Copy, Clone, Debug)]
+pub struct MouseButtonEvent {
+    pub timestamp: u64,
+    /// The window with mouse focus, if any.
+    pub window_id: u32,
+    /// The mouse instance id, or [`crate::mouse::TOUCH_MOUSE_ID`] for touch input.
+    pub which: MouseId,
+    pub button: MouseButton,
+    pub down: bool,
+    /// 1 for a single click, 2 for a double click, etc.
+    pub clicks: u8,
+    pub x: f32,
+    pub y: f32,
+}
+
+impl MouseButtonEvent {
+    fn from_ll(event: &sys::SDL_MouseButtonEvent) -> Self {
+        Self {
+            timestamp: event.timestamp,
+            window_id: event.windowID,
+            which: event.which,
+            button: MouseButton::from_ll(event.button),
+            down: event.down,
+            clicks: event.clicks,
+            x: event.x,
+            y: event.y,
+        }
+    }
+}
+
+/// The mouse wheel was scrolled.
+#[derive(Copy, Clone, Debug)]
+pub struct MouseWheelEvent {
+    pub timestamp: u64,
+    /// The window with mouse focus, if any.
+    pub window_id: u32,
+    /// The mouse instance id.
+    pub which: MouseId,
+    /// Amount scrolled horizontally, positive to the right and negative to the left.
+    pub x: f32,
+    /// Amount scrolled vertically, positive away from the user and negative towards the user.
+    pub y: f32,
+    /// When [`MouseWheelDirection::Flipped`], `x` and `y` are inverted; multiply by -1 to get the
+    /// physical direction.
+    pub direction: MouseWheelDirection,
+    /// Mouse x position, relative to the window.
+    pub mouse_x: f32,
+    /// Mouse y position, relative to the window.
+    pub mouse_y: f32,
+}
+
+/// A finger touched, moved on or left a touch device.
+///
+/// For [`crate::touch::TouchDeviceType::Direct`] devices, coordinates are normalized between 0 and
+/// 1 relative to the window, with the origin at the upper left. Other devices report normalized
+/// device coordinates.
+#[derive(Copy, Clone, Debug)]
+pub struct TouchFingerEvent {
+    pub timestamp: u64,
+    /// Instance id of the touch device, see [`crate::touch::TouchDevice::from_id`].
+    pub touch_id: TouchId,
+    pub finger_id: FingerId,
+    pub x: f32,
+    pub y: f32,
+    pub dx: f32,
+    pub dy: f32,
+    /// Normalized pressure, between 0 and 1.
+    pub pressure: f32,
+    /// The window underneath the finger, if any.
+    pub window_id: u32,
+}
+
+impl TouchFingerEvent {
+    fn from_ll(ll: &sys::SDL_TouchFingerEvent) -> Self {
+        Self {
+            timestamp: ll.timestamp,
+            touch_id: ll.touchID,
+            finger_id: ll.fingerID,
+            x: ll.x,
+            y: ll.y,
+            dx: ll.dx,
+            dy: ll.dy,
+            pressure: ll.pressure,
+            window_id: ll.windowID,
+        }
+    }
+}
+
+/// A pen entered or left proximity of the system.
+#[derive(Copy, Clone, Debug)]
+pub struct PenProximityEvent {
+    pub timestamp: u64,
+    /// The window with pen focus, if any.
+    pub window_id: u32,
+    pub which: PenId,
+}
+
+impl PenProximityEvent {
+    fn from_ll(ll: &sys::SDL_PenProximityEvent) -> Self {
+        Self {
+            timestamp: ll.timestamp,
+            window_id: ll.windowID,
+            which: ll.which,
+        }
+    }
+}
+
+/// A pen touched or was lifted from the drawing surface.
+#[derive(Copy, Clone, Debug)]
+pub struct PenTouchEvent {
+    pub timestamp: u64,
+    /// The window with pen focus, if any.
+    pub window_id: u32,
+    pub which: PenId,
+    pub pen_state: PenInputFlags,
+    pub x: f32,
+    pub y: f32,
+    /// True if the eraser end of the pen is used.
+    pub eraser: bool,
+    pub down: bool,
+}
+
+impl PenTouchEvent {
+    fn from_ll(ll: &sys::SDL_PenTouchEvent) -> Self {
+        Self {
+            timestamp: ll.timestamp,
+            window_id: ll.windowID,
+            which: ll.which,
+            pen_state: PenInputFlags::from_ll(ll.pen_state),
+            x: ll.x,
+            y: ll.y,
+            eraser: ll.eraser,
+            down: ll.down,
+        }
+    }
+}
+
+/// A button on a pen was pressed or released.
+#[derive(Copy, Clone, Debug)]
+pub struct PenButtonEvent {
+    pub timestamp: u64,
+    /// The window with pen focus, if any.
+    pub window_id: u32,
+    pub which: PenId,
+    pub pen_state: PenInputFlags,
+    pub x: f32,
+    pub y: f32,
+    /// The pen button index, starting at 1.
+    pub button: u8,
+    pub down: bool,
+}
+
+impl PenButtonEvent {
+    fn from_ll(ll: &sys::SDL_PenButtonEvent) -> Self {
+        Self {
+            timestamp: ll.timestamp,
+            window_id: ll.windowID,
+            which: ll.which,
+            pen_state: PenInputFlags::from_ll(ll.pen_state),
+            x: ll.x,
+            y: ll.y,
+            button: ll.button,
+            down: ll.down,
+        }
+    }
+}
+
+/// A pen moved.
+///
+/// Motion is also reported while the pen hovers; check [`PenInputFlags::DOWN`] to know if it's
+/// drawing.
+#[derive(Copy, Clone, Debug)]
+pub struct PenMotionEvent {
+    pub timestamp: u64,
+    /// The window with pen focus, if any.
+    pub window_id: u32,
+    pub which: PenId,
+    pub pen_state: PenInputFlags,
+    pub x: f32,
+    pub y: f32,
+}
+
+/// An axis of a pen, such as its pressure or tilt, changed.
+#[derive(Copy, Clone, Debug)]
+pub struct PenAxisEvent {
+    pub timestamp: u64,
+    /// The window with pen focus, if any.
+    pub window_id: u32,
+    pub which: PenId,
+    pub pen_state: PenInputFlags,
+    pub x: f32,
+    pub y: f32,
+    pub axis: PenAxis,
+    /// The new value of the axis, see [`PenAxis`] for its range.
+    pub value: f32,
+}
+
+/// An event tied to a [`crate::joystick::Joystick`].
+#[derive(Copy, Clone, Debug)]
+pub struct JoystickEvent {
+    pub payload: JoystickEventPayload,
+    pub timestamp: u64,
+    /// Instance id of the joystick that generated the event.
+    pub which: JoystickId,
+}
+
+/// Payload of an event tied to a [`crate::joystick::Joystick`].
+#[derive(Copy, Clone, Debug)]
+pub enum JoystickEventPayload {
+    /// A new joystick was connected.
+    Added,
+    /// An opened joystick was disconnected.
+    Removed,
+    /// Joystick update is complete; all state changes of this update have been delivered.
+    UpdateComplete,
+    AxisMotion {
+        axis: u8,
+        value: i16,
+    },
+    BallMotion {
+        ball: u8,
+        xrel: i16,
+        yrel: i16,
+    },
+    HatMotion {
+        hat: u8,
+        value: JoystickHat,
+    },
+    Button {
+        button: u8,
+        down: bool,
+    },
+    /// The battery state of the joystick changed. Also sent for gamepads.
+    BatteryUpdated(PowerInfo),
+}
+
+/// An event tied to a [`crate::gamepad::Gamepad`].
 #[derive(Copy, Clone, Debug)]
-pub enum CameraEvent {
+pub struct GamepadEvent {
+    pub payload: GamepadEventPayload,
+    pub timestamp: u64,
+    /// Instance id of the gamepad that generated the event.
+    pub which: JoystickId,
+}
+
+/// Payload of an event tied to a [`crate::gamepad::Gamepad`].
+///
+/// Touchpad coordinates are normalized between 0 and 1, with the origin at the upper left.
+#[derive(Copy, Clone, Debug)]
+pub enum GamepadEventPayload {
+    /// A new gamepad was connected.
+    Added,
+    /// An opened gamepad was disconnected.
+    Removed,
+    /// The mapping of the gamepad was updated.
+    Remapped,
+    /// Gamepad update is complete; all state changes of this update have been delivered.
+    UpdateComplete,
+    AxisMotion {
+        axis: GamepadAxis,
+        value: i16,
+    },
+    Button {
+        button: GamepadButton,
+        down: bool,
+    },
+    TouchpadDown {
+        touchpad: u32,
+        finger: u32,
+        x: f32,
+        y: f32,
+        pressure: f32,
+    },
+    TouchpadMotion {
+        touchpad: u32,
+        finger: u32,
+        x: f32,
+        y: f32,
+        pressure: f32,
+    },
+    TouchpadUp {
+        touchpad: u32,
+        finger: u32,
+        x: f32,
+        y: f32,
+        pressure: f32,
+    },
+}
+
+/// A sensor of an opened [`crate::gamepad::Gamepad`] was updated.
+#[derive(Copy, Clone, Debug)]
+pub struct GamepadSensorEvent {
+    pub timestamp: u64,
+    /// Instance id of the gamepad that generated the event.
+    pub which: JoystickId,
+    pub sensor: SensorType,
+    /// Up to 3 values from the sensor, see [`SensorType`] for their meaning.
+    pub data: [f32; 3],
+    /// Timestamp of the sensor reading in nanoseconds, not necessarily synchronized with the
+    /// system clock.
+    pub sensor_timestamp: u64,
+}
+
+/// An opened [`crate::sensor::Sensor`] was updated.
+#[derive(Copy, Clone, Debug)]
+pub struct SensorEvent {
+    pub timestamp: u64,
+    /// Instance id of the sensor that generated the event.
+    pub which: SensorId,
+    /// Up to 6 values from the sensor, see [`crate::sensor::SensorType`] for their meaning.
+    pub data: [f32; 6],
+    /// Timestamp of the sensor reading in nanoseconds, not necessarily synchronized with the
+    /// system clock.
+    pub sensor_timestamp: u64,
+}
+
+/// An event of a drag and drop operation onto a window.
+///
+/// A drop starts with [`DropEventPayload::Begin`], then sends [`DropEventPayload::Position`]
+/// while the cursor moves over the window, one [`DropEventPayload::File`] or
+/// [`DropEventPayload::Text`] per dropped item, and ends with [`DropEventPayload::Complete`].
+#[derive(Clone, Debug)]
+pub struct DropEvent {
+    pub payload: DropEventPayload,
+    pub timestamp: u64,
+    /// The window that was dropped on, if any.
+    pub window_id: u32,
+    /// Coordinates relative to the window. Unused by [`DropEventPayload::Begin`].
+    pub x: f32,
+    pub y: f32,
+    /// The application the data was dragged from, if known.
+    pub source: Option<String>,
+}
+
+impl DropEvent {
+    /// SAFETY: the strings of `event` must be valid, which holds until the next event is polled.
+    unsafe fn from_ll(event: &sys::SDL_DropEvent) -> Self {
+        let string = |ptr: *const core::ffi::c_char| {
+            (!ptr.is_null()).then(|| {
+                unsafe { CStr::from_ptr(ptr) }
+                    .to_string_lossy()
+                    .into_owned()
+            })
+        };
+        let payload = match event.type_ {
+            sys::SDL_EventType_SDL_EVENT_DROP_BEGIN => DropEventPayload::Begin,
+            sys::SDL_EventType_SDL_EVENT_DROP_FILE => {
+                DropEventPayload::File(string(event.data).unwrap_or_default())
+            }
+            sys::SDL_EventType_SDL_EVENT_DROP_TEXT => {
+                DropEventPayload::Text(string(event.data).unwrap_or_default())
+            }
+            sys::SDL_EventType_SDL_EVENT_DROP_POSITION => DropEventPayload::Position,
+            _ => DropEventPayload::Complete,
+        };
+        Self {
+            payload,
+            timestamp: event.timestamp,
+            window_id: event.windowID,
+            x: event.x,
+            y: event.y,
+            source: string(event.source),
+        }
+    }
+
+    /// Returns the cursor position relative to the window, or `None` for
+    /// [`DropEventPayload::Begin`].
+    pub fn position(&self) -> Option<PointF32> {
+        match self.payload {
+            DropEventPayload::Begin => None,
+            _ => Some(PointF32::new(self.x, self.y)),
+        }
+    }
+
+    /// Returns the cursor position in the render coordinates of `renderer`, e.g. to highlight
+    /// the drop target while a [`DropEventPayload::Position`] stream comes in.
+    ///
+    /// `renderer` should belong to the window the event was sent to, see
+    /// [`DropEvent::window_id`].
+    pub fn render_position<T>(&self, renderer: &Renderer<T>) -> Result<Option<PointF32>, Error> {
+        let Some(position) = self.position() else {
+            return Ok(None);
+        };
+        let (x, y) = renderer.coordinates_from_window(position.x(), position.y())?;
+        Ok(Some(PointF32::new(x, y)))
+    }
+}
+
+/// Payload of a [`DropEvent`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum DropEventPayload {
+    Begin,
+    /// A file was dropped; holds its path.
+    File(String),
+    /// Text was dropped.
+    Text(String),
+    /// The cursor moved over the window while dragging.
+    Position,
+    Complete,
+}
+
+/// An event tied to a [`crate::render::Renderer`].
+#[derive(Copy, Clone, Debug)]
+pub struct RenderEvent {
+    pub payload: RenderEventPayload,
+    pub timestamp: u64,
+    /// Id of the window of the renderer, or 0 for renderers without a window.
+    pub window_id: u32,
+}
+
+/// Payload of an event tied to a [`crate::render::Renderer`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RenderEventPayload {
+    /// The contents of render target textures were lost and must be redrawn.
+    TargetsReset,
+    /// The device was reset; every texture must be recreated.
+    DeviceReset,
+    /// The device was lost and can't be recovered; the renderer must be recreated.
+    DeviceLost,
+}
+
+/// An event tied to an audio device.
+#[derive(Copy, Clone, Debug)]
+pub struct AudioDeviceEvent {
+    pub payload: AudioDeviceEventPayload,
+    pub timestamp: u64,
+    /// Id of the physical or logical device that generated the event.
+    pub which: AudioDeviceId,
+    /// `true` for a recording device, `false` for a playback device.
+    pub recording: bool,
+}
+
+/// Payload of an event tied to an audio device.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AudioDeviceEventPayload {
+    /// A new device is available.
+    Added,
+    /// A device was lost, e.g. headphones were unplugged. Streams bound to it stop playing;
+    /// rebind them to another device, such as the default one, to keep the audio going.
+    Removed,
+    /// The format of a device changed, usually after the user picked a different sample rate or
+    /// speaker layout in the system settings.
+    FormatChanged,
+}
+
+/// An event tied to a camera device.
+#[derive(Copy, Clone, Debug)]
+pub struct CameraEvent {
+    pub payload: CameraEventPayload,
+    pub timestamp: u64,
+    /// Instance id of the camera that generated the event.
+    pub which: CameraId,
+}
+
+/// Payload of an event tied to a camera device.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CameraEventPayload {
+    /// A new camera was connected.
+    Added,
+    /// A camera was disconnected. Opened cameras keep providing blank frames until dropped; see
+    /// [`crate::camera::Camera::reopen_preferred`] to switch to a replacement device.
+    Removed,
+    /// The user approved the use of an opened camera.
     DeviceApproved,
+    /// The user denied the use of an opened camera.
     DeviceDenied,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn decodes_gamepad_sensor_updates() {
+        let event = Event(sys::SDL_Event {
+            gsensor: sys::SDL_GamepadSensorEvent {
+                type_: sys::SDL_EventType_SDL_EVENT_GAMEPAD_SENSOR_UPDATE,
+                reserved: 0,
+                timestamp: 10,
+                which: 3,
+                sensor: sys::SDL_SensorType_SDL_SENSOR_GYRO,
+                data: [1.0, 2.0, 3.0],
+                sensor_timestamp: 20,
+            },
+        });
+        let EventPayload::GamepadSensor(payload) = event.payload() else {
+            panic!("expected a gamepad sensor event");
+        };
+        assert_eq!(payload.timestamp, 10);
+        assert_eq!(payload.which, 3);
+        assert_eq!(payload.sensor, SensorType::Gyroscope);
+        assert_eq!(payload.data, [1.0, 2.0, 3.0]);
+        assert_eq!(payload.sensor_timestamp, 20);
+    }
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(EventAction: Add, Get, Peek);
+        assert_round_trips!(EventType: First, Quit, Terminating, LowMemory, WillEnterBackground, DidEnterBackground, WillEnterForeground, DidEnterForeground, LocaleChanged, SystemThemeChanged, DisplayOrientation, DisplayAdded, DisplayRemoved, DisplayMoved, DisplayDesktopModeChanged, DisplayCurrentModeChanged, DisplayContentScaleChanged, WindowShown, WindowHidden, WindowExposed, WindowMoved, WindowResized, WindowPixelSizeChanged, WindowMetalViewResized, WindowMinimized, WindowMaximized, WindowRestored, WindowMouseEnter, WindowMouseLeave, WindowFocusGained, WindowFocusLost, WindowCloseRequested, WindowHitTest, WindowIccProfChanged, WindowDisplayChanged, WindowDisplayScaleChanged, WindowSafeAreaChanged, WindowOccluded, WindowEnterFullscreen, WindowLeaveFullscreen, WindowDestroyed, WindowHdrStateChanged, KeyDown, KeyUp, TextEditing, TextInput, KeymapChanged, KeyboardAdded, KeyboardRemoved, TextEditingCandidates, MouseMotion, MouseButtonDown, MouseButtonUp, MouseWheel, MouseAdded, MouseRemoved, JoystickAxisMotion, JoystickBallMotion, JoystickHatMotion, JoystickButtonDown, JoystickButtonUp, JoystickAdded, JoystickRemoved, JoystickBatteryUpdated, JoystickUpdateComplete, GamepadAxisMotion, GamepadButtonDown, GamepadButtonUp, GamepadAdded, GamepadRemoved, GamepadRemapped, GamepadTouchpadDown, GamepadTouchpadMotion, GamepadTouchpadUp, GamepadSensorUpdate, GamepadUpdateComplete, GamepadSteamHandleUpdated, FingerDown, FingerUp, FingerMotion, FingerCanceled, ClipboardUpdate, DropFile, DropText, DropBegin, DropComplete, DropPosition, AudioDeviceAdded, AudioDeviceRemoved, AudioDeviceFormatChanged, SensorUpdate, PenProximityIn, PenProximityOut, PenDown, PenUp, PenButtonDown, PenButtonUp, PenMotion, PenAxis, CameraDeviceAdded, CameraDeviceRemoved, CameraDeviceApproved, CameraDeviceDenied, RenderTargetsReset, RenderDeviceReset, RenderDeviceLost, User, Last);
+    }
+}
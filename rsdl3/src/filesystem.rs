@@ -0,0 +1,201 @@
+use crate::sys;
+use crate::Error;
+use alloc::ffi::CString;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::{c_char, c_void, CStr};
+use core::mem::MaybeUninit;
+
+/// Returns the directory the application was run from, ending with a path separator.
+///
+/// This is where bundled assets usually live; on macOS and iOS it's the bundle's resource
+/// directory.
+pub fn base_path() -> Result<String, Error> {
+    let ptr = unsafe { sys::SDL_GetBasePath() };
+    if ptr.is_null() {
+        return Err(Error::new());
+    }
+    Ok(unsafe { CStr::from_ptr(ptr) }
+        .to_string_lossy()
+        .into_owned())
+}
+
+/// Returns a writable directory for the application's preferences and save data, ending with a
+/// path separator. The directory is created if it doesn't exist.
+///
+/// `org` and `app` should stay the same for the lifetime of the application, since they're
+/// part of the path. Only use letters, numbers and spaces in them.
+pub fn pref_path(org: &str, app: &str) -> Result<String, Error> {
+    let org = CString::new(org)?;
+    let app = CString::new(app)?;
+    unsafe {
+        let ptr = sys::SDL_GetPrefPath(org.as_ptr(), app.as_ptr());
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        let path = CStr::from_ptr(ptr).to_string_lossy().into_owned();
+        sys::SDL_free(ptr as *mut c_void);
+        Ok(path)
+    }
+}
+
+/// Returns the path of one of the user's special folders, ending with a path separator.
+///
+/// Returns an error if the platform has no such folder.
+pub fn user_folder(folder: Folder) -> Result<String, Error> {
+    let ptr = unsafe { sys::SDL_GetUserFolder(folder.to_ll()) };
+    if ptr.is_null() {
+        return Err(Error::new());
+    }
+    Ok(unsafe { CStr::from_ptr(ptr) }
+        .to_string_lossy()
+        .into_owned())
+}
+
+/// Returns the names of the entries of a directory.
+pub fn enumerate_directory(path: &str) -> Result<Vec<String>, Error> {
+    let path = CString::new(path)?;
+    let mut entries: Vec<String> = Vec::new();
+    let result = unsafe {
+        sys::SDL_EnumerateDirectory(
+            path.as_ptr(),
+            Some(enumerate_directory_marshall),
+            &raw mut entries as *mut c_void,
+        )
+    };
+    if !result {
+        return Err(Error::new());
+    }
+    Ok(entries)
+}
+
+/// Returns information about a file or directory.
+pub fn path_info(path: &str) -> Result<PathInfo, Error> {
+    let path = CString::new(path)?;
+    let mut info: MaybeUninit<sys::SDL_PathInfo> = MaybeUninit::uninit();
+    let result = unsafe { sys::SDL_GetPathInfo(path.as_ptr(), info.as_mut_ptr()) };
+    if !result {
+        return Err(Error::new());
+    }
+    Ok(PathInfo::from_ll(unsafe { info.assume_init() }))
+}
+
+/// Creates a directory, along with any missing parent directories.
+///
+/// Succeeds if the directory already exists.
+pub fn create_directory(path: &str) -> Result<(), Error> {
+    let path = CString::new(path)?;
+    let result = unsafe { sys::SDL_CreateDirectory(path.as_ptr()) };
+    if !result {
+        return Err(Error::new());
+    }
+    Ok(())
+}
+
+/// The user's special folders, see [`user_folder`].
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Folder {
+    Home = sys::SDL_Folder_SDL_FOLDER_HOME,
+    Desktop = sys::SDL_Folder_SDL_FOLDER_DESKTOP,
+    Documents = sys::SDL_Folder_SDL_FOLDER_DOCUMENTS,
+    Downloads = sys::SDL_Folder_SDL_FOLDER_DOWNLOADS,
+    Music = sys::SDL_Folder_SDL_FOLDER_MUSIC,
+    Pictures = sys::SDL_Folder_SDL_FOLDER_PICTURES,
+    /// Files shared with other users.
+    PublicShare = sys::SDL_Folder_SDL_FOLDER_PUBLICSHARE,
+    /// Save games. Prefer [`pref_path`] or [`crate::storage::Storage::open_user`] for the
+    /// application's own data.
+    SavedGames = sys::SDL_Folder_SDL_FOLDER_SAVEDGAMES,
+    Screenshots = sys::SDL_Folder_SDL_FOLDER_SCREENSHOTS,
+    /// Document templates.
+    Templates = sys::SDL_Folder_SDL_FOLDER_TEMPLATES,
+    Videos = sys::SDL_Folder_SDL_FOLDER_VIDEOS,
+}
+
+impl Folder {
+    /// Converts a raw `SDL_Folder` into a `Folder`.
+    pub fn try_from_ll(ll: sys::SDL_Folder) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_Folder_SDL_FOLDER_HOME => Self::Home,
+            sys::SDL_Folder_SDL_FOLDER_DESKTOP => Self::Desktop,
+            sys::SDL_Folder_SDL_FOLDER_DOCUMENTS => Self::Documents,
+            sys::SDL_Folder_SDL_FOLDER_DOWNLOADS => Self::Downloads,
+            sys::SDL_Folder_SDL_FOLDER_MUSIC => Self::Music,
+            sys::SDL_Folder_SDL_FOLDER_PICTURES => Self::Pictures,
+            sys::SDL_Folder_SDL_FOLDER_PUBLICSHARE => Self::PublicShare,
+            sys::SDL_Folder_SDL_FOLDER_SAVEDGAMES => Self::SavedGames,
+            sys::SDL_Folder_SDL_FOLDER_SCREENSHOTS => Self::Screenshots,
+            sys::SDL_Folder_SDL_FOLDER_TEMPLATES => Self::Templates,
+            sys::SDL_Folder_SDL_FOLDER_VIDEOS => Self::Videos,
+            _ => return Err(Error::register(c"Unknown folder.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_Folder {
+        *self as u32
+    }
+}
+
+/// The kind of entry a path points to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PathType {
+    File,
+    Directory,
+    /// Anything else, such as a device node.
+    Other,
+}
+
+/// Information about a file or directory.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PathInfo {
+    pub path_type: PathType,
+    /// The size of the file in bytes.
+    pub size: u64,
+    /// Creation time, in nanoseconds since the Unix epoch.
+    pub create_time: i64,
+    /// Last modification time, in nanoseconds since the Unix epoch.
+    pub modify_time: i64,
+    /// Last access time, in nanoseconds since the Unix epoch.
+    pub access_time: i64,
+}
+
+impl PathInfo {
+    pub(crate) fn from_ll(info: sys::SDL_PathInfo) -> Self {
+        let path_type = match info.type_ {
+            sys::SDL_PathType_SDL_PATHTYPE_FILE => PathType::File,
+            sys::SDL_PathType_SDL_PATHTYPE_DIRECTORY => PathType::Directory,
+            _ => PathType::Other,
+        };
+        Self {
+            path_type,
+            size: info.size,
+            create_time: info.create_time,
+            modify_time: info.modify_time,
+            access_time: info.access_time,
+        }
+    }
+}
+
+pub(crate) unsafe extern "C" fn enumerate_directory_marshall(
+    userdata: *mut c_void,
+    _dirname: *const c_char,
+    fname: *const c_char,
+) -> sys::SDL_EnumerationResult {
+    let entries = unsafe { &mut *(userdata as *mut Vec<String>) };
+    let name = unsafe { CStr::from_ptr(fname) };
+    entries.push(name.to_string_lossy().into_owned());
+    sys::SDL_EnumerationResult_SDL_ENUM_CONTINUE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(Folder: Home, Desktop, Documents, Downloads, Music, Pictures, PublicShare, SavedGames, Screenshots, Templates, Videos);
+    }
+}
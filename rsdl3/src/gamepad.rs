@@ -0,0 +1,413 @@
+use crate::joystick::{joystick_ids_from_ll, ConnectionState, JoystickId, PowerInfo};
+use crate::sys;
+use crate::Error;
+use crate::GamepadSubsystem;
+use alloc::ffi::CString;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::c_char;
+use core::ffi::c_void;
+use core::ffi::CStr;
+use core::ptr::NonNull;
+
+impl GamepadSubsystem {
+    /// Returns a list of currently connected gamepads.
+    pub fn gamepads(&self) -> Result<Vec<JoystickId>, Error> {
+        unsafe {
+            let mut count = 0;
+            let ptr = sys::SDL_GetGamepads(&raw mut count);
+            joystick_ids_from_ll(ptr, count)
+        }
+    }
+
+    /// Returns true if the joystick with the given id is supported by the gamepad interface.
+    pub fn is_gamepad(&self, id: JoystickId) -> bool {
+        unsafe { sys::SDL_IsGamepad(id) }
+    }
+
+    /// Returns the implementation dependent name of a gamepad.
+    ///
+    /// This can be called before any gamepads are opened.
+    pub fn gamepad_name(&self, id: JoystickId) -> Result<String, Error> {
+        let ptr = unsafe { sys::SDL_GetGamepadNameForID(id) };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        Ok(unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned())
+    }
+
+    /// Returns the mapping of a gamepad, or `None` if no mapping is available.
+    ///
+    /// This can be called before any gamepads are opened.
+    pub fn gamepad_mapping(&self, id: JoystickId) -> Option<String> {
+        unsafe { mapping_from_ll(sys::SDL_GetGamepadMappingForID(id)) }
+    }
+
+    /// Adds support for gamepads that SDL is unaware of or changes the binding of an existing
+    /// gamepad.
+    ///
+    /// The mapping string has the format "GUID,name,mapping", where GUID is the string value from
+    /// `SDL_GUIDToString()`, name is the human readable string for the device and mappings are
+    /// gamepad mappings to joystick ones, e.g.
+    /// `"341a3608000000000000504944564944,Afterglow PS3 Controller,a:b1,b:b2,y:b3,x:b0,start:b9,guide:b12,back:b8,dpup:h0.1,dpleft:h0.8,dpdown:h0.4,dpright:h0.2,leftshoulder:b4,rightshoulder:b5,leftstick:b10,rightstick:b11,leftx:a0,lefty:a1,rightx:a2,righty:a3,lefttrigger:b6,righttrigger:b7"`.
+    ///
+    /// Returns true if a new mapping was added, or false if an existing mapping was updated.
+    pub fn add_mapping(&self, mapping: &str) -> Result<bool, Error> {
+        let mapping = CString::new(mapping)?;
+        let result = unsafe { sys::SDL_AddGamepadMapping(mapping.as_ptr()) };
+        match result {
+            1 => Ok(true),
+            0 => Ok(false),
+            _ => Err(Error::new()),
+        }
+    }
+
+    /// Sets the current mapping of a joystick or gamepad, or clears it if `mapping` is `None`.
+    pub fn set_mapping(&self, id: JoystickId, mapping: Option<&str>) -> Result<(), Error> {
+        let mapping = mapping.map(CString::new).transpose()?;
+        let mapping_ptr = mapping
+            .as_ref()
+            .map(|mapping| mapping.as_ptr())
+            .unwrap_or(core::ptr::null());
+        let result = unsafe { sys::SDL_SetGamepadMapping(id, mapping_ptr) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Equivalent to [`Gamepad::open`].
+    pub fn open_gamepad(&self, id: JoystickId) -> Result<Gamepad, Error> {
+        Gamepad::open(self, id)
+    }
+}
+
+/// SAFETY: `ptr` must be null or a string allocated by SDL.
+unsafe fn mapping_from_ll(ptr: *mut c_char) -> Option<String> {
+    if ptr.is_null() {
+        return None;
+    }
+    let mapping = unsafe { CStr::from_ptr(ptr) }
+        .to_string_lossy()
+        .into_owned();
+    unsafe { sys::SDL_free(ptr as *mut c_void) };
+    Some(mapping)
+}
+
+/// An opened gamepad.
+///
+/// Gamepads are joysticks with a known layout: a directional pad, two analog sticks, four face
+/// buttons, shoulder buttons and triggers, like console controllers.
+pub struct Gamepad {
+    _subsystem: GamepadSubsystem,
+    ptr: NonNull<sys::SDL_Gamepad>,
+}
+
+impl Gamepad {
+    /// Opens a gamepad for use.
+    ///
+    /// Gamepad events are delivered for opened gamepads only.
+    pub fn open(subsystem: &GamepadSubsystem, id: JoystickId) -> Result<Self, Error> {
+        let ptr = NonNull::new(unsafe { sys::SDL_OpenGamepad(id) }).ok_or_else(Error::new)?;
+        Ok(Self {
+            _subsystem: subsystem.clone(),
+            ptr,
+        })
+    }
+
+    /// Returns the instance id of the gamepad.
+    pub fn id(&self) -> Result<JoystickId, Error> {
+        let result = unsafe { sys::SDL_GetGamepadID(self.raw()) };
+        if result == 0 {
+            return Err(Error::new());
+        }
+        Ok(result)
+    }
+
+    /// Returns the implementation dependent name of the gamepad.
+    pub fn name(&self) -> Result<String, Error> {
+        let ptr = unsafe { sys::SDL_GetGamepadName(self.raw()) };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        Ok(unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned())
+    }
+
+    /// Returns the current mapping of the gamepad, or `None` if it has no mapping.
+    ///
+    /// Details about mappings are discussed with [`GamepadSubsystem::add_mapping`].
+    pub fn mapping(&self) -> Option<String> {
+        unsafe { mapping_from_ll(sys::SDL_GetGamepadMapping(self.raw())) }
+    }
+
+    /// Returns true if the gamepad is still connected.
+    pub fn is_connected(&self) -> bool {
+        unsafe { sys::SDL_GamepadConnected(self.raw()) }
+    }
+
+    /// Returns true if the gamepad has the given button.
+    pub fn has_button(&self, button: GamepadButton) -> bool {
+        unsafe { sys::SDL_GamepadHasButton(self.raw(), button.to_ll()) }
+    }
+
+    /// Returns true if the gamepad has the given axis.
+    pub fn has_axis(&self, axis: GamepadAxis) -> bool {
+        unsafe { sys::SDL_GamepadHasAxis(self.raw(), axis.to_ll()) }
+    }
+
+    /// Returns true if the button is currently pressed.
+    pub fn button(&self, button: GamepadButton) -> bool {
+        unsafe { sys::SDL_GetGamepadButton(self.raw(), button.to_ll()) }
+    }
+
+    /// Returns the current state of an axis.
+    ///
+    /// Thumbstick axes range from -32768 to 32767, and triggers range from 0 when released to
+    /// 32767 when fully pressed.
+    pub fn axis(&self, axis: GamepadAxis) -> i16 {
+        unsafe { sys::SDL_GetGamepadAxis(self.raw(), axis.to_ll()) }
+    }
+
+    /// Starts a rumble effect.
+    ///
+    /// Each call cancels any previous rumble effect, and calling it with 0 intensity stops any
+    /// rumbling. Intensities range from 0 to 0xFFFF.
+    pub fn rumble(
+        &mut self,
+        low_frequency_rumble: u16,
+        high_frequency_rumble: u16,
+        duration_ms: u32,
+    ) -> Result<(), Error> {
+        let result = unsafe {
+            sys::SDL_RumbleGamepad(
+                self.raw(),
+                low_frequency_rumble,
+                high_frequency_rumble,
+                duration_ms,
+            )
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Starts a rumble effect in the gamepad's triggers.
+    ///
+    /// Each call cancels any previous trigger rumble effect, and calling it with 0 intensity stops
+    /// any rumbling. This is currently only supported on Xbox One controllers.
+    pub fn rumble_triggers(
+        &mut self,
+        left_rumble: u16,
+        right_rumble: u16,
+        duration_ms: u32,
+    ) -> Result<(), Error> {
+        let result = unsafe {
+            sys::SDL_RumbleGamepadTriggers(self.raw(), left_rumble, right_rumble, duration_ms)
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Updates the gamepad's LED color, if it has one.
+    pub fn set_led(&mut self, red: u8, green: u8, blue: u8) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_SetGamepadLED(self.raw(), red, green, blue) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns the battery state of the gamepad.
+    pub fn power_info(&self) -> Result<PowerInfo, Error> {
+        let mut percent = -1;
+        let state = unsafe { sys::SDL_GetGamepadPowerInfo(self.raw(), &raw mut percent) };
+        if state == sys::SDL_PowerState_SDL_POWERSTATE_ERROR {
+            return Err(Error::new());
+        }
+        PowerInfo::from_ll(state, percent)
+    }
+
+    /// Returns whether the gamepad is connected with a cable or wirelessly.
+    pub fn connection_state(&self) -> Result<ConnectionState, Error> {
+        let state = unsafe { sys::SDL_GetGamepadConnectionState(self.raw()) };
+        if state == sys::SDL_JoystickConnectionState_SDL_JOYSTICK_CONNECTION_INVALID {
+            return Err(Error::new());
+        }
+        ConnectionState::try_from_ll(state)
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_Gamepad {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for Gamepad {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_CloseGamepad(self.ptr.as_ptr()) };
+    }
+}
+
+/// The list of buttons available on a gamepad.
+///
+/// Face buttons are named by their position (south, east, west, north) rather than by label,
+/// since labels differ between controller brands.
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GamepadButton {
+    Invalid = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_INVALID,
+    /// Bottom face button (e.g. Xbox A button).
+    South = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_SOUTH,
+    /// Right face button (e.g. Xbox B button).
+    East = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_EAST,
+    /// Left face button (e.g. Xbox X button).
+    West = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_WEST,
+    /// Top face button (e.g. Xbox Y button).
+    North = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_NORTH,
+    Back = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_BACK,
+    Guide = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_GUIDE,
+    Start = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_START,
+    LeftStick = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_LEFT_STICK,
+    RightStick = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_RIGHT_STICK,
+    LeftShoulder = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_LEFT_SHOULDER,
+    RightShoulder = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_RIGHT_SHOULDER,
+    DpadUp = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_DPAD_UP,
+    DpadDown = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_DPAD_DOWN,
+    DpadLeft = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_DPAD_LEFT,
+    DpadRight = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_DPAD_RIGHT,
+    /// Additional button (e.g. Xbox Series X share button, PS5 microphone button).
+    Misc1 = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_MISC1,
+    /// Upper or primary paddle, under your right hand.
+    RightPaddle1 = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_RIGHT_PADDLE1,
+    /// Upper or primary paddle, under your left hand.
+    LeftPaddle1 = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_LEFT_PADDLE1,
+    /// Lower or secondary paddle, under your right hand.
+    RightPaddle2 = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_RIGHT_PADDLE2,
+    /// Lower or secondary paddle, under your left hand.
+    LeftPaddle2 = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_LEFT_PADDLE2,
+    /// PS4/PS5 touchpad button.
+    Touchpad = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_TOUCHPAD,
+    Misc2 = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_MISC2,
+    Misc3 = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_MISC3,
+    Misc4 = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_MISC4,
+    Misc5 = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_MISC5,
+    Misc6 = sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_MISC6,
+}
+
+impl GamepadButton {
+    /// Converts a raw `SDL_GamepadButton` into a `GamepadButton`.
+    pub fn try_from_ll(ll: sys::SDL_GamepadButton) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_INVALID => Self::Invalid,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_SOUTH => Self::South,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_EAST => Self::East,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_WEST => Self::West,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_NORTH => Self::North,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_BACK => Self::Back,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_GUIDE => Self::Guide,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_START => Self::Start,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_LEFT_STICK => Self::LeftStick,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_RIGHT_STICK => Self::RightStick,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_LEFT_SHOULDER => Self::LeftShoulder,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_RIGHT_SHOULDER => Self::RightShoulder,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_DPAD_UP => Self::DpadUp,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_DPAD_DOWN => Self::DpadDown,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_DPAD_LEFT => Self::DpadLeft,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_DPAD_RIGHT => Self::DpadRight,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_MISC1 => Self::Misc1,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_RIGHT_PADDLE1 => Self::RightPaddle1,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_LEFT_PADDLE1 => Self::LeftPaddle1,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_RIGHT_PADDLE2 => Self::RightPaddle2,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_LEFT_PADDLE2 => Self::LeftPaddle2,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_TOUCHPAD => Self::Touchpad,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_MISC2 => Self::Misc2,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_MISC3 => Self::Misc3,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_MISC4 => Self::Misc4,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_MISC5 => Self::Misc5,
+            sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_MISC6 => Self::Misc6,
+            _ => return Err(Error::register(c"Unknown gamepad button.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GamepadButton {
+        *self as i32
+    }
+
+    pub(crate) fn from_ll(ll: sys::SDL_GamepadButton) -> Self {
+        if (sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_SOUTH
+            ..sys::SDL_GamepadButton_SDL_GAMEPAD_BUTTON_COUNT)
+            .contains(&ll)
+        {
+            // SAFETY: the enum covers every value in this range.
+            unsafe { core::mem::transmute::<i32, Self>(ll) }
+        } else {
+            Self::Invalid
+        }
+    }
+}
+
+/// The list of axes available on a gamepad.
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GamepadAxis {
+    Invalid = sys::SDL_GamepadAxis_SDL_GAMEPAD_AXIS_INVALID,
+    LeftX = sys::SDL_GamepadAxis_SDL_GAMEPAD_AXIS_LEFTX,
+    LeftY = sys::SDL_GamepadAxis_SDL_GAMEPAD_AXIS_LEFTY,
+    RightX = sys::SDL_GamepadAxis_SDL_GAMEPAD_AXIS_RIGHTX,
+    RightY = sys::SDL_GamepadAxis_SDL_GAMEPAD_AXIS_RIGHTY,
+    LeftTrigger = sys::SDL_GamepadAxis_SDL_GAMEPAD_AXIS_LEFT_TRIGGER,
+    RightTrigger = sys::SDL_GamepadAxis_SDL_GAMEPAD_AXIS_RIGHT_TRIGGER,
+}
+
+impl GamepadAxis {
+    /// Converts a raw `SDL_GamepadAxis` into a `GamepadAxis`.
+    pub fn try_from_ll(ll: sys::SDL_GamepadAxis) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GamepadAxis_SDL_GAMEPAD_AXIS_INVALID => Self::Invalid,
+            sys::SDL_GamepadAxis_SDL_GAMEPAD_AXIS_LEFTX => Self::LeftX,
+            sys::SDL_GamepadAxis_SDL_GAMEPAD_AXIS_LEFTY => Self::LeftY,
+            sys::SDL_GamepadAxis_SDL_GAMEPAD_AXIS_RIGHTX => Self::RightX,
+            sys::SDL_GamepadAxis_SDL_GAMEPAD_AXIS_RIGHTY => Self::RightY,
+            sys::SDL_GamepadAxis_SDL_GAMEPAD_AXIS_LEFT_TRIGGER => Self::LeftTrigger,
+            sys::SDL_GamepadAxis_SDL_GAMEPAD_AXIS_RIGHT_TRIGGER => Self::RightTrigger,
+            _ => return Err(Error::register(c"Unknown gamepad axis.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GamepadAxis {
+        *self as i32
+    }
+
+    pub(crate) fn from_ll(ll: sys::SDL_GamepadAxis) -> Self {
+        if (sys::SDL_GamepadAxis_SDL_GAMEPAD_AXIS_LEFTX
+            ..sys::SDL_GamepadAxis_SDL_GAMEPAD_AXIS_COUNT)
+            .contains(&ll)
+        {
+            // SAFETY: the enum covers every value in this range.
+            unsafe { core::mem::transmute::<i32, Self>(ll) }
+        } else {
+            Self::Invalid
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(GamepadButton: Invalid, South, East, West, North, Back, Guide, Start, LeftStick, RightStick, LeftShoulder, RightShoulder, DpadUp, DpadDown, DpadLeft, DpadRight, Misc1, RightPaddle1, LeftPaddle1, RightPaddle2, LeftPaddle2, Touchpad, Misc2, Misc3, Misc4, Misc5, Misc6);
+        assert_round_trips!(GamepadAxis: Invalid, LeftX, LeftY, RightX, RightY, LeftTrigger, RightTrigger);
+    }
+}
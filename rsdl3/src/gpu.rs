@@ -0,0 +1,2753 @@
+use crate::pixels::ColorF32;
+use crate::properties::Properties;
+use crate::rect::Rect;
+use crate::video::{Window, WindowRef};
+use crate::{sys, Error, VideoSubsystem};
+use alloc::ffi::CString;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::CStr;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ops::{BitOr, Deref, DerefMut};
+use core::ptr::NonNull;
+
+impl VideoSubsystem {
+    /// Returns the number of GPU drivers compiled into SDL.
+    pub fn num_gpu_drivers(&self) -> Result<usize, Error> {
+        Ok(usize::try_from(unsafe { sys::SDL_GetNumGPUDrivers() })?)
+    }
+
+    /// Returns the name of a builtin GPU driver.
+    /// The number of drivers can be obtained by calling [`VideoSubsystem::num_gpu_drivers`].
+    pub fn gpu_driver(&self, index: usize) -> Result<String, Error> {
+        unsafe {
+            let ptr = sys::SDL_GetGPUDriver(i32::try_from(index)?);
+            if ptr.is_null() {
+                return Err(Error::new());
+            }
+            Ok(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+        }
+    }
+
+    /// Creates a `GpuDevice`.
+    /// This method is equivalent to [`GpuDevice::new`].
+    pub fn create_gpu_device(
+        &self,
+        formats: GpuShaderFormat,
+        debug_mode: bool,
+        driver: Option<&str>,
+    ) -> Result<GpuDevice, Error> {
+        GpuDevice::new(self, formats, debug_mode, driver)
+    }
+}
+
+/// A GPU context.
+///
+/// Cloning a device is cheap; the underlying SDL device is destroyed once the last clone and
+/// every resource created from it are dropped.
+#[derive(Clone)]
+pub struct GpuDevice {
+    internal: Rc<GpuDeviceInternal>,
+}
+
+struct GpuDeviceInternal {
+    _video: VideoSubsystem,
+    ptr: NonNull<sys::SDL_GPUDevice>,
+}
+
+impl Drop for GpuDeviceInternal {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_DestroyGPUDevice(self.ptr.as_ptr()) };
+    }
+}
+
+impl GpuDevice {
+    /// Creates a GPU context.
+    ///
+    /// `formats` lists the shader formats the application can provide; SDL picks a driver that
+    /// supports at least one of them. If `driver` is `None`, SDL will choose the best available
+    /// option.
+    ///
+    /// `debug_mode` enables the backend's validation layers, which is slow but helps catching
+    /// API misuse.
+    pub fn new(
+        video: &VideoSubsystem,
+        formats: GpuShaderFormat,
+        debug_mode: bool,
+        driver: Option<&str>,
+    ) -> Result<Self, Error> {
+        let driver = driver.map(CString::new).transpose()?;
+        let driver_ptr = driver
+            .as_ref()
+            .map(|driver| driver.as_ptr())
+            .unwrap_or(core::ptr::null());
+        let ptr = unsafe { sys::SDL_CreateGPUDevice(formats.0, debug_mode, driver_ptr) };
+        Self::from_ptr(video, ptr)
+    }
+
+    /// Creates a GPU context from a group of `SDL.gpu.device.create.*` properties.
+    pub fn with_properties(video: &VideoSubsystem, props: &Properties) -> Result<Self, Error> {
+        let ptr = unsafe { sys::SDL_CreateGPUDeviceWithProperties(props.raw()) };
+        Self::from_ptr(video, ptr)
+    }
+
+    fn from_ptr(video: &VideoSubsystem, ptr: *mut sys::SDL_GPUDevice) -> Result<Self, Error> {
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            internal: Rc::new(GpuDeviceInternal {
+                _video: video.clone(),
+                ptr,
+            }),
+        })
+    }
+
+    /// Returns the name of the backend used to create this device.
+    pub fn driver(&self) -> Result<String, Error> {
+        unsafe {
+            let ptr = sys::SDL_GetGPUDeviceDriver(self.raw());
+            if ptr.is_null() {
+                return Err(Error::new());
+            }
+            Ok(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+        }
+    }
+
+    /// Returns the shader formats supported by this device.
+    pub fn shader_formats(&self) -> GpuShaderFormat {
+        GpuShaderFormat(unsafe { sys::SDL_GetGPUShaderFormats(self.raw()) })
+    }
+
+    /// Returns true if `format` can be used to create textures of `texture_type` with `usage`.
+    pub fn texture_supports_format(
+        &self,
+        format: GpuTextureFormat,
+        texture_type: GpuTextureType,
+        usage: GpuTextureUsage,
+    ) -> bool {
+        unsafe {
+            sys::SDL_GPUTextureSupportsFormat(self.raw(), format.0, texture_type.to_ll(), usage.0)
+        }
+    }
+
+    /// Blocks the thread until the GPU is completely idle.
+    pub fn wait_for_idle(&self) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_WaitForGPUIdle(self.raw()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Claims a window, creating a swapchain structure for it. Consumes the [`Window`].
+    ///
+    /// The window can be accessed again via [`GpuWindow::as_window_ref`] or
+    /// [`GpuWindow::as_window_mut`], and released with [`GpuWindow::into_window`].
+    pub fn claim_window(&self, window: Window) -> Result<GpuWindow, Error> {
+        let result =
+            unsafe { sys::SDL_ClaimWindowForGPUDevice(self.raw(), window.as_ptr() as *mut _) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(GpuWindow {
+            device: self.clone(),
+            window,
+        })
+    }
+
+    /// Acquires a command buffer.
+    ///
+    /// Commands are recorded into the buffer and only sent to the GPU once it's submitted.
+    pub fn acquire_command_buffer(&self) -> Result<GpuCommandBuffer<'_>, Error> {
+        let ptr = unsafe { sys::SDL_AcquireGPUCommandBuffer(self.raw()) };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(GpuCommandBuffer { device: self, ptr })
+    }
+
+    /// Equivalent to [`GpuBuffer::new`].
+    pub fn create_buffer(&self, usage: GpuBufferUsage, size: u32) -> Result<GpuBuffer, Error> {
+        GpuBuffer::new(self, usage, size)
+    }
+
+    /// Equivalent to [`GpuTransferBuffer::new`].
+    pub fn create_transfer_buffer(
+        &self,
+        usage: GpuTransferBufferUsage,
+        size: u32,
+    ) -> Result<GpuTransferBuffer, Error> {
+        GpuTransferBuffer::new(self, usage, size)
+    }
+
+    /// Equivalent to [`GpuTexture::new`].
+    pub fn create_texture(&self, info: &GpuTextureCreateInfo) -> Result<GpuTexture, Error> {
+        GpuTexture::new(self, info)
+    }
+
+    /// Equivalent to [`GpuSampler::new`].
+    pub fn create_sampler(&self, info: &GpuSamplerCreateInfo) -> Result<GpuSampler, Error> {
+        GpuSampler::new(self, info)
+    }
+
+    /// Equivalent to [`GpuShader::new`].
+    pub fn create_shader(&self, info: &GpuShaderCreateInfo) -> Result<GpuShader, Error> {
+        GpuShader::new(self, info)
+    }
+
+    /// Equivalent to [`GpuGraphicsPipeline::new`].
+    pub fn create_graphics_pipeline(
+        &self,
+        info: &GpuGraphicsPipelineCreateInfo,
+    ) -> Result<GpuGraphicsPipeline, Error> {
+        GpuGraphicsPipeline::new(self, info)
+    }
+
+    /// Equivalent to [`GpuComputePipeline::new`].
+    pub fn create_compute_pipeline(
+        &self,
+        info: &GpuComputePipelineCreateInfo,
+    ) -> Result<GpuComputePipeline, Error> {
+        GpuComputePipeline::new(self, info)
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_GPUDevice {
+        self.internal.ptr.as_ptr()
+    }
+}
+
+/// A [`Window`] claimed by a [`GpuDevice`].
+///
+/// The window is released from the device when this value is dropped.
+pub struct GpuWindow {
+    device: GpuDevice,
+    window: Window,
+}
+
+impl GpuWindow {
+    /// Returns a reference to the claimed window.
+    #[inline]
+    pub fn as_window_ref(&self) -> &WindowRef {
+        &self.window
+    }
+
+    /// Returns a mutable reference to the claimed window.
+    #[inline]
+    pub fn as_window_mut(&mut self) -> &mut WindowRef {
+        &mut self.window
+    }
+
+    /// Releases the window from the device, destroying its swapchain, and returns it.
+    pub fn into_window(self) -> Window {
+        let this = ManuallyDrop::new(self);
+        unsafe {
+            sys::SDL_ReleaseWindowFromGPUDevice(this.device.raw(), this.raw_window());
+            // SAFETY: `this` is never dropped, so each field is read exactly once.
+            let _device = core::ptr::read(&this.device);
+            core::ptr::read(&this.window)
+        }
+    }
+
+    /// Returns the texture format of the window's swapchain.
+    pub fn swapchain_texture_format(&self) -> GpuTextureFormat {
+        GpuTextureFormat(unsafe {
+            sys::SDL_GetGPUSwapchainTextureFormat(self.device.raw(), self.raw_window())
+        })
+    }
+
+    /// Returns true if the window's swapchain supports `composition`.
+    pub fn supports_swapchain_composition(&self, composition: GpuSwapchainComposition) -> bool {
+        unsafe {
+            sys::SDL_WindowSupportsGPUSwapchainComposition(
+                self.device.raw(),
+                self.raw_window(),
+                composition.to_ll(),
+            )
+        }
+    }
+
+    /// Returns true if the window's swapchain supports `present_mode`.
+    pub fn supports_present_mode(&self, present_mode: GpuPresentMode) -> bool {
+        unsafe {
+            sys::SDL_WindowSupportsGPUPresentMode(
+                self.device.raw(),
+                self.raw_window(),
+                present_mode.to_ll(),
+            )
+        }
+    }
+
+    /// Changes the swapchain parameters of the window.
+    ///
+    /// The window is created with [`GpuSwapchainComposition::Sdr`] and [`GpuPresentMode::Vsync`],
+    /// which are always supported.
+    pub fn set_swapchain_parameters(
+        &mut self,
+        composition: GpuSwapchainComposition,
+        present_mode: GpuPresentMode,
+    ) -> Result<(), Error> {
+        let result = unsafe {
+            sys::SDL_SetGPUSwapchainParameters(
+                self.device.raw(),
+                self.raw_window(),
+                composition.to_ll(),
+                present_mode.to_ll(),
+            )
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn raw_window(&self) -> *mut sys::SDL_Window {
+        self.window.as_ptr() as *mut _
+    }
+}
+
+impl Drop for GpuWindow {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_ReleaseWindowFromGPUDevice(self.device.raw(), self.raw_window()) };
+    }
+}
+
+/// A buffer of recorded GPU commands.
+///
+/// The buffer is submitted when dropped; use [`GpuCommandBuffer::cancel`] to discard it instead.
+pub struct GpuCommandBuffer<'a> {
+    device: &'a GpuDevice,
+    ptr: NonNull<sys::SDL_GPUCommandBuffer>,
+}
+
+impl<'a> GpuCommandBuffer<'a> {
+    /// Submits the commands to the GPU.
+    pub fn submit(self) -> Result<(), Error> {
+        let this = ManuallyDrop::new(self);
+        let result = unsafe { sys::SDL_SubmitGPUCommandBuffer(this.raw()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Submits the commands to the GPU, returning a fence that is signaled once they're done.
+    pub fn submit_and_acquire_fence(self) -> Result<GpuFence, Error> {
+        let this = ManuallyDrop::new(self);
+        let ptr = unsafe { sys::SDL_SubmitGPUCommandBufferAndAcquireFence(this.raw()) };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(GpuFence {
+            device: this.device.clone(),
+            ptr,
+        })
+    }
+
+    /// Discards the recorded commands.
+    ///
+    /// This fails if a swapchain texture was acquired with this command buffer, since the
+    /// swapchain must be presented.
+    pub fn cancel(self) -> Result<(), Error> {
+        let this = ManuallyDrop::new(self);
+        let result = unsafe { sys::SDL_CancelGPUCommandBuffer(this.raw()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Blocks until a swapchain texture is available and acquires it.
+    ///
+    /// Returns `None` if the window is minimized or otherwise not presentable; the command
+    /// buffer should still be submitted in that case. The texture is presented once the command
+    /// buffer is submitted, and must not be used afterwards.
+    pub fn wait_and_acquire_swapchain_texture<'w>(
+        &mut self,
+        window: &'w GpuWindow,
+    ) -> Result<Option<GpuSwapchainTexture<'w>>, Error> {
+        self.acquire_swapchain_texture_with(window, sys::SDL_WaitAndAcquireGPUSwapchainTexture)
+    }
+
+    /// Acquires a swapchain texture without blocking.
+    ///
+    /// Returns `None` if too many frames are in flight or the window is not presentable.
+    /// See [`GpuCommandBuffer::wait_and_acquire_swapchain_texture`].
+    pub fn acquire_swapchain_texture<'w>(
+        &mut self,
+        window: &'w GpuWindow,
+    ) -> Result<Option<GpuSwapchainTexture<'w>>, Error> {
+        self.acquire_swapchain_texture_with(window, sys::SDL_AcquireGPUSwapchainTexture)
+    }
+
+    fn acquire_swapchain_texture_with<'w>(
+        &mut self,
+        window: &'w GpuWindow,
+        acquire: unsafe extern "C" fn(
+            *mut sys::SDL_GPUCommandBuffer,
+            *mut sys::SDL_Window,
+            *mut *mut sys::SDL_GPUTexture,
+            *mut u32,
+            *mut u32,
+        ) -> bool,
+    ) -> Result<Option<GpuSwapchainTexture<'w>>, Error> {
+        let mut texture = core::ptr::null_mut();
+        let mut width = 0;
+        let mut height = 0;
+        let result = unsafe {
+            acquire(
+                self.raw(),
+                window.raw_window(),
+                &raw mut texture,
+                &raw mut width,
+                &raw mut height,
+            )
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(NonNull::new(texture).map(|ptr| GpuSwapchainTexture {
+            _window: PhantomData,
+            ptr,
+            width,
+            height,
+        }))
+    }
+
+    /// Pushes data to a vertex shader uniform slot, for use by subsequent draw calls.
+    pub fn push_vertex_uniform_data(&mut self, slot: u32, data: &[u8]) -> Result<(), Error> {
+        let len = u32::try_from(data.len())?;
+        unsafe { sys::SDL_PushGPUVertexUniformData(self.raw(), slot, data.as_ptr() as _, len) };
+        Ok(())
+    }
+
+    /// Pushes data to a fragment shader uniform slot, for use by subsequent draw calls.
+    pub fn push_fragment_uniform_data(&mut self, slot: u32, data: &[u8]) -> Result<(), Error> {
+        let len = u32::try_from(data.len())?;
+        unsafe { sys::SDL_PushGPUFragmentUniformData(self.raw(), slot, data.as_ptr() as _, len) };
+        Ok(())
+    }
+
+    /// Pushes data to a compute shader uniform slot, for use by subsequent dispatch calls.
+    pub fn push_compute_uniform_data(&mut self, slot: u32, data: &[u8]) -> Result<(), Error> {
+        let len = u32::try_from(data.len())?;
+        unsafe { sys::SDL_PushGPUComputeUniformData(self.raw(), slot, data.as_ptr() as _, len) };
+        Ok(())
+    }
+
+    /// Begins a render pass drawing into `color_targets` and, optionally, a depth stencil target.
+    ///
+    /// The pass ends when the returned value is dropped.
+    pub fn begin_render_pass(
+        &mut self,
+        color_targets: &[GpuColorTargetInfo],
+        depth_stencil_target: Option<&GpuDepthStencilTargetInfo>,
+    ) -> Result<GpuRenderPass<'_>, Error> {
+        let num_color_targets = u32::try_from(color_targets.len())?;
+        let depth_stencil_ptr = depth_stencil_target
+            .map(|target| &target.0 as *const _)
+            .unwrap_or(core::ptr::null());
+        let ptr = unsafe {
+            sys::SDL_BeginGPURenderPass(
+                self.raw(),
+                color_targets.as_ptr() as *const _,
+                num_color_targets,
+                depth_stencil_ptr,
+            )
+        };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(GpuRenderPass {
+            _command_buffer: PhantomData,
+            ptr,
+        })
+    }
+
+    /// Begins a copy pass, used to move data between buffers, textures and transfer buffers.
+    ///
+    /// The pass ends when the returned value is dropped.
+    pub fn begin_copy_pass(&mut self) -> Result<GpuCopyPass<'_>, Error> {
+        let ptr = unsafe { sys::SDL_BeginGPUCopyPass(self.raw()) };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(GpuCopyPass {
+            _command_buffer: PhantomData,
+            ptr,
+        })
+    }
+
+    /// Begins a compute pass, writing to the given storage textures and buffers.
+    ///
+    /// The pass ends when the returned value is dropped.
+    pub fn begin_compute_pass(
+        &mut self,
+        storage_textures: &[GpuStorageTextureBinding],
+        storage_buffers: &[GpuStorageBufferBinding],
+    ) -> Result<GpuComputePass<'_>, Error> {
+        let num_storage_textures = u32::try_from(storage_textures.len())?;
+        let num_storage_buffers = u32::try_from(storage_buffers.len())?;
+        let ptr = unsafe {
+            sys::SDL_BeginGPUComputePass(
+                self.raw(),
+                storage_textures.as_ptr() as *const _,
+                num_storage_textures,
+                storage_buffers.as_ptr() as *const _,
+                num_storage_buffers,
+            )
+        };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(GpuComputePass {
+            _command_buffer: PhantomData,
+            ptr,
+        })
+    }
+
+    /// Generates mipmaps for `texture`, which must have been created with more than one level.
+    pub fn generate_mipmaps(&mut self, texture: &GpuTexture) {
+        unsafe { sys::SDL_GenerateMipmapsForGPUTexture(self.raw(), texture.raw()) };
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_GPUCommandBuffer {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for GpuCommandBuffer<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_SubmitGPUCommandBuffer(self.raw()) };
+    }
+}
+
+/// A render pass, recording draw calls into a [`GpuCommandBuffer`].
+pub struct GpuRenderPass<'a> {
+    _command_buffer: PhantomData<&'a mut ()>,
+    ptr: NonNull<sys::SDL_GPURenderPass>,
+}
+
+impl GpuRenderPass<'_> {
+    /// Binds a graphics pipeline for subsequent draw calls.
+    pub fn bind_graphics_pipeline(&mut self, pipeline: &GpuGraphicsPipeline) {
+        unsafe { sys::SDL_BindGPUGraphicsPipeline(self.raw(), pipeline.raw()) };
+    }
+
+    /// Sets the current viewport state.
+    pub fn set_viewport(&mut self, viewport: GpuViewport) {
+        let viewport = viewport.to_ll();
+        unsafe { sys::SDL_SetGPUViewport(self.raw(), &raw const viewport) };
+    }
+
+    /// Sets the current scissor state.
+    pub fn set_scissor(&mut self, scissor: Rect) {
+        unsafe { sys::SDL_SetGPUScissor(self.raw(), scissor.as_raw()) };
+    }
+
+    /// Sets the stencil reference value used by stencil tests.
+    pub fn set_stencil_reference(&mut self, reference: u8) {
+        unsafe { sys::SDL_SetGPUStencilReference(self.raw(), reference) };
+    }
+
+    /// Binds vertex buffers starting at `first_slot`, for subsequent draw calls.
+    pub fn bind_vertex_buffers(
+        &mut self,
+        first_slot: u32,
+        bindings: &[GpuBufferBinding],
+    ) -> Result<(), Error> {
+        let num_bindings = u32::try_from(bindings.len())?;
+        unsafe {
+            sys::SDL_BindGPUVertexBuffers(
+                self.raw(),
+                first_slot,
+                bindings.as_ptr() as *const _,
+                num_bindings,
+            )
+        };
+        Ok(())
+    }
+
+    /// Binds an index buffer for subsequent indexed draw calls.
+    pub fn bind_index_buffer(&mut self, binding: &GpuBufferBinding, size: GpuIndexElementSize) {
+        unsafe { sys::SDL_BindGPUIndexBuffer(self.raw(), &binding.0, size.to_ll()) };
+    }
+
+    /// Binds texture-sampler pairs for use in the vertex shader, starting at `first_slot`.
+    pub fn bind_vertex_samplers(
+        &mut self,
+        first_slot: u32,
+        bindings: &[GpuTextureSamplerBinding],
+    ) -> Result<(), Error> {
+        let num_bindings = u32::try_from(bindings.len())?;
+        unsafe {
+            sys::SDL_BindGPUVertexSamplers(
+                self.raw(),
+                first_slot,
+                bindings.as_ptr() as *const _,
+                num_bindings,
+            )
+        };
+        Ok(())
+    }
+
+    /// Binds texture-sampler pairs for use in the fragment shader, starting at `first_slot`.
+    pub fn bind_fragment_samplers(
+        &mut self,
+        first_slot: u32,
+        bindings: &[GpuTextureSamplerBinding],
+    ) -> Result<(), Error> {
+        let num_bindings = u32::try_from(bindings.len())?;
+        unsafe {
+            sys::SDL_BindGPUFragmentSamplers(
+                self.raw(),
+                first_slot,
+                bindings.as_ptr() as *const _,
+                num_bindings,
+            )
+        };
+        Ok(())
+    }
+
+    /// Draws primitives using the bound graphics state.
+    pub fn draw_primitives(
+        &mut self,
+        num_vertices: u32,
+        num_instances: u32,
+        first_vertex: u32,
+        first_instance: u32,
+    ) {
+        unsafe {
+            sys::SDL_DrawGPUPrimitives(
+                self.raw(),
+                num_vertices,
+                num_instances,
+                first_vertex,
+                first_instance,
+            )
+        };
+    }
+
+    /// Draws indexed primitives using the bound graphics state and index buffer.
+    ///
+    /// `vertex_offset` is added to each index before reading the vertex buffer.
+    pub fn draw_indexed_primitives(
+        &mut self,
+        num_indices: u32,
+        num_instances: u32,
+        first_index: u32,
+        vertex_offset: i32,
+        first_instance: u32,
+    ) {
+        unsafe {
+            sys::SDL_DrawGPUIndexedPrimitives(
+                self.raw(),
+                num_indices,
+                num_instances,
+                first_index,
+                vertex_offset,
+                first_instance,
+            )
+        };
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_GPURenderPass {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for GpuRenderPass<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_EndGPURenderPass(self.raw()) };
+    }
+}
+
+/// A copy pass, recording data transfers into a [`GpuCommandBuffer`].
+pub struct GpuCopyPass<'a> {
+    _command_buffer: PhantomData<&'a mut ()>,
+    ptr: NonNull<sys::SDL_GPUCopyPass>,
+}
+
+impl GpuCopyPass<'_> {
+    /// Uploads `size` bytes from a transfer buffer to a buffer.
+    ///
+    /// If `cycle` is true and the buffer is still in use by the GPU, SDL writes to a fresh copy
+    /// instead of waiting.
+    pub fn upload_to_buffer(
+        &mut self,
+        source: &GpuTransferBuffer,
+        source_offset: u32,
+        destination: &GpuBuffer,
+        destination_offset: u32,
+        size: u32,
+        cycle: bool,
+    ) {
+        let source = sys::SDL_GPUTransferBufferLocation {
+            transfer_buffer: source.raw(),
+            offset: source_offset,
+        };
+        let destination = sys::SDL_GPUBufferRegion {
+            buffer: destination.raw(),
+            offset: destination_offset,
+            size,
+        };
+        unsafe {
+            sys::SDL_UploadToGPUBuffer(self.raw(), &raw const source, &raw const destination, cycle)
+        };
+    }
+
+    /// Downloads `size` bytes from a buffer to a transfer buffer.
+    ///
+    /// The data is only available once the command buffer has completed; see
+    /// [`GpuCommandBuffer::submit_and_acquire_fence`].
+    pub fn download_from_buffer(
+        &mut self,
+        source: &GpuBuffer,
+        source_offset: u32,
+        size: u32,
+        destination: &GpuTransferBuffer,
+        destination_offset: u32,
+    ) {
+        let source = sys::SDL_GPUBufferRegion {
+            buffer: source.raw(),
+            offset: source_offset,
+            size,
+        };
+        let destination = sys::SDL_GPUTransferBufferLocation {
+            transfer_buffer: destination.raw(),
+            offset: destination_offset,
+        };
+        unsafe {
+            sys::SDL_DownloadFromGPUBuffer(self.raw(), &raw const source, &raw const destination)
+        };
+    }
+
+    /// Uploads tightly packed pixel data from a transfer buffer to a region of a texture.
+    pub fn upload_to_texture(
+        &mut self,
+        source: &GpuTransferBuffer,
+        source_offset: u32,
+        destination: &GpuTexture,
+        region: GpuTextureRegion,
+        cycle: bool,
+    ) {
+        let source = sys::SDL_GPUTextureTransferInfo {
+            transfer_buffer: source.raw(),
+            offset: source_offset,
+            pixels_per_row: 0,
+            rows_per_layer: 0,
+        };
+        let destination = region.to_ll(destination.raw());
+        unsafe {
+            sys::SDL_UploadToGPUTexture(
+                self.raw(),
+                &raw const source,
+                &raw const destination,
+                cycle,
+            )
+        };
+    }
+
+    /// Downloads a region of a texture to a transfer buffer, tightly packed.
+    pub fn download_from_texture(
+        &mut self,
+        source: &GpuTexture,
+        region: GpuTextureRegion,
+        destination: &GpuTransferBuffer,
+        destination_offset: u32,
+    ) {
+        let source = region.to_ll(source.raw());
+        let destination = sys::SDL_GPUTextureTransferInfo {
+            transfer_buffer: destination.raw(),
+            offset: destination_offset,
+            pixels_per_row: 0,
+            rows_per_layer: 0,
+        };
+        unsafe {
+            sys::SDL_DownloadFromGPUTexture(self.raw(), &raw const source, &raw const destination)
+        };
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_GPUCopyPass {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for GpuCopyPass<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_EndGPUCopyPass(self.raw()) };
+    }
+}
+
+/// A compute pass, recording compute dispatches into a [`GpuCommandBuffer`].
+pub struct GpuComputePass<'a> {
+    _command_buffer: PhantomData<&'a mut ()>,
+    ptr: NonNull<sys::SDL_GPUComputePass>,
+}
+
+impl GpuComputePass<'_> {
+    /// Binds a compute pipeline for subsequent dispatch calls.
+    pub fn bind_compute_pipeline(&mut self, pipeline: &GpuComputePipeline) {
+        unsafe { sys::SDL_BindGPUComputePipeline(self.raw(), pipeline.raw()) };
+    }
+
+    /// Binds texture-sampler pairs for use in the compute shader, starting at `first_slot`.
+    pub fn bind_samplers(
+        &mut self,
+        first_slot: u32,
+        bindings: &[GpuTextureSamplerBinding],
+    ) -> Result<(), Error> {
+        let num_bindings = u32::try_from(bindings.len())?;
+        unsafe {
+            sys::SDL_BindGPUComputeSamplers(
+                self.raw(),
+                first_slot,
+                bindings.as_ptr() as *const _,
+                num_bindings,
+            )
+        };
+        Ok(())
+    }
+
+    /// Binds read-only storage textures for use in the compute shader, starting at `first_slot`.
+    pub fn bind_storage_textures(
+        &mut self,
+        first_slot: u32,
+        textures: &[&GpuTexture],
+    ) -> Result<(), Error> {
+        let num_bindings = u32::try_from(textures.len())?;
+        let textures: Vec<_> = textures.iter().map(|texture| texture.raw()).collect();
+        unsafe {
+            sys::SDL_BindGPUComputeStorageTextures(
+                self.raw(),
+                first_slot,
+                textures.as_ptr(),
+                num_bindings,
+            )
+        };
+        Ok(())
+    }
+
+    /// Binds read-only storage buffers for use in the compute shader, starting at `first_slot`.
+    pub fn bind_storage_buffers(
+        &mut self,
+        first_slot: u32,
+        buffers: &[&GpuBuffer],
+    ) -> Result<(), Error> {
+        let num_bindings = u32::try_from(buffers.len())?;
+        let buffers: Vec<_> = buffers.iter().map(|buffer| buffer.raw()).collect();
+        unsafe {
+            sys::SDL_BindGPUComputeStorageBuffers(
+                self.raw(),
+                first_slot,
+                buffers.as_ptr(),
+                num_bindings,
+            )
+        };
+        Ok(())
+    }
+
+    /// Dispatches compute work groups using the bound compute state.
+    pub fn dispatch(&mut self, groupcount_x: u32, groupcount_y: u32, groupcount_z: u32) {
+        unsafe {
+            sys::SDL_DispatchGPUCompute(self.raw(), groupcount_x, groupcount_y, groupcount_z)
+        };
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_GPUComputePass {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for GpuComputePass<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_EndGPUComputePass(self.raw()) };
+    }
+}
+
+/// A fence signaled once a submitted command buffer has completed.
+pub struct GpuFence {
+    device: GpuDevice,
+    ptr: NonNull<sys::SDL_GPUFence>,
+}
+
+impl GpuFence {
+    /// Returns true if the command buffer has completed.
+    pub fn is_signaled(&self) -> bool {
+        unsafe { sys::SDL_QueryGPUFence(self.device.raw(), self.raw()) }
+    }
+
+    /// Blocks the thread until the command buffer has completed.
+    pub fn wait(&self) -> Result<(), Error> {
+        let fences = [self.raw()];
+        let result =
+            unsafe { sys::SDL_WaitForGPUFences(self.device.raw(), true, fences.as_ptr(), 1) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_GPUFence {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for GpuFence {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_ReleaseGPUFence(self.device.raw(), self.raw()) };
+    }
+}
+
+/// A texture of a window's swapchain, acquired with [`GpuCommandBuffer::acquire_swapchain_texture`].
+///
+/// The texture is presented when the command buffer is submitted.
+#[derive(Copy, Clone)]
+pub struct GpuSwapchainTexture<'a> {
+    _window: PhantomData<&'a GpuWindow>,
+    ptr: NonNull<sys::SDL_GPUTexture>,
+    width: u32,
+    height: u32,
+}
+
+impl GpuSwapchainTexture<'_> {
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_GPUTexture {
+        self.ptr.as_ptr()
+    }
+}
+
+/// A GPU buffer, used for vertices, indices, indirect draw arguments or storage.
+pub struct GpuBuffer {
+    device: GpuDevice,
+    ptr: NonNull<sys::SDL_GPUBuffer>,
+    size: u32,
+}
+
+impl GpuBuffer {
+    /// Creates a buffer of `size` bytes.
+    ///
+    /// The contents are undefined until data is uploaded with a [`GpuCopyPass`].
+    pub fn new(device: &GpuDevice, usage: GpuBufferUsage, size: u32) -> Result<Self, Error> {
+        let info = sys::SDL_GPUBufferCreateInfo {
+            usage: usage.0,
+            size,
+            props: 0,
+        };
+        let ptr = unsafe { sys::SDL_CreateGPUBuffer(device.raw(), &raw const info) };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            device: device.clone(),
+            ptr,
+            size,
+        })
+    }
+
+    /// Returns the size of the buffer, in bytes.
+    #[inline]
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// Sets a name for the buffer, shown in graphics debuggers.
+    pub fn set_name(&mut self, name: &str) -> Result<(), Error> {
+        let name = CString::new(name)?;
+        unsafe { sys::SDL_SetGPUBufferName(self.device.raw(), self.raw(), name.as_ptr()) };
+        Ok(())
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_GPUBuffer {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for GpuBuffer {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_ReleaseGPUBuffer(self.device.raw(), self.raw()) };
+    }
+}
+
+/// A buffer used to move data between the CPU and the GPU.
+pub struct GpuTransferBuffer {
+    device: GpuDevice,
+    ptr: NonNull<sys::SDL_GPUTransferBuffer>,
+    size: u32,
+}
+
+impl GpuTransferBuffer {
+    /// Creates a transfer buffer of `size` bytes.
+    pub fn new(
+        device: &GpuDevice,
+        usage: GpuTransferBufferUsage,
+        size: u32,
+    ) -> Result<Self, Error> {
+        let info = sys::SDL_GPUTransferBufferCreateInfo {
+            usage: usage.to_ll(),
+            size,
+            props: 0,
+        };
+        let ptr = unsafe { sys::SDL_CreateGPUTransferBuffer(device.raw(), &raw const info) };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            device: device.clone(),
+            ptr,
+            size,
+        })
+    }
+
+    /// Returns the size of the buffer, in bytes.
+    #[inline]
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// Maps the buffer into application memory.
+    ///
+    /// If `cycle` is true and the buffer is still in use by the GPU, SDL maps a fresh copy
+    /// instead of waiting. The buffer is unmapped when the returned value is dropped.
+    pub fn map(&mut self, cycle: bool) -> Result<GpuTransferBufferMapping<'_>, Error> {
+        let ptr = unsafe { sys::SDL_MapGPUTransferBuffer(self.device.raw(), self.raw(), cycle) };
+        let ptr = NonNull::new(ptr as *mut u8).ok_or_else(Error::new)?;
+        Ok(GpuTransferBufferMapping { buffer: self, ptr })
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_GPUTransferBuffer {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for GpuTransferBuffer {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_ReleaseGPUTransferBuffer(self.device.raw(), self.raw()) };
+    }
+}
+
+/// A mapped [`GpuTransferBuffer`], accessible as a byte slice.
+pub struct GpuTransferBufferMapping<'a> {
+    buffer: &'a mut GpuTransferBuffer,
+    ptr: NonNull<u8>,
+}
+
+impl Deref for GpuTransferBufferMapping<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.buffer.size as usize) }
+    }
+}
+
+impl DerefMut for GpuTransferBufferMapping<'_> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.buffer.size as usize) }
+    }
+}
+
+impl Drop for GpuTransferBufferMapping<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_UnmapGPUTransferBuffer(self.buffer.device.raw(), self.buffer.raw()) };
+    }
+}
+
+/// A GPU texture.
+pub struct GpuTexture {
+    device: GpuDevice,
+    ptr: NonNull<sys::SDL_GPUTexture>,
+}
+
+impl GpuTexture {
+    /// Creates a texture.
+    ///
+    /// The contents are undefined until data is uploaded with a [`GpuCopyPass`] or the texture is
+    /// drawn into.
+    pub fn new(device: &GpuDevice, info: &GpuTextureCreateInfo) -> Result<Self, Error> {
+        let info = info.to_ll();
+        let ptr = unsafe { sys::SDL_CreateGPUTexture(device.raw(), &raw const info) };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            device: device.clone(),
+            ptr,
+        })
+    }
+
+    /// Sets a name for the texture, shown in graphics debuggers.
+    pub fn set_name(&mut self, name: &str) -> Result<(), Error> {
+        let name = CString::new(name)?;
+        unsafe { sys::SDL_SetGPUTextureName(self.device.raw(), self.raw(), name.as_ptr()) };
+        Ok(())
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_GPUTexture {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for GpuTexture {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_ReleaseGPUTexture(self.device.raw(), self.raw()) };
+    }
+}
+
+/// A texture sampler.
+pub struct GpuSampler {
+    device: GpuDevice,
+    ptr: NonNull<sys::SDL_GPUSampler>,
+}
+
+impl GpuSampler {
+    /// Creates a sampler.
+    pub fn new(device: &GpuDevice, info: &GpuSamplerCreateInfo) -> Result<Self, Error> {
+        let info = info.to_ll();
+        let ptr = unsafe { sys::SDL_CreateGPUSampler(device.raw(), &raw const info) };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            device: device.clone(),
+            ptr,
+        })
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_GPUSampler {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for GpuSampler {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_ReleaseGPUSampler(self.device.raw(), self.raw()) };
+    }
+}
+
+/// A compiled shader, used to create a [`GpuGraphicsPipeline`].
+pub struct GpuShader {
+    device: GpuDevice,
+    ptr: NonNull<sys::SDL_GPUShader>,
+}
+
+impl GpuShader {
+    /// Creates a shader from compiled code in one of the device's [`GpuDevice::shader_formats`].
+    pub fn new(device: &GpuDevice, info: &GpuShaderCreateInfo) -> Result<Self, Error> {
+        let entrypoint = CString::new(info.entrypoint)?;
+        let info = sys::SDL_GPUShaderCreateInfo {
+            code_size: info.code.len(),
+            code: info.code.as_ptr(),
+            entrypoint: entrypoint.as_ptr(),
+            format: info.format.0,
+            stage: info.stage.to_ll(),
+            num_samplers: info.num_samplers,
+            num_storage_textures: info.num_storage_textures,
+            num_storage_buffers: info.num_storage_buffers,
+            num_uniform_buffers: info.num_uniform_buffers,
+            props: 0,
+        };
+        let ptr = unsafe { sys::SDL_CreateGPUShader(device.raw(), &raw const info) };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            device: device.clone(),
+            ptr,
+        })
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_GPUShader {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for GpuShader {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_ReleaseGPUShader(self.device.raw(), self.raw()) };
+    }
+}
+
+/// A graphics pipeline, bundling shaders with the fixed-function state used to draw.
+pub struct GpuGraphicsPipeline {
+    device: GpuDevice,
+    ptr: NonNull<sys::SDL_GPUGraphicsPipeline>,
+}
+
+impl GpuGraphicsPipeline {
+    /// Creates a graphics pipeline.
+    ///
+    /// The shaders can be dropped once the pipeline is created.
+    pub fn new(device: &GpuDevice, info: &GpuGraphicsPipelineCreateInfo) -> Result<Self, Error> {
+        let num_vertex_buffers = u32::try_from(info.vertex_buffers.len())?;
+        let num_vertex_attributes = u32::try_from(info.vertex_attributes.len())?;
+        let num_color_targets = u32::try_from(info.color_targets.len())?;
+        let mut ll: sys::SDL_GPUGraphicsPipelineCreateInfo = unsafe { core::mem::zeroed() };
+        ll.vertex_shader = info.vertex_shader.raw();
+        ll.fragment_shader = info.fragment_shader.raw();
+        ll.vertex_input_state = sys::SDL_GPUVertexInputState {
+            vertex_buffer_descriptions: info.vertex_buffers.as_ptr() as *const _,
+            num_vertex_buffers,
+            vertex_attributes: info.vertex_attributes.as_ptr() as *const _,
+            num_vertex_attributes,
+        };
+        ll.primitive_type = info.primitive_type.to_ll();
+        ll.rasterizer_state.fill_mode = info.fill_mode.to_ll();
+        ll.rasterizer_state.cull_mode = info.cull_mode.to_ll();
+        ll.rasterizer_state.front_face = info.front_face.to_ll();
+        ll.rasterizer_state.enable_depth_clip = true;
+        ll.multisample_state.sample_count = info.sample_count.to_ll();
+        if let Some(compare_op) = info.depth_test {
+            ll.depth_stencil_state.compare_op = compare_op.to_ll();
+            ll.depth_stencil_state.enable_depth_test = true;
+            ll.depth_stencil_state.enable_depth_write = info.depth_write;
+        }
+        ll.target_info.color_target_descriptions = info.color_targets.as_ptr() as *const _;
+        ll.target_info.num_color_targets = num_color_targets;
+        if let Some(format) = info.depth_stencil_format {
+            ll.target_info.depth_stencil_format = format.0;
+            ll.target_info.has_depth_stencil_target = true;
+        }
+        let ptr = unsafe { sys::SDL_CreateGPUGraphicsPipeline(device.raw(), &raw const ll) };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            device: device.clone(),
+            ptr,
+        })
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_GPUGraphicsPipeline {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for GpuGraphicsPipeline {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_ReleaseGPUGraphicsPipeline(self.device.raw(), self.raw()) };
+    }
+}
+
+/// A compute pipeline, created directly from compute shader code.
+pub struct GpuComputePipeline {
+    device: GpuDevice,
+    ptr: NonNull<sys::SDL_GPUComputePipeline>,
+}
+
+impl GpuComputePipeline {
+    /// Creates a compute pipeline.
+    pub fn new(device: &GpuDevice, info: &GpuComputePipelineCreateInfo) -> Result<Self, Error> {
+        let entrypoint = CString::new(info.entrypoint)?;
+        let (threadcount_x, threadcount_y, threadcount_z) = info.threadcount;
+        let info = sys::SDL_GPUComputePipelineCreateInfo {
+            code_size: info.code.len(),
+            code: info.code.as_ptr(),
+            entrypoint: entrypoint.as_ptr(),
+            format: info.format.0,
+            num_samplers: info.num_samplers,
+            num_readonly_storage_textures: info.num_readonly_storage_textures,
+            num_readonly_storage_buffers: info.num_readonly_storage_buffers,
+            num_readwrite_storage_textures: info.num_readwrite_storage_textures,
+            num_readwrite_storage_buffers: info.num_readwrite_storage_buffers,
+            num_uniform_buffers: info.num_uniform_buffers,
+            threadcount_x,
+            threadcount_y,
+            threadcount_z,
+            props: 0,
+        };
+        let ptr = unsafe { sys::SDL_CreateGPUComputePipeline(device.raw(), &raw const info) };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            device: device.clone(),
+            ptr,
+        })
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_GPUComputePipeline {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for GpuComputePipeline {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_ReleaseGPUComputePipeline(self.device.raw(), self.raw()) };
+    }
+}
+
+/// Parameters of [`GpuShader::new`].
+#[derive(Copy, Clone, Debug)]
+pub struct GpuShaderCreateInfo<'a> {
+    pub code: &'a [u8],
+    /// Name of the entry point function, usually `"main"`.
+    pub entrypoint: &'a str,
+    pub format: GpuShaderFormat,
+    pub stage: GpuShaderStage,
+    pub num_samplers: u32,
+    pub num_storage_textures: u32,
+    pub num_storage_buffers: u32,
+    pub num_uniform_buffers: u32,
+}
+
+/// Parameters of [`GpuComputePipeline::new`].
+#[derive(Copy, Clone, Debug)]
+pub struct GpuComputePipelineCreateInfo<'a> {
+    pub code: &'a [u8],
+    /// Name of the entry point function, usually `"main"`.
+    pub entrypoint: &'a str,
+    pub format: GpuShaderFormat,
+    pub num_samplers: u32,
+    pub num_readonly_storage_textures: u32,
+    pub num_readonly_storage_buffers: u32,
+    pub num_readwrite_storage_textures: u32,
+    pub num_readwrite_storage_buffers: u32,
+    pub num_uniform_buffers: u32,
+    /// Number of threads in each work group, matching the shader's declaration.
+    pub threadcount: (u32, u32, u32),
+}
+
+/// Parameters of [`GpuGraphicsPipeline::new`].
+///
+/// Stencil testing and depth bias are left disabled.
+#[derive(Copy, Clone)]
+pub struct GpuGraphicsPipelineCreateInfo<'a> {
+    pub vertex_shader: &'a GpuShader,
+    pub fragment_shader: &'a GpuShader,
+    pub vertex_buffers: &'a [GpuVertexBufferDescription],
+    pub vertex_attributes: &'a [GpuVertexAttribute],
+    pub primitive_type: GpuPrimitiveType,
+    pub fill_mode: GpuFillMode,
+    pub cull_mode: GpuCullMode,
+    pub front_face: GpuFrontFace,
+    pub sample_count: GpuSampleCount,
+    pub color_targets: &'a [GpuColorTargetDescription],
+    /// Format of the depth stencil target, or `None` if the pipeline doesn't use one.
+    pub depth_stencil_format: Option<GpuTextureFormat>,
+    /// Comparison used for depth testing, or `None` to disable it.
+    pub depth_test: Option<GpuCompareOp>,
+    /// Whether passing fragments write their depth. Ignored unless `depth_test` is set.
+    pub depth_write: bool,
+}
+
+/// Parameters of [`GpuTexture::new`].
+#[derive(Copy, Clone, Debug)]
+pub struct GpuTextureCreateInfo {
+    pub texture_type: GpuTextureType,
+    pub format: GpuTextureFormat,
+    pub usage: GpuTextureUsage,
+    pub width: u32,
+    pub height: u32,
+    /// Number of layers for array and cube textures, or the depth of 3D textures.
+    pub layer_count_or_depth: u32,
+    pub num_levels: u32,
+    pub sample_count: GpuSampleCount,
+}
+
+impl GpuTextureCreateInfo {
+    /// Parameters of a single-level 2D texture.
+    pub fn new_2d(
+        format: GpuTextureFormat,
+        usage: GpuTextureUsage,
+        width: u32,
+        height: u32,
+    ) -> Self {
+        Self {
+            texture_type: GpuTextureType::Texture2D,
+            format,
+            usage,
+            width,
+            height,
+            layer_count_or_depth: 1,
+            num_levels: 1,
+            sample_count: GpuSampleCount::One,
+        }
+    }
+
+    fn to_ll(self) -> sys::SDL_GPUTextureCreateInfo {
+        sys::SDL_GPUTextureCreateInfo {
+            type_: self.texture_type.to_ll(),
+            format: self.format.0,
+            usage: self.usage.0,
+            width: self.width,
+            height: self.height,
+            layer_count_or_depth: self.layer_count_or_depth,
+            num_levels: self.num_levels,
+            sample_count: self.sample_count.to_ll(),
+            props: 0,
+        }
+    }
+}
+
+/// Parameters of [`GpuSampler::new`].
+#[derive(Copy, Clone, Debug, Default)]
+pub struct GpuSamplerCreateInfo {
+    pub min_filter: GpuFilter,
+    pub mag_filter: GpuFilter,
+    pub mipmap_mode: GpuSamplerMipmapMode,
+    pub address_mode_u: GpuSamplerAddressMode,
+    pub address_mode_v: GpuSamplerAddressMode,
+    pub address_mode_w: GpuSamplerAddressMode,
+    /// Maximum anisotropy, or `None` to disable anisotropic filtering.
+    pub max_anisotropy: Option<f32>,
+}
+
+impl GpuSamplerCreateInfo {
+    fn to_ll(self) -> sys::SDL_GPUSamplerCreateInfo {
+        let mut ll: sys::SDL_GPUSamplerCreateInfo = unsafe { core::mem::zeroed() };
+        ll.min_filter = self.min_filter.to_ll();
+        ll.mag_filter = self.mag_filter.to_ll();
+        ll.mipmap_mode = self.mipmap_mode.to_ll();
+        ll.address_mode_u = self.address_mode_u.to_ll();
+        ll.address_mode_v = self.address_mode_v.to_ll();
+        ll.address_mode_w = self.address_mode_w.to_ll();
+        ll.max_lod = 1000.0;
+        if let Some(max_anisotropy) = self.max_anisotropy {
+            ll.max_anisotropy = max_anisotropy;
+            ll.enable_anisotropy = true;
+        }
+        ll
+    }
+}
+
+/// A region of a [`GpuTexture`], used by [`GpuCopyPass`] transfers.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct GpuTextureRegion {
+    pub mip_level: u32,
+    pub layer: u32,
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+    pub w: u32,
+    pub h: u32,
+    pub d: u32,
+}
+
+impl GpuTextureRegion {
+    /// A region of the first level and layer of a 2D texture.
+    pub fn new_2d(x: u32, y: u32, w: u32, h: u32) -> Self {
+        Self {
+            x,
+            y,
+            w,
+            h,
+            d: 1,
+            ..Default::default()
+        }
+    }
+
+    fn to_ll(self, texture: *mut sys::SDL_GPUTexture) -> sys::SDL_GPUTextureRegion {
+        sys::SDL_GPUTextureRegion {
+            texture,
+            mip_level: self.mip_level,
+            layer: self.layer,
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            w: self.w,
+            h: self.h,
+            d: self.d,
+        }
+    }
+}
+
+/// A viewport, in pixels of the render target.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GpuViewport {
+    pub x: f32,
+    pub y: f32,
+    pub w: f32,
+    pub h: f32,
+    pub min_depth: f32,
+    pub max_depth: f32,
+}
+
+impl GpuViewport {
+    /// A viewport covering the given rectangle and the full depth range.
+    pub fn new(x: f32, y: f32, w: f32, h: f32) -> Self {
+        Self {
+            x,
+            y,
+            w,
+            h,
+            min_depth: 0.0,
+            max_depth: 1.0,
+        }
+    }
+
+    fn to_ll(self) -> sys::SDL_GPUViewport {
+        sys::SDL_GPUViewport {
+            x: self.x,
+            y: self.y,
+            w: self.w,
+            h: self.h,
+            min_depth: self.min_depth,
+            max_depth: self.max_depth,
+        }
+    }
+}
+
+/// Implemented by the texture types that can be used as render targets.
+pub trait GpuTextureHandle: private::Sealed {
+    fn raw(&self) -> *mut sys::SDL_GPUTexture;
+}
+
+impl GpuTextureHandle for GpuTexture {
+    #[inline]
+    fn raw(&self) -> *mut sys::SDL_GPUTexture {
+        GpuTexture::raw(self)
+    }
+}
+
+impl GpuTextureHandle for GpuSwapchainTexture<'_> {
+    #[inline]
+    fn raw(&self) -> *mut sys::SDL_GPUTexture {
+        GpuSwapchainTexture::raw(self)
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::GpuTexture {}
+    impl Sealed for super::GpuSwapchainTexture<'_> {}
+}
+
+/// A color target of a render pass.
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+pub struct GpuColorTargetInfo<'a>(sys::SDL_GPUColorTargetInfo, PhantomData<&'a ()>);
+
+impl<'a> GpuColorTargetInfo<'a> {
+    pub fn new<T: GpuTextureHandle>(
+        texture: &'a T,
+        load_op: GpuLoadOp,
+        store_op: GpuStoreOp,
+    ) -> Self {
+        let mut ll: sys::SDL_GPUColorTargetInfo = unsafe { core::mem::zeroed() };
+        ll.texture = texture.raw();
+        ll.load_op = load_op.to_ll();
+        ll.store_op = store_op.to_ll();
+        Self(ll, PhantomData)
+    }
+
+    /// A target that is cleared to `color` and stored at the end of the pass.
+    pub fn clear<T: GpuTextureHandle>(texture: &'a T, color: ColorF32) -> Self {
+        let mut info = Self::new(texture, GpuLoadOp::Clear, GpuStoreOp::Store);
+        info.set_clear_color(color);
+        info
+    }
+
+    /// Sets the color the target is cleared to when the load op is [`GpuLoadOp::Clear`].
+    pub fn set_clear_color(&mut self, color: ColorF32) {
+        self.0.clear_color = color.to_ll();
+    }
+
+    /// Sets the mip level and layer (or depth plane, for 3D textures) used as the target.
+    pub fn set_subresource(&mut self, mip_level: u32, layer_or_depth_plane: u32) {
+        self.0.mip_level = mip_level;
+        self.0.layer_or_depth_plane = layer_or_depth_plane;
+    }
+
+    /// If true and the texture is still in use by the GPU, SDL draws into a fresh copy instead of
+    /// waiting. Ignored when the load op is [`GpuLoadOp::Load`].
+    pub fn set_cycle(&mut self, cycle: bool) {
+        self.0.cycle = cycle;
+    }
+}
+
+/// The depth stencil target of a render pass.
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+pub struct GpuDepthStencilTargetInfo<'a>(sys::SDL_GPUDepthStencilTargetInfo, PhantomData<&'a ()>);
+
+impl<'a> GpuDepthStencilTargetInfo<'a> {
+    pub fn new(
+        texture: &'a GpuTexture,
+        clear_depth: f32,
+        load_op: GpuLoadOp,
+        store_op: GpuStoreOp,
+    ) -> Self {
+        let mut ll: sys::SDL_GPUDepthStencilTargetInfo = unsafe { core::mem::zeroed() };
+        ll.texture = texture.raw();
+        ll.clear_depth = clear_depth;
+        ll.load_op = load_op.to_ll();
+        ll.store_op = store_op.to_ll();
+        ll.stencil_load_op = GpuLoadOp::DontCare.to_ll();
+        ll.stencil_store_op = GpuStoreOp::DontCare.to_ll();
+        Self(ll, PhantomData)
+    }
+
+    /// Sets the load and store ops of the stencil aspect, and the value it's cleared to.
+    pub fn set_stencil(&mut self, load_op: GpuLoadOp, store_op: GpuStoreOp, clear_stencil: u8) {
+        self.0.stencil_load_op = load_op.to_ll();
+        self.0.stencil_store_op = store_op.to_ll();
+        self.0.clear_stencil = clear_stencil;
+    }
+
+    /// See [`GpuColorTargetInfo::set_cycle`].
+    pub fn set_cycle(&mut self, cycle: bool) {
+        self.0.cycle = cycle;
+    }
+}
+
+/// A buffer bound to a render pass, starting at `offset` bytes.
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+pub struct GpuBufferBinding<'a>(sys::SDL_GPUBufferBinding, PhantomData<&'a ()>);
+
+impl<'a> GpuBufferBinding<'a> {
+    pub fn new(buffer: &'a GpuBuffer, offset: u32) -> Self {
+        Self(
+            sys::SDL_GPUBufferBinding {
+                buffer: buffer.raw(),
+                offset,
+            },
+            PhantomData,
+        )
+    }
+}
+
+/// A texture and the sampler used to read it.
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+pub struct GpuTextureSamplerBinding<'a>(sys::SDL_GPUTextureSamplerBinding, PhantomData<&'a ()>);
+
+impl<'a> GpuTextureSamplerBinding<'a> {
+    pub fn new(texture: &'a GpuTexture, sampler: &'a GpuSampler) -> Self {
+        Self(
+            sys::SDL_GPUTextureSamplerBinding {
+                texture: texture.raw(),
+                sampler: sampler.raw(),
+            },
+            PhantomData,
+        )
+    }
+}
+
+/// A storage texture written by a compute pass.
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+pub struct GpuStorageTextureBinding<'a>(
+    sys::SDL_GPUStorageTextureReadWriteBinding,
+    PhantomData<&'a ()>,
+);
+
+impl<'a> GpuStorageTextureBinding<'a> {
+    pub fn new(texture: &'a GpuTexture, mip_level: u32, layer: u32, cycle: bool) -> Self {
+        let mut ll: sys::SDL_GPUStorageTextureReadWriteBinding = unsafe { core::mem::zeroed() };
+        ll.texture = texture.raw();
+        ll.mip_level = mip_level;
+        ll.layer = layer;
+        ll.cycle = cycle;
+        Self(ll, PhantomData)
+    }
+}
+
+/// A storage buffer written by a compute pass.
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+pub struct GpuStorageBufferBinding<'a>(
+    sys::SDL_GPUStorageBufferReadWriteBinding,
+    PhantomData<&'a ()>,
+);
+
+impl<'a> GpuStorageBufferBinding<'a> {
+    pub fn new(buffer: &'a GpuBuffer, cycle: bool) -> Self {
+        let mut ll: sys::SDL_GPUStorageBufferReadWriteBinding = unsafe { core::mem::zeroed() };
+        ll.buffer = buffer.raw();
+        ll.cycle = cycle;
+        Self(ll, PhantomData)
+    }
+}
+
+/// Describes a vertex buffer slot of a [`GpuGraphicsPipeline`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug)]
+pub struct GpuVertexBufferDescription(sys::SDL_GPUVertexBufferDescription);
+
+impl GpuVertexBufferDescription {
+    /// `pitch` is the number of bytes between consecutive elements of the buffer.
+    pub fn new(slot: u32, pitch: u32, input_rate: GpuVertexInputRate) -> Self {
+        Self(sys::SDL_GPUVertexBufferDescription {
+            slot,
+            pitch,
+            input_rate: input_rate.to_ll(),
+            instance_step_rate: 0,
+        })
+    }
+}
+
+/// Describes a vertex attribute of a [`GpuGraphicsPipeline`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug)]
+pub struct GpuVertexAttribute(sys::SDL_GPUVertexAttribute);
+
+impl GpuVertexAttribute {
+    /// `offset` is the attribute's byte offset relative to the start of the vertex element.
+    pub fn new(
+        location: u32,
+        buffer_slot: u32,
+        format: GpuVertexElementFormat,
+        offset: u32,
+    ) -> Self {
+        Self(sys::SDL_GPUVertexAttribute {
+            location,
+            buffer_slot,
+            format: format.0,
+            offset,
+        })
+    }
+}
+
+/// Describes a color target of a [`GpuGraphicsPipeline`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug)]
+pub struct GpuColorTargetDescription(sys::SDL_GPUColorTargetDescription);
+
+impl GpuColorTargetDescription {
+    /// Describes a target of the given format, with blending disabled if `blend_state` is `None`.
+    pub fn new(format: GpuTextureFormat, blend_state: Option<GpuBlendState>) -> Self {
+        let mut ll: sys::SDL_GPUColorTargetDescription = unsafe { core::mem::zeroed() };
+        ll.format = format.0;
+        if let Some(blend) = blend_state {
+            ll.blend_state.src_color_blendfactor = blend.src_color_factor.to_ll();
+            ll.blend_state.dst_color_blendfactor = blend.dst_color_factor.to_ll();
+            ll.blend_state.color_blend_op = blend.color_op.to_ll();
+            ll.blend_state.src_alpha_blendfactor = blend.src_alpha_factor.to_ll();
+            ll.blend_state.dst_alpha_blendfactor = blend.dst_alpha_factor.to_ll();
+            ll.blend_state.alpha_blend_op = blend.alpha_op.to_ll();
+            ll.blend_state.enable_blend = true;
+        }
+        Self(ll)
+    }
+}
+
+/// Blending equations of a color target.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GpuBlendState {
+    pub src_color_factor: GpuBlendFactor,
+    pub dst_color_factor: GpuBlendFactor,
+    pub color_op: GpuBlendOp,
+    pub src_alpha_factor: GpuBlendFactor,
+    pub dst_alpha_factor: GpuBlendFactor,
+    pub alpha_op: GpuBlendOp,
+}
+
+impl GpuBlendState {
+    /// Straight alpha blending: `dst = src * srcA + dst * (1 - srcA)`.
+    pub const ALPHA: Self = Self {
+        src_color_factor: GpuBlendFactor::SrcAlpha,
+        dst_color_factor: GpuBlendFactor::OneMinusSrcAlpha,
+        color_op: GpuBlendOp::Add,
+        src_alpha_factor: GpuBlendFactor::One,
+        dst_alpha_factor: GpuBlendFactor::OneMinusSrcAlpha,
+        alpha_op: GpuBlendOp::Add,
+    };
+
+    /// Premultiplied alpha blending: `dst = src + dst * (1 - srcA)`.
+    pub const PREMULTIPLIED: Self = Self {
+        src_color_factor: GpuBlendFactor::One,
+        dst_color_factor: GpuBlendFactor::OneMinusSrcAlpha,
+        color_op: GpuBlendOp::Add,
+        src_alpha_factor: GpuBlendFactor::One,
+        dst_alpha_factor: GpuBlendFactor::OneMinusSrcAlpha,
+        alpha_op: GpuBlendOp::Add,
+    };
+}
+
+/// A set of shader formats.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct GpuShaderFormat(sys::SDL_GPUShaderFormat);
+
+impl GpuShaderFormat {
+    pub const INVALID: Self = Self(sys::SDL_GPU_SHADERFORMAT_INVALID);
+    /// Precompiled shaders for NDA'd platforms.
+    pub const PRIVATE: Self = Self(sys::SDL_GPU_SHADERFORMAT_PRIVATE);
+    /// SPIR-V shaders for Vulkan.
+    pub const SPIRV: Self = Self(sys::SDL_GPU_SHADERFORMAT_SPIRV);
+    /// DXBC SM5_1 shaders for D3D12.
+    pub const DXBC: Self = Self(sys::SDL_GPU_SHADERFORMAT_DXBC);
+    /// DXIL SM6_0 shaders for D3D12.
+    pub const DXIL: Self = Self(sys::SDL_GPU_SHADERFORMAT_DXIL);
+    /// MSL shaders for Metal.
+    pub const MSL: Self = Self(sys::SDL_GPU_SHADERFORMAT_MSL);
+    /// Precompiled metallib shaders for Metal.
+    pub const METALLIB: Self = Self(sys::SDL_GPU_SHADERFORMAT_METALLIB);
+
+    #[inline]
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUShaderFormat {
+        self.0
+    }
+}
+
+impl BitOr for GpuShaderFormat {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// How a [`GpuBuffer`] is used.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct GpuBufferUsage(sys::SDL_GPUBufferUsageFlags);
+
+impl GpuBufferUsage {
+    pub const VERTEX: Self = Self(sys::SDL_GPU_BUFFERUSAGE_VERTEX);
+    pub const INDEX: Self = Self(sys::SDL_GPU_BUFFERUSAGE_INDEX);
+    pub const INDIRECT: Self = Self(sys::SDL_GPU_BUFFERUSAGE_INDIRECT);
+    pub const GRAPHICS_STORAGE_READ: Self = Self(sys::SDL_GPU_BUFFERUSAGE_GRAPHICS_STORAGE_READ);
+    pub const COMPUTE_STORAGE_READ: Self = Self(sys::SDL_GPU_BUFFERUSAGE_COMPUTE_STORAGE_READ);
+    pub const COMPUTE_STORAGE_WRITE: Self = Self(sys::SDL_GPU_BUFFERUSAGE_COMPUTE_STORAGE_WRITE);
+
+    #[inline]
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUBufferUsageFlags {
+        self.0
+    }
+}
+
+impl BitOr for GpuBufferUsage {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// How a [`GpuTexture`] is used.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct GpuTextureUsage(sys::SDL_GPUTextureUsageFlags);
+
+impl GpuTextureUsage {
+    pub const SAMPLER: Self = Self(sys::SDL_GPU_TEXTUREUSAGE_SAMPLER);
+    pub const COLOR_TARGET: Self = Self(sys::SDL_GPU_TEXTUREUSAGE_COLOR_TARGET);
+    pub const DEPTH_STENCIL_TARGET: Self = Self(sys::SDL_GPU_TEXTUREUSAGE_DEPTH_STENCIL_TARGET);
+    pub const GRAPHICS_STORAGE_READ: Self = Self(sys::SDL_GPU_TEXTUREUSAGE_GRAPHICS_STORAGE_READ);
+    pub const COMPUTE_STORAGE_READ: Self = Self(sys::SDL_GPU_TEXTUREUSAGE_COMPUTE_STORAGE_READ);
+    pub const COMPUTE_STORAGE_WRITE: Self = Self(sys::SDL_GPU_TEXTUREUSAGE_COMPUTE_STORAGE_WRITE);
+    pub const COMPUTE_STORAGE_SIMULTANEOUS_READ_WRITE: Self =
+        Self(sys::SDL_GPU_TEXTUREUSAGE_COMPUTE_STORAGE_SIMULTANEOUS_READ_WRITE);
+
+    #[inline]
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUTextureUsageFlags {
+        self.0
+    }
+}
+
+impl BitOr for GpuTextureUsage {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// The pixel format of a [`GpuTexture`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GpuTextureFormat(sys::SDL_GPUTextureFormat);
+
+impl GpuTextureFormat {
+    pub const INVALID: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_INVALID);
+    pub const A8_UNORM: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_A8_UNORM);
+    pub const R8_UNORM: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R8_UNORM);
+    pub const R8G8_UNORM: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R8G8_UNORM);
+    pub const R8G8B8A8_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R8G8B8A8_UNORM);
+    pub const R16_UNORM: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R16_UNORM);
+    pub const R16G16_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R16G16_UNORM);
+    pub const R16G16B16A16_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R16G16B16A16_UNORM);
+    pub const R10G10B10A2_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R10G10B10A2_UNORM);
+    pub const B5G6R5_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_B5G6R5_UNORM);
+    pub const B5G5R5A1_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_B5G5R5A1_UNORM);
+    pub const B4G4R4A4_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_B4G4R4A4_UNORM);
+    pub const B8G8R8A8_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_B8G8R8A8_UNORM);
+    pub const BC1_RGBA_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_BC1_RGBA_UNORM);
+    pub const BC2_RGBA_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_BC2_RGBA_UNORM);
+    pub const BC3_RGBA_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_BC3_RGBA_UNORM);
+    pub const BC4_R_UNORM: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_BC4_R_UNORM);
+    pub const BC5_RG_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_BC5_RG_UNORM);
+    pub const BC7_RGBA_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_BC7_RGBA_UNORM);
+    pub const BC6H_RGB_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_BC6H_RGB_FLOAT);
+    pub const BC6H_RGB_UFLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_BC6H_RGB_UFLOAT);
+    pub const R8_SNORM: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R8_SNORM);
+    pub const R8G8_SNORM: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R8G8_SNORM);
+    pub const R8G8B8A8_SNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R8G8B8A8_SNORM);
+    pub const R16_SNORM: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R16_SNORM);
+    pub const R16G16_SNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R16G16_SNORM);
+    pub const R16G16B16A16_SNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R16G16B16A16_SNORM);
+    pub const R16_FLOAT: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R16_FLOAT);
+    pub const R16G16_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R16G16_FLOAT);
+    pub const R16G16B16A16_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R16G16B16A16_FLOAT);
+    pub const R32_FLOAT: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R32_FLOAT);
+    pub const R32G32_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R32G32_FLOAT);
+    pub const R32G32B32A32_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R32G32B32A32_FLOAT);
+    pub const R11G11B10_UFLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R11G11B10_UFLOAT);
+    pub const R8_UINT: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R8_UINT);
+    pub const R8G8_UINT: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R8G8_UINT);
+    pub const R8G8B8A8_UINT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R8G8B8A8_UINT);
+    pub const R16_UINT: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R16_UINT);
+    pub const R16G16_UINT: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R16G16_UINT);
+    pub const R16G16B16A16_UINT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R16G16B16A16_UINT);
+    pub const R32_UINT: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R32_UINT);
+    pub const R32G32_UINT: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R32G32_UINT);
+    pub const R32G32B32A32_UINT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R32G32B32A32_UINT);
+    pub const R8_INT: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R8_INT);
+    pub const R8G8_INT: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R8G8_INT);
+    pub const R8G8B8A8_INT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R8G8B8A8_INT);
+    pub const R16_INT: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R16_INT);
+    pub const R16G16_INT: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R16G16_INT);
+    pub const R16G16B16A16_INT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R16G16B16A16_INT);
+    pub const R32_INT: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R32_INT);
+    pub const R32G32_INT: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R32G32_INT);
+    pub const R32G32B32A32_INT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R32G32B32A32_INT);
+    pub const R8G8B8A8_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_R8G8B8A8_UNORM_SRGB);
+    pub const B8G8R8A8_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_B8G8R8A8_UNORM_SRGB);
+    pub const BC1_RGBA_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_BC1_RGBA_UNORM_SRGB);
+    pub const BC2_RGBA_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_BC2_RGBA_UNORM_SRGB);
+    pub const BC3_RGBA_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_BC3_RGBA_UNORM_SRGB);
+    pub const BC7_RGBA_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_BC7_RGBA_UNORM_SRGB);
+    pub const D16_UNORM: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_D16_UNORM);
+    pub const D24_UNORM: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_D24_UNORM);
+    pub const D32_FLOAT: Self = Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_D32_FLOAT);
+    pub const D24_UNORM_S8_UINT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_D24_UNORM_S8_UINT);
+    pub const D32_FLOAT_S8_UINT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_D32_FLOAT_S8_UINT);
+    pub const ASTC_4X4_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_4x4_UNORM);
+    pub const ASTC_5X4_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_5x4_UNORM);
+    pub const ASTC_5X5_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_5x5_UNORM);
+    pub const ASTC_6X5_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_6x5_UNORM);
+    pub const ASTC_6X6_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_6x6_UNORM);
+    pub const ASTC_8X5_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_8x5_UNORM);
+    pub const ASTC_8X6_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_8x6_UNORM);
+    pub const ASTC_8X8_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_8x8_UNORM);
+    pub const ASTC_10X5_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_10x5_UNORM);
+    pub const ASTC_10X6_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_10x6_UNORM);
+    pub const ASTC_10X8_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_10x8_UNORM);
+    pub const ASTC_10X10_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_10x10_UNORM);
+    pub const ASTC_12X10_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_12x10_UNORM);
+    pub const ASTC_12X12_UNORM: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_12x12_UNORM);
+    pub const ASTC_4X4_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_4x4_UNORM_SRGB);
+    pub const ASTC_5X4_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_5x4_UNORM_SRGB);
+    pub const ASTC_5X5_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_5x5_UNORM_SRGB);
+    pub const ASTC_6X5_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_6x5_UNORM_SRGB);
+    pub const ASTC_6X6_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_6x6_UNORM_SRGB);
+    pub const ASTC_8X5_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_8x5_UNORM_SRGB);
+    pub const ASTC_8X6_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_8x6_UNORM_SRGB);
+    pub const ASTC_8X8_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_8x8_UNORM_SRGB);
+    pub const ASTC_10X5_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_10x5_UNORM_SRGB);
+    pub const ASTC_10X6_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_10x6_UNORM_SRGB);
+    pub const ASTC_10X8_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_10x8_UNORM_SRGB);
+    pub const ASTC_10X10_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_10x10_UNORM_SRGB);
+    pub const ASTC_12X10_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_12x10_UNORM_SRGB);
+    pub const ASTC_12X12_UNORM_SRGB: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_12x12_UNORM_SRGB);
+    pub const ASTC_4X4_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_4x4_FLOAT);
+    pub const ASTC_5X4_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_5x4_FLOAT);
+    pub const ASTC_5X5_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_5x5_FLOAT);
+    pub const ASTC_6X5_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_6x5_FLOAT);
+    pub const ASTC_6X6_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_6x6_FLOAT);
+    pub const ASTC_8X5_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_8x5_FLOAT);
+    pub const ASTC_8X6_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_8x6_FLOAT);
+    pub const ASTC_8X8_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_8x8_FLOAT);
+    pub const ASTC_10X5_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_10x5_FLOAT);
+    pub const ASTC_10X6_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_10x6_FLOAT);
+    pub const ASTC_10X8_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_10x8_FLOAT);
+    pub const ASTC_10X10_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_10x10_FLOAT);
+    pub const ASTC_12X10_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_12x10_FLOAT);
+    pub const ASTC_12X12_FLOAT: Self =
+        Self(sys::SDL_GPUTextureFormat_SDL_GPU_TEXTUREFORMAT_ASTC_12x12_FLOAT);
+
+    /// Returns the size, in bytes, of a texel block of this format.
+    pub fn texel_block_size(&self) -> u32 {
+        unsafe { sys::SDL_GPUTextureFormatTexelBlockSize(self.0) }
+    }
+
+    #[inline]
+    pub fn from_ll(ll: sys::SDL_GPUTextureFormat) -> Self {
+        Self(ll)
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUTextureFormat {
+        self.0
+    }
+}
+
+/// The format of a vertex attribute.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GpuVertexElementFormat(sys::SDL_GPUVertexElementFormat);
+
+impl GpuVertexElementFormat {
+    pub const INVALID: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_INVALID);
+    pub const INT: Self = Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_INT);
+    pub const INT2: Self = Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_INT2);
+    pub const INT3: Self = Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_INT3);
+    pub const INT4: Self = Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_INT4);
+    pub const UINT: Self = Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_UINT);
+    pub const UINT2: Self = Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_UINT2);
+    pub const UINT3: Self = Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_UINT3);
+    pub const UINT4: Self = Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_UINT4);
+    pub const FLOAT: Self = Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_FLOAT);
+    pub const FLOAT2: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_FLOAT2);
+    pub const FLOAT3: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_FLOAT3);
+    pub const FLOAT4: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_FLOAT4);
+    pub const BYTE2: Self = Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_BYTE2);
+    pub const BYTE4: Self = Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_BYTE4);
+    pub const UBYTE2: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_UBYTE2);
+    pub const UBYTE4: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_UBYTE4);
+    pub const BYTE2_NORM: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_BYTE2_NORM);
+    pub const BYTE4_NORM: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_BYTE4_NORM);
+    pub const UBYTE2_NORM: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_UBYTE2_NORM);
+    pub const UBYTE4_NORM: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_UBYTE4_NORM);
+    pub const SHORT2: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_SHORT2);
+    pub const SHORT4: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_SHORT4);
+    pub const USHORT2: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_USHORT2);
+    pub const USHORT4: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_USHORT4);
+    pub const SHORT2_NORM: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_SHORT2_NORM);
+    pub const SHORT4_NORM: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_SHORT4_NORM);
+    pub const USHORT2_NORM: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_USHORT2_NORM);
+    pub const USHORT4_NORM: Self =
+        Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_USHORT4_NORM);
+    pub const HALF2: Self = Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_HALF2);
+    pub const HALF4: Self = Self(sys::SDL_GPUVertexElementFormat_SDL_GPU_VERTEXELEMENTFORMAT_HALF4);
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUVertexElementFormat {
+        self.0
+    }
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GpuShaderStage {
+    Vertex = sys::SDL_GPUShaderStage_SDL_GPU_SHADERSTAGE_VERTEX,
+    Fragment = sys::SDL_GPUShaderStage_SDL_GPU_SHADERSTAGE_FRAGMENT,
+}
+
+impl GpuShaderStage {
+    /// Converts a raw `SDL_GPUShaderStage` into a `GpuShaderStage`.
+    pub fn try_from_ll(ll: sys::SDL_GPUShaderStage) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUShaderStage_SDL_GPU_SHADERSTAGE_VERTEX => Self::Vertex,
+            sys::SDL_GPUShaderStage_SDL_GPU_SHADERSTAGE_FRAGMENT => Self::Fragment,
+            _ => return Err(Error::register(c"Unknown GPU shader stage.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUShaderStage {
+        *self as u32
+    }
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum GpuPrimitiveType {
+    #[default]
+    TriangleList = sys::SDL_GPUPrimitiveType_SDL_GPU_PRIMITIVETYPE_TRIANGLELIST,
+    TriangleStrip = sys::SDL_GPUPrimitiveType_SDL_GPU_PRIMITIVETYPE_TRIANGLESTRIP,
+    LineList = sys::SDL_GPUPrimitiveType_SDL_GPU_PRIMITIVETYPE_LINELIST,
+    LineStrip = sys::SDL_GPUPrimitiveType_SDL_GPU_PRIMITIVETYPE_LINESTRIP,
+    PointList = sys::SDL_GPUPrimitiveType_SDL_GPU_PRIMITIVETYPE_POINTLIST,
+}
+
+impl GpuPrimitiveType {
+    /// Converts a raw `SDL_GPUPrimitiveType` into a `GpuPrimitiveType`.
+    pub fn try_from_ll(ll: sys::SDL_GPUPrimitiveType) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUPrimitiveType_SDL_GPU_PRIMITIVETYPE_TRIANGLELIST => Self::TriangleList,
+            sys::SDL_GPUPrimitiveType_SDL_GPU_PRIMITIVETYPE_TRIANGLESTRIP => Self::TriangleStrip,
+            sys::SDL_GPUPrimitiveType_SDL_GPU_PRIMITIVETYPE_LINELIST => Self::LineList,
+            sys::SDL_GPUPrimitiveType_SDL_GPU_PRIMITIVETYPE_LINESTRIP => Self::LineStrip,
+            sys::SDL_GPUPrimitiveType_SDL_GPU_PRIMITIVETYPE_POINTLIST => Self::PointList,
+            _ => return Err(Error::register(c"Unknown GPU primitive type.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUPrimitiveType {
+        *self as u32
+    }
+}
+
+/// What happens to the contents of a render target at the beginning of a render pass.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GpuLoadOp {
+    /// The previous contents are preserved.
+    Load = sys::SDL_GPULoadOp_SDL_GPU_LOADOP_LOAD,
+    /// The contents are cleared to the target's clear value.
+    Clear = sys::SDL_GPULoadOp_SDL_GPU_LOADOP_CLEAR,
+    /// The previous contents are undefined.
+    DontCare = sys::SDL_GPULoadOp_SDL_GPU_LOADOP_DONT_CARE,
+}
+
+impl GpuLoadOp {
+    /// Converts a raw `SDL_GPULoadOp` into a `GpuLoadOp`.
+    pub fn try_from_ll(ll: sys::SDL_GPULoadOp) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPULoadOp_SDL_GPU_LOADOP_LOAD => Self::Load,
+            sys::SDL_GPULoadOp_SDL_GPU_LOADOP_CLEAR => Self::Clear,
+            sys::SDL_GPULoadOp_SDL_GPU_LOADOP_DONT_CARE => Self::DontCare,
+            _ => return Err(Error::register(c"Unknown GPU load op.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPULoadOp {
+        *self as u32
+    }
+}
+
+/// What happens to the contents of a render target at the end of a render pass.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GpuStoreOp {
+    /// The contents are written to memory.
+    Store = sys::SDL_GPUStoreOp_SDL_GPU_STOREOP_STORE,
+    /// The contents are undefined after the pass.
+    DontCare = sys::SDL_GPUStoreOp_SDL_GPU_STOREOP_DONT_CARE,
+    /// The multisample contents are resolved into the resolve texture.
+    Resolve = sys::SDL_GPUStoreOp_SDL_GPU_STOREOP_RESOLVE,
+    /// The multisample contents are resolved and also written to memory.
+    ResolveAndStore = sys::SDL_GPUStoreOp_SDL_GPU_STOREOP_RESOLVE_AND_STORE,
+}
+
+impl GpuStoreOp {
+    /// Converts a raw `SDL_GPUStoreOp` into a `GpuStoreOp`.
+    pub fn try_from_ll(ll: sys::SDL_GPUStoreOp) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUStoreOp_SDL_GPU_STOREOP_STORE => Self::Store,
+            sys::SDL_GPUStoreOp_SDL_GPU_STOREOP_DONT_CARE => Self::DontCare,
+            sys::SDL_GPUStoreOp_SDL_GPU_STOREOP_RESOLVE => Self::Resolve,
+            sys::SDL_GPUStoreOp_SDL_GPU_STOREOP_RESOLVE_AND_STORE => Self::ResolveAndStore,
+            _ => return Err(Error::register(c"Unknown GPU store op.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUStoreOp {
+        *self as u32
+    }
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GpuIndexElementSize {
+    U16 = sys::SDL_GPUIndexElementSize_SDL_GPU_INDEXELEMENTSIZE_16BIT,
+    U32 = sys::SDL_GPUIndexElementSize_SDL_GPU_INDEXELEMENTSIZE_32BIT,
+}
+
+impl GpuIndexElementSize {
+    /// Converts a raw `SDL_GPUIndexElementSize` into a `GpuIndexElementSize`.
+    pub fn try_from_ll(ll: sys::SDL_GPUIndexElementSize) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUIndexElementSize_SDL_GPU_INDEXELEMENTSIZE_16BIT => Self::U16,
+            sys::SDL_GPUIndexElementSize_SDL_GPU_INDEXELEMENTSIZE_32BIT => Self::U32,
+            _ => return Err(Error::register(c"Unknown GPU index element size.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUIndexElementSize {
+        *self as u32
+    }
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum GpuTextureType {
+    #[default]
+    Texture2D = sys::SDL_GPUTextureType_SDL_GPU_TEXTURETYPE_2D,
+    Texture2DArray = sys::SDL_GPUTextureType_SDL_GPU_TEXTURETYPE_2D_ARRAY,
+    Texture3D = sys::SDL_GPUTextureType_SDL_GPU_TEXTURETYPE_3D,
+    Cube = sys::SDL_GPUTextureType_SDL_GPU_TEXTURETYPE_CUBE,
+    CubeArray = sys::SDL_GPUTextureType_SDL_GPU_TEXTURETYPE_CUBE_ARRAY,
+}
+
+impl GpuTextureType {
+    /// Converts a raw `SDL_GPUTextureType` into a `GpuTextureType`.
+    pub fn try_from_ll(ll: sys::SDL_GPUTextureType) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUTextureType_SDL_GPU_TEXTURETYPE_2D => Self::Texture2D,
+            sys::SDL_GPUTextureType_SDL_GPU_TEXTURETYPE_2D_ARRAY => Self::Texture2DArray,
+            sys::SDL_GPUTextureType_SDL_GPU_TEXTURETYPE_3D => Self::Texture3D,
+            sys::SDL_GPUTextureType_SDL_GPU_TEXTURETYPE_CUBE => Self::Cube,
+            sys::SDL_GPUTextureType_SDL_GPU_TEXTURETYPE_CUBE_ARRAY => Self::CubeArray,
+            _ => return Err(Error::register(c"Unknown GPU texture type.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUTextureType {
+        *self as u32
+    }
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum GpuSampleCount {
+    #[default]
+    One = sys::SDL_GPUSampleCount_SDL_GPU_SAMPLECOUNT_1,
+    Two = sys::SDL_GPUSampleCount_SDL_GPU_SAMPLECOUNT_2,
+    Four = sys::SDL_GPUSampleCount_SDL_GPU_SAMPLECOUNT_4,
+    Eight = sys::SDL_GPUSampleCount_SDL_GPU_SAMPLECOUNT_8,
+}
+
+impl GpuSampleCount {
+    /// Converts a raw `SDL_GPUSampleCount` into a `GpuSampleCount`.
+    pub fn try_from_ll(ll: sys::SDL_GPUSampleCount) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUSampleCount_SDL_GPU_SAMPLECOUNT_1 => Self::One,
+            sys::SDL_GPUSampleCount_SDL_GPU_SAMPLECOUNT_2 => Self::Two,
+            sys::SDL_GPUSampleCount_SDL_GPU_SAMPLECOUNT_4 => Self::Four,
+            sys::SDL_GPUSampleCount_SDL_GPU_SAMPLECOUNT_8 => Self::Eight,
+            _ => return Err(Error::register(c"Unknown GPU sample count.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUSampleCount {
+        *self as u32
+    }
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GpuTransferBufferUsage {
+    /// The buffer is written by the application and uploaded to the GPU.
+    Upload = sys::SDL_GPUTransferBufferUsage_SDL_GPU_TRANSFERBUFFERUSAGE_UPLOAD,
+    /// The buffer is written by the GPU and read back by the application.
+    Download = sys::SDL_GPUTransferBufferUsage_SDL_GPU_TRANSFERBUFFERUSAGE_DOWNLOAD,
+}
+
+impl GpuTransferBufferUsage {
+    /// Converts a raw `SDL_GPUTransferBufferUsage` into a `GpuTransferBufferUsage`.
+    pub fn try_from_ll(ll: sys::SDL_GPUTransferBufferUsage) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUTransferBufferUsage_SDL_GPU_TRANSFERBUFFERUSAGE_UPLOAD => Self::Upload,
+            sys::SDL_GPUTransferBufferUsage_SDL_GPU_TRANSFERBUFFERUSAGE_DOWNLOAD => Self::Download,
+            _ => return Err(Error::register(c"Unknown GPU transfer buffer usage.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUTransferBufferUsage {
+        *self as u32
+    }
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum GpuVertexInputRate {
+    /// Attributes advance once per vertex.
+    #[default]
+    Vertex = sys::SDL_GPUVertexInputRate_SDL_GPU_VERTEXINPUTRATE_VERTEX,
+    /// Attributes advance once per instance.
+    Instance = sys::SDL_GPUVertexInputRate_SDL_GPU_VERTEXINPUTRATE_INSTANCE,
+}
+
+impl GpuVertexInputRate {
+    /// Converts a raw `SDL_GPUVertexInputRate` into a `GpuVertexInputRate`.
+    pub fn try_from_ll(ll: sys::SDL_GPUVertexInputRate) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUVertexInputRate_SDL_GPU_VERTEXINPUTRATE_VERTEX => Self::Vertex,
+            sys::SDL_GPUVertexInputRate_SDL_GPU_VERTEXINPUTRATE_INSTANCE => Self::Instance,
+            _ => return Err(Error::register(c"Unknown GPU vertex input rate.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUVertexInputRate {
+        *self as u32
+    }
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum GpuFillMode {
+    #[default]
+    Fill = sys::SDL_GPUFillMode_SDL_GPU_FILLMODE_FILL,
+    Line = sys::SDL_GPUFillMode_SDL_GPU_FILLMODE_LINE,
+}
+
+impl GpuFillMode {
+    /// Converts a raw `SDL_GPUFillMode` into a `GpuFillMode`.
+    pub fn try_from_ll(ll: sys::SDL_GPUFillMode) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUFillMode_SDL_GPU_FILLMODE_FILL => Self::Fill,
+            sys::SDL_GPUFillMode_SDL_GPU_FILLMODE_LINE => Self::Line,
+            _ => return Err(Error::register(c"Unknown GPU fill mode.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUFillMode {
+        *self as u32
+    }
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum GpuCullMode {
+    #[default]
+    None = sys::SDL_GPUCullMode_SDL_GPU_CULLMODE_NONE,
+    Front = sys::SDL_GPUCullMode_SDL_GPU_CULLMODE_FRONT,
+    Back = sys::SDL_GPUCullMode_SDL_GPU_CULLMODE_BACK,
+}
+
+impl GpuCullMode {
+    /// Converts a raw `SDL_GPUCullMode` into a `GpuCullMode`.
+    pub fn try_from_ll(ll: sys::SDL_GPUCullMode) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUCullMode_SDL_GPU_CULLMODE_NONE => Self::None,
+            sys::SDL_GPUCullMode_SDL_GPU_CULLMODE_FRONT => Self::Front,
+            sys::SDL_GPUCullMode_SDL_GPU_CULLMODE_BACK => Self::Back,
+            _ => return Err(Error::register(c"Unknown GPU cull mode.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUCullMode {
+        *self as u32
+    }
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum GpuFrontFace {
+    #[default]
+    CounterClockwise = sys::SDL_GPUFrontFace_SDL_GPU_FRONTFACE_COUNTER_CLOCKWISE,
+    Clockwise = sys::SDL_GPUFrontFace_SDL_GPU_FRONTFACE_CLOCKWISE,
+}
+
+impl GpuFrontFace {
+    /// Converts a raw `SDL_GPUFrontFace` into a `GpuFrontFace`.
+    pub fn try_from_ll(ll: sys::SDL_GPUFrontFace) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUFrontFace_SDL_GPU_FRONTFACE_COUNTER_CLOCKWISE => Self::CounterClockwise,
+            sys::SDL_GPUFrontFace_SDL_GPU_FRONTFACE_CLOCKWISE => Self::Clockwise,
+            _ => return Err(Error::register(c"Unknown GPU front face.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUFrontFace {
+        *self as u32
+    }
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GpuCompareOp {
+    Never = sys::SDL_GPUCompareOp_SDL_GPU_COMPAREOP_NEVER,
+    Less = sys::SDL_GPUCompareOp_SDL_GPU_COMPAREOP_LESS,
+    Equal = sys::SDL_GPUCompareOp_SDL_GPU_COMPAREOP_EQUAL,
+    LessOrEqual = sys::SDL_GPUCompareOp_SDL_GPU_COMPAREOP_LESS_OR_EQUAL,
+    Greater = sys::SDL_GPUCompareOp_SDL_GPU_COMPAREOP_GREATER,
+    NotEqual = sys::SDL_GPUCompareOp_SDL_GPU_COMPAREOP_NOT_EQUAL,
+    GreaterOrEqual = sys::SDL_GPUCompareOp_SDL_GPU_COMPAREOP_GREATER_OR_EQUAL,
+    Always = sys::SDL_GPUCompareOp_SDL_GPU_COMPAREOP_ALWAYS,
+}
+
+impl GpuCompareOp {
+    /// Converts a raw `SDL_GPUCompareOp` into a `GpuCompareOp`.
+    pub fn try_from_ll(ll: sys::SDL_GPUCompareOp) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUCompareOp_SDL_GPU_COMPAREOP_NEVER => Self::Never,
+            sys::SDL_GPUCompareOp_SDL_GPU_COMPAREOP_LESS => Self::Less,
+            sys::SDL_GPUCompareOp_SDL_GPU_COMPAREOP_EQUAL => Self::Equal,
+            sys::SDL_GPUCompareOp_SDL_GPU_COMPAREOP_LESS_OR_EQUAL => Self::LessOrEqual,
+            sys::SDL_GPUCompareOp_SDL_GPU_COMPAREOP_GREATER => Self::Greater,
+            sys::SDL_GPUCompareOp_SDL_GPU_COMPAREOP_NOT_EQUAL => Self::NotEqual,
+            sys::SDL_GPUCompareOp_SDL_GPU_COMPAREOP_GREATER_OR_EQUAL => Self::GreaterOrEqual,
+            sys::SDL_GPUCompareOp_SDL_GPU_COMPAREOP_ALWAYS => Self::Always,
+            _ => return Err(Error::register(c"Unknown GPU compare op.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUCompareOp {
+        *self as u32
+    }
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GpuBlendOp {
+    /// `src * src_factor + dst * dst_factor`
+    Add = sys::SDL_GPUBlendOp_SDL_GPU_BLENDOP_ADD,
+    /// `src * src_factor - dst * dst_factor`
+    Subtract = sys::SDL_GPUBlendOp_SDL_GPU_BLENDOP_SUBTRACT,
+    /// `dst * dst_factor - src * src_factor`
+    ReverseSubtract = sys::SDL_GPUBlendOp_SDL_GPU_BLENDOP_REVERSE_SUBTRACT,
+    /// `min(src, dst)`
+    Min = sys::SDL_GPUBlendOp_SDL_GPU_BLENDOP_MIN,
+    /// `max(src, dst)`
+    Max = sys::SDL_GPUBlendOp_SDL_GPU_BLENDOP_MAX,
+}
+
+impl GpuBlendOp {
+    /// Converts a raw `SDL_GPUBlendOp` into a `GpuBlendOp`.
+    pub fn try_from_ll(ll: sys::SDL_GPUBlendOp) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUBlendOp_SDL_GPU_BLENDOP_ADD => Self::Add,
+            sys::SDL_GPUBlendOp_SDL_GPU_BLENDOP_SUBTRACT => Self::Subtract,
+            sys::SDL_GPUBlendOp_SDL_GPU_BLENDOP_REVERSE_SUBTRACT => Self::ReverseSubtract,
+            sys::SDL_GPUBlendOp_SDL_GPU_BLENDOP_MIN => Self::Min,
+            sys::SDL_GPUBlendOp_SDL_GPU_BLENDOP_MAX => Self::Max,
+            _ => return Err(Error::register(c"Unknown GPU blend op.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUBlendOp {
+        *self as u32
+    }
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GpuBlendFactor {
+    Zero = sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_ZERO,
+    One = sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_ONE,
+    SrcColor = sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_SRC_COLOR,
+    OneMinusSrcColor = sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_ONE_MINUS_SRC_COLOR,
+    DstColor = sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_DST_COLOR,
+    OneMinusDstColor = sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_ONE_MINUS_DST_COLOR,
+    SrcAlpha = sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_SRC_ALPHA,
+    OneMinusSrcAlpha = sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_ONE_MINUS_SRC_ALPHA,
+    DstAlpha = sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_DST_ALPHA,
+    OneMinusDstAlpha = sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_ONE_MINUS_DST_ALPHA,
+    ConstantColor = sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_CONSTANT_COLOR,
+    OneMinusConstantColor = sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_ONE_MINUS_CONSTANT_COLOR,
+    SrcAlphaSaturate = sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_SRC_ALPHA_SATURATE,
+}
+
+impl GpuBlendFactor {
+    /// Converts a raw `SDL_GPUBlendFactor` into a `GpuBlendFactor`.
+    pub fn try_from_ll(ll: sys::SDL_GPUBlendFactor) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_ZERO => Self::Zero,
+            sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_ONE => Self::One,
+            sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_SRC_COLOR => Self::SrcColor,
+            sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_ONE_MINUS_SRC_COLOR => {
+                Self::OneMinusSrcColor
+            }
+            sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_DST_COLOR => Self::DstColor,
+            sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_ONE_MINUS_DST_COLOR => {
+                Self::OneMinusDstColor
+            }
+            sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_SRC_ALPHA => Self::SrcAlpha,
+            sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_ONE_MINUS_SRC_ALPHA => {
+                Self::OneMinusSrcAlpha
+            }
+            sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_DST_ALPHA => Self::DstAlpha,
+            sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_ONE_MINUS_DST_ALPHA => {
+                Self::OneMinusDstAlpha
+            }
+            sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_CONSTANT_COLOR => Self::ConstantColor,
+            sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_ONE_MINUS_CONSTANT_COLOR => {
+                Self::OneMinusConstantColor
+            }
+            sys::SDL_GPUBlendFactor_SDL_GPU_BLENDFACTOR_SRC_ALPHA_SATURATE => {
+                Self::SrcAlphaSaturate
+            }
+            _ => return Err(Error::register(c"Unknown GPU blend factor.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUBlendFactor {
+        *self as u32
+    }
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum GpuFilter {
+    #[default]
+    Nearest = sys::SDL_GPUFilter_SDL_GPU_FILTER_NEAREST,
+    Linear = sys::SDL_GPUFilter_SDL_GPU_FILTER_LINEAR,
+}
+
+impl GpuFilter {
+    /// Converts a raw `SDL_GPUFilter` into a `GpuFilter`.
+    pub fn try_from_ll(ll: sys::SDL_GPUFilter) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUFilter_SDL_GPU_FILTER_NEAREST => Self::Nearest,
+            sys::SDL_GPUFilter_SDL_GPU_FILTER_LINEAR => Self::Linear,
+            _ => return Err(Error::register(c"Unknown GPU filter.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUFilter {
+        *self as u32
+    }
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum GpuSamplerMipmapMode {
+    #[default]
+    Nearest = sys::SDL_GPUSamplerMipmapMode_SDL_GPU_SAMPLERMIPMAPMODE_NEAREST,
+    Linear = sys::SDL_GPUSamplerMipmapMode_SDL_GPU_SAMPLERMIPMAPMODE_LINEAR,
+}
+
+impl GpuSamplerMipmapMode {
+    /// Converts a raw `SDL_GPUSamplerMipmapMode` into a `GpuSamplerMipmapMode`.
+    pub fn try_from_ll(ll: sys::SDL_GPUSamplerMipmapMode) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUSamplerMipmapMode_SDL_GPU_SAMPLERMIPMAPMODE_NEAREST => Self::Nearest,
+            sys::SDL_GPUSamplerMipmapMode_SDL_GPU_SAMPLERMIPMAPMODE_LINEAR => Self::Linear,
+            _ => return Err(Error::register(c"Unknown GPU sampler mipmap mode.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUSamplerMipmapMode {
+        *self as u32
+    }
+}
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum GpuSamplerAddressMode {
+    #[default]
+    Repeat = sys::SDL_GPUSamplerAddressMode_SDL_GPU_SAMPLERADDRESSMODE_REPEAT,
+    MirroredRepeat = sys::SDL_GPUSamplerAddressMode_SDL_GPU_SAMPLERADDRESSMODE_MIRRORED_REPEAT,
+    ClampToEdge = sys::SDL_GPUSamplerAddressMode_SDL_GPU_SAMPLERADDRESSMODE_CLAMP_TO_EDGE,
+}
+
+impl GpuSamplerAddressMode {
+    /// Converts a raw `SDL_GPUSamplerAddressMode` into a `GpuSamplerAddressMode`.
+    pub fn try_from_ll(ll: sys::SDL_GPUSamplerAddressMode) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUSamplerAddressMode_SDL_GPU_SAMPLERADDRESSMODE_REPEAT => Self::Repeat,
+            sys::SDL_GPUSamplerAddressMode_SDL_GPU_SAMPLERADDRESSMODE_MIRRORED_REPEAT => {
+                Self::MirroredRepeat
+            }
+            sys::SDL_GPUSamplerAddressMode_SDL_GPU_SAMPLERADDRESSMODE_CLAMP_TO_EDGE => {
+                Self::ClampToEdge
+            }
+            _ => return Err(Error::register(c"Unknown GPU sampler address mode.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUSamplerAddressMode {
+        *self as u32
+    }
+}
+
+/// How a swapchain texture is presented.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GpuPresentMode {
+    /// Waits for vblank before presenting. Always supported.
+    Vsync = sys::SDL_GPUPresentMode_SDL_GPU_PRESENTMODE_VSYNC,
+    /// Presents immediately, which may cause tearing.
+    Immediate = sys::SDL_GPUPresentMode_SDL_GPU_PRESENTMODE_IMMEDIATE,
+    /// Waits for vblank, replacing pending frames instead of queueing them.
+    Mailbox = sys::SDL_GPUPresentMode_SDL_GPU_PRESENTMODE_MAILBOX,
+}
+
+impl GpuPresentMode {
+    /// Converts a raw `SDL_GPUPresentMode` into a `GpuPresentMode`.
+    pub fn try_from_ll(ll: sys::SDL_GPUPresentMode) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUPresentMode_SDL_GPU_PRESENTMODE_VSYNC => Self::Vsync,
+            sys::SDL_GPUPresentMode_SDL_GPU_PRESENTMODE_IMMEDIATE => Self::Immediate,
+            sys::SDL_GPUPresentMode_SDL_GPU_PRESENTMODE_MAILBOX => Self::Mailbox,
+            _ => return Err(Error::register(c"Unknown GPU present mode.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUPresentMode {
+        *self as u32
+    }
+}
+
+/// The color space and format of a swapchain.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GpuSwapchainComposition {
+    /// B8G8R8A8 or R8G8B8A8 in sRGB gamma. Always supported.
+    Sdr = sys::SDL_GPUSwapchainComposition_SDL_GPU_SWAPCHAINCOMPOSITION_SDR,
+    /// B8G8R8A8_UNORM_SRGB or R8G8B8A8_UNORM_SRGB, in sRGB gamma.
+    SdrLinear = sys::SDL_GPUSwapchainComposition_SDL_GPU_SWAPCHAINCOMPOSITION_SDR_LINEAR,
+    /// R16G16B16A16_FLOAT in extended linear sRGB.
+    HdrExtendedLinear =
+        sys::SDL_GPUSwapchainComposition_SDL_GPU_SWAPCHAINCOMPOSITION_HDR_EXTENDED_LINEAR,
+    /// A2R10G10B10 or A2B10G10R10 in BT.2020 ST2084 (PQ).
+    Hdr10St2084 = sys::SDL_GPUSwapchainComposition_SDL_GPU_SWAPCHAINCOMPOSITION_HDR10_ST2084,
+}
+
+impl GpuSwapchainComposition {
+    /// Converts a raw `SDL_GPUSwapchainComposition` into a `GpuSwapchainComposition`.
+    pub fn try_from_ll(ll: sys::SDL_GPUSwapchainComposition) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GPUSwapchainComposition_SDL_GPU_SWAPCHAINCOMPOSITION_SDR => Self::Sdr,
+            sys::SDL_GPUSwapchainComposition_SDL_GPU_SWAPCHAINCOMPOSITION_SDR_LINEAR => {
+                Self::SdrLinear
+            }
+            sys::SDL_GPUSwapchainComposition_SDL_GPU_SWAPCHAINCOMPOSITION_HDR_EXTENDED_LINEAR => {
+                Self::HdrExtendedLinear
+            }
+            sys::SDL_GPUSwapchainComposition_SDL_GPU_SWAPCHAINCOMPOSITION_HDR10_ST2084 => {
+                Self::Hdr10St2084
+            }
+            _ => return Err(Error::register(c"Unknown GPU swapchain composition.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GPUSwapchainComposition {
+        *self as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(GpuShaderStage: Vertex, Fragment);
+        assert_round_trips!(GpuPrimitiveType: TriangleList, TriangleStrip, LineList, LineStrip, PointList);
+        assert_round_trips!(GpuLoadOp: Load, Clear, DontCare);
+        assert_round_trips!(GpuStoreOp: Store, DontCare, Resolve, ResolveAndStore);
+        assert_round_trips!(GpuIndexElementSize: U16, U32);
+        assert_round_trips!(GpuTextureType: Texture2D, Texture2DArray, Texture3D, Cube, CubeArray);
+        assert_round_trips!(GpuSampleCount: One, Two, Four, Eight);
+        assert_round_trips!(GpuTransferBufferUsage: Upload, Download);
+        assert_round_trips!(GpuVertexInputRate: Vertex, Instance);
+        assert_round_trips!(GpuFillMode: Fill, Line);
+        assert_round_trips!(GpuCullMode: None, Front, Back);
+        assert_round_trips!(GpuFrontFace: CounterClockwise, Clockwise);
+        assert_round_trips!(GpuCompareOp: Never, Less, Equal, LessOrEqual, Greater, NotEqual, GreaterOrEqual, Always);
+        assert_round_trips!(GpuBlendOp: Add, Subtract, ReverseSubtract, Min, Max);
+        assert_round_trips!(GpuBlendFactor: Zero, One, SrcColor, OneMinusSrcColor, DstColor, OneMinusDstColor, SrcAlpha, OneMinusSrcAlpha, DstAlpha, OneMinusDstAlpha, ConstantColor, OneMinusConstantColor, SrcAlphaSaturate);
+        assert_round_trips!(GpuFilter: Nearest, Linear);
+        assert_round_trips!(GpuSamplerMipmapMode: Nearest, Linear);
+        assert_round_trips!(GpuSamplerAddressMode: Repeat, MirroredRepeat, ClampToEdge);
+        assert_round_trips!(GpuPresentMode: Vsync, Immediate, Mailbox);
+        assert_round_trips!(GpuSwapchainComposition: Sdr, SdrLinear, HdrExtendedLinear, Hdr10St2084);
+    }
+}
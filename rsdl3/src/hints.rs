@@ -0,0 +1,237 @@
+use crate::sys;
+use crate::Error;
+use alloc::ffi::CString;
+use alloc::string::String;
+use core::ffi::c_char;
+use core::ffi::c_void;
+use core::ffi::CStr;
+
+/// The name of a configuration hint.
+///
+/// Hints tune SDL's behavior and can be set at any time, but many of them are only read when a
+/// subsystem is initialized or an object is created, so they should be set as early as possible.
+/// The most common hints are provided as constants; any other hint can be named with
+/// [`Hint::new`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Hint(&'static CStr);
+
+impl Hint {
+    /// The name of the application, used by audio servers, screen readers and the like.
+    pub const APP_NAME: Self = Self(c"SDL_APP_NAME");
+    /// A unique identifier for the application, used to match it with its desktop entry on
+    /// Linux.
+    pub const APP_ID: Self = Self(c"SDL_APP_ID");
+    /// A comma-separated list of render drivers to try, such as `"opengl"` or `"software"`.
+    pub const RENDER_DRIVER: Self = Self(c"SDL_RENDER_DRIVER");
+    /// Whether renderers synchronize presentation with the display refresh rate: `"0"` or `"1"`.
+    pub const RENDER_VSYNC: Self = Self(c"SDL_RENDER_VSYNC");
+    /// The GPU driver to use for GPU devices, such as `"vulkan"` or `"metal"`.
+    pub const GPU_DRIVER: Self = Self(c"SDL_GPU_DRIVER");
+    /// A comma-separated list of video drivers to try, such as `"x11"` or `"wayland"`.
+    pub const VIDEO_DRIVER: Self = Self(c"SDL_VIDEO_DRIVER");
+    /// A comma-separated list of audio drivers to try, such as `"pipewire"` or `"alsa"`.
+    pub const AUDIO_DRIVER: Self = Self(c"SDL_AUDIO_DRIVER");
+    /// The IME UI elements drawn by the application instead of the system: `"composition"`,
+    /// `"candidates"` or both, separated by a comma.
+    pub const IME_IMPLEMENTED_UI: Self = Self(c"SDL_IME_IMPLEMENTED_UI");
+    /// Whether the screensaver may run while the application is active: `"0"` or `"1"`.
+    pub const VIDEO_ALLOW_SCREENSAVER: Self = Self(c"SDL_VIDEO_ALLOW_SCREENSAVER");
+    /// Whether fullscreen windows are minimized when they lose focus: `"0"` or `"1"`.
+    pub const VIDEO_MINIMIZE_ON_FOCUS_LOSS: Self = Self(c"SDL_VIDEO_MINIMIZE_ON_FOCUS_LOSS");
+    /// Whether a click that focuses a window is also delivered as a mouse event: `"0"` or
+    /// `"1"`.
+    pub const MOUSE_FOCUS_CLICKTHROUGH: Self = Self(c"SDL_MOUSE_FOCUS_CLICKTHROUGH");
+    /// Whether joystick events are delivered while the application is in the background: `"0"`
+    /// or `"1"`.
+    pub const JOYSTICK_ALLOW_BACKGROUND_EVENTS: Self =
+        Self(c"SDL_JOYSTICK_ALLOW_BACKGROUND_EVENTS");
+    /// Whether sensors are updated by the event loop: `"0"` or `"1"`.
+    pub const AUTO_UPDATE_SENSORS: Self = Self(c"SDL_AUTO_UPDATE_SENSORS");
+    /// Whether closing the last window sends a quit event: `"0"` or `"1"`.
+    pub const QUIT_ON_LAST_WINDOW_CLOSE: Self = Self(c"SDL_QUIT_ON_LAST_WINDOW_CLOSE");
+    /// The rate, in iterations per second, at which the main callbacks are called; `"0"` runs
+    /// them as fast as possible and `"waitevent"` only runs them when events arrive.
+    pub const MAIN_CALLBACK_RATE: Self = Self(c"SDL_MAIN_CALLBACK_RATE");
+
+    /// Names a hint that has no constant in this module.
+    #[inline]
+    pub const fn new(name: &'static CStr) -> Self {
+        Self(name)
+    }
+
+    #[inline]
+    pub fn name(&self) -> &'static CStr {
+        self.0
+    }
+}
+
+/// Sets a hint with normal priority.
+///
+/// The hint is left unchanged if an environment variable of the same name is set, or if it was
+/// set with [`HintPriority::Override`].
+pub fn set_hint(hint: Hint, value: &str) -> Result<(), Error> {
+    let value = CString::new(value)?;
+    let result = unsafe { sys::SDL_SetHint(hint.0.as_ptr(), value.as_ptr()) };
+    if !result {
+        return Err(Error::new());
+    }
+    Ok(())
+}
+
+/// Sets a hint with a specific priority.
+///
+/// The hint is left unchanged if its current value has a higher priority. Environment variables
+/// have [`HintPriority::Override`] priority.
+pub fn set_hint_with_priority(
+    hint: Hint,
+    value: &str,
+    priority: HintPriority,
+) -> Result<(), Error> {
+    let value = CString::new(value)?;
+    let result =
+        unsafe { sys::SDL_SetHintWithPriority(hint.0.as_ptr(), value.as_ptr(), priority.to_ll()) };
+    if !result {
+        return Err(Error::new());
+    }
+    Ok(())
+}
+
+/// Returns the value of a hint, or `None` if it isn't set.
+pub fn get_hint(hint: Hint) -> Option<String> {
+    let ptr = unsafe { sys::SDL_GetHint(hint.0.as_ptr()) };
+    if ptr.is_null() {
+        return None;
+    }
+    Some(
+        unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned(),
+    )
+}
+
+/// Returns the boolean value of a hint, or `default_value` if it isn't set.
+pub fn get_hint_boolean(hint: Hint, default_value: bool) -> bool {
+    unsafe { sys::SDL_GetHintBoolean(hint.0.as_ptr(), default_value) }
+}
+
+/// Resets a hint to its default value, which is the value of the environment variable of the
+/// same name, if any.
+///
+/// Callbacks are called if the value changes.
+pub fn reset_hint(hint: Hint) -> Result<(), Error> {
+    let result = unsafe { sys::SDL_ResetHint(hint.0.as_ptr()) };
+    if !result {
+        return Err(Error::new());
+    }
+    Ok(())
+}
+
+/// Resets all hints to their default values. See [`reset_hint`].
+pub fn reset_hints() {
+    unsafe { sys::SDL_ResetHints() };
+}
+
+/// Adds a callback that is called whenever the value of `hint` changes.
+///
+/// The callback is called once immediately with the current value of the hint, and is removed
+/// when the returned [`HintWatch`] is dropped.
+pub fn add_hint_callback<T: HintCallback>(
+    hint: Hint,
+    callback: &T,
+) -> Result<HintWatch<'_, T>, Error> {
+    let marshall: sys::SDL_HintCallback = Some(hint_callback_marshall::<T>);
+    let result = unsafe {
+        sys::SDL_AddHintCallback(hint.0.as_ptr(), marshall, callback as *const T as *mut _)
+    };
+    if !result {
+        return Err(Error::new());
+    }
+    Ok(HintWatch {
+        hint,
+        callback: marshall,
+        data: callback,
+    })
+}
+
+/// Receives changes to the value of a hint. See [`add_hint_callback`].
+///
+/// The callback may be called from any thread that changes the hint.
+pub trait HintCallback: Send + Sync {
+    /// `old_value` and `new_value` are `None` when the hint is unset.
+    fn changed(&self, name: &str, old_value: Option<&str>, new_value: Option<&str>);
+}
+
+/// Keeps a [`HintCallback`] registered until dropped.
+pub struct HintWatch<'a, T: HintCallback> {
+    hint: Hint,
+    callback: sys::SDL_HintCallback,
+    data: &'a T,
+}
+
+impl<T: HintCallback> Drop for HintWatch<'_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            sys::SDL_RemoveHintCallback(
+                self.hint.0.as_ptr(),
+                self.callback,
+                self.data as *const T as *mut _,
+            );
+        }
+    }
+}
+
+unsafe extern "C" fn hint_callback_marshall<T: HintCallback>(
+    user_data: *mut c_void,
+    name: *const c_char,
+    old_value: *const c_char,
+    new_value: *const c_char,
+) {
+    let f: &T = unsafe { &*(user_data as *const _) };
+    let value = |ptr: *const c_char| {
+        (!ptr.is_null()).then(|| unsafe { CStr::from_ptr(ptr) }.to_string_lossy())
+    };
+    let name = unsafe { CStr::from_ptr(name) }.to_string_lossy();
+    let old_value = value(old_value);
+    let new_value = value(new_value);
+    f.changed(&name, old_value.as_deref(), new_value.as_deref());
+}
+
+/// The priority of a hint value.
+///
+/// A hint can only be changed by a call with the same or a higher priority.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum HintPriority {
+    Default = sys::SDL_HintPriority_SDL_HINT_DEFAULT,
+    Normal = sys::SDL_HintPriority_SDL_HINT_NORMAL,
+    /// The same priority as environment variables; replaces any other value.
+    Override = sys::SDL_HintPriority_SDL_HINT_OVERRIDE,
+}
+
+impl HintPriority {
+    /// Converts a raw `SDL_HintPriority` into a `HintPriority`.
+    pub fn try_from_ll(ll: sys::SDL_HintPriority) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_HintPriority_SDL_HINT_DEFAULT => Self::Default,
+            sys::SDL_HintPriority_SDL_HINT_NORMAL => Self::Normal,
+            sys::SDL_HintPriority_SDL_HINT_OVERRIDE => Self::Override,
+            _ => return Err(Error::register(c"Unknown hint priority.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_HintPriority {
+        *self as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(HintPriority: Default, Normal, Override);
+    }
+}
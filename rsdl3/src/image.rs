@@ -0,0 +1,284 @@
+use crate::iostream::IOStream;
+use crate::surface::{Surface, SurfaceRef};
+use crate::sys;
+use crate::Error;
+use crate::VideoSubsystem;
+use alloc::ffi::CString;
+use core::ffi::CStr;
+use core::ptr::NonNull;
+use core::time::Duration;
+
+/// The image formats SDL_image can detect.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    Avif,
+    Bmp,
+    Cur,
+    Gif,
+    Ico,
+    Jpg,
+    Jxl,
+    Lbm,
+    Pcx,
+    Png,
+    Pnm,
+    Qoi,
+    Svg,
+    /// TGA files have no signature, so this format is never detected; pass it to
+    /// [`Surface::load_image_typed_from_io`] instead.
+    Tga,
+    Tif,
+    Webp,
+    Xcf,
+    Xpm,
+    Xv,
+}
+
+impl ImageFormat {
+    // Every format that can be detected, which leaves out `Tga`.
+    const ALL: [ImageFormat; 18] = [
+        ImageFormat::Avif,
+        ImageFormat::Bmp,
+        ImageFormat::Cur,
+        ImageFormat::Gif,
+        ImageFormat::Ico,
+        ImageFormat::Jpg,
+        ImageFormat::Jxl,
+        ImageFormat::Lbm,
+        ImageFormat::Pcx,
+        ImageFormat::Png,
+        ImageFormat::Pnm,
+        ImageFormat::Qoi,
+        ImageFormat::Svg,
+        ImageFormat::Tif,
+        ImageFormat::Webp,
+        ImageFormat::Xcf,
+        ImageFormat::Xpm,
+        ImageFormat::Xv,
+    ];
+
+    /// Returns the format of the data at the stream's current position, or `None` if it's not
+    /// recognized.
+    ///
+    /// Only a few bytes are read and the stream is seeked back to where it was, so the image can
+    /// be loaded from it afterwards. I/O errors are reported as an unrecognized format.
+    pub fn detect(io: &mut IOStream) -> Option<ImageFormat> {
+        Self::ALL.into_iter().find(|format| format.matches(io))
+    }
+
+    /// Returns whether the data at the stream's current position is in this format.
+    ///
+    /// The stream is seeked back to where it was.
+    pub fn matches(&self, io: &mut IOStream) -> bool {
+        let src = io.raw();
+        unsafe {
+            match self {
+                ImageFormat::Avif => sys::image::IMG_isAVIF(src),
+                ImageFormat::Bmp => sys::image::IMG_isBMP(src),
+                ImageFormat::Cur => sys::image::IMG_isCUR(src),
+                ImageFormat::Gif => sys::image::IMG_isGIF(src),
+                ImageFormat::Ico => sys::image::IMG_isICO(src),
+                ImageFormat::Jpg => sys::image::IMG_isJPG(src),
+                ImageFormat::Jxl => sys::image::IMG_isJXL(src),
+                ImageFormat::Lbm => sys::image::IMG_isLBM(src),
+                ImageFormat::Pcx => sys::image::IMG_isPCX(src),
+                ImageFormat::Png => sys::image::IMG_isPNG(src),
+                ImageFormat::Pnm => sys::image::IMG_isPNM(src),
+                ImageFormat::Qoi => sys::image::IMG_isQOI(src),
+                ImageFormat::Svg => sys::image::IMG_isSVG(src),
+                ImageFormat::Tga => false,
+                ImageFormat::Tif => sys::image::IMG_isTIF(src),
+                ImageFormat::Webp => sys::image::IMG_isWEBP(src),
+                ImageFormat::Xcf => sys::image::IMG_isXCF(src),
+                ImageFormat::Xpm => sys::image::IMG_isXPM(src),
+                ImageFormat::Xv => sys::image::IMG_isXV(src),
+            }
+        }
+    }
+
+    /// Returns the name SDL_image uses for the format when loading typed data.
+    pub fn name(&self) -> &'static CStr {
+        match self {
+            ImageFormat::Avif => c"AVIF",
+            ImageFormat::Bmp => c"BMP",
+            ImageFormat::Cur => c"CUR",
+            ImageFormat::Gif => c"GIF",
+            ImageFormat::Ico => c"ICO",
+            ImageFormat::Jpg => c"JPG",
+            ImageFormat::Jxl => c"JXL",
+            ImageFormat::Lbm => c"LBM",
+            ImageFormat::Pcx => c"PCX",
+            ImageFormat::Png => c"PNG",
+            ImageFormat::Pnm => c"PNM",
+            ImageFormat::Qoi => c"QOI",
+            ImageFormat::Svg => c"SVG",
+            ImageFormat::Tga => c"TGA",
+            ImageFormat::Tif => c"TIF",
+            ImageFormat::Webp => c"WEBP",
+            ImageFormat::Xcf => c"XCF",
+            ImageFormat::Xpm => c"XPM",
+            ImageFormat::Xv => c"XV",
+        }
+    }
+}
+
+/// The frames of an animated image, such as a GIF or an animated WEBP, and how long each of
+/// them is shown.
+///
+/// Formats that aren't animated load as a single frame.
+pub struct Animation {
+    _video: VideoSubsystem,
+    ptr: NonNull<sys::image::IMG_Animation>,
+}
+
+impl Animation {
+    /// Loads an animation from a file.
+    pub fn load(video: &VideoSubsystem, path: &str) -> Result<Self, Error> {
+        let path = CString::new(path)?;
+        let ptr = unsafe { sys::image::IMG_LoadAnimation(path.as_ptr()) };
+        Self::from_ptr(video, ptr)
+    }
+
+    /// Loads an animation from a stream. If `format` is `None`, it's detected from the data.
+    pub fn load_from_io(
+        video: &VideoSubsystem,
+        io: IOStream,
+        format: Option<ImageFormat>,
+    ) -> Result<Self, Error> {
+        let ptr = unsafe {
+            match format {
+                Some(format) => {
+                    sys::image::IMG_LoadAnimationTyped_IO(io.raw(), false, format.name().as_ptr())
+                }
+                None => sys::image::IMG_LoadAnimation_IO(io.raw(), false),
+            }
+        };
+        Self::from_ptr(video, ptr)
+    }
+
+    /// Loads an animation from encoded image data in memory, such as an embedded asset.
+    pub fn load_from_bytes(video: &VideoSubsystem, bytes: &[u8]) -> Result<Self, Error> {
+        let ptr = unsafe {
+            let io = sys::SDL_IOFromConstMem(bytes.as_ptr() as *const _, bytes.len());
+            if io.is_null() {
+                return Err(Error::new());
+            }
+            sys::image::IMG_LoadAnimation_IO(io, true)
+        };
+        Self::from_ptr(video, ptr)
+    }
+
+    fn from_ptr(
+        video: &VideoSubsystem,
+        ptr: *mut sys::image::IMG_Animation,
+    ) -> Result<Self, Error> {
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            _video: video.clone(),
+            ptr,
+        })
+    }
+
+    pub fn width(&self) -> u32 {
+        unsafe { self.ptr.as_ref().w.max(0) as u32 }
+    }
+
+    pub fn height(&self) -> u32 {
+        unsafe { self.ptr.as_ref().h.max(0) as u32 }
+    }
+
+    /// Returns the number of frames.
+    pub fn len(&self) -> usize {
+        unsafe { self.ptr.as_ref().count.max(0) as usize }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a frame, or `None` if `index` is out of bounds.
+    pub fn frame(&self, index: usize) -> Option<&SurfaceRef> {
+        if index >= self.len() {
+            return None;
+        }
+        unsafe {
+            let ptr = *self.ptr.as_ref().frames.add(index);
+            Some(SurfaceRef::from_ptr(ptr))
+        }
+    }
+
+    /// Returns how long a frame is shown, or `None` if `index` is out of bounds.
+    pub fn delay(&self, index: usize) -> Option<Duration> {
+        if index >= self.len() {
+            return None;
+        }
+        let delay = unsafe { *self.ptr.as_ref().delays.add(index) };
+        Some(Duration::from_millis(delay.max(0) as u64))
+    }
+
+    /// Returns the frames in order, along with how long each of them is shown.
+    pub fn frames(&self) -> impl Iterator<Item = (&SurfaceRef, Duration)> + '_ {
+        (0..self.len()).filter_map(|index| Some((self.frame(index)?, self.delay(index)?)))
+    }
+
+    /// Returns the total duration of one loop of the animation.
+    pub fn duration(&self) -> Duration {
+        self.frames().map(|(_, delay)| delay).sum()
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::image::IMG_Animation {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for Animation {
+    fn drop(&mut self) {
+        unsafe { sys::image::IMG_FreeAnimation(self.raw()) };
+    }
+}
+
+impl Surface<'static> {
+    /// Creates a new `Surface` by decoding encoded image data in memory, such as an embedded
+    /// asset. The format is detected from the data.
+    pub fn load_image_from_bytes(video: &VideoSubsystem, bytes: &[u8]) -> Result<Self, Error> {
+        unsafe {
+            let io = sys::SDL_IOFromConstMem(bytes.as_ptr() as *const _, bytes.len());
+            if io.is_null() {
+                return Err(Error::new());
+            }
+            let surface = sys::image::IMG_Load_IO(io, true);
+            if surface.is_null() {
+                return Err(Error::new());
+            }
+            Ok(Self::from_mut_ptr(video, surface))
+        }
+    }
+
+    /// Creates a new `Surface` by loading an image in a known format from a stream.
+    ///
+    /// The format is used as a hint: SDL_image still checks the data, but some formats, such as
+    /// TGA, can't be detected without it.
+    pub fn load_image_typed_from_io(
+        video: &VideoSubsystem,
+        io: IOStream,
+        format: ImageFormat,
+    ) -> Result<Self, Error> {
+        let ptr = unsafe { sys::image::IMG_LoadTyped_IO(io.raw(), false, format.name().as_ptr()) };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        Ok(unsafe { Self::from_mut_ptr(video, ptr) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tga_is_only_loaded_by_name() {
+        assert_eq!(ImageFormat::Tga.name(), c"TGA");
+        assert!(!ImageFormat::ALL.contains(&ImageFormat::Tga));
+    }
+}
@@ -1,8 +1,11 @@
-use crate::events::EventPump;
+use crate::events::{EventCallbacks, EventPump};
 use crate::sys;
 use crate::Error;
+use alloc::boxed::Box;
 use alloc::rc::{Rc, Weak};
 use core::cell::RefCell;
+use core::ffi::c_void;
+use core::marker::PhantomData;
 use core::sync::atomic::{AtomicBool, Ordering};
 
 static IS_SDL_INITIALIZED: AtomicBool = AtomicBool::new(false);
@@ -21,6 +24,7 @@ pub struct Sdl {
     video: Weak<Subsystem<{ sys::SDL_INIT_VIDEO }>>,
     sensor: Weak<Subsystem<{ sys::SDL_INIT_SENSOR }>>,
     event_pump: Weak<RefCell<EventPump>>,
+    event_callbacks: Weak<EventCallbacks>,
 }
 
 #[allow(unused)]
@@ -34,6 +38,8 @@ pub struct CameraSubsystem(pub(crate) Rc<Subsystem<{ sys::SDL_INIT_CAMERA }>>);
 #[allow(unused)]
 #[derive(Clone)]
 pub struct EventsSubsystem {
+    // Declared first so the callbacks are removed before the subsystem is shut down.
+    pub(crate) callbacks: Rc<EventCallbacks>,
     pub(crate) subsystem: Rc<Subsystem<{ sys::SDL_INIT_EVENTS }>>,
     pub(crate) event_pump: Rc<RefCell<EventPump>>,
 }
@@ -58,6 +64,81 @@ pub struct VideoSubsystem(pub(crate) Rc<Subsystem<{ sys::SDL_INIT_VIDEO }>>);
 #[derive(Clone)]
 pub struct SensorSubsystem(pub(crate) Rc<Subsystem<{ sys::SDL_INIT_SENSOR }>>);
 
+/// Keeps a subsystem, and SDL itself, initialized for as long as it's held.
+///
+/// Unlike the subsystem handles, a guard doesn't give access to the subsystem's functions. It lets a
+/// library make sure a subsystem outlives the resources it created without holding on to the
+/// handles of the application. Guards are obtained from the matching subsystem, e.g.
+/// [`VideoSubsystem::guard`].
+#[allow(unused)]
+#[derive(Clone)]
+pub struct SubsystemGuard<const INIT_FLAG: u32>(Rc<Subsystem<INIT_FLAG>>);
+
+pub type AudioGuard = SubsystemGuard<{ sys::SDL_INIT_AUDIO }>;
+pub type CameraGuard = SubsystemGuard<{ sys::SDL_INIT_CAMERA }>;
+pub type EventsGuard = SubsystemGuard<{ sys::SDL_INIT_EVENTS }>;
+pub type GamepadGuard = SubsystemGuard<{ sys::SDL_INIT_GAMEPAD }>;
+pub type HapticGuard = SubsystemGuard<{ sys::SDL_INIT_HAPTIC }>;
+pub type JoystickGuard = SubsystemGuard<{ sys::SDL_INIT_JOYSTICK }>;
+pub type VideoGuard = SubsystemGuard<{ sys::SDL_INIT_VIDEO }>;
+pub type SensorGuard = SubsystemGuard<{ sys::SDL_INIT_SENSOR }>;
+
+impl AudioSubsystem {
+    /// Returns a guard that keeps the audio subsystem initialized.
+    pub fn guard(&self) -> AudioGuard {
+        SubsystemGuard(Rc::clone(&self.0))
+    }
+}
+
+impl CameraSubsystem {
+    /// Returns a guard that keeps the camera subsystem initialized.
+    pub fn guard(&self) -> CameraGuard {
+        SubsystemGuard(Rc::clone(&self.0))
+    }
+}
+
+impl EventsSubsystem {
+    /// Returns a guard that keeps the events subsystem initialized.
+    pub fn guard(&self) -> EventsGuard {
+        SubsystemGuard(Rc::clone(&self.subsystem))
+    }
+}
+
+impl GamepadSubsystem {
+    /// Returns a guard that keeps the gamepad subsystem initialized.
+    pub fn guard(&self) -> GamepadGuard {
+        SubsystemGuard(Rc::clone(&self.0))
+    }
+}
+
+impl HapticSubsystem {
+    /// Returns a guard that keeps the haptic subsystem initialized.
+    pub fn guard(&self) -> HapticGuard {
+        SubsystemGuard(Rc::clone(&self.0))
+    }
+}
+
+impl JoystickSubsystem {
+    /// Returns a guard that keeps the joystick subsystem initialized.
+    pub fn guard(&self) -> JoystickGuard {
+        SubsystemGuard(Rc::clone(&self.0))
+    }
+}
+
+impl VideoSubsystem {
+    /// Returns a guard that keeps the video subsystem initialized.
+    pub fn guard(&self) -> VideoGuard {
+        SubsystemGuard(Rc::clone(&self.0))
+    }
+}
+
+impl SensorSubsystem {
+    /// Returns a guard that keeps the sensor subsystem initialized.
+    pub fn guard(&self) -> SensorGuard {
+        SubsystemGuard(Rc::clone(&self.0))
+    }
+}
+
 impl Sdl {
     /// Initializes SDL.
     /// Will return an [`Error`] if SDL can't be initialized or if SDL is *already* initialized.
@@ -75,6 +156,7 @@ impl Sdl {
             sensor: Weak::new(),
             drop: Rc::new(SdlDrop::init()?),
             event_pump: Weak::new(),
+            event_callbacks: Weak::new(),
         })
     }
 
@@ -105,7 +187,16 @@ impl Sdl {
                 event_pump
             }
         };
+        let callbacks = match self.event_callbacks.upgrade() {
+            Some(callbacks) => callbacks,
+            None => {
+                let callbacks = Rc::new(EventCallbacks::default());
+                self.event_callbacks = Rc::downgrade(&callbacks);
+                callbacks
+            }
+        };
         Ok(EventsSubsystem {
+            callbacks,
             subsystem,
             event_pump,
         })
@@ -142,6 +233,22 @@ impl Sdl {
         Self::get_or_init(&mut self.sensor, &self.drop).map(SensorSubsystem)
     }
 
+    /// Runs `callback` on the main thread. Since `Sdl` never leaves the main thread, this runs it
+    /// right away; use [`Sdl::main_thread_runner`] to run callbacks from other threads.
+    pub fn run_on_main_thread<F: FnOnce() + Send + 'static>(
+        &self,
+        callback: F,
+        wait: bool,
+    ) -> Result<(), Error> {
+        self.main_thread_runner().run(callback, wait)
+    }
+
+    /// Returns a [`MainThreadRunner`], which other threads can use to run callbacks on the main
+    /// thread.
+    pub fn main_thread_runner(&self) -> MainThreadRunner<'_> {
+        MainThreadRunner(PhantomData)
+    }
+
     fn get_or_init<const N: u32>(
         s: &mut Weak<Subsystem<N>>,
         drop: &Rc<SdlDrop>,
@@ -157,6 +264,75 @@ impl Sdl {
     }
 }
 
+/// Runs callbacks on the main thread, obtained from [`Sdl::main_thread_runner`].
+///
+/// Some platforms, such as macOS, iOS and Emscripten, require windows and renderers to be used
+/// from the main thread only; other threads can use this to hand that work over.
+///
+/// `MainThreadRunner` is `Send` and `Sync`. Its lifetime is tied to the [`Sdl`], which limits its
+/// use to scoped threads.
+#[derive(Copy, Clone)]
+pub struct MainThreadRunner<'a>(PhantomData<&'a ()>);
+
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<MainThreadRunner<'static>>();
+};
+
+impl MainThreadRunner<'_> {
+    /// Runs `callback` on the main thread. If this is the main thread, it runs right away;
+    /// otherwise it runs the next time the main thread pumps events.
+    ///
+    /// If `wait` is true, this blocks until the callback has run. Don't wait from a thread the
+    /// main thread is blocked on, e.g. while it joins the scoped thread, or neither will make
+    /// progress.
+    ///
+    /// Fails if the callback couldn't be scheduled, or if `wait` is true and the callback was
+    /// cancelled because SDL shut down.
+    pub fn run<F: FnOnce() + Send + 'static>(&self, callback: F, wait: bool) -> Result<(), Error> {
+        let callback = Box::into_raw(Box::new(callback));
+        let result = unsafe {
+            sys::SDL_RunOnMainThread(Some(main_thread_marshall::<F>), callback.cast(), wait)
+        };
+        if !result {
+            // SDL only reports an error if the callback didn't and won't run.
+            drop(unsafe { Box::from_raw(callback) });
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+}
+
+unsafe extern "C" fn main_thread_marshall<F: FnOnce() + Send + 'static>(user_data: *mut c_void) {
+    let callback = unsafe { Box::from_raw(user_data as *mut F) };
+    callback();
+}
+
+/// Returns true if this is the main thread, the thread SDL was initialized on.
+pub fn is_main_thread() -> bool {
+    unsafe { sys::SDL_IsMainThread() }
+}
+
+/// Returns true if an [`Sdl`] instance is alive, i.e. [`Sdl::init`] would fail.
+///
+/// Meant for libraries built on rsdl3 that need to know whether the application already owns
+/// SDL before initializing it themselves.
+pub fn is_initialized() -> bool {
+    IS_SDL_INITIALIZED.load(Ordering::Relaxed)
+}
+
+/// Returns true if the video subsystem is initialized, by this crate or by anything else in the
+/// process.
+pub fn is_video_initialized() -> bool {
+    unsafe { sys::SDL_WasInit(sys::SDL_INIT_VIDEO) & sys::SDL_INIT_VIDEO != 0 }
+}
+
+/// Returns true if the events subsystem is initialized, by this crate or by anything else in the
+/// process. Initializing the video subsystem also initializes it.
+pub fn is_events_initialized() -> bool {
+    unsafe { sys::SDL_WasInit(sys::SDL_INIT_EVENTS) & sys::SDL_INIT_EVENTS != 0 }
+}
+
 pub struct Subsystem<const INIT_FLAG: u32> {
     _drop: Rc<SdlDrop>,
 }
@@ -1,8 +1,10 @@
 use crate::init::SdlDrop;
 use crate::sys;
 use crate::{init::Sdl, Error};
+use alloc::boxed::Box;
 use alloc::ffi::CString;
 use alloc::rc::Rc;
+use alloc::vec::Vec;
 use core::ffi::c_void;
 use core::marker::PhantomData;
 
@@ -10,6 +12,8 @@ use core::marker::PhantomData;
 pub struct IOStream<'a> {
     _sdl: Rc<SdlDrop>,
     ptr: *mut sys::SDL_IOStream,
+    /// Streams owned by another SDL object, such as a process's pipes, are not closed on drop.
+    owned: bool,
     _m: PhantomData<&'a ()>,
 }
 
@@ -19,14 +23,44 @@ impl IOStream<'static> {
         let file = CString::new(file)?;
         let mode = CString::new(mode)?;
         let ptr = unsafe { sys::SDL_IOFromFile(file.as_ptr(), mode.as_ptr()) };
-        if ptr.is_null() {
+        Self::from_ptr(sdl, ptr)
+    }
+
+    /// Creates an empty `IOStream` backed by memory that grows as data is written to it.
+    ///
+    /// Seek back to the start and call [`IOStream::read_to_end`] to get the written bytes.
+    pub fn from_dynamic_mem(sdl: &Sdl) -> Result<Self, Error> {
+        let ptr = unsafe { sys::SDL_IOFromDynamicMem() };
+        Self::from_ptr(sdl, ptr)
+    }
+
+    /// Creates a read-write `IOStream` that owns `bytes`, e.g. an asset fetched over the network.
+    ///
+    /// Writing past the end grows the buffer. Unlike [`IOStream::from_bytes`] and
+    /// [`IOStream::from_dynamic_mem`], this doesn't need the data to outlive the stream or to be
+    /// copied into it, which makes it a good fit for targets without a real filesystem, such as
+    /// Emscripten.
+    pub fn from_vec(sdl: &Sdl, bytes: Vec<u8>) -> Result<Self, Error> {
+        Self::from_interface(sdl, VecStream { bytes, position: 0 })
+    }
+
+    /// Reads the entire contents of a file.
+    pub fn load_file(_sdl: &Sdl, file: &str) -> Result<Vec<u8>, Error> {
+        let file = CString::new(file)?;
+        let mut len = 0;
+        let ptr = unsafe { sys::SDL_LoadFile(file.as_ptr(), &raw mut len) };
+        unsafe { take_sdl_bytes(ptr, len) }
+    }
+
+    /// Writes `data` to a file, replacing its contents.
+    pub fn save_file(_sdl: &Sdl, file: &str, data: &[u8]) -> Result<(), Error> {
+        let file = CString::new(file)?;
+        let result =
+            unsafe { sys::SDL_SaveFile(file.as_ptr(), data.as_ptr() as *const c_void, data.len()) };
+        if !result {
             return Err(Error::new());
         }
-        Ok(IOStream {
-            _sdl: Rc::clone(&sdl.drop),
-            ptr,
-            _m: PhantomData,
-        })
+        Ok(())
     }
 }
 
@@ -34,34 +68,513 @@ impl<'a> IOStream<'a> {
     /// Creates a new `IOStream` from an existing mutable byte buffer.
     pub fn from_bytes_mut(sdl: &Sdl, bytes: &'a mut [u8]) -> Result<Self, Error> {
         let ptr = unsafe { sys::SDL_IOFromMem(bytes.as_mut_ptr() as *mut c_void, bytes.len()) };
+        Self::from_ptr(sdl, ptr)
+    }
+
+    /// Creates an `IOStream` from an existing read-only buffer.
+    pub fn from_bytes(sdl: &Sdl, bytes: &'a [u8]) -> Result<Self, Error> {
+        let ptr = unsafe { sys::SDL_IOFromConstMem(bytes.as_ptr() as *const c_void, bytes.len()) };
+        Self::from_ptr(sdl, ptr)
+    }
+
+    /// Creates an `IOStream` that forwards every operation to `interface`.
+    ///
+    /// The interface is dropped when the stream is closed.
+    pub fn from_interface<T: IOStreamInterface + 'a>(
+        sdl: &Sdl,
+        interface: T,
+    ) -> Result<Self, Error> {
+        let iface = sys::SDL_IOStreamInterface {
+            version: size_of::<sys::SDL_IOStreamInterface>() as u32,
+            size: Some(io_size_marshall::<T>),
+            seek: Some(io_seek_marshall::<T>),
+            read: Some(io_read_marshall::<T>),
+            write: Some(io_write_marshall::<T>),
+            flush: Some(io_flush_marshall::<T>),
+            close: Some(io_close_marshall::<T>),
+        };
+        let userdata = Box::into_raw(Box::new(interface));
+        let ptr = unsafe { sys::SDL_OpenIO(&raw const iface, userdata as *mut c_void) };
+        if ptr.is_null() {
+            // SDL doesn't call `close` when opening fails.
+            drop(unsafe { Box::from_raw(userdata) });
+            return Err(Error::new());
+        }
+        Self::from_ptr(sdl, ptr)
+    }
+
+    fn from_ptr(sdl: &Sdl, ptr: *mut sys::SDL_IOStream) -> Result<Self, Error> {
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
         Ok(IOStream {
             _sdl: Rc::clone(&sdl.drop),
             ptr,
+            owned: true,
             _m: PhantomData,
         })
     }
 
-    /// Creates an `IOStream` from an existing read-only buffer.
-    pub fn from_bytes(sdl: &Sdl, bytes: &'a [u8]) -> Result<Self, Error> {
-        let ptr = unsafe { sys::SDL_IOFromConstMem(bytes.as_ptr() as *const c_void, bytes.len()) };
+    /// Wraps a stream owned by another SDL object, which must outlive `'a`.
+    pub(crate) unsafe fn from_borrowed_ptr(
+        sdl: &Rc<SdlDrop>,
+        ptr: *mut sys::SDL_IOStream,
+    ) -> Result<Self, Error> {
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
         Ok(IOStream {
-            _sdl: Rc::clone(&sdl.drop),
+            _sdl: Rc::clone(sdl),
             ptr,
+            owned: false,
             _m: PhantomData,
         })
     }
 
+    /// Reads up to `buf.len()` bytes and returns how many were read.
+    ///
+    /// Returns `Ok(0)` at the end of the stream, or if a non-blocking stream has no data ready.
+    pub fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        let read = unsafe { sys::SDL_ReadIO(self.ptr, buf.as_mut_ptr() as *mut c_void, buf.len()) };
+        if read < buf.len() && self.status() == IOStatus::Error {
+            return Err(Error::new());
+        }
+        Ok(read)
+    }
+
+    /// Reads everything from the current position to the end of the stream.
+    pub fn read_to_end(&mut self) -> Result<Vec<u8>, Error> {
+        let mut len = 0;
+        let ptr = unsafe { sys::SDL_LoadFile_IO(self.ptr, &raw mut len, false) };
+        unsafe { take_sdl_bytes(ptr, len) }
+    }
+
+    /// Writes all of `buf` to the stream.
+    pub fn write(&mut self, buf: &[u8]) -> Result<(), Error> {
+        let written =
+            unsafe { sys::SDL_WriteIO(self.ptr, buf.as_ptr() as *const c_void, buf.len()) };
+        if written < buf.len() {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Writes any buffered data to the underlying storage.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_FlushIO(self.ptr) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Moves the read/write position and returns the new position, from the start of the stream.
+    pub fn seek(&mut self, offset: i64, whence: IOWhence) -> Result<u64, Error> {
+        let position = unsafe { sys::SDL_SeekIO(self.ptr, offset, whence.to_ll()) };
+        u64::try_from(position).map_err(|_| Error::new())
+    }
+
+    /// Returns the current read/write position, from the start of the stream.
+    pub fn tell(&self) -> Result<u64, Error> {
+        let position = unsafe { sys::SDL_TellIO(self.ptr) };
+        u64::try_from(position).map_err(|_| Error::new())
+    }
+
+    /// Returns the size of the stream in bytes.
+    pub fn size(&self) -> Result<u64, Error> {
+        let size = unsafe { sys::SDL_GetIOSize(self.ptr) };
+        u64::try_from(size).map_err(|_| Error::new())
+    }
+
+    /// Returns the status of the last read or write.
+    pub fn status(&self) -> IOStatus {
+        IOStatus::from_ll(unsafe { sys::SDL_GetIOStatus(self.ptr) })
+    }
+
     #[inline]
     pub fn raw(&self) -> *mut sys::SDL_IOStream {
         self.ptr
     }
 }
 
+macro_rules! impl_typed_io {
+    ($($ty:ty, $order:literal, $read:ident => $sdl_read:ident, $write:ident => $sdl_write:ident;)*) => {
+        impl IOStream<'_> {
+            $(
+                #[doc = concat!("Reads a ", $order, "`", stringify!($ty), "`.")]
+                pub fn $read(&mut self) -> Result<$ty, Error> {
+                    let mut value = 0;
+                    let result = unsafe { sys::$sdl_read(self.ptr, &raw mut value) };
+                    if !result {
+                        return Err(Error::new());
+                    }
+                    Ok(value)
+                }
+
+                #[doc = concat!("Writes a ", $order, "`", stringify!($ty), "`.")]
+                pub fn $write(&mut self, value: $ty) -> Result<(), Error> {
+                    let result = unsafe { sys::$sdl_write(self.ptr, value) };
+                    if !result {
+                        return Err(Error::new());
+                    }
+                    Ok(())
+                }
+            )*
+        }
+    };
+}
+
+impl_typed_io! {
+    u8, "", read_u8 => SDL_ReadU8, write_u8 => SDL_WriteU8;
+    i8, "", read_i8 => SDL_ReadS8, write_i8 => SDL_WriteS8;
+    u16, "little-endian ", read_u16_le => SDL_ReadU16LE, write_u16_le => SDL_WriteU16LE;
+    u16, "big-endian ", read_u16_be => SDL_ReadU16BE, write_u16_be => SDL_WriteU16BE;
+    i16, "little-endian ", read_i16_le => SDL_ReadS16LE, write_i16_le => SDL_WriteS16LE;
+    i16, "big-endian ", read_i16_be => SDL_ReadS16BE, write_i16_be => SDL_WriteS16BE;
+    u32, "little-endian ", read_u32_le => SDL_ReadU32LE, write_u32_le => SDL_WriteU32LE;
+    u32, "big-endian ", read_u32_be => SDL_ReadU32BE, write_u32_be => SDL_WriteU32BE;
+    i32, "little-endian ", read_i32_le => SDL_ReadS32LE, write_i32_le => SDL_WriteS32LE;
+    i32, "big-endian ", read_i32_be => SDL_ReadS32BE, write_i32_be => SDL_WriteS32BE;
+    u64, "little-endian ", read_u64_le => SDL_ReadU64LE, write_u64_le => SDL_WriteU64LE;
+    u64, "big-endian ", read_u64_be => SDL_ReadU64BE, write_u64_be => SDL_WriteU64BE;
+    i64, "little-endian ", read_i64_le => SDL_ReadS64LE, write_i64_le => SDL_WriteS64LE;
+    i64, "big-endian ", read_i64_be => SDL_ReadS64BE, write_i64_be => SDL_WriteS64BE;
+}
+
 impl<'a> Drop for IOStream<'a> {
     fn drop(&mut self) {
         // SAFETY:
         // SDL is guaranteed to live beyond IOStream's lifetime via _drop.
-        // The ptr is owned by the IOStream and not shared.
-        unsafe { sys::SDL_CloseIO(self.ptr) };
+        // The ptr is owned by the IOStream and not shared, unless `owned` is false.
+        if self.owned {
+            unsafe { sys::SDL_CloseIO(self.ptr) };
+        }
+    }
+}
+
+/// The reference point of [`IOStream::seek`].
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IOWhence {
+    /// Seek from the start of the stream.
+    Set = sys::SDL_IOWhence_SDL_IO_SEEK_SET,
+    /// Seek relative to the current position.
+    Cur = sys::SDL_IOWhence_SDL_IO_SEEK_CUR,
+    /// Seek relative to the end of the stream.
+    End = sys::SDL_IOWhence_SDL_IO_SEEK_END,
+}
+
+impl IOWhence {
+    /// Converts a raw `SDL_IOWhence` into a `IOWhence`.
+    pub fn try_from_ll(ll: sys::SDL_IOWhence) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_IOWhence_SDL_IO_SEEK_SET => Self::Set,
+            sys::SDL_IOWhence_SDL_IO_SEEK_CUR => Self::Cur,
+            sys::SDL_IOWhence_SDL_IO_SEEK_END => Self::End,
+            _ => return Err(Error::register(c"Unknown IO whence.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_IOWhence {
+        *self as u32
+    }
+
+    fn from_ll(ll: sys::SDL_IOWhence) -> Option<Self> {
+        match ll {
+            sys::SDL_IOWhence_SDL_IO_SEEK_SET => Some(Self::Set),
+            sys::SDL_IOWhence_SDL_IO_SEEK_CUR => Some(Self::Cur),
+            sys::SDL_IOWhence_SDL_IO_SEEK_END => Some(Self::End),
+            _ => None,
+        }
+    }
+}
+
+/// The state of an [`IOStream`] after its last read or write.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IOStatus {
+    Ready = sys::SDL_IOStatus_SDL_IO_STATUS_READY,
+    Error = sys::SDL_IOStatus_SDL_IO_STATUS_ERROR,
+    Eof = sys::SDL_IOStatus_SDL_IO_STATUS_EOF,
+    /// A non-blocking stream has no data or room available yet.
+    NotReady = sys::SDL_IOStatus_SDL_IO_STATUS_NOT_READY,
+    ReadOnly = sys::SDL_IOStatus_SDL_IO_STATUS_READONLY,
+    WriteOnly = sys::SDL_IOStatus_SDL_IO_STATUS_WRITEONLY,
+}
+
+impl IOStatus {
+    /// Converts a raw `SDL_IOStatus` into a `IOStatus`.
+    pub fn try_from_ll(ll: sys::SDL_IOStatus) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_IOStatus_SDL_IO_STATUS_READY => Self::Ready,
+            sys::SDL_IOStatus_SDL_IO_STATUS_ERROR => Self::Error,
+            sys::SDL_IOStatus_SDL_IO_STATUS_EOF => Self::Eof,
+            sys::SDL_IOStatus_SDL_IO_STATUS_NOT_READY => Self::NotReady,
+            sys::SDL_IOStatus_SDL_IO_STATUS_READONLY => Self::ReadOnly,
+            sys::SDL_IOStatus_SDL_IO_STATUS_WRITEONLY => Self::WriteOnly,
+            _ => return Err(Error::register(c"Unknown IO status.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_IOStatus {
+        *self as u32
+    }
+
+    fn from_ll(ll: sys::SDL_IOStatus) -> Self {
+        match ll {
+            sys::SDL_IOStatus_SDL_IO_STATUS_READY => Self::Ready,
+            sys::SDL_IOStatus_SDL_IO_STATUS_EOF => Self::Eof,
+            sys::SDL_IOStatus_SDL_IO_STATUS_NOT_READY => Self::NotReady,
+            sys::SDL_IOStatus_SDL_IO_STATUS_READONLY => Self::ReadOnly,
+            sys::SDL_IOStatus_SDL_IO_STATUS_WRITEONLY => Self::WriteOnly,
+            _ => Self::Error,
+        }
+    }
+}
+
+/// A data source or sink that backs an [`IOStream`] created with [`IOStream::from_interface`].
+///
+/// Every method has a default, so read-only sources only need [`IOStreamInterface::read`] and
+/// write-only sinks only need [`IOStreamInterface::write`].
+pub trait IOStreamInterface {
+    /// Returns the total size of the data, or `None` if it is unknown.
+    fn size(&mut self) -> Option<u64> {
+        None
+    }
+
+    /// Moves the position and returns the new one, from the start, or `None` if seeking is not
+    /// supported or the position is invalid.
+    fn seek(&mut self, offset: i64, whence: IOWhence) -> Option<u64> {
+        let _ = (offset, whence);
+        None
+    }
+
+    /// Reads up to `buf.len()` bytes and returns how many were read. `Ok(0)` means the end of the
+    /// data was reached.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, IOStatus> {
+        let _ = buf;
+        Err(IOStatus::WriteOnly)
+    }
+
+    /// Writes up to `buf.len()` bytes and returns how many were written.
+    fn write(&mut self, buf: &[u8]) -> Result<usize, IOStatus> {
+        let _ = buf;
+        Err(IOStatus::ReadOnly)
+    }
+
+    fn flush(&mut self) -> Result<(), IOStatus> {
+        Ok(())
+    }
+}
+
+/// The interface behind [`IOStream::from_vec`].
+struct VecStream {
+    bytes: Vec<u8>,
+    position: usize,
+}
+
+impl IOStreamInterface for VecStream {
+    fn size(&mut self) -> Option<u64> {
+        u64::try_from(self.bytes.len()).ok()
+    }
+
+    fn seek(&mut self, offset: i64, whence: IOWhence) -> Option<u64> {
+        let base = match whence {
+            IOWhence::Set => 0,
+            IOWhence::Cur => self.position,
+            IOWhence::End => self.bytes.len(),
+        };
+        let position = i64::try_from(base).ok()?.checked_add(offset)?;
+        self.position = usize::try_from(position).ok()?;
+        u64::try_from(self.position).ok()
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, IOStatus> {
+        let available = self.bytes.get(self.position..).unwrap_or_default();
+        let read = buf.len().min(available.len());
+        buf[..read].copy_from_slice(&available[..read]);
+        self.position += read;
+        Ok(read)
+    }
+
+    fn write(&mut self, buf: &[u8]) -> Result<usize, IOStatus> {
+        let end = self
+            .position
+            .checked_add(buf.len())
+            .ok_or(IOStatus::Error)?;
+        if end > self.bytes.len() {
+            self.bytes.resize(end, 0);
+        }
+        self.bytes[self.position..end].copy_from_slice(buf);
+        self.position = end;
+        Ok(buf.len())
+    }
+}
+
+/// SAFETY: `ptr` must be null or point to `len` bytes allocated by SDL.
+unsafe fn take_sdl_bytes(ptr: *mut c_void, len: usize) -> Result<Vec<u8>, Error> {
+    if ptr.is_null() {
+        return Err(Error::new());
+    }
+    let bytes = unsafe { core::slice::from_raw_parts(ptr as *const u8, len) }.to_vec();
+    unsafe { sys::SDL_free(ptr) };
+    Ok(bytes)
+}
+
+fn io_status_error(status: IOStatus) -> &'static core::ffi::CStr {
+    match status {
+        IOStatus::ReadOnly => c"Stream is read-only",
+        IOStatus::WriteOnly => c"Stream is write-only",
+        _ => c"Stream operation failed",
+    }
+}
+
+unsafe extern "C" fn io_size_marshall<T: IOStreamInterface>(userdata: *mut c_void) -> i64 {
+    let interface = unsafe { &mut *(userdata as *mut T) };
+    interface
+        .size()
+        .and_then(|size| i64::try_from(size).ok())
+        .unwrap_or(-1)
+}
+
+unsafe extern "C" fn io_seek_marshall<T: IOStreamInterface>(
+    userdata: *mut c_void,
+    offset: i64,
+    whence: sys::SDL_IOWhence,
+) -> i64 {
+    let interface = unsafe { &mut *(userdata as *mut T) };
+    let position = IOWhence::from_ll(whence)
+        .and_then(|whence| interface.seek(offset, whence))
+        .and_then(|position| i64::try_from(position).ok());
+    match position {
+        Some(position) => position,
+        None => {
+            Error::register(c"Stream does not support seeking to this position");
+            -1
+        }
+    }
+}
+
+unsafe extern "C" fn io_read_marshall<T: IOStreamInterface>(
+    userdata: *mut c_void,
+    ptr: *mut c_void,
+    size: usize,
+    status: *mut sys::SDL_IOStatus,
+) -> usize {
+    // SDL may pass a null `ptr` when there's nothing to read.
+    if size == 0 {
+        return 0;
+    }
+    let interface = unsafe { &mut *(userdata as *mut T) };
+    let buf = unsafe { core::slice::from_raw_parts_mut(ptr as *mut u8, size) };
+    match interface.read(buf) {
+        Ok(0) => {
+            unsafe { *status = IOStatus::Eof.to_ll() };
+            0
+        }
+        Ok(read) => read.min(size),
+        Err(err) => {
+            if err != IOStatus::NotReady {
+                Error::register(io_status_error(err));
+            }
+            unsafe { *status = err.to_ll() };
+            0
+        }
+    }
+}
+
+unsafe extern "C" fn io_write_marshall<T: IOStreamInterface>(
+    userdata: *mut c_void,
+    ptr: *const c_void,
+    size: usize,
+    status: *mut sys::SDL_IOStatus,
+) -> usize {
+    // SDL may pass a null `ptr` when there's nothing to write.
+    if size == 0 {
+        return 0;
+    }
+    let interface = unsafe { &mut *(userdata as *mut T) };
+    let buf = unsafe { core::slice::from_raw_parts(ptr as *const u8, size) };
+    match interface.write(buf) {
+        Ok(written) => written.min(size),
+        Err(err) => {
+            if err != IOStatus::NotReady {
+                Error::register(io_status_error(err));
+            }
+            unsafe { *status = err.to_ll() };
+            0
+        }
+    }
+}
+
+unsafe extern "C" fn io_flush_marshall<T: IOStreamInterface>(
+    userdata: *mut c_void,
+    status: *mut sys::SDL_IOStatus,
+) -> bool {
+    let interface = unsafe { &mut *(userdata as *mut T) };
+    match interface.flush() {
+        Ok(()) => true,
+        Err(err) => {
+            Error::register(io_status_error(err));
+            unsafe { *status = err.to_ll() };
+            false
+        }
+    }
+}
+
+unsafe extern "C" fn io_close_marshall<T: IOStreamInterface>(userdata: *mut c_void) -> bool {
+    drop(unsafe { Box::from_raw(userdata as *mut T) });
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(IOWhence: Set, Cur, End);
+        assert_round_trips!(IOStatus: Ready, Error, Eof, NotReady, ReadOnly, WriteOnly);
+    }
+
+    fn vec_stream(bytes: &[u8]) -> VecStream {
+        VecStream {
+            bytes: bytes.to_vec(),
+            position: 0,
+        }
+    }
+
+    #[test]
+    fn vec_stream_seeks_past_the_end() {
+        let mut stream = vec_stream(&[1, 2, 3, 4]);
+        assert_eq!(stream.seek(2, IOWhence::End), Some(6));
+        assert_eq!(stream.read(&mut [0; 4]), Ok(0));
+        assert_eq!(stream.write(&[5]), Ok(1));
+        assert_eq!(stream.bytes, [1, 2, 3, 4, 0, 0, 5]);
+        assert_eq!(stream.seek(-8, IOWhence::Cur), None);
+    }
+
+    #[test]
+    fn vec_stream_reads_nothing_at_eof() {
+        let mut stream = vec_stream(&[1, 2, 3]);
+        let mut buf = [0; 2];
+        assert_eq!(stream.read(&mut buf), Ok(2));
+        assert_eq!(buf, [1, 2]);
+        assert_eq!(stream.read(&mut buf), Ok(1));
+        assert_eq!(buf[0], 3);
+        assert_eq!(stream.read(&mut buf), Ok(0));
+    }
+
+    #[test]
+    fn vec_stream_overwrites_in_the_middle() {
+        let mut stream = vec_stream(&[1, 2, 3, 4]);
+        assert_eq!(stream.seek(1, IOWhence::Set), Some(1));
+        assert_eq!(stream.write(&[8, 9]), Ok(2));
+        assert_eq!(stream.bytes, [1, 8, 9, 4]);
+        assert_eq!(stream.seek(0, IOWhence::Cur), Some(3));
+        assert_eq!(stream.size(), Some(4));
     }
 }
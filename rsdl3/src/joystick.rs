@@ -0,0 +1,382 @@
+use crate::sys;
+use crate::Error;
+use crate::JoystickSubsystem;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::c_void;
+use core::ffi::CStr;
+use core::ptr::NonNull;
+
+impl JoystickSubsystem {
+    /// Returns a list of currently connected joysticks.
+    pub fn joysticks(&self) -> Result<Vec<JoystickId>, Error> {
+        unsafe {
+            let mut count = 0;
+            let ptr = sys::SDL_GetJoysticks(&raw mut count);
+            joystick_ids_from_ll(ptr, count)
+        }
+    }
+
+    /// Returns the implementation dependent name of a joystick.
+    ///
+    /// This can be called before any joysticks are opened.
+    pub fn joystick_name(&self, id: JoystickId) -> Result<String, Error> {
+        let ptr = unsafe { sys::SDL_GetJoystickNameForID(id) };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        Ok(unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned())
+    }
+
+    /// Equivalent to [`Joystick::open`].
+    pub fn open_joystick(&self, id: JoystickId) -> Result<Joystick, Error> {
+        Joystick::open(self, id)
+    }
+}
+
+/// Instance id of a joystick, also used to identify gamepads.
+///
+/// The id is unique for as long as the device stays connected. If the device is disconnected and
+/// reconnected, it will get a new id.
+pub type JoystickId = sys::SDL_JoystickID;
+
+/// SAFETY: `ptr` must be null or an id array allocated by SDL with `count` elements.
+pub(crate) unsafe fn joystick_ids_from_ll(
+    ptr: *mut sys::SDL_JoystickID,
+    count: i32,
+) -> Result<Vec<JoystickId>, Error> {
+    if ptr.is_null() {
+        return Err(Error::new());
+    }
+    let vec = match usize::try_from(count) {
+        Ok(len) => unsafe { core::slice::from_raw_parts(ptr, len) }.to_vec(),
+        Err(err) => {
+            unsafe { sys::SDL_free(ptr as *mut c_void) };
+            return Err(err.into());
+        }
+    };
+    unsafe { sys::SDL_free(ptr as *mut c_void) };
+    Ok(vec)
+}
+
+/// An opened joystick.
+///
+/// Joysticks report raw axes, buttons and hats without any layout information. Use
+/// [`crate::gamepad::Gamepad`] for devices with a known, console-style layout.
+pub struct Joystick {
+    _subsystem: JoystickSubsystem,
+    ptr: NonNull<sys::SDL_Joystick>,
+}
+
+impl Joystick {
+    /// Opens a joystick for use.
+    ///
+    /// Joystick events are delivered for opened joysticks only.
+    pub fn open(subsystem: &JoystickSubsystem, id: JoystickId) -> Result<Self, Error> {
+        let ptr = NonNull::new(unsafe { sys::SDL_OpenJoystick(id) }).ok_or_else(Error::new)?;
+        Ok(Self {
+            _subsystem: subsystem.clone(),
+            ptr,
+        })
+    }
+
+    /// Returns the instance id of the joystick.
+    pub fn id(&self) -> Result<JoystickId, Error> {
+        let result = unsafe { sys::SDL_GetJoystickID(self.raw()) };
+        if result == 0 {
+            return Err(Error::new());
+        }
+        Ok(result)
+    }
+
+    /// Returns the implementation dependent name of the joystick.
+    pub fn name(&self) -> Result<String, Error> {
+        let ptr = unsafe { sys::SDL_GetJoystickName(self.raw()) };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        Ok(unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned())
+    }
+
+    /// Returns true if the joystick is still connected.
+    pub fn is_connected(&self) -> bool {
+        unsafe { sys::SDL_JoystickConnected(self.raw()) }
+    }
+
+    /// Returns the number of axes on the joystick.
+    pub fn num_axes(&self) -> Result<u32, Error> {
+        let result = unsafe { sys::SDL_GetNumJoystickAxes(self.raw()) };
+        if result < 0 {
+            return Err(Error::new());
+        }
+        Ok(result as u32)
+    }
+
+    /// Returns the number of buttons on the joystick.
+    pub fn num_buttons(&self) -> Result<u32, Error> {
+        let result = unsafe { sys::SDL_GetNumJoystickButtons(self.raw()) };
+        if result < 0 {
+            return Err(Error::new());
+        }
+        Ok(result as u32)
+    }
+
+    /// Returns the number of POV hats on the joystick.
+    pub fn num_hats(&self) -> Result<u32, Error> {
+        let result = unsafe { sys::SDL_GetNumJoystickHats(self.raw()) };
+        if result < 0 {
+            return Err(Error::new());
+        }
+        Ok(result as u32)
+    }
+
+    /// Returns the current state of an axis, ranging from -32768 to 32767.
+    ///
+    /// On most modern joysticks the X axis is usually represented by axis 0 and the Y axis by
+    /// axis 1. The value may also be 0 if the axis index is invalid.
+    pub fn axis(&self, axis: u32) -> i16 {
+        let axis = i32::try_from(axis).unwrap_or(i32::MAX);
+        unsafe { sys::SDL_GetJoystickAxis(self.raw(), axis) }
+    }
+
+    /// Returns true if the button is currently pressed.
+    pub fn button(&self, button: u32) -> bool {
+        let button = i32::try_from(button).unwrap_or(i32::MAX);
+        unsafe { sys::SDL_GetJoystickButton(self.raw(), button) }
+    }
+
+    /// Returns the current position of a POV hat.
+    pub fn hat(&self, hat: u32) -> JoystickHat {
+        let hat = i32::try_from(hat).unwrap_or(i32::MAX);
+        JoystickHat::from_ll(unsafe { sys::SDL_GetJoystickHat(self.raw(), hat) })
+    }
+
+    /// Starts a rumble effect.
+    ///
+    /// Each call cancels any previous rumble effect, and calling it with 0 intensity stops any
+    /// rumbling. Intensities range from 0 to 0xFFFF.
+    pub fn rumble(
+        &mut self,
+        low_frequency_rumble: u16,
+        high_frequency_rumble: u16,
+        duration_ms: u32,
+    ) -> Result<(), Error> {
+        let result = unsafe {
+            sys::SDL_RumbleJoystick(
+                self.raw(),
+                low_frequency_rumble,
+                high_frequency_rumble,
+                duration_ms,
+            )
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Starts a rumble effect in the joystick's triggers.
+    ///
+    /// Each call cancels any previous trigger rumble effect, and calling it with 0 intensity stops
+    /// any rumbling. This is currently only supported on Xbox One controllers.
+    pub fn rumble_triggers(
+        &mut self,
+        left_rumble: u16,
+        right_rumble: u16,
+        duration_ms: u32,
+    ) -> Result<(), Error> {
+        let result = unsafe {
+            sys::SDL_RumbleJoystickTriggers(self.raw(), left_rumble, right_rumble, duration_ms)
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Updates the joystick's LED color, if it has one.
+    pub fn set_led(&mut self, red: u8, green: u8, blue: u8) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_SetJoystickLED(self.raw(), red, green, blue) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns the battery state of the joystick.
+    pub fn power_info(&self) -> Result<PowerInfo, Error> {
+        let mut percent = -1;
+        let state = unsafe { sys::SDL_GetJoystickPowerInfo(self.raw(), &raw mut percent) };
+        if state == sys::SDL_PowerState_SDL_POWERSTATE_ERROR {
+            return Err(Error::new());
+        }
+        PowerInfo::from_ll(state, percent)
+    }
+
+    /// Returns whether the joystick is connected with a cable or wirelessly.
+    pub fn connection_state(&self) -> Result<ConnectionState, Error> {
+        let state = unsafe { sys::SDL_GetJoystickConnectionState(self.raw()) };
+        if state == sys::SDL_JoystickConnectionState_SDL_JOYSTICK_CONNECTION_INVALID {
+            return Err(Error::new());
+        }
+        ConnectionState::try_from_ll(state)
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_Joystick {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for Joystick {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_CloseJoystick(self.ptr.as_ptr()) };
+    }
+}
+
+/// Position of a joystick POV hat.
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JoystickHat {
+    Centered = sys::SDL_HAT_CENTERED as u8,
+    Up = sys::SDL_HAT_UP as u8,
+    Right = sys::SDL_HAT_RIGHT as u8,
+    Down = sys::SDL_HAT_DOWN as u8,
+    Left = sys::SDL_HAT_LEFT as u8,
+    RightUp = sys::SDL_HAT_RIGHTUP as u8,
+    RightDown = sys::SDL_HAT_RIGHTDOWN as u8,
+    LeftUp = sys::SDL_HAT_LEFTUP as u8,
+    LeftDown = sys::SDL_HAT_LEFTDOWN as u8,
+}
+
+impl JoystickHat {
+    /// Converts a raw value into a `JoystickHat`.
+    pub fn try_from_ll(ll: u8) -> Result<Self, Error> {
+        Ok(match ll as u32 {
+            sys::SDL_HAT_CENTERED => Self::Centered,
+            sys::SDL_HAT_UP => Self::Up,
+            sys::SDL_HAT_RIGHT => Self::Right,
+            sys::SDL_HAT_DOWN => Self::Down,
+            sys::SDL_HAT_LEFT => Self::Left,
+            sys::SDL_HAT_RIGHTUP => Self::RightUp,
+            sys::SDL_HAT_RIGHTDOWN => Self::RightDown,
+            sys::SDL_HAT_LEFTUP => Self::LeftUp,
+            sys::SDL_HAT_LEFTDOWN => Self::LeftDown,
+            _ => return Err(Error::register(c"Unknown joystick hat.")),
+        })
+    }
+
+    pub(crate) fn from_ll(ll: u8) -> Self {
+        match ll as u32 {
+            sys::SDL_HAT_UP => Self::Up,
+            sys::SDL_HAT_RIGHT => Self::Right,
+            sys::SDL_HAT_DOWN => Self::Down,
+            sys::SDL_HAT_LEFT => Self::Left,
+            sys::SDL_HAT_RIGHTUP => Self::RightUp,
+            sys::SDL_HAT_RIGHTDOWN => Self::RightDown,
+            sys::SDL_HAT_LEFTUP => Self::LeftUp,
+            sys::SDL_HAT_LEFTDOWN => Self::LeftDown,
+            _ => Self::Centered,
+        }
+    }
+}
+
+/// The battery state of a device, see [`Joystick::power_info`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PowerInfo {
+    pub state: PowerState,
+    /// The remaining charge, from 0 to 100, if it's known.
+    pub percent: Option<u8>,
+}
+
+impl PowerInfo {
+    pub(crate) fn from_ll(state: sys::SDL_PowerState, percent: i32) -> Result<Self, Error> {
+        Ok(Self {
+            state: PowerState::try_from_ll(state)?,
+            percent: u8::try_from(percent).ok().map(|percent| percent.min(100)),
+        })
+    }
+
+    /// Returns true if the device runs on a battery that's at or below `percent`.
+    pub fn is_low(&self, percent: u8) -> bool {
+        self.state == PowerState::OnBattery && self.percent.is_some_and(|p| p <= percent)
+    }
+}
+
+/// Whether a device runs on a battery, and whether it's charging.
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PowerState {
+    /// The power state can't be determined.
+    Unknown = sys::SDL_PowerState_SDL_POWERSTATE_UNKNOWN,
+    /// Not plugged in, running on the battery.
+    OnBattery = sys::SDL_PowerState_SDL_POWERSTATE_ON_BATTERY,
+    /// Plugged in, with no battery available.
+    NoBattery = sys::SDL_PowerState_SDL_POWERSTATE_NO_BATTERY,
+    /// Plugged in, charging the battery.
+    Charging = sys::SDL_PowerState_SDL_POWERSTATE_CHARGING,
+    /// Plugged in, with the battery charged.
+    Charged = sys::SDL_PowerState_SDL_POWERSTATE_CHARGED,
+}
+
+impl PowerState {
+    /// Converts a raw `SDL_PowerState` into a `PowerState`.
+    pub fn try_from_ll(value: sys::SDL_PowerState) -> Result<Self, Error> {
+        match value {
+            sys::SDL_PowerState_SDL_POWERSTATE_UNKNOWN => Ok(Self::Unknown),
+            sys::SDL_PowerState_SDL_POWERSTATE_ON_BATTERY => Ok(Self::OnBattery),
+            sys::SDL_PowerState_SDL_POWERSTATE_NO_BATTERY => Ok(Self::NoBattery),
+            sys::SDL_PowerState_SDL_POWERSTATE_CHARGING => Ok(Self::Charging),
+            sys::SDL_PowerState_SDL_POWERSTATE_CHARGED => Ok(Self::Charged),
+            _ => Err(Error::register(c"Unknown power state.")),
+        }
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_PowerState {
+        *self as i32
+    }
+}
+
+/// How a joystick is connected to the system.
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionState {
+    Unknown = sys::SDL_JoystickConnectionState_SDL_JOYSTICK_CONNECTION_UNKNOWN,
+    Wired = sys::SDL_JoystickConnectionState_SDL_JOYSTICK_CONNECTION_WIRED,
+    Wireless = sys::SDL_JoystickConnectionState_SDL_JOYSTICK_CONNECTION_WIRELESS,
+}
+
+impl ConnectionState {
+    /// Converts a raw `SDL_JoystickConnectionState` into a `ConnectionState`.
+    pub fn try_from_ll(value: sys::SDL_JoystickConnectionState) -> Result<Self, Error> {
+        match value {
+            sys::SDL_JoystickConnectionState_SDL_JOYSTICK_CONNECTION_UNKNOWN => Ok(Self::Unknown),
+            sys::SDL_JoystickConnectionState_SDL_JOYSTICK_CONNECTION_WIRED => Ok(Self::Wired),
+            sys::SDL_JoystickConnectionState_SDL_JOYSTICK_CONNECTION_WIRELESS => Ok(Self::Wireless),
+            _ => Err(Error::register(c"Unknown connection state.")),
+        }
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_JoystickConnectionState {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(JoystickHat: Centered, Up, Right, Down, Left, RightUp, RightDown, LeftUp, LeftDown);
+        assert_round_trips!(PowerState: Unknown, OnBattery, NoBattery, Charging, Charged);
+        assert_round_trips!(ConnectionState: Unknown, Wired, Wireless);
+    }
+}
@@ -4,7 +4,9 @@ use core::ptr::NonNull;
 
 use alloc::string::String;
 
+use crate::rect::Rect;
 use crate::sys;
+use crate::video::WindowRef;
 use crate::Error;
 use crate::EventsSubsystem;
 
@@ -20,7 +22,7 @@ impl EventsSubsystem {
     pub fn keyboards(&self) -> Result<Keyboards, Error> {
         let mut count = 0;
         let keyboards = unsafe { sys::SDL_GetKeyboards(&raw mut count) };
-        let ptr = NonNull::new(keyboards).ok_or(Error::new())?;
+        let ptr = NonNull::new(keyboards).ok_or_else(Error::new)?;
         Ok(Keyboards { ptr })
     }
 
@@ -47,10 +49,104 @@ impl EventsSubsystem {
             let mut numkeys = 0;
             let state = sys::SDL_GetKeyboardState(&raw mut numkeys);
             let numkeys = usize::try_from(numkeys)?;
-            let ptr = NonNull::new(state as *mut _).ok_or(Error::new())?;
+            let ptr = NonNull::new(state as *mut _).ok_or_else(Error::new)?;
             Ok(KeyboardState { ptr, numkeys })
         }
     }
+
+    /// Returns true if a keyboard is connected.
+    pub fn has_keyboard(&self) -> bool {
+        unsafe { sys::SDL_HasKeyboard() }
+    }
+
+    /// Returns the current modifier state of the keyboard.
+    pub fn mod_state(&self) -> Keymod {
+        Keymod(unsafe { sys::SDL_GetModState() })
+    }
+
+    /// Overrides the modifier state of the keyboard.
+    ///
+    /// This doesn't change the state of the physical keys, only what SDL reports.
+    pub fn set_mod_state(&self, modstate: Keymod) {
+        unsafe { sys::SDL_SetModState(modstate.0) };
+    }
+
+    /// Clears the state of the keyboard, generating key up events for every pressed key.
+    pub fn reset_keyboard(&self) {
+        unsafe { sys::SDL_ResetKeyboard() };
+    }
+
+    /// Returns true if the platform has a screen keyboard.
+    pub fn has_screen_keyboard_support(&self) -> bool {
+        unsafe { sys::SDL_HasScreenKeyboardSupport() }
+    }
+}
+
+impl WindowRef {
+    /// Starts accepting Unicode text input events in the window.
+    ///
+    /// Text input events are not delivered by default. This may also show a screen keyboard or
+    /// enable an IME, so it should only be enabled while the user is editing text.
+    pub fn start_text_input(&mut self) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_StartTextInput(self.as_ptr() as *mut _) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Stops receiving text input events in the window.
+    pub fn stop_text_input(&mut self) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_StopTextInput(self.as_ptr() as *mut _) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns true if text input events are enabled for the window.
+    pub fn is_text_input_active(&self) -> bool {
+        unsafe { sys::SDL_TextInputActive(self.as_ptr() as *mut _) }
+    }
+
+    /// Sets the area used to type Unicode text, so an IME can place its candidate list nearby.
+    ///
+    /// `cursor` is the offset of the text cursor relative to `area.x()`, in window coordinates.
+    /// Pass `None` to clear the area.
+    pub fn set_text_input_area(&mut self, area: Option<Rect>, cursor: i32) -> Result<(), Error> {
+        let area = area.as_ref().map(Rect::as_raw).unwrap_or(core::ptr::null());
+        let result = unsafe { sys::SDL_SetTextInputArea(self.as_ptr() as *mut _, area, cursor) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns the area used to type Unicode text and the offset of the text cursor within it.
+    ///
+    /// The area is `None` if it was never set or has been cleared.
+    pub fn text_input_area(&self) -> Result<(Option<Rect>, i32), Error> {
+        let mut area = sys::SDL_Rect {
+            x: 0,
+            y: 0,
+            w: 0,
+            h: 0,
+        };
+        let mut cursor = 0;
+        let result = unsafe {
+            sys::SDL_GetTextInputArea(self.as_ptr() as *mut _, &raw mut area, &raw mut cursor)
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        let area = (area.w > 0 && area.h > 0).then(|| Rect::from_ll(area));
+        Ok((area, cursor))
+    }
+
+    /// Returns true if the screen keyboard is shown for the window.
+    pub fn is_screen_keyboard_shown(&self) -> bool {
+        unsafe { sys::SDL_ScreenKeyboardShown(self.as_ptr() as *mut _) }
+    }
 }
 
 /// A view into the current state of the keyboard.
@@ -79,6 +175,67 @@ impl KeyboardState {
         };
         unsafe { *self.ptr.as_ptr().offset(offset) }
     }
+
+    /// Copies the current state of every key, which this `KeyboardState` otherwise keeps reading
+    /// from SDL as events are pumped.
+    pub fn to_owned_snapshot(&self) -> KeyboardSnapshot {
+        let mut snapshot = KeyboardSnapshot::default();
+        let numkeys = self.numkeys.min(KeyboardSnapshot::KEYS);
+        // SAFETY: SDL's array holds `numkeys` entries and lives as long as the events subsystem.
+        let keys = unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), numkeys) };
+        for (index, &pressed) in keys.iter().enumerate() {
+            if pressed {
+                snapshot.bits[index / 64] |= 1 << (index % 64);
+            }
+        }
+        snapshot
+    }
+}
+
+/// An owned copy of the keyboard state, created by [`KeyboardState::to_owned_snapshot`].
+///
+/// Snapshots are small and `Copy`, so they can be stored every frame for input recording or
+/// rollback.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct KeyboardSnapshot {
+    bits: [u64; KeyboardSnapshot::KEYS / 64],
+}
+
+impl KeyboardSnapshot {
+    const KEYS: usize = sys::SDL_Scancode_SDL_SCANCODE_COUNT as usize;
+
+    /// Returns true if the key was pressed when the snapshot was taken.
+    #[inline]
+    pub fn get(&self, scancode: Scancode) -> bool {
+        let index = scancode.as_index();
+        index < Self::KEYS && self.bits[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Changes the state of a key, e.g. to synthesize input during replays.
+    #[inline]
+    pub fn set(&mut self, scancode: Scancode, pressed: bool) {
+        let index = scancode.as_index();
+        if index >= Self::KEYS {
+            return;
+        }
+        if pressed {
+            self.bits[index / 64] |= 1 << (index % 64);
+        } else {
+            self.bits[index / 64] &= !(1 << (index % 64));
+        }
+    }
+
+    /// Returns true if the key is pressed in this snapshot but not in `previous`.
+    #[inline]
+    pub fn just_pressed(&self, previous: &KeyboardSnapshot, scancode: Scancode) -> bool {
+        self.get(scancode) && !previous.get(scancode)
+    }
+
+    /// Returns true if no key was pressed.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&bits| bits == 0)
+    }
 }
 
 /// Holds the current set of available keyboards.
@@ -488,9 +645,687 @@ pub enum Scancode {
 }
 
 impl Scancode {
+    /// Converts a raw `SDL_Scancode` into a `Scancode`.
+    pub fn try_from_ll(ll: sys::SDL_Scancode) -> Result<Self, Error> {
+        match Self::from_ll(ll) {
+            Self::Unknown if ll != sys::SDL_Scancode_SDL_SCANCODE_UNKNOWN => {
+                Err(Error::register(c"Unknown scancode."))
+            }
+            scancode => Ok(scancode),
+        }
+    }
+
     /// Converts the scancode into an index that can be used to access a key's current state.
     #[inline]
     pub fn as_index(&self) -> usize {
         *self as u32 as usize
     }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_Scancode {
+        *self as u32
+    }
+
+    /// Returns a human-readable name for the scancode, or an empty string if it has no name.
+    ///
+    /// The name is the same on every keyboard layout; use [`Keycode::name`] to show the key's
+    /// label to users.
+    pub fn name(&self) -> String {
+        let ptr = unsafe { sys::SDL_GetScancodeName(self.to_ll()) };
+        if ptr.is_null() {
+            return String::new();
+        }
+        unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Looks up a scancode from its name, returning [`Scancode::Unknown`] if there is no match.
+    pub fn from_name(name: &str) -> Self {
+        crate::with_cstr(name, |name| {
+            Self::from_ll(unsafe { sys::SDL_GetScancodeFromName(name.as_ptr()) })
+        })
+        .unwrap_or(Self::Unknown)
+    }
+
+    /// Returns the keycode produced by this scancode in the current keyboard layout.
+    ///
+    /// `modstate` selects the shifted variant of the key, if any. If `key_event` is true, the
+    /// keycode is the one reported by key events; otherwise it's the one used for text, which
+    /// may differ for keys like the numpad.
+    pub fn to_keycode(&self, modstate: Keymod, key_event: bool) -> Keycode {
+        Keycode(unsafe { sys::SDL_GetKeyFromScancode(self.to_ll(), modstate.0, key_event) })
+    }
+
+    /// Converts a raw scancode, mapping values without a variant to [`Scancode::Unknown`].
+    pub(crate) fn from_ll(ll: sys::SDL_Scancode) -> Self {
+        match ll {
+            sys::SDL_Scancode_SDL_SCANCODE_A => Self::A,
+            sys::SDL_Scancode_SDL_SCANCODE_B => Self::B,
+            sys::SDL_Scancode_SDL_SCANCODE_C => Self::C,
+            sys::SDL_Scancode_SDL_SCANCODE_D => Self::D,
+            sys::SDL_Scancode_SDL_SCANCODE_E => Self::E,
+            sys::SDL_Scancode_SDL_SCANCODE_F => Self::F,
+            sys::SDL_Scancode_SDL_SCANCODE_G => Self::G,
+            sys::SDL_Scancode_SDL_SCANCODE_H => Self::H,
+            sys::SDL_Scancode_SDL_SCANCODE_I => Self::I,
+            sys::SDL_Scancode_SDL_SCANCODE_J => Self::J,
+            sys::SDL_Scancode_SDL_SCANCODE_K => Self::K,
+            sys::SDL_Scancode_SDL_SCANCODE_L => Self::L,
+            sys::SDL_Scancode_SDL_SCANCODE_M => Self::M,
+            sys::SDL_Scancode_SDL_SCANCODE_N => Self::N,
+            sys::SDL_Scancode_SDL_SCANCODE_O => Self::O,
+            sys::SDL_Scancode_SDL_SCANCODE_P => Self::P,
+            sys::SDL_Scancode_SDL_SCANCODE_Q => Self::Q,
+            sys::SDL_Scancode_SDL_SCANCODE_R => Self::R,
+            sys::SDL_Scancode_SDL_SCANCODE_S => Self::S,
+            sys::SDL_Scancode_SDL_SCANCODE_T => Self::T,
+            sys::SDL_Scancode_SDL_SCANCODE_U => Self::U,
+            sys::SDL_Scancode_SDL_SCANCODE_V => Self::V,
+            sys::SDL_Scancode_SDL_SCANCODE_W => Self::W,
+            sys::SDL_Scancode_SDL_SCANCODE_X => Self::X,
+            sys::SDL_Scancode_SDL_SCANCODE_Y => Self::Y,
+            sys::SDL_Scancode_SDL_SCANCODE_Z => Self::Z,
+            sys::SDL_Scancode_SDL_SCANCODE_1 => Self::Num1,
+            sys::SDL_Scancode_SDL_SCANCODE_2 => Self::Num2,
+            sys::SDL_Scancode_SDL_SCANCODE_3 => Self::Num3,
+            sys::SDL_Scancode_SDL_SCANCODE_4 => Self::Num4,
+            sys::SDL_Scancode_SDL_SCANCODE_5 => Self::Num5,
+            sys::SDL_Scancode_SDL_SCANCODE_6 => Self::Num6,
+            sys::SDL_Scancode_SDL_SCANCODE_7 => Self::Num7,
+            sys::SDL_Scancode_SDL_SCANCODE_8 => Self::Num8,
+            sys::SDL_Scancode_SDL_SCANCODE_9 => Self::Num9,
+            sys::SDL_Scancode_SDL_SCANCODE_0 => Self::Num0,
+            sys::SDL_Scancode_SDL_SCANCODE_RETURN => Self::Return,
+            sys::SDL_Scancode_SDL_SCANCODE_ESCAPE => Self::Escape,
+            sys::SDL_Scancode_SDL_SCANCODE_BACKSPACE => Self::Backspace,
+            sys::SDL_Scancode_SDL_SCANCODE_TAB => Self::Tab,
+            sys::SDL_Scancode_SDL_SCANCODE_SPACE => Self::Space,
+            sys::SDL_Scancode_SDL_SCANCODE_MINUS => Self::Minus,
+            sys::SDL_Scancode_SDL_SCANCODE_EQUALS => Self::Equals,
+            sys::SDL_Scancode_SDL_SCANCODE_LEFTBRACKET => Self::LeftBracket,
+            sys::SDL_Scancode_SDL_SCANCODE_RIGHTBRACKET => Self::RightBracket,
+            sys::SDL_Scancode_SDL_SCANCODE_BACKSLASH => Self::Backslash,
+            sys::SDL_Scancode_SDL_SCANCODE_NONUSHASH => Self::NonUSHash,
+            sys::SDL_Scancode_SDL_SCANCODE_SEMICOLON => Self::Semicolon,
+            sys::SDL_Scancode_SDL_SCANCODE_APOSTROPHE => Self::Apostrophe,
+            sys::SDL_Scancode_SDL_SCANCODE_GRAVE => Self::Grave,
+            sys::SDL_Scancode_SDL_SCANCODE_COMMA => Self::Comma,
+            sys::SDL_Scancode_SDL_SCANCODE_PERIOD => Self::Period,
+            sys::SDL_Scancode_SDL_SCANCODE_SLASH => Self::Slash,
+            sys::SDL_Scancode_SDL_SCANCODE_CAPSLOCK => Self::CapsLock,
+            sys::SDL_Scancode_SDL_SCANCODE_F1 => Self::F1,
+            sys::SDL_Scancode_SDL_SCANCODE_F2 => Self::F2,
+            sys::SDL_Scancode_SDL_SCANCODE_F3 => Self::F3,
+            sys::SDL_Scancode_SDL_SCANCODE_F4 => Self::F4,
+            sys::SDL_Scancode_SDL_SCANCODE_F5 => Self::F5,
+            sys::SDL_Scancode_SDL_SCANCODE_F6 => Self::F6,
+            sys::SDL_Scancode_SDL_SCANCODE_F7 => Self::F7,
+            sys::SDL_Scancode_SDL_SCANCODE_F8 => Self::F8,
+            sys::SDL_Scancode_SDL_SCANCODE_F9 => Self::F9,
+            sys::SDL_Scancode_SDL_SCANCODE_F10 => Self::F10,
+            sys::SDL_Scancode_SDL_SCANCODE_F11 => Self::F11,
+            sys::SDL_Scancode_SDL_SCANCODE_F12 => Self::F12,
+            sys::SDL_Scancode_SDL_SCANCODE_PRINTSCREEN => Self::PrintScreen,
+            sys::SDL_Scancode_SDL_SCANCODE_SCROLLLOCK => Self::ScrollLock,
+            sys::SDL_Scancode_SDL_SCANCODE_PAUSE => Self::Pause,
+            sys::SDL_Scancode_SDL_SCANCODE_INSERT => Self::Insert,
+            sys::SDL_Scancode_SDL_SCANCODE_HOME => Self::Home,
+            sys::SDL_Scancode_SDL_SCANCODE_PAGEUP => Self::PageUp,
+            sys::SDL_Scancode_SDL_SCANCODE_DELETE => Self::Delete,
+            sys::SDL_Scancode_SDL_SCANCODE_END => Self::End,
+            sys::SDL_Scancode_SDL_SCANCODE_PAGEDOWN => Self::PageDown,
+            sys::SDL_Scancode_SDL_SCANCODE_RIGHT => Self::Right,
+            sys::SDL_Scancode_SDL_SCANCODE_LEFT => Self::Left,
+            sys::SDL_Scancode_SDL_SCANCODE_DOWN => Self::Down,
+            sys::SDL_Scancode_SDL_SCANCODE_UP => Self::Up,
+            sys::SDL_Scancode_SDL_SCANCODE_NUMLOCKCLEAR => Self::NumLockClear,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_DIVIDE => Self::KpDivide,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_MULTIPLY => Self::KpMultiply,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_MINUS => Self::KpMinus,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_PLUS => Self::KpPlus,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_ENTER => Self::KpEnter,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_1 => Self::Kp1,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_2 => Self::Kp2,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_3 => Self::Kp3,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_4 => Self::Kp4,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_5 => Self::Kp5,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_6 => Self::Kp6,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_7 => Self::Kp7,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_8 => Self::Kp8,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_9 => Self::Kp9,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_0 => Self::Kp0,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_PERIOD => Self::KpPeriod,
+            sys::SDL_Scancode_SDL_SCANCODE_NONUSBACKSLASH => Self::NonUSBackslash,
+            sys::SDL_Scancode_SDL_SCANCODE_APPLICATION => Self::Application,
+            sys::SDL_Scancode_SDL_SCANCODE_POWER => Self::Power,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_EQUALS => Self::KpEquals,
+            sys::SDL_Scancode_SDL_SCANCODE_F13 => Self::F13,
+            sys::SDL_Scancode_SDL_SCANCODE_F14 => Self::F14,
+            sys::SDL_Scancode_SDL_SCANCODE_F15 => Self::F15,
+            sys::SDL_Scancode_SDL_SCANCODE_F16 => Self::F16,
+            sys::SDL_Scancode_SDL_SCANCODE_F17 => Self::F17,
+            sys::SDL_Scancode_SDL_SCANCODE_F18 => Self::F18,
+            sys::SDL_Scancode_SDL_SCANCODE_F19 => Self::F19,
+            sys::SDL_Scancode_SDL_SCANCODE_F20 => Self::F20,
+            sys::SDL_Scancode_SDL_SCANCODE_F21 => Self::F21,
+            sys::SDL_Scancode_SDL_SCANCODE_F22 => Self::F22,
+            sys::SDL_Scancode_SDL_SCANCODE_F23 => Self::F23,
+            sys::SDL_Scancode_SDL_SCANCODE_F24 => Self::F24,
+            sys::SDL_Scancode_SDL_SCANCODE_EXECUTE => Self::Execute,
+            sys::SDL_Scancode_SDL_SCANCODE_HELP => Self::Help,
+            sys::SDL_Scancode_SDL_SCANCODE_MENU => Self::Menu,
+            sys::SDL_Scancode_SDL_SCANCODE_SELECT => Self::Select,
+            sys::SDL_Scancode_SDL_SCANCODE_STOP => Self::Stop,
+            sys::SDL_Scancode_SDL_SCANCODE_AGAIN => Self::Again,
+            sys::SDL_Scancode_SDL_SCANCODE_UNDO => Self::Undo,
+            sys::SDL_Scancode_SDL_SCANCODE_CUT => Self::Cut,
+            sys::SDL_Scancode_SDL_SCANCODE_COPY => Self::Copy,
+            sys::SDL_Scancode_SDL_SCANCODE_PASTE => Self::Paste,
+            sys::SDL_Scancode_SDL_SCANCODE_FIND => Self::Find,
+            sys::SDL_Scancode_SDL_SCANCODE_MUTE => Self::Mute,
+            sys::SDL_Scancode_SDL_SCANCODE_VOLUMEUP => Self::VolumeUp,
+            sys::SDL_Scancode_SDL_SCANCODE_VOLUMEDOWN => Self::VolumeDown,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_COMMA => Self::KpComma,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_EQUALSAS400 => Self::KpEqualsAs400,
+            sys::SDL_Scancode_SDL_SCANCODE_INTERNATIONAL1 => Self::International1,
+            sys::SDL_Scancode_SDL_SCANCODE_INTERNATIONAL2 => Self::International2,
+            sys::SDL_Scancode_SDL_SCANCODE_INTERNATIONAL3 => Self::International3,
+            sys::SDL_Scancode_SDL_SCANCODE_INTERNATIONAL4 => Self::International4,
+            sys::SDL_Scancode_SDL_SCANCODE_INTERNATIONAL5 => Self::International5,
+            sys::SDL_Scancode_SDL_SCANCODE_INTERNATIONAL6 => Self::International6,
+            sys::SDL_Scancode_SDL_SCANCODE_INTERNATIONAL7 => Self::International7,
+            sys::SDL_Scancode_SDL_SCANCODE_INTERNATIONAL8 => Self::International8,
+            sys::SDL_Scancode_SDL_SCANCODE_INTERNATIONAL9 => Self::International9,
+            sys::SDL_Scancode_SDL_SCANCODE_LANG1 => Self::Lang1,
+            sys::SDL_Scancode_SDL_SCANCODE_LANG2 => Self::Lang2,
+            sys::SDL_Scancode_SDL_SCANCODE_LANG3 => Self::Lang3,
+            sys::SDL_Scancode_SDL_SCANCODE_LANG4 => Self::Lang4,
+            sys::SDL_Scancode_SDL_SCANCODE_LANG5 => Self::Lang5,
+            sys::SDL_Scancode_SDL_SCANCODE_LANG6 => Self::Lang6,
+            sys::SDL_Scancode_SDL_SCANCODE_LANG7 => Self::Lang7,
+            sys::SDL_Scancode_SDL_SCANCODE_LANG8 => Self::Lang8,
+            sys::SDL_Scancode_SDL_SCANCODE_LANG9 => Self::Lang9,
+            sys::SDL_Scancode_SDL_SCANCODE_ALTERASE => Self::AltErase,
+            sys::SDL_Scancode_SDL_SCANCODE_SYSREQ => Self::SysReq,
+            sys::SDL_Scancode_SDL_SCANCODE_CANCEL => Self::Cancel,
+            sys::SDL_Scancode_SDL_SCANCODE_CLEAR => Self::Clear,
+            sys::SDL_Scancode_SDL_SCANCODE_PRIOR => Self::Prior,
+            sys::SDL_Scancode_SDL_SCANCODE_RETURN2 => Self::Return2,
+            sys::SDL_Scancode_SDL_SCANCODE_SEPARATOR => Self::Separator,
+            sys::SDL_Scancode_SDL_SCANCODE_OUT => Self::Out,
+            sys::SDL_Scancode_SDL_SCANCODE_OPER => Self::Oper,
+            sys::SDL_Scancode_SDL_SCANCODE_CLEARAGAIN => Self::ClearAgain,
+            sys::SDL_Scancode_SDL_SCANCODE_CRSEL => Self::CrSel,
+            sys::SDL_Scancode_SDL_SCANCODE_EXSEL => Self::ExSel,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_00 => Self::Kp00,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_000 => Self::Kp000,
+            sys::SDL_Scancode_SDL_SCANCODE_THOUSANDSSEPARATOR => Self::ThousandsSeparator,
+            sys::SDL_Scancode_SDL_SCANCODE_DECIMALSEPARATOR => Self::DecimalSeparator,
+            sys::SDL_Scancode_SDL_SCANCODE_CURRENCYUNIT => Self::CurrencyUnit,
+            sys::SDL_Scancode_SDL_SCANCODE_CURRENCYSUBUNIT => Self::CurrencySubUnit,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_LEFTPAREN => Self::KpLeftParen,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_RIGHTPAREN => Self::KpRightParen,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_LEFTBRACE => Self::KpLeftBrace,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_RIGHTBRACE => Self::KpRightBrace,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_TAB => Self::KpTab,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_BACKSPACE => Self::KpBackspace,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_A => Self::KpA,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_B => Self::KpB,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_C => Self::KpC,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_D => Self::KpD,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_E => Self::KpE,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_F => Self::KpF,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_XOR => Self::KpXor,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_POWER => Self::KpPower,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_PERCENT => Self::KpPercent,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_LESS => Self::KpLess,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_GREATER => Self::KpGreater,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_AMPERSAND => Self::KpAmpersand,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_DBLAMPERSAND => Self::KpDblAmpersand,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_VERTICALBAR => Self::KpVerticalBar,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_DBLVERTICALBAR => Self::KpDblVerticalBar,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_COLON => Self::KpColon,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_HASH => Self::KpHash,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_SPACE => Self::KpSpace,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_AT => Self::KpAt,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_EXCLAM => Self::KpExclam,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_MEMSTORE => Self::KpMemStore,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_MEMRECALL => Self::KpMemRecall,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_MEMCLEAR => Self::KpMemClear,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_MEMADD => Self::KpMemAdd,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_MEMSUBTRACT => Self::KpMemSubtract,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_MEMMULTIPLY => Self::KpMemMultiply,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_MEMDIVIDE => Self::KpMemDivide,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_PLUSMINUS => Self::KpPlusMinus,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_CLEAR => Self::KpClear,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_CLEARENTRY => Self::KpClearEntry,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_BINARY => Self::KpBinary,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_OCTAL => Self::KpOctal,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_DECIMAL => Self::KpDecimal,
+            sys::SDL_Scancode_SDL_SCANCODE_KP_HEXADECIMAL => Self::KpHexadecimal,
+            sys::SDL_Scancode_SDL_SCANCODE_LCTRL => Self::LCtrl,
+            sys::SDL_Scancode_SDL_SCANCODE_LSHIFT => Self::LShift,
+            sys::SDL_Scancode_SDL_SCANCODE_LALT => Self::LAlt,
+            sys::SDL_Scancode_SDL_SCANCODE_LGUI => Self::LGui,
+            sys::SDL_Scancode_SDL_SCANCODE_RCTRL => Self::RCtrl,
+            sys::SDL_Scancode_SDL_SCANCODE_RSHIFT => Self::RShift,
+            sys::SDL_Scancode_SDL_SCANCODE_RALT => Self::RAlt,
+            sys::SDL_Scancode_SDL_SCANCODE_RGUI => Self::RGui,
+            sys::SDL_Scancode_SDL_SCANCODE_MODE => Self::Mode,
+            sys::SDL_Scancode_SDL_SCANCODE_SLEEP => Self::Sleep,
+            sys::SDL_Scancode_SDL_SCANCODE_WAKE => Self::Wake,
+            sys::SDL_Scancode_SDL_SCANCODE_CHANNEL_INCREMENT => Self::ChannelIncrement,
+            sys::SDL_Scancode_SDL_SCANCODE_CHANNEL_DECREMENT => Self::ChannelDecrement,
+            sys::SDL_Scancode_SDL_SCANCODE_MEDIA_PLAY => Self::MediaPlay,
+            sys::SDL_Scancode_SDL_SCANCODE_MEDIA_PAUSE => Self::MediaPause,
+            sys::SDL_Scancode_SDL_SCANCODE_MEDIA_RECORD => Self::MediaRecord,
+            sys::SDL_Scancode_SDL_SCANCODE_MEDIA_FAST_FORWARD => Self::MediaFastForward,
+            sys::SDL_Scancode_SDL_SCANCODE_MEDIA_REWIND => Self::MediaRewind,
+            sys::SDL_Scancode_SDL_SCANCODE_MEDIA_NEXT_TRACK => Self::MediaNextTrack,
+            sys::SDL_Scancode_SDL_SCANCODE_MEDIA_PREVIOUS_TRACK => Self::MediaPreviousTrack,
+            sys::SDL_Scancode_SDL_SCANCODE_MEDIA_STOP => Self::MediaStop,
+            sys::SDL_Scancode_SDL_SCANCODE_MEDIA_EJECT => Self::MediaEject,
+            sys::SDL_Scancode_SDL_SCANCODE_MEDIA_PLAY_PAUSE => Self::MediaPlayPause,
+            sys::SDL_Scancode_SDL_SCANCODE_MEDIA_SELECT => Self::MediaSelect,
+            sys::SDL_Scancode_SDL_SCANCODE_AC_NEW => Self::AcNew,
+            sys::SDL_Scancode_SDL_SCANCODE_AC_OPEN => Self::AcOpen,
+            sys::SDL_Scancode_SDL_SCANCODE_AC_CLOSE => Self::AcClose,
+            sys::SDL_Scancode_SDL_SCANCODE_AC_EXIT => Self::AcExit,
+            sys::SDL_Scancode_SDL_SCANCODE_AC_SAVE => Self::AcSave,
+            sys::SDL_Scancode_SDL_SCANCODE_AC_PRINT => Self::AcPrint,
+            sys::SDL_Scancode_SDL_SCANCODE_AC_PROPERTIES => Self::AcProperties,
+            sys::SDL_Scancode_SDL_SCANCODE_AC_SEARCH => Self::AcSearch,
+            sys::SDL_Scancode_SDL_SCANCODE_AC_HOME => Self::AcHome,
+            sys::SDL_Scancode_SDL_SCANCODE_AC_BACK => Self::AcBack,
+            sys::SDL_Scancode_SDL_SCANCODE_AC_FORWARD => Self::AcForward,
+            sys::SDL_Scancode_SDL_SCANCODE_AC_STOP => Self::AcStop,
+            sys::SDL_Scancode_SDL_SCANCODE_AC_REFRESH => Self::AcRefresh,
+            sys::SDL_Scancode_SDL_SCANCODE_AC_BOOKMARKS => Self::AcBookmarks,
+            sys::SDL_Scancode_SDL_SCANCODE_SOFTLEFT => Self::SoftLeft,
+            sys::SDL_Scancode_SDL_SCANCODE_SOFTRIGHT => Self::SoftRight,
+            sys::SDL_Scancode_SDL_SCANCODE_CALL => Self::Call,
+            sys::SDL_Scancode_SDL_SCANCODE_ENDCALL => Self::EndCall,
+            sys::SDL_Scancode_SDL_SCANCODE_RESERVED => Self::Reserved,
+            sys::SDL_Scancode_SDL_SCANCODE_COUNT => Self::Count,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// A virtual key, as laid out by the current keyboard layout.
+///
+/// Unlike [`Scancode`], which identifies a physical key position, a keycode identifies what the
+/// key means: the key labeled "Q" on an AZERTY keyboard produces [`Keycode::A`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Keycode(sys::SDL_Keycode);
+
+impl Keycode {
+    pub const UNKNOWN: Self = Self(sys::SDLK_UNKNOWN);
+    pub const RETURN: Self = Self(sys::SDLK_RETURN);
+    pub const ESCAPE: Self = Self(sys::SDLK_ESCAPE);
+    pub const BACKSPACE: Self = Self(sys::SDLK_BACKSPACE);
+    pub const TAB: Self = Self(sys::SDLK_TAB);
+    pub const SPACE: Self = Self(sys::SDLK_SPACE);
+    pub const EXCLAIM: Self = Self(sys::SDLK_EXCLAIM);
+    pub const DBLAPOSTROPHE: Self = Self(sys::SDLK_DBLAPOSTROPHE);
+    pub const HASH: Self = Self(sys::SDLK_HASH);
+    pub const DOLLAR: Self = Self(sys::SDLK_DOLLAR);
+    pub const PERCENT: Self = Self(sys::SDLK_PERCENT);
+    pub const AMPERSAND: Self = Self(sys::SDLK_AMPERSAND);
+    pub const APOSTROPHE: Self = Self(sys::SDLK_APOSTROPHE);
+    pub const LEFTPAREN: Self = Self(sys::SDLK_LEFTPAREN);
+    pub const RIGHTPAREN: Self = Self(sys::SDLK_RIGHTPAREN);
+    pub const ASTERISK: Self = Self(sys::SDLK_ASTERISK);
+    pub const PLUS: Self = Self(sys::SDLK_PLUS);
+    pub const COMMA: Self = Self(sys::SDLK_COMMA);
+    pub const MINUS: Self = Self(sys::SDLK_MINUS);
+    pub const PERIOD: Self = Self(sys::SDLK_PERIOD);
+    pub const SLASH: Self = Self(sys::SDLK_SLASH);
+    pub const NUM_0: Self = Self(sys::SDLK_0);
+    pub const NUM_1: Self = Self(sys::SDLK_1);
+    pub const NUM_2: Self = Self(sys::SDLK_2);
+    pub const NUM_3: Self = Self(sys::SDLK_3);
+    pub const NUM_4: Self = Self(sys::SDLK_4);
+    pub const NUM_5: Self = Self(sys::SDLK_5);
+    pub const NUM_6: Self = Self(sys::SDLK_6);
+    pub const NUM_7: Self = Self(sys::SDLK_7);
+    pub const NUM_8: Self = Self(sys::SDLK_8);
+    pub const NUM_9: Self = Self(sys::SDLK_9);
+    pub const COLON: Self = Self(sys::SDLK_COLON);
+    pub const SEMICOLON: Self = Self(sys::SDLK_SEMICOLON);
+    pub const LESS: Self = Self(sys::SDLK_LESS);
+    pub const EQUALS: Self = Self(sys::SDLK_EQUALS);
+    pub const GREATER: Self = Self(sys::SDLK_GREATER);
+    pub const QUESTION: Self = Self(sys::SDLK_QUESTION);
+    pub const AT: Self = Self(sys::SDLK_AT);
+    pub const LEFTBRACKET: Self = Self(sys::SDLK_LEFTBRACKET);
+    pub const BACKSLASH: Self = Self(sys::SDLK_BACKSLASH);
+    pub const RIGHTBRACKET: Self = Self(sys::SDLK_RIGHTBRACKET);
+    pub const CARET: Self = Self(sys::SDLK_CARET);
+    pub const UNDERSCORE: Self = Self(sys::SDLK_UNDERSCORE);
+    pub const GRAVE: Self = Self(sys::SDLK_GRAVE);
+    pub const A: Self = Self(sys::SDLK_A);
+    pub const B: Self = Self(sys::SDLK_B);
+    pub const C: Self = Self(sys::SDLK_C);
+    pub const D: Self = Self(sys::SDLK_D);
+    pub const E: Self = Self(sys::SDLK_E);
+    pub const F: Self = Self(sys::SDLK_F);
+    pub const G: Self = Self(sys::SDLK_G);
+    pub const H: Self = Self(sys::SDLK_H);
+    pub const I: Self = Self(sys::SDLK_I);
+    pub const J: Self = Self(sys::SDLK_J);
+    pub const K: Self = Self(sys::SDLK_K);
+    pub const L: Self = Self(sys::SDLK_L);
+    pub const M: Self = Self(sys::SDLK_M);
+    pub const N: Self = Self(sys::SDLK_N);
+    pub const O: Self = Self(sys::SDLK_O);
+    pub const P: Self = Self(sys::SDLK_P);
+    pub const Q: Self = Self(sys::SDLK_Q);
+    pub const R: Self = Self(sys::SDLK_R);
+    pub const S: Self = Self(sys::SDLK_S);
+    pub const T: Self = Self(sys::SDLK_T);
+    pub const U: Self = Self(sys::SDLK_U);
+    pub const V: Self = Self(sys::SDLK_V);
+    pub const W: Self = Self(sys::SDLK_W);
+    pub const X: Self = Self(sys::SDLK_X);
+    pub const Y: Self = Self(sys::SDLK_Y);
+    pub const Z: Self = Self(sys::SDLK_Z);
+    pub const LEFTBRACE: Self = Self(sys::SDLK_LEFTBRACE);
+    pub const PIPE: Self = Self(sys::SDLK_PIPE);
+    pub const RIGHTBRACE: Self = Self(sys::SDLK_RIGHTBRACE);
+    pub const TILDE: Self = Self(sys::SDLK_TILDE);
+    pub const DELETE: Self = Self(sys::SDLK_DELETE);
+    pub const PLUSMINUS: Self = Self(sys::SDLK_PLUSMINUS);
+    pub const CAPSLOCK: Self = Self(sys::SDLK_CAPSLOCK);
+    pub const F1: Self = Self(sys::SDLK_F1);
+    pub const F2: Self = Self(sys::SDLK_F2);
+    pub const F3: Self = Self(sys::SDLK_F3);
+    pub const F4: Self = Self(sys::SDLK_F4);
+    pub const F5: Self = Self(sys::SDLK_F5);
+    pub const F6: Self = Self(sys::SDLK_F6);
+    pub const F7: Self = Self(sys::SDLK_F7);
+    pub const F8: Self = Self(sys::SDLK_F8);
+    pub const F9: Self = Self(sys::SDLK_F9);
+    pub const F10: Self = Self(sys::SDLK_F10);
+    pub const F11: Self = Self(sys::SDLK_F11);
+    pub const F12: Self = Self(sys::SDLK_F12);
+    pub const PRINTSCREEN: Self = Self(sys::SDLK_PRINTSCREEN);
+    pub const SCROLLLOCK: Self = Self(sys::SDLK_SCROLLLOCK);
+    pub const PAUSE: Self = Self(sys::SDLK_PAUSE);
+    pub const INSERT: Self = Self(sys::SDLK_INSERT);
+    pub const HOME: Self = Self(sys::SDLK_HOME);
+    pub const PAGEUP: Self = Self(sys::SDLK_PAGEUP);
+    pub const END: Self = Self(sys::SDLK_END);
+    pub const PAGEDOWN: Self = Self(sys::SDLK_PAGEDOWN);
+    pub const RIGHT: Self = Self(sys::SDLK_RIGHT);
+    pub const LEFT: Self = Self(sys::SDLK_LEFT);
+    pub const DOWN: Self = Self(sys::SDLK_DOWN);
+    pub const UP: Self = Self(sys::SDLK_UP);
+    pub const NUMLOCKCLEAR: Self = Self(sys::SDLK_NUMLOCKCLEAR);
+    pub const KP_DIVIDE: Self = Self(sys::SDLK_KP_DIVIDE);
+    pub const KP_MULTIPLY: Self = Self(sys::SDLK_KP_MULTIPLY);
+    pub const KP_MINUS: Self = Self(sys::SDLK_KP_MINUS);
+    pub const KP_PLUS: Self = Self(sys::SDLK_KP_PLUS);
+    pub const KP_ENTER: Self = Self(sys::SDLK_KP_ENTER);
+    pub const KP_1: Self = Self(sys::SDLK_KP_1);
+    pub const KP_2: Self = Self(sys::SDLK_KP_2);
+    pub const KP_3: Self = Self(sys::SDLK_KP_3);
+    pub const KP_4: Self = Self(sys::SDLK_KP_4);
+    pub const KP_5: Self = Self(sys::SDLK_KP_5);
+    pub const KP_6: Self = Self(sys::SDLK_KP_6);
+    pub const KP_7: Self = Self(sys::SDLK_KP_7);
+    pub const KP_8: Self = Self(sys::SDLK_KP_8);
+    pub const KP_9: Self = Self(sys::SDLK_KP_9);
+    pub const KP_0: Self = Self(sys::SDLK_KP_0);
+    pub const KP_PERIOD: Self = Self(sys::SDLK_KP_PERIOD);
+    pub const APPLICATION: Self = Self(sys::SDLK_APPLICATION);
+    pub const POWER: Self = Self(sys::SDLK_POWER);
+    pub const KP_EQUALS: Self = Self(sys::SDLK_KP_EQUALS);
+    pub const F13: Self = Self(sys::SDLK_F13);
+    pub const F14: Self = Self(sys::SDLK_F14);
+    pub const F15: Self = Self(sys::SDLK_F15);
+    pub const F16: Self = Self(sys::SDLK_F16);
+    pub const F17: Self = Self(sys::SDLK_F17);
+    pub const F18: Self = Self(sys::SDLK_F18);
+    pub const F19: Self = Self(sys::SDLK_F19);
+    pub const F20: Self = Self(sys::SDLK_F20);
+    pub const F21: Self = Self(sys::SDLK_F21);
+    pub const F22: Self = Self(sys::SDLK_F22);
+    pub const F23: Self = Self(sys::SDLK_F23);
+    pub const F24: Self = Self(sys::SDLK_F24);
+    pub const EXECUTE: Self = Self(sys::SDLK_EXECUTE);
+    pub const HELP: Self = Self(sys::SDLK_HELP);
+    pub const MENU: Self = Self(sys::SDLK_MENU);
+    pub const SELECT: Self = Self(sys::SDLK_SELECT);
+    pub const STOP: Self = Self(sys::SDLK_STOP);
+    pub const AGAIN: Self = Self(sys::SDLK_AGAIN);
+    pub const UNDO: Self = Self(sys::SDLK_UNDO);
+    pub const CUT: Self = Self(sys::SDLK_CUT);
+    pub const COPY: Self = Self(sys::SDLK_COPY);
+    pub const PASTE: Self = Self(sys::SDLK_PASTE);
+    pub const FIND: Self = Self(sys::SDLK_FIND);
+    pub const MUTE: Self = Self(sys::SDLK_MUTE);
+    pub const VOLUMEUP: Self = Self(sys::SDLK_VOLUMEUP);
+    pub const VOLUMEDOWN: Self = Self(sys::SDLK_VOLUMEDOWN);
+    pub const KP_COMMA: Self = Self(sys::SDLK_KP_COMMA);
+    pub const KP_EQUALSAS400: Self = Self(sys::SDLK_KP_EQUALSAS400);
+    pub const ALTERASE: Self = Self(sys::SDLK_ALTERASE);
+    pub const SYSREQ: Self = Self(sys::SDLK_SYSREQ);
+    pub const CANCEL: Self = Self(sys::SDLK_CANCEL);
+    pub const CLEAR: Self = Self(sys::SDLK_CLEAR);
+    pub const PRIOR: Self = Self(sys::SDLK_PRIOR);
+    pub const RETURN2: Self = Self(sys::SDLK_RETURN2);
+    pub const SEPARATOR: Self = Self(sys::SDLK_SEPARATOR);
+    pub const OUT: Self = Self(sys::SDLK_OUT);
+    pub const OPER: Self = Self(sys::SDLK_OPER);
+    pub const CLEARAGAIN: Self = Self(sys::SDLK_CLEARAGAIN);
+    pub const CRSEL: Self = Self(sys::SDLK_CRSEL);
+    pub const EXSEL: Self = Self(sys::SDLK_EXSEL);
+    pub const KP_00: Self = Self(sys::SDLK_KP_00);
+    pub const KP_000: Self = Self(sys::SDLK_KP_000);
+    pub const THOUSANDSSEPARATOR: Self = Self(sys::SDLK_THOUSANDSSEPARATOR);
+    pub const DECIMALSEPARATOR: Self = Self(sys::SDLK_DECIMALSEPARATOR);
+    pub const CURRENCYUNIT: Self = Self(sys::SDLK_CURRENCYUNIT);
+    pub const CURRENCYSUBUNIT: Self = Self(sys::SDLK_CURRENCYSUBUNIT);
+    pub const KP_LEFTPAREN: Self = Self(sys::SDLK_KP_LEFTPAREN);
+    pub const KP_RIGHTPAREN: Self = Self(sys::SDLK_KP_RIGHTPAREN);
+    pub const KP_LEFTBRACE: Self = Self(sys::SDLK_KP_LEFTBRACE);
+    pub const KP_RIGHTBRACE: Self = Self(sys::SDLK_KP_RIGHTBRACE);
+    pub const KP_TAB: Self = Self(sys::SDLK_KP_TAB);
+    pub const KP_BACKSPACE: Self = Self(sys::SDLK_KP_BACKSPACE);
+    pub const KP_A: Self = Self(sys::SDLK_KP_A);
+    pub const KP_B: Self = Self(sys::SDLK_KP_B);
+    pub const KP_C: Self = Self(sys::SDLK_KP_C);
+    pub const KP_D: Self = Self(sys::SDLK_KP_D);
+    pub const KP_E: Self = Self(sys::SDLK_KP_E);
+    pub const KP_F: Self = Self(sys::SDLK_KP_F);
+    pub const KP_XOR: Self = Self(sys::SDLK_KP_XOR);
+    pub const KP_POWER: Self = Self(sys::SDLK_KP_POWER);
+    pub const KP_PERCENT: Self = Self(sys::SDLK_KP_PERCENT);
+    pub const KP_LESS: Self = Self(sys::SDLK_KP_LESS);
+    pub const KP_GREATER: Self = Self(sys::SDLK_KP_GREATER);
+    pub const KP_AMPERSAND: Self = Self(sys::SDLK_KP_AMPERSAND);
+    pub const KP_DBLAMPERSAND: Self = Self(sys::SDLK_KP_DBLAMPERSAND);
+    pub const KP_VERTICALBAR: Self = Self(sys::SDLK_KP_VERTICALBAR);
+    pub const KP_DBLVERTICALBAR: Self = Self(sys::SDLK_KP_DBLVERTICALBAR);
+    pub const KP_COLON: Self = Self(sys::SDLK_KP_COLON);
+    pub const KP_HASH: Self = Self(sys::SDLK_KP_HASH);
+    pub const KP_SPACE: Self = Self(sys::SDLK_KP_SPACE);
+    pub const KP_AT: Self = Self(sys::SDLK_KP_AT);
+    pub const KP_EXCLAM: Self = Self(sys::SDLK_KP_EXCLAM);
+    pub const KP_MEMSTORE: Self = Self(sys::SDLK_KP_MEMSTORE);
+    pub const KP_MEMRECALL: Self = Self(sys::SDLK_KP_MEMRECALL);
+    pub const KP_MEMCLEAR: Self = Self(sys::SDLK_KP_MEMCLEAR);
+    pub const KP_MEMADD: Self = Self(sys::SDLK_KP_MEMADD);
+    pub const KP_MEMSUBTRACT: Self = Self(sys::SDLK_KP_MEMSUBTRACT);
+    pub const KP_MEMMULTIPLY: Self = Self(sys::SDLK_KP_MEMMULTIPLY);
+    pub const KP_MEMDIVIDE: Self = Self(sys::SDLK_KP_MEMDIVIDE);
+    pub const KP_PLUSMINUS: Self = Self(sys::SDLK_KP_PLUSMINUS);
+    pub const KP_CLEAR: Self = Self(sys::SDLK_KP_CLEAR);
+    pub const KP_CLEARENTRY: Self = Self(sys::SDLK_KP_CLEARENTRY);
+    pub const KP_BINARY: Self = Self(sys::SDLK_KP_BINARY);
+    pub const KP_OCTAL: Self = Self(sys::SDLK_KP_OCTAL);
+    pub const KP_DECIMAL: Self = Self(sys::SDLK_KP_DECIMAL);
+    pub const KP_HEXADECIMAL: Self = Self(sys::SDLK_KP_HEXADECIMAL);
+    pub const LCTRL: Self = Self(sys::SDLK_LCTRL);
+    pub const LSHIFT: Self = Self(sys::SDLK_LSHIFT);
+    pub const LALT: Self = Self(sys::SDLK_LALT);
+    pub const LGUI: Self = Self(sys::SDLK_LGUI);
+    pub const RCTRL: Self = Self(sys::SDLK_RCTRL);
+    pub const RSHIFT: Self = Self(sys::SDLK_RSHIFT);
+    pub const RALT: Self = Self(sys::SDLK_RALT);
+    pub const RGUI: Self = Self(sys::SDLK_RGUI);
+    pub const MODE: Self = Self(sys::SDLK_MODE);
+    pub const SLEEP: Self = Self(sys::SDLK_SLEEP);
+    pub const WAKE: Self = Self(sys::SDLK_WAKE);
+    pub const CHANNEL_INCREMENT: Self = Self(sys::SDLK_CHANNEL_INCREMENT);
+    pub const CHANNEL_DECREMENT: Self = Self(sys::SDLK_CHANNEL_DECREMENT);
+    pub const MEDIA_PLAY: Self = Self(sys::SDLK_MEDIA_PLAY);
+    pub const MEDIA_PAUSE: Self = Self(sys::SDLK_MEDIA_PAUSE);
+    pub const MEDIA_RECORD: Self = Self(sys::SDLK_MEDIA_RECORD);
+    pub const MEDIA_FAST_FORWARD: Self = Self(sys::SDLK_MEDIA_FAST_FORWARD);
+    pub const MEDIA_REWIND: Self = Self(sys::SDLK_MEDIA_REWIND);
+    pub const MEDIA_NEXT_TRACK: Self = Self(sys::SDLK_MEDIA_NEXT_TRACK);
+    pub const MEDIA_PREVIOUS_TRACK: Self = Self(sys::SDLK_MEDIA_PREVIOUS_TRACK);
+    pub const MEDIA_STOP: Self = Self(sys::SDLK_MEDIA_STOP);
+    pub const MEDIA_EJECT: Self = Self(sys::SDLK_MEDIA_EJECT);
+    pub const MEDIA_PLAY_PAUSE: Self = Self(sys::SDLK_MEDIA_PLAY_PAUSE);
+    pub const MEDIA_SELECT: Self = Self(sys::SDLK_MEDIA_SELECT);
+    pub const AC_NEW: Self = Self(sys::SDLK_AC_NEW);
+    pub const AC_OPEN: Self = Self(sys::SDLK_AC_OPEN);
+    pub const AC_CLOSE: Self = Self(sys::SDLK_AC_CLOSE);
+    pub const AC_EXIT: Self = Self(sys::SDLK_AC_EXIT);
+    pub const AC_SAVE: Self = Self(sys::SDLK_AC_SAVE);
+    pub const AC_PRINT: Self = Self(sys::SDLK_AC_PRINT);
+    pub const AC_PROPERTIES: Self = Self(sys::SDLK_AC_PROPERTIES);
+    pub const AC_SEARCH: Self = Self(sys::SDLK_AC_SEARCH);
+    pub const AC_HOME: Self = Self(sys::SDLK_AC_HOME);
+    pub const AC_BACK: Self = Self(sys::SDLK_AC_BACK);
+    pub const AC_FORWARD: Self = Self(sys::SDLK_AC_FORWARD);
+    pub const AC_STOP: Self = Self(sys::SDLK_AC_STOP);
+    pub const AC_REFRESH: Self = Self(sys::SDLK_AC_REFRESH);
+    pub const AC_BOOKMARKS: Self = Self(sys::SDLK_AC_BOOKMARKS);
+    pub const SOFTLEFT: Self = Self(sys::SDLK_SOFTLEFT);
+    pub const SOFTRIGHT: Self = Self(sys::SDLK_SOFTRIGHT);
+    pub const CALL: Self = Self(sys::SDLK_CALL);
+    pub const ENDCALL: Self = Self(sys::SDLK_ENDCALL);
+    pub const LEFT_TAB: Self = Self(sys::SDLK_LEFT_TAB);
+    pub const LEVEL5_SHIFT: Self = Self(sys::SDLK_LEVEL5_SHIFT);
+    pub const MULTI_KEY_COMPOSE: Self = Self(sys::SDLK_MULTI_KEY_COMPOSE);
+    pub const LMETA: Self = Self(sys::SDLK_LMETA);
+    pub const RMETA: Self = Self(sys::SDLK_RMETA);
+    pub const LHYPER: Self = Self(sys::SDLK_LHYPER);
+    pub const RHYPER: Self = Self(sys::SDLK_RHYPER);
+
+    #[inline]
+    pub fn from_ll(ll: sys::SDL_Keycode) -> Self {
+        Self(ll)
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_Keycode {
+        self.0
+    }
+
+    /// Looks up a keycode from its name, returning [`Keycode::UNKNOWN`] if there is no match.
+    pub fn from_name(name: &str) -> Self {
+        crate::with_cstr(name, |name| {
+            Self(unsafe { sys::SDL_GetKeyFromName(name.as_ptr()) })
+        })
+        .unwrap_or(Self::UNKNOWN)
+    }
+
+    /// Returns the scancode that produces this keycode in the current keyboard layout, along
+    /// with the modifiers needed to produce it.
+    pub fn to_scancode(&self) -> (Scancode, Keymod) {
+        let mut modstate = 0;
+        let scancode = unsafe { sys::SDL_GetScancodeFromKey(self.0, &raw mut modstate) };
+        (Scancode::from_ll(scancode), Keymod(modstate))
+    }
+
+    /// Returns a human-readable name for the key, or an empty string if the key has no name.
+    pub fn name(&self) -> String {
+        let ptr = unsafe { sys::SDL_GetKeyName(self.0) };
+        if ptr.is_null() {
+            return String::new();
+        }
+        unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+/// Set of keyboard modifiers held down while a key event happened.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Keymod(sys::SDL_Keymod);
+
+impl Keymod {
+    pub const NONE: Self = Self(sys::SDL_KMOD_NONE as u16);
+    pub const LSHIFT: Self = Self(sys::SDL_KMOD_LSHIFT as u16);
+    pub const RSHIFT: Self = Self(sys::SDL_KMOD_RSHIFT as u16);
+    pub const LEVEL5: Self = Self(sys::SDL_KMOD_LEVEL5 as u16);
+    pub const LCTRL: Self = Self(sys::SDL_KMOD_LCTRL as u16);
+    pub const RCTRL: Self = Self(sys::SDL_KMOD_RCTRL as u16);
+    pub const LALT: Self = Self(sys::SDL_KMOD_LALT as u16);
+    pub const RALT: Self = Self(sys::SDL_KMOD_RALT as u16);
+    pub const LGUI: Self = Self(sys::SDL_KMOD_LGUI as u16);
+    pub const RGUI: Self = Self(sys::SDL_KMOD_RGUI as u16);
+    pub const NUM: Self = Self(sys::SDL_KMOD_NUM as u16);
+    pub const CAPS: Self = Self(sys::SDL_KMOD_CAPS as u16);
+    pub const MODE: Self = Self(sys::SDL_KMOD_MODE as u16);
+    pub const SCROLL: Self = Self(sys::SDL_KMOD_SCROLL as u16);
+    /// Either control key.
+    pub const CTRL: Self = Self(sys::SDL_KMOD_CTRL as u16);
+    /// Either shift key.
+    pub const SHIFT: Self = Self(sys::SDL_KMOD_SHIFT as u16);
+    /// Either alt key.
+    pub const ALT: Self = Self(sys::SDL_KMOD_ALT as u16);
+    /// Either GUI key (Windows, Command or Meta).
+    pub const GUI: Self = Self(sys::SDL_KMOD_GUI as u16);
+
+    /// Returns true if any of the modifiers in `other` is set.
+    ///
+    /// Combined values like [`Keymod::CTRL`] match either their left or right variant.
+    #[inline]
+    pub fn intersects(&self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Returns true if all of the modifiers in `other` are set.
+    #[inline]
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    pub fn from_ll(ll: sys::SDL_Keymod) -> Self {
+        Self(ll)
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_Keymod {
+        self.0
+    }
+}
+
+impl core::ops::BitOr for Keymod {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(Scancode: Unknown, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z, Num1, Num2, Num3, Num4, Num5, Num6, Num7, Num8, Num9, Num0, Return, Escape, Backspace, Tab, Space, Minus, Equals, LeftBracket, RightBracket, Backslash, NonUSHash, Semicolon, Apostrophe, Grave, Comma, Period, Slash, CapsLock, F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12, PrintScreen, ScrollLock, Pause, Insert, Home, PageUp, Delete, End, PageDown, Right, Left, Down, Up, NumLockClear, KpDivide, KpMultiply, KpMinus, KpPlus, KpEnter, Kp1, Kp2, Kp3, Kp4, Kp5, Kp6, Kp7, Kp8, Kp9, Kp0, KpPeriod, NonUSBackslash, Application, Power, KpEquals, F13, F14, F15, F16, F17, F18, F19, F20, F21, F22, F23, F24, Execute, Help, Menu, Select, Stop, Again, Undo, Cut, Copy, Paste, Find, Mute, VolumeUp, VolumeDown, KpComma, KpEqualsAs400, International1, International2, International3, International4, International5, International6, International7, International8, International9, Lang1, Lang2, Lang3, Lang4, Lang5, Lang6, Lang7, Lang8, Lang9, AltErase, SysReq, Cancel, Clear, Prior, Return2, Separator, Out, Oper, ClearAgain, CrSel, ExSel, Kp00, Kp000, ThousandsSeparator, DecimalSeparator, CurrencyUnit, CurrencySubUnit, KpLeftParen, KpRightParen, KpLeftBrace, KpRightBrace, KpTab, KpBackspace, KpA, KpB, KpC, KpD, KpE, KpF, KpXor, KpPower, KpPercent, KpLess, KpGreater, KpAmpersand, KpDblAmpersand, KpVerticalBar, KpDblVerticalBar, KpColon, KpHash, KpSpace, KpAt, KpExclam, KpMemStore, KpMemRecall, KpMemClear, KpMemAdd, KpMemSubtract, KpMemMultiply, KpMemDivide, KpPlusMinus, KpClear, KpClearEntry, KpBinary, KpOctal, KpDecimal, KpHexadecimal, LCtrl, LShift, LAlt, LGui, RCtrl, RShift, RAlt, RGui, Mode, Sleep, Wake, ChannelIncrement, ChannelDecrement, MediaPlay, MediaPause, MediaRecord, MediaFastForward, MediaRewind, MediaNextTrack, MediaPreviousTrack, MediaStop, MediaEject, MediaPlayPause, MediaSelect, AcNew, AcOpen, AcClose, AcExit, AcSave, AcPrint, AcProperties, AcSearch, AcHome, AcBack, AcForward, AcStop, AcRefresh, AcBookmarks, SoftLeft, SoftRight, Call, EndCall, Reserved, Count);
+    }
 }
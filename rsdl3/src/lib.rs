@@ -4,24 +4,53 @@
 extern crate alloc;
 
 pub mod allocator;
+pub mod asyncio;
+pub mod audio;
 pub mod blendmode;
 pub mod camera;
 pub mod clipboard;
+pub mod draw;
 pub mod events;
+pub mod filesystem;
+pub mod gamepad;
+pub mod gpu;
+pub mod hints;
+#[cfg(feature = "image")]
+#[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+pub mod image;
 mod init;
 pub mod iostream;
+pub mod joystick;
 pub mod keyboard;
 pub mod logs;
+pub mod main_loop;
+pub mod messagebox;
+pub mod mouse;
+#[cfg(feature = "must")]
+#[cfg_attr(docsrs, doc(cfg(feature = "must")))]
+pub mod must;
+pub mod pen;
 pub mod pixels;
+pub mod process;
+pub mod properties;
 pub mod rect;
 pub mod render;
 #[cfg(feature = "main")]
 pub mod runtime;
+pub mod sensor;
+pub mod storage;
 pub mod surface;
+pub mod touch;
+pub mod tray;
+#[cfg(feature = "ttf")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ttf")))]
+pub mod ttf;
 pub mod video;
 
+#[cfg(test)]
+mod testing;
+
 use core::ffi::CStr;
-use core::marker::PhantomData;
 
 use alloc::string::String;
 use alloc::string::ToString;
@@ -32,62 +61,107 @@ pub use runtime::application;
 #[cfg(all(feature = "main", not(feature = "callbacks")))]
 pub use runtime::main;
 
-/// Zero-sized error type for any operations involving SDL.
+/// Error type for any operations involving SDL.
+///
+/// Failed SDL calls produce [`Error::Sdl`], which holds the message SDL reported at the failure
+/// site, so it isn't lost when another SDL call fails before the error is read. Errors detected
+/// by this crate before calling into SDL have their own variants.
 ///
-/// The actual error message is stored by SDL and can be retrieved by calling
-/// [`get_error`].
-#[allow(unused)]
-#[derive(Clone)]
-pub struct Error {
-    _m: PhantomData<*const ()>, // !Send + !Sync
+/// For compatibility with code that reads the message with [`get_error`], errors created by this
+/// crate also set SDL's error message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// An SDL call failed, with the message SDL reported.
+    Sdl(String),
+    /// An argument or state was rejected before reaching SDL.
+    InvalidInput(&'static str),
+    /// An integer didn't fit in the type SDL expects.
+    IntConversion,
+    /// A string passed to SDL contained an interior NUL byte.
+    Nul,
 }
 
 impl Error {
+    /// Captures SDL's error message for the calling thread.
+    ///
+    /// Call this right after the SDL call that failed.
     pub fn new() -> Self {
-        Self { _m: PhantomData }
+        Self::Sdl(get_error().unwrap_or_default())
     }
 
-    /// This methods sets SDL's internal error message .
-    pub(crate) fn register(err: &CStr) -> Self {
-        unsafe { sys::SDL_SetError(err.as_ptr()) };
-        Self { _m: PhantomData }
+    /// Sets SDL's internal error message and returns it as [`Error::InvalidInput`].
+    pub(crate) fn register(err: &'static CStr) -> Self {
+        unsafe { sys::SDL_SetError(c"%s".as_ptr(), err.as_ptr()) };
+        Self::InvalidInput(err.to_str().unwrap_or("Invalid input."))
     }
 }
 
 impl core::error::Error for Error {}
 
-impl core::fmt::Debug for Error {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "SDL Error")
-    }
-}
-
 impl core::fmt::Display for Error {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "SDL Error")
+        match self {
+            Error::Sdl(message) if message.is_empty() => write!(f, "SDL Error"),
+            Error::Sdl(message) => write!(f, "{message}"),
+            Error::InvalidInput(message) => write!(f, "{message}"),
+            Error::IntConversion => write!(f, "Integer out of range."),
+            Error::Nul => write!(f, "Interior NUL byte found in string."),
+        }
     }
 }
 
 impl From<alloc::ffi::NulError> for Error {
     fn from(_: alloc::ffi::NulError) -> Self {
-        static ERROR_MESSAGE: &CStr = c"alloc::ffi::NulError";
-        Error::register(ERROR_MESSAGE)
+        unsafe { sys::SDL_SetError(c"alloc::ffi::NulError".as_ptr()) };
+        Error::Nul
     }
 }
 
 impl From<core::num::TryFromIntError> for Error {
     fn from(_value: core::num::TryFromIntError) -> Self {
-        static ERROR_MESSAGE: &CStr = c"alloc::ffi::NulError";
-        Error::register(ERROR_MESSAGE)
+        unsafe { sys::SDL_SetError(c"core::num::TryFromIntError".as_ptr()) };
+        Error::IntConversion
     }
 }
 
 impl From<Error> for String {
-    fn from(_: Error) -> Self {
-        get_error().unwrap_or(String::from("No error reported in SDL."))
+    fn from(error: Error) -> Self {
+        error.to_string()
     }
 }
 
+/// Length of the stack buffer used by [`with_cstr`], including the NUL terminator.
+const STACK_CSTR_LEN: usize = 128;
+
+/// Calls `f` with `s` converted into a `CStr`.
+///
+/// Short strings are copied into a stack buffer so per-frame calls don't hit the allocator;
+/// longer strings fall back to a `CString`.
+pub(crate) fn with_cstr<R>(s: &str, f: impl FnOnce(&CStr) -> R) -> Result<R, alloc::ffi::NulError> {
+    if s.len() < STACK_CSTR_LEN && !s.as_bytes().contains(&0) {
+        let mut buffer = [0u8; STACK_CSTR_LEN];
+        buffer[..s.len()].copy_from_slice(s.as_bytes());
+        // SAFETY: `s` has no interior NUL bytes and is followed by at least one zero.
+        let c_str = unsafe { CStr::from_bytes_with_nul_unchecked(&buffer[..=s.len()]) };
+        return Ok(f(c_str));
+    }
+    let c_string = alloc::ffi::CString::new(s)?;
+    Ok(f(&c_string))
+}
+
+/// Converts `s` into a `CString`, replacing interior NUL bytes with U+FFFD.
+pub(crate) fn cstring_lossy(s: &str) -> alloc::ffi::CString {
+    let bytes = if s.contains('\0') {
+        s.replace('\0', "\u{FFFD}").into_bytes()
+    } else {
+        s.as_bytes().to_vec()
+    };
+    // SAFETY: every NUL byte was replaced above.
+    unsafe { alloc::ffi::CString::from_vec_unchecked(bytes) }
+}
+
+/// Returns the message of the last error that happened on the calling thread.
 pub fn get_error() -> Option<String> {
     unsafe {
         let msg = sys::SDL_GetError();
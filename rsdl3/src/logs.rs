@@ -1,5 +1,13 @@
-use crate::sys;
+//! Logging through SDL's log system.
+//!
+//! SDL serializes log output internally, so every function and macro in this module can be
+//! called from any thread.
+
+use crate::{sys, Error};
+use alloc::boxed::Box;
 use alloc::{borrow::ToOwned, ffi::CString, string::String};
+use core::cell::UnsafeCell;
+use core::ffi::{c_char, c_int, c_void, CStr};
 use core::fmt::Arguments;
 
 #[macro_export]
@@ -84,6 +92,128 @@ pub fn set_log_priority(category: LogCategory, priority: LogPriority) {
     }
 }
 
+/// Set the priority of every log category.
+pub fn set_all_priorities(priority: LogPriority) {
+    unsafe { sys::SDL_SetLogPriorities(priority.to_ll()) };
+}
+
+/// Replaces SDL's log output with `output`, e.g. to forward messages to the `log` or `tracing`
+/// crates, until the returned guard is dropped.
+///
+/// `output` receives messages that passed the priority filter, with the category and priority
+/// they were logged at. It can be called from any thread, but never concurrently.
+///
+/// Only one output function set with this is active at a time. Setting another one replaces and
+/// frees it, after which its guard does nothing. Dropping the guard of the active function
+/// restores the output that was in place before any was set, so guards can be dropped in any
+/// order. This must not be called from within an output function.
+#[must_use = "the log output function is restored when the guard is dropped"]
+pub fn set_log_output_function<F>(output: F) -> LogOutputGuard
+where
+    F: Fn(LogCategory, LogPriority, &str) + Send + Sync + 'static,
+{
+    let output: *mut LogOutputFunction = Box::into_raw(Box::new(Box::new(output)));
+    let (generation, replaced) = LOG_OUTPUT.with(|state| {
+        if state.output.is_null() {
+            unsafe {
+                sys::SDL_GetLogOutputFunction(
+                    &raw mut state.original,
+                    &raw mut state.original_userdata,
+                )
+            };
+        }
+        unsafe { sys::SDL_SetLogOutputFunction(Some(log_output_marshall), output as *mut c_void) };
+        state.generation += 1;
+        (
+            state.generation,
+            core::mem::replace(&mut state.output, output),
+        )
+    });
+    // SDL holds its log lock while calling the output function and while replacing it, so no
+    // call can still be using the closure once it's replaced.
+    if !replaced.is_null() {
+        drop(unsafe { Box::from_raw(replaced) });
+    }
+    LogOutputGuard { generation }
+}
+
+type LogOutputFunction = Box<dyn Fn(LogCategory, LogPriority, &str) + Send + Sync>;
+
+/// Restores the previous log output function when dropped. See [`set_log_output_function`].
+pub struct LogOutputGuard {
+    generation: u64,
+}
+
+impl Drop for LogOutputGuard {
+    fn drop(&mut self) {
+        let output = LOG_OUTPUT.with(|state| {
+            if state.generation != self.generation {
+                // Another function replaced this one, which freed it.
+                return core::ptr::null_mut();
+            }
+            unsafe { sys::SDL_SetLogOutputFunction(state.original, state.original_userdata) };
+            core::mem::replace(&mut state.output, core::ptr::null_mut())
+        });
+        if !output.is_null() {
+            drop(unsafe { Box::from_raw(output) });
+        }
+    }
+}
+
+// The output function installed by `set_log_output_function`. There's a single slot, so a guard
+// never reinstalls a function another guard freed, whatever order they're dropped in.
+static LOG_OUTPUT: LogOutputSlot = LogOutputSlot {
+    lock: UnsafeCell::new(0),
+    state: UnsafeCell::new(LogOutputState {
+        generation: 0,
+        output: core::ptr::null_mut(),
+        original: None,
+        original_userdata: core::ptr::null_mut(),
+    }),
+};
+
+struct LogOutputSlot {
+    lock: UnsafeCell<sys::SDL_SpinLock>,
+    state: UnsafeCell<LogOutputState>,
+}
+
+struct LogOutputState {
+    /// Incremented every time a function is set, to tell which guard is the active one.
+    generation: u64,
+    /// The active function, or null if the original output is in place.
+    output: *mut LogOutputFunction,
+    /// The output that was in place before a function was set.
+    original: sys::SDL_LogOutputFunction,
+    original_userdata: *mut c_void,
+}
+
+// SAFETY: `state` is only accessed while holding `lock`.
+unsafe impl Sync for LogOutputSlot {}
+
+impl LogOutputSlot {
+    fn with<R>(&self, f: impl FnOnce(&mut LogOutputState) -> R) -> R {
+        unsafe { sys::SDL_LockSpinlock(self.lock.get()) };
+        let result = f(unsafe { &mut *self.state.get() });
+        unsafe { sys::SDL_UnlockSpinlock(self.lock.get()) };
+        result
+    }
+}
+
+unsafe extern "C" fn log_output_marshall(
+    userdata: *mut c_void,
+    category: c_int,
+    priority: sys::SDL_LogPriority,
+    message: *const c_char,
+) {
+    let output = unsafe { &*(userdata as *const LogOutputFunction) };
+    let message = unsafe { CStr::from_ptr(message) }.to_string_lossy();
+    output(
+        LogCategory::from_ll(category),
+        LogPriority(priority),
+        &message,
+    );
+}
+
 pub fn log_priority(category: LogCategory) -> LogPriority {
     LogPriority(unsafe { sys::SDL_GetLogPriority(category.to_ll() as i32) })
 }
@@ -138,7 +268,7 @@ fn log_category(
 }
 
 #[repr(transparent)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct LogPriority(u32);
 
 impl LogPriority {
@@ -164,7 +294,7 @@ impl LogPriority {
 }
 
 #[repr(u32)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum LogCategory {
     Application = sys::SDL_LogCategory_SDL_LOG_CATEGORY_APPLICATION,
     Error = sys::SDL_LogCategory_SDL_LOG_CATEGORY_ERROR,
@@ -189,10 +319,191 @@ pub enum LogCategory {
 }
 
 impl LogCategory {
+    /// Converts a raw `SDL_LogCategory` into a `LogCategory`.
+    pub fn try_from_ll(ll: sys::SDL_LogCategory) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_APPLICATION => Self::Application,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_ERROR => Self::Error,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_ASSERT => Self::Assert,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_SYSTEM => Self::System,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_AUDIO => Self::Audio,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_VIDEO => Self::Video,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RENDER => Self::Render,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_INPUT => Self::Input,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_TEST => Self::Test,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_GPU => Self::Gpu,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED2 => Self::Reserved2,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED3 => Self::Reserved3,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED4 => Self::Reserved4,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED5 => Self::Reserved5,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED6 => Self::Reserved6,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED7 => Self::Reserved7,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED8 => Self::Reserved8,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED9 => Self::Reserved9,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED10 => Self::Reserved10,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_CUSTOM => Self::Custom,
+            _ => return Err(Error::register(c"Unknown log category.")),
+        })
+    }
+
     #[inline]
     fn to_ll(&self) -> u32 {
         *self as u32
     }
+
+    #[cfg(any(feature = "log-compat", feature = "tracing-compat"))]
+    fn target(&self) -> &'static str {
+        match self {
+            Self::Application => "sdl::application",
+            Self::Error => "sdl::error",
+            Self::Assert => "sdl::assert",
+            Self::System => "sdl::system",
+            Self::Audio => "sdl::audio",
+            Self::Video => "sdl::video",
+            Self::Render => "sdl::render",
+            Self::Input => "sdl::input",
+            Self::Test => "sdl::test",
+            Self::Gpu => "sdl::gpu",
+            Self::Reserved2
+            | Self::Reserved3
+            | Self::Reserved4
+            | Self::Reserved5
+            | Self::Reserved6
+            | Self::Reserved7
+            | Self::Reserved8
+            | Self::Reserved9
+            | Self::Reserved10 => "sdl::reserved",
+            Self::Custom => "sdl::custom",
+        }
+    }
+
+    /// Application-defined categories, from [`LogCategory::Custom`] onwards, all map to
+    /// [`LogCategory::Custom`].
+    fn from_ll(value: c_int) -> Self {
+        match value as u32 {
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_APPLICATION => Self::Application,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_ERROR => Self::Error,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_ASSERT => Self::Assert,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_SYSTEM => Self::System,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_AUDIO => Self::Audio,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_VIDEO => Self::Video,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RENDER => Self::Render,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_INPUT => Self::Input,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_TEST => Self::Test,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_GPU => Self::Gpu,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED2 => Self::Reserved2,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED3 => Self::Reserved3,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED4 => Self::Reserved4,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED5 => Self::Reserved5,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED6 => Self::Reserved6,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED7 => Self::Reserved7,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED8 => Self::Reserved8,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED9 => Self::Reserved9,
+            sys::SDL_LogCategory_SDL_LOG_CATEGORY_RESERVED10 => Self::Reserved10,
+            _ => Self::Custom,
+        }
+    }
+}
+
+/// Forwards SDL's log output to the [`log`](mod@log) crate until the returned guard is dropped.
+///
+/// Messages are logged with a target of `sdl::<category>`, e.g. `sdl::render`. SDL's verbose
+/// and critical priorities map to [`log::Level::Trace`] and [`log::Level::Error`]. Keep the guard
+/// for as long as SDL should log, or leak it with [`core::mem::forget`] to forward for the rest
+/// of the program. Don't combine this with [`SdlLogger`], or messages will go back and forth.
+#[cfg(feature = "log-compat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "log-compat")))]
+#[must_use = "the log output function is restored when the guard is dropped"]
+pub fn forward_to_log() -> LogOutputGuard {
+    set_log_output_function(|category, priority, message| {
+        let level = match priority {
+            LogPriority::CRITICAL | LogPriority::ERROR => log::Level::Error,
+            LogPriority::WARN => log::Level::Warn,
+            LogPriority::INFO => log::Level::Info,
+            LogPriority::DEBUG => log::Level::Debug,
+            _ => log::Level::Trace,
+        };
+        log::log!(target: category.target(), level, "{message}");
+    })
+}
+
+/// A [`log::Log`] implementation that writes records through SDL's log system, so they end up
+/// wherever SDL logs go on the platform, such as logcat on Android.
+///
+/// Records are logged in [`LogCategory::Application`], and SDL's priority for that category
+/// filters them further.
+#[cfg(feature = "log-compat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "log-compat")))]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SdlLogger;
+
+#[cfg(feature = "log-compat")]
+impl SdlLogger {
+    /// Installs the logger as the global [`log`](mod@log) logger and sets the maximum level.
+    pub fn init(max_level: log::LevelFilter) -> Result<(), log::SetLoggerError> {
+        static LOGGER: SdlLogger = SdlLogger;
+        log::set_logger(&LOGGER)?;
+        log::set_max_level(max_level);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "log-compat")]
+impl log::Log for SdlLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let priority = match record.level() {
+            log::Level::Error => LogPriority::ERROR,
+            log::Level::Warn => LogPriority::WARN,
+            log::Level::Info => LogPriority::INFO,
+            log::Level::Debug => LogPriority::DEBUG,
+            log::Level::Trace => LogPriority::TRACE,
+        };
+        let message =
+            crate::cstring_lossy(&alloc::format!("{}: {}", record.target(), record.args()));
+        unsafe {
+            sys::SDL_LogMessage(
+                LogCategory::Application.to_ll() as i32,
+                priority.to_ll(),
+                c"%s".as_ptr(),
+                message.as_ptr(),
+            )
+        };
+    }
+
+    fn flush(&self) {}
+}
+
+/// Forwards SDL's log output to the [`tracing`] crate as events, until the returned guard is
+/// dropped.
+///
+/// Events carry the SDL category in a `category` field, e.g. `category = "sdl::render"`. SDL's
+/// verbose and critical priorities map to [`tracing::Level::TRACE`] and
+/// [`tracing::Level::ERROR`]. Keep the guard for as long as SDL should log, or leak it with
+/// [`core::mem::forget`] to forward for the rest of the program.
+#[cfg(feature = "tracing-compat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing-compat")))]
+#[must_use = "the log output function is restored when the guard is dropped"]
+pub fn forward_to_tracing() -> LogOutputGuard {
+    set_log_output_function(|category, priority, message| {
+        let category = category.target();
+        // tracing needs the level of an event at compile time.
+        match priority {
+            LogPriority::CRITICAL | LogPriority::ERROR => {
+                tracing::error!(category, "{message}")
+            }
+            LogPriority::WARN => tracing::warn!(category, "{message}"),
+            LogPriority::INFO => tracing::info!(category, "{message}"),
+            LogPriority::DEBUG => tracing::debug!(category, "{message}"),
+            _ => tracing::trace!(category, "{message}"),
+        }
+    })
 }
 
 fn args_to_c_string(args: Arguments) -> CString {
@@ -201,3 +512,14 @@ fn args_to_c_string(args: Arguments) -> CString {
     buf.write_fmt(args).unwrap();
     CString::new(buf.to_owned().replace("%", "%%")).unwrap()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(LogCategory: Application, Error, Assert, System, Audio, Video, Render, Input, Test, Gpu, Reserved2, Reserved3, Reserved4, Reserved5, Reserved6, Reserved7, Reserved8, Reserved9, Reserved10, Custom);
+    }
+}
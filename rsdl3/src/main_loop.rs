@@ -0,0 +1,64 @@
+//! A frame loop that works the same on native targets and in the browser.
+//!
+//! Browsers don't allow a program to loop forever: the page freezes until the function that
+//! loops returns. On Emscripten, [`Sdl::run_frame_loop`] instead asks the browser to call the
+//! frame function before every repaint, through `requestAnimationFrame`.
+
+use crate::init::Sdl;
+
+/// Returned by the frame function of [`Sdl::run_frame_loop`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MainLoopControl {
+    /// Runs another frame.
+    Continue,
+    /// Stops the loop after this frame.
+    Exit,
+}
+
+impl Sdl {
+    /// Calls `frame` once per frame until it returns [`MainLoopControl::Exit`].
+    ///
+    /// On native targets this is a plain loop that returns once `frame` exits, so `frame` should
+    /// pace itself, e.g. by presenting with vsync enabled.
+    ///
+    /// On Emscripten, the browser calls `frame` before every repaint and this function never
+    /// returns: the stack is unwound to give control back to the browser, without running the
+    /// destructors of the values on it. Anything the loop needs should therefore be moved into
+    /// `frame`, which is dropped after it exits. Since this only borrows `self`, move clones of
+    /// the [`Sdl`] and subsystem handles into `frame` so that they are released along with it.
+    pub fn run_frame_loop<F>(&self, frame: F)
+    where
+        F: FnMut() -> MainLoopControl + 'static,
+    {
+        run_frame_loop(frame)
+    }
+}
+
+#[cfg(not(target_os = "emscripten"))]
+fn run_frame_loop<F: FnMut() -> MainLoopControl + 'static>(mut frame: F) {
+    while frame() == MainLoopControl::Continue {}
+}
+
+#[cfg(target_os = "emscripten")]
+fn run_frame_loop<F: FnMut() -> MainLoopControl + 'static>(frame: F) {
+    use crate::sys::emscripten;
+    use alloc::boxed::Box;
+    use core::ffi::c_void;
+
+    unsafe extern "C" fn frame_marshall<F: FnMut() -> MainLoopControl + 'static>(arg: *mut c_void) {
+        let frame = unsafe { &mut *(arg as *mut F) };
+        if frame() == MainLoopControl::Exit {
+            // The closure isn't running anymore and the browser won't call it again.
+            unsafe {
+                emscripten::emscripten_cancel_main_loop();
+                drop(Box::from_raw(arg as *mut F));
+            }
+        }
+    }
+
+    let arg = Box::into_raw(Box::new(frame));
+    // An fps of 0 uses requestAnimationFrame.
+    unsafe {
+        emscripten::emscripten_set_main_loop_arg(Some(frame_marshall::<F>), arg.cast(), 0, true)
+    };
+}
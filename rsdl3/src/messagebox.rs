@@ -0,0 +1,280 @@
+use crate::pixels::Color;
+use crate::sys;
+use crate::video::WindowRef;
+use crate::Error;
+use alloc::ffi::CString;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::BitOr;
+
+/// Displays a simple modal message box with a single "OK" button.
+///
+/// This can be called at any time, even before SDL is initialized, which makes it suitable for
+/// reporting initialization errors to users. `window` is used as the parent of the message box,
+/// if given.
+///
+/// Note that this blocks the calling thread until the user dismisses the message box.
+pub fn show_simple_message_box(
+    flags: MessageBoxFlags,
+    title: &str,
+    message: &str,
+    window: Option<&WindowRef>,
+) -> Result<(), Error> {
+    let title = CString::new(title)?;
+    let message = CString::new(message)?;
+    let window = window
+        .map(|w| w.as_ptr() as *mut sys::SDL_Window)
+        .unwrap_or(core::ptr::null_mut());
+    let result =
+        unsafe { sys::SDL_ShowSimpleMessageBox(flags.0, title.as_ptr(), message.as_ptr(), window) };
+    if !result {
+        return Err(Error::new());
+    }
+    Ok(())
+}
+
+/// A modal message box with custom buttons.
+///
+/// ```no_run
+/// use rsdl3::messagebox::{MessageBox, MessageBoxButton, MessageBoxFlags};
+///
+/// let choice = MessageBox::new("Unsaved changes", "Save before quitting?")
+///     .flags(MessageBoxFlags::WARNING)
+///     .button(MessageBoxButton::new(0, "Save").return_key_default())
+///     .button(MessageBoxButton::new(1, "Discard"))
+///     .button(MessageBoxButton::new(2, "Cancel").escape_key_default())
+///     .show()
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct MessageBox<'a> {
+    flags: MessageBoxFlags,
+    window: Option<&'a WindowRef>,
+    title: &'a str,
+    message: &'a str,
+    buttons: Vec<MessageBoxButton>,
+    color_scheme: Option<MessageBoxColorScheme>,
+}
+
+impl<'a> MessageBox<'a> {
+    /// Creates a message box with no buttons and no parent window.
+    pub fn new(title: &'a str, message: &'a str) -> Self {
+        Self {
+            flags: MessageBoxFlags::default(),
+            window: None,
+            title,
+            message,
+            buttons: Vec::new(),
+            color_scheme: None,
+        }
+    }
+
+    /// Sets the icon and button layout of the message box.
+    pub fn flags(mut self, flags: MessageBoxFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Sets the parent window of the message box.
+    pub fn parent(mut self, window: &'a WindowRef) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    /// Appends a button to the message box.
+    pub fn button(mut self, button: MessageBoxButton) -> Self {
+        self.buttons.push(button);
+        self
+    }
+
+    /// Sets the colors of the message box.
+    ///
+    /// This is ignored on platforms that use native message boxes, such as Windows and macOS.
+    pub fn color_scheme(mut self, color_scheme: MessageBoxColorScheme) -> Self {
+        self.color_scheme = Some(color_scheme);
+        self
+    }
+
+    /// Displays the message box and blocks until the user dismisses it.
+    ///
+    /// Returns the id of the button that was pressed, or `None` if the message box was closed
+    /// without pressing a button.
+    pub fn show(&self) -> Result<Option<i32>, Error> {
+        let title = CString::new(self.title)?;
+        let message = CString::new(self.message)?;
+        let texts = self
+            .buttons
+            .iter()
+            .map(|button| CString::new(button.text.as_str()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let buttons: Vec<sys::SDL_MessageBoxButtonData> = self
+            .buttons
+            .iter()
+            .zip(&texts)
+            .map(|(button, text)| sys::SDL_MessageBoxButtonData {
+                flags: button.flags.0,
+                buttonID: button.id,
+                text: text.as_ptr(),
+            })
+            .collect();
+        let color_scheme = self.color_scheme.map(MessageBoxColorScheme::to_ll);
+        let data = sys::SDL_MessageBoxData {
+            flags: self.flags.0,
+            window: self
+                .window
+                .map(|w| w.as_ptr() as *mut sys::SDL_Window)
+                .unwrap_or(core::ptr::null_mut()),
+            title: title.as_ptr(),
+            message: message.as_ptr(),
+            numbuttons: i32::try_from(buttons.len())?,
+            buttons: buttons.as_ptr(),
+            colorScheme: color_scheme
+                .as_ref()
+                .map(|c| c as *const sys::SDL_MessageBoxColorScheme)
+                .unwrap_or(core::ptr::null()),
+        };
+        let mut button_id = -1;
+        let result = unsafe { sys::SDL_ShowMessageBox(&raw const data, &raw mut button_id) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok((button_id != -1).then_some(button_id))
+    }
+}
+
+/// A button of a [`MessageBox`].
+#[derive(Clone, Debug)]
+pub struct MessageBoxButton {
+    flags: MessageBoxButtonFlags,
+    id: i32,
+    text: String,
+}
+
+impl MessageBoxButton {
+    /// Creates a button. `id` is returned by [`MessageBox::show`] when the button is pressed.
+    pub fn new(id: i32, text: &str) -> Self {
+        Self {
+            flags: MessageBoxButtonFlags::default(),
+            id,
+            text: String::from(text),
+        }
+    }
+
+    /// Makes this the button pressed by the return key.
+    pub fn return_key_default(mut self) -> Self {
+        self.flags = self.flags | MessageBoxButtonFlags::RETURNKEY_DEFAULT;
+        self
+    }
+
+    /// Makes this the button pressed by the escape key.
+    pub fn escape_key_default(mut self) -> Self {
+        self.flags = self.flags | MessageBoxButtonFlags::ESCAPEKEY_DEFAULT;
+        self
+    }
+
+    #[inline]
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+
+    #[inline]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    #[inline]
+    pub fn flags(&self) -> MessageBoxButtonFlags {
+        self.flags
+    }
+}
+
+/// Colors used by a [`MessageBox`]. The alpha channel of each color is ignored.
+#[derive(Copy, Clone, Debug)]
+pub struct MessageBoxColorScheme {
+    pub background: Color,
+    pub text: Color,
+    pub button_border: Color,
+    pub button_background: Color,
+    pub button_selected: Color,
+}
+
+impl MessageBoxColorScheme {
+    fn to_ll(self) -> sys::SDL_MessageBoxColorScheme {
+        let color = |c: Color| sys::SDL_MessageBoxColor {
+            r: c.r(),
+            g: c.g(),
+            b: c.b(),
+        };
+        // Indexed by `SDL_MessageBoxColorType`.
+        sys::SDL_MessageBoxColorScheme {
+            colors: [
+                color(self.background),
+                color(self.text),
+                color(self.button_border),
+                color(self.button_background),
+                color(self.button_selected),
+            ],
+        }
+    }
+}
+
+/// Flags of a [`MessageBox`].
+///
+/// At most one of `ERROR`, `WARNING` and `INFORMATION` should be set.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct MessageBoxFlags(sys::SDL_MessageBoxFlags);
+
+impl MessageBoxFlags {
+    pub const ERROR: Self = Self(sys::SDL_MESSAGEBOX_ERROR);
+    pub const WARNING: Self = Self(sys::SDL_MESSAGEBOX_WARNING);
+    pub const INFORMATION: Self = Self(sys::SDL_MESSAGEBOX_INFORMATION);
+    pub const BUTTONS_LEFT_TO_RIGHT: Self = Self(sys::SDL_MESSAGEBOX_BUTTONS_LEFT_TO_RIGHT);
+    pub const BUTTONS_RIGHT_TO_LEFT: Self = Self(sys::SDL_MESSAGEBOX_BUTTONS_RIGHT_TO_LEFT);
+
+    #[inline]
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_MessageBoxFlags {
+        self.0
+    }
+}
+
+impl BitOr for MessageBoxFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Flags of a [`MessageBoxButton`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct MessageBoxButtonFlags(sys::SDL_MessageBoxButtonFlags);
+
+impl MessageBoxButtonFlags {
+    pub const RETURNKEY_DEFAULT: Self = Self(sys::SDL_MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT);
+    pub const ESCAPEKEY_DEFAULT: Self = Self(sys::SDL_MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT);
+
+    #[inline]
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_MessageBoxButtonFlags {
+        self.0
+    }
+}
+
+impl BitOr for MessageBoxButtonFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
@@ -0,0 +1,383 @@
+use crate::init::{EventsSubsystem, VideoSubsystem};
+use crate::surface::{ScaleMode, SurfaceRef};
+use crate::{sys, Error};
+use alloc::string::String;
+use core::ffi::CStr;
+use core::ptr::NonNull;
+
+impl EventsSubsystem {
+    /// Returns true if a mouse is connected.
+    pub fn has_mouse(&self) -> bool {
+        unsafe { sys::SDL_HasMouse() }
+    }
+
+    /// Returns the name of a mouse.
+    ///
+    /// Returns an empty string if the mouse doesn't have a name.
+    pub fn mouse_name(&self, id: MouseId) -> Result<String, Error> {
+        let ptr = unsafe { sys::SDL_GetMouseNameForID(id) };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        Ok(unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned())
+    }
+
+    /// Returns the cached state of the mouse, relative to the focused window.
+    ///
+    /// The state is updated as events are processed, so it matches the last mouse event that was
+    /// pumped.
+    pub fn mouse_state(&self) -> MouseState {
+        let mut x = 0.0;
+        let mut y = 0.0;
+        let buttons = unsafe { sys::SDL_GetMouseState(&raw mut x, &raw mut y) };
+        MouseState {
+            buttons: MouseButtonFlags(buttons),
+            x,
+            y,
+        }
+    }
+
+    /// Queries the platform for the current state of the mouse, in desktop coordinates.
+    ///
+    /// This works even when the mouse is outside every window, but it's slower than
+    /// [`EventsSubsystem::mouse_state`] and not synchronized with the event queue.
+    pub fn global_mouse_state(&self) -> MouseState {
+        let mut x = 0.0;
+        let mut y = 0.0;
+        let buttons = unsafe { sys::SDL_GetGlobalMouseState(&raw mut x, &raw mut y) };
+        MouseState {
+            buttons: MouseButtonFlags(buttons),
+            x,
+            y,
+        }
+    }
+
+    /// Returns the mouse motion accumulated since the last call to this function.
+    pub fn relative_mouse_state(&mut self) -> MouseState {
+        let mut x = 0.0;
+        let mut y = 0.0;
+        let buttons = unsafe { sys::SDL_GetRelativeMouseState(&raw mut x, &raw mut y) };
+        MouseState {
+            buttons: MouseButtonFlags(buttons),
+            x,
+            y,
+        }
+    }
+
+    /// Moves the mouse cursor to the given position in desktop coordinates.
+    pub fn warp_mouse_global(&self, x: f32, y: f32) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_WarpMouseGlobal(x, y) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Captures the mouse, tracking it outside of the focused window.
+    ///
+    /// While captured, mouse events keep being reported to the window even when the cursor
+    /// leaves it, which is useful to implement dragging. Capture should only be enabled while a
+    /// mouse button is held down.
+    pub fn capture_mouse(&self, enabled: bool) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_CaptureMouse(enabled) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Shows the cursor.
+    pub fn show_cursor(&self) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_ShowCursor() };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Hides the cursor.
+    pub fn hide_cursor(&self) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_HideCursor() };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns true if the cursor is shown.
+    pub fn is_cursor_visible(&self) -> bool {
+        unsafe { sys::SDL_CursorVisible() }
+    }
+
+    /// Sets the active cursor.
+    ///
+    /// The cursor should be kept alive for as long as it's active; SDL falls back to the default
+    /// cursor once it's dropped.
+    pub fn set_cursor(&self, cursor: &Cursor) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_SetCursor(cursor.raw()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Restores the system's default cursor.
+    pub fn reset_cursor(&self) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_SetCursor(sys::SDL_GetDefaultCursor()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+}
+
+/// Position and buttons of the mouse, as returned by [`EventsSubsystem::mouse_state`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MouseState {
+    pub buttons: MouseButtonFlags,
+    pub x: f32,
+    pub y: f32,
+}
+
+/// A mouse cursor.
+pub struct Cursor {
+    _video: VideoSubsystem,
+    ptr: NonNull<sys::SDL_Cursor>,
+}
+
+impl Cursor {
+    /// Creates one of the cursors provided by the system.
+    pub fn from_system(video: &VideoSubsystem, id: SystemCursor) -> Result<Self, Error> {
+        let ptr = unsafe { sys::SDL_CreateSystemCursor(id.to_ll()) };
+        Self::from_ptr(video, ptr)
+    }
+
+    /// Creates a color cursor from a surface.
+    ///
+    /// `hot_x` and `hot_y` are the position of the cursor's hot spot within the surface. The
+    /// surface can be dropped once the cursor is created.
+    pub fn from_surface(
+        video: &VideoSubsystem,
+        surface: &SurfaceRef,
+        hot_x: u32,
+        hot_y: u32,
+    ) -> Result<Self, Error> {
+        let hot_x = i32::try_from(hot_x)?;
+        let hot_y = i32::try_from(hot_y)?;
+        let ptr = unsafe { sys::SDL_CreateColorCursor(surface.raw(), hot_x, hot_y) };
+        Self::from_ptr(video, ptr)
+    }
+
+    /// Creates a color cursor from `image` scaled to `width` x `height`, with alternate images
+    /// for each factor in `scales` so SDL picks the best one for the display scale.
+    ///
+    /// `image` should be a single high resolution asset, and `hot_x` and `hot_y` are relative to
+    /// the `width` x `height` image. Images are scaled with [`ScaleMode::Linear`]; see
+    /// [`SurfaceRef::scaled_image_set`] to use another mode.
+    pub fn from_scaled_image(
+        video: &VideoSubsystem,
+        image: &SurfaceRef,
+        width: u32,
+        height: u32,
+        scales: &[f32],
+        hot_x: u32,
+        hot_y: u32,
+    ) -> Result<Self, Error> {
+        let surface = image.scaled_image_set(video, width, height, scales, ScaleMode::Linear)?;
+        Self::from_surface(video, &surface, hot_x, hot_y)
+    }
+
+    fn from_ptr(video: &VideoSubsystem, ptr: *mut sys::SDL_Cursor) -> Result<Self, Error> {
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            _video: video.clone(),
+            ptr,
+        })
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_Cursor {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for Cursor {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_DestroyCursor(self.ptr.as_ptr()) };
+    }
+}
+
+/// Cursors provided by the system.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SystemCursor {
+    /// The default cursor, usually an arrow.
+    Default = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_DEFAULT,
+    /// Text selection, usually an I-beam.
+    Text = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_TEXT,
+    Wait = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_WAIT,
+    Crosshair = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_CROSSHAIR,
+    /// Program is busy but still interactive, usually an arrow with a spinner.
+    Progress = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_PROGRESS,
+    NwseResize = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_NWSE_RESIZE,
+    NeswResize = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_NESW_RESIZE,
+    EwResize = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_EW_RESIZE,
+    NsResize = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_NS_RESIZE,
+    Move = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_MOVE,
+    NotAllowed = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_NOT_ALLOWED,
+    /// Pointer that indicates a link, usually a pointing hand.
+    Pointer = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_POINTER,
+    NwResize = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_NW_RESIZE,
+    NResize = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_N_RESIZE,
+    NeResize = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_NE_RESIZE,
+    EResize = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_E_RESIZE,
+    SeResize = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_SE_RESIZE,
+    SResize = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_S_RESIZE,
+    SwResize = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_SW_RESIZE,
+    WResize = sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_W_RESIZE,
+}
+
+impl SystemCursor {
+    /// Converts a raw `SDL_SystemCursor` into a `SystemCursor`.
+    pub fn try_from_ll(ll: sys::SDL_SystemCursor) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_DEFAULT => Self::Default,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_TEXT => Self::Text,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_WAIT => Self::Wait,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_CROSSHAIR => Self::Crosshair,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_PROGRESS => Self::Progress,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_NWSE_RESIZE => Self::NwseResize,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_NESW_RESIZE => Self::NeswResize,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_EW_RESIZE => Self::EwResize,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_NS_RESIZE => Self::NsResize,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_MOVE => Self::Move,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_NOT_ALLOWED => Self::NotAllowed,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_POINTER => Self::Pointer,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_NW_RESIZE => Self::NwResize,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_N_RESIZE => Self::NResize,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_NE_RESIZE => Self::NeResize,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_E_RESIZE => Self::EResize,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_SE_RESIZE => Self::SeResize,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_S_RESIZE => Self::SResize,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_SW_RESIZE => Self::SwResize,
+            sys::SDL_SystemCursor_SDL_SYSTEM_CURSOR_W_RESIZE => Self::WResize,
+            _ => return Err(Error::register(c"Unknown system cursor.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_SystemCursor {
+        *self as u32
+    }
+}
+
+/// Instance id of a mouse device.
+///
+/// Events generated by touch devices report [`TOUCH_MOUSE_ID`] instead.
+pub type MouseId = sys::SDL_MouseID;
+
+/// Mouse id reported by mouse events synthesized from touch input.
+pub const TOUCH_MOUSE_ID: MouseId = u32::MAX;
+
+/// A mouse button.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    Left,
+    Middle,
+    Right,
+    X1,
+    X2,
+    /// Any other button, identified by its SDL button index.
+    Other(u8),
+}
+
+impl MouseButton {
+    pub fn from_ll(ll: u8) -> Self {
+        match ll as u32 {
+            sys::SDL_BUTTON_LEFT => Self::Left,
+            sys::SDL_BUTTON_MIDDLE => Self::Middle,
+            sys::SDL_BUTTON_RIGHT => Self::Right,
+            sys::SDL_BUTTON_X1 => Self::X1,
+            sys::SDL_BUTTON_X2 => Self::X2,
+            _ => Self::Other(ll),
+        }
+    }
+
+    pub fn to_ll(&self) -> u8 {
+        match self {
+            Self::Left => sys::SDL_BUTTON_LEFT as u8,
+            Self::Middle => sys::SDL_BUTTON_MIDDLE as u8,
+            Self::Right => sys::SDL_BUTTON_RIGHT as u8,
+            Self::X1 => sys::SDL_BUTTON_X1 as u8,
+            Self::X2 => sys::SDL_BUTTON_X2 as u8,
+            Self::Other(button) => *button,
+        }
+    }
+}
+
+/// Set of mouse buttons held down.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct MouseButtonFlags(sys::SDL_MouseButtonFlags);
+
+impl MouseButtonFlags {
+    /// Returns true if `button` is held down.
+    #[inline]
+    pub fn is_pressed(&self, button: MouseButton) -> bool {
+        let index = button.to_ll();
+        (1..=32).contains(&index) && self.0 & (1 << (index - 1)) != 0
+    }
+
+    #[inline]
+    pub fn from_ll(ll: sys::SDL_MouseButtonFlags) -> Self {
+        Self(ll)
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_MouseButtonFlags {
+        self.0
+    }
+}
+
+/// Scroll direction reported by a mouse wheel event.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MouseWheelDirection {
+    /// The scroll direction is normal.
+    Normal = sys::SDL_MouseWheelDirection_SDL_MOUSEWHEEL_NORMAL,
+    /// The scroll direction is flipped, also known as "natural" scrolling.
+    Flipped = sys::SDL_MouseWheelDirection_SDL_MOUSEWHEEL_FLIPPED,
+}
+
+impl MouseWheelDirection {
+    /// Converts a raw `SDL_MouseWheelDirection` into a `MouseWheelDirection`.
+    pub fn try_from_ll(ll: sys::SDL_MouseWheelDirection) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_MouseWheelDirection_SDL_MOUSEWHEEL_NORMAL => Self::Normal,
+            sys::SDL_MouseWheelDirection_SDL_MOUSEWHEEL_FLIPPED => Self::Flipped,
+            _ => return Err(Error::register(c"Unknown mouse wheel direction.")),
+        })
+    }
+
+    pub fn from_ll(ll: sys::SDL_MouseWheelDirection) -> Self {
+        if ll == sys::SDL_MouseWheelDirection_SDL_MOUSEWHEEL_FLIPPED {
+            Self::Flipped
+        } else {
+            Self::Normal
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(SystemCursor: Default, Text, Wait, Crosshair, Progress, NwseResize, NeswResize, EwResize, NsResize, Move, NotAllowed, Pointer, NwResize, NResize, NeResize, EResize, SeResize, SResize, SwResize, WResize);
+        assert_round_trips!(MouseWheelDirection: Normal, Flipped);
+    }
+}
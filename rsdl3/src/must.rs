@@ -0,0 +1,62 @@
+//! Panicking shorthands for prototyping.
+//!
+//! Every fallible function in this crate returns a `Result<T, Error>`. That's the right choice
+//! for applications, but it adds a lot of `?` and `unwrap` noise to examples, prototypes and game
+//! jam entries. Importing [`MustExt`] adds a [`MustExt::must`] method to those results which
+//! unwraps them, panicking with SDL's error message on failure.
+//!
+//! ```ignore
+//! use rsdl3::must::MustExt;
+//!
+//! renderer.set_draw_color(Color::new(0, 0, 0, 255)).must();
+//! renderer.clear().must();
+//! renderer.present().must();
+//! ```
+
+use crate::Error;
+
+/// Extension trait that unwraps results, panicking with SDL's error message.
+pub trait MustExt<T> {
+    /// Returns the contained `Ok` value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is an `Err`, with the error's message.
+    fn must(self) -> T;
+
+    /// Returns the contained `Ok` value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is an `Err`, with `context` followed by the error's message.
+    fn must_with(self, context: &str) -> T;
+}
+
+impl<T> MustExt<T> for Result<T, Error> {
+    #[track_caller]
+    #[inline]
+    fn must(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(error) => fail(None, &error),
+        }
+    }
+
+    #[track_caller]
+    #[inline]
+    fn must_with(self, context: &str) -> T {
+        match self {
+            Ok(value) => value,
+            Err(error) => fail(Some(context), &error),
+        }
+    }
+}
+
+#[cold]
+#[track_caller]
+fn fail(context: Option<&str>, error: &Error) -> ! {
+    match context {
+        Some(context) => panic!("{context}: SDL error: {error}"),
+        None => panic!("SDL error: {error}"),
+    }
+}
@@ -0,0 +1,116 @@
+use crate::mouse::MouseId;
+use crate::{sys, Error};
+use core::ops::BitOr;
+
+/// Instance id of a pen.
+///
+/// The id stays the same for as long as SDL recognizes the tool as the same pen; a pen that
+/// leaves proximity and comes back may get a new id.
+pub type PenId = sys::SDL_PenID;
+
+/// Mouse id reported by mouse events synthesized from pen input.
+pub const PEN_MOUSE_ID: MouseId = u32::MAX - 1;
+
+/// State of a pen, as reported by pen events.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct PenInputFlags(sys::SDL_PenInputFlags);
+
+impl PenInputFlags {
+    /// The pen is touching the surface.
+    pub const DOWN: Self = Self(sys::SDL_PEN_INPUT_DOWN);
+    pub const BUTTON_1: Self = Self(sys::SDL_PEN_INPUT_BUTTON_1);
+    pub const BUTTON_2: Self = Self(sys::SDL_PEN_INPUT_BUTTON_2);
+    pub const BUTTON_3: Self = Self(sys::SDL_PEN_INPUT_BUTTON_3);
+    pub const BUTTON_4: Self = Self(sys::SDL_PEN_INPUT_BUTTON_4);
+    pub const BUTTON_5: Self = Self(sys::SDL_PEN_INPUT_BUTTON_5);
+    /// The eraser end of the pen is in use.
+    pub const ERASER_TIP: Self = Self(sys::SDL_PEN_INPUT_ERASER_TIP);
+
+    #[inline]
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    pub fn from_ll(ll: sys::SDL_PenInputFlags) -> Self {
+        Self(ll)
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_PenInputFlags {
+        self.0
+    }
+}
+
+impl BitOr for PenInputFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// A pen axis, as reported by [`crate::events::PenAxisEvent`].
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PenAxis {
+    /// Pressure, between 0 (no pressure) and 1 (full pressure).
+    Pressure = sys::SDL_PenAxis_SDL_PEN_AXIS_PRESSURE,
+    /// Horizontal tilt in degrees, between -90 (left) and 90 (right).
+    XTilt = sys::SDL_PenAxis_SDL_PEN_AXIS_XTILT,
+    /// Vertical tilt in degrees, between -90 (up) and 90 (down).
+    YTilt = sys::SDL_PenAxis_SDL_PEN_AXIS_YTILT,
+    /// Distance to the drawing surface, between 0 and 1.
+    Distance = sys::SDL_PenAxis_SDL_PEN_AXIS_DISTANCE,
+    /// Barrel rotation in degrees, between -180 and 179.9.
+    Rotation = sys::SDL_PenAxis_SDL_PEN_AXIS_ROTATION,
+    /// Position of the pen's finger wheel or slider, between 0 and 1.
+    Slider = sys::SDL_PenAxis_SDL_PEN_AXIS_SLIDER,
+    /// Pressure from squeezing the pen ("barrel pressure").
+    TangentialPressure = sys::SDL_PenAxis_SDL_PEN_AXIS_TANGENTIAL_PRESSURE,
+    /// An axis that isn't known to this crate.
+    Unknown = sys::SDL_PenAxis_SDL_PEN_AXIS_COUNT,
+}
+
+impl PenAxis {
+    /// Converts a raw `SDL_PenAxis` into a `PenAxis`.
+    pub fn try_from_ll(ll: sys::SDL_PenAxis) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_PenAxis_SDL_PEN_AXIS_PRESSURE => Self::Pressure,
+            sys::SDL_PenAxis_SDL_PEN_AXIS_XTILT => Self::XTilt,
+            sys::SDL_PenAxis_SDL_PEN_AXIS_YTILT => Self::YTilt,
+            sys::SDL_PenAxis_SDL_PEN_AXIS_DISTANCE => Self::Distance,
+            sys::SDL_PenAxis_SDL_PEN_AXIS_ROTATION => Self::Rotation,
+            sys::SDL_PenAxis_SDL_PEN_AXIS_SLIDER => Self::Slider,
+            sys::SDL_PenAxis_SDL_PEN_AXIS_TANGENTIAL_PRESSURE => Self::TangentialPressure,
+            sys::SDL_PenAxis_SDL_PEN_AXIS_COUNT => Self::Unknown,
+            _ => return Err(Error::register(c"Unknown pen axis.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_PenAxis {
+        *self as u32
+    }
+
+    pub(crate) fn from_ll(ll: sys::SDL_PenAxis) -> Self {
+        if ll < sys::SDL_PenAxis_SDL_PEN_AXIS_COUNT {
+            // SAFETY: the enum covers every value in this range.
+            unsafe { core::mem::transmute::<u32, Self>(ll) }
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(PenAxis: Pressure, XTilt, YTilt, Distance, Rotation, Slider, TangentialPressure, Unknown);
+    }
+}
@@ -86,6 +86,21 @@ impl From<(u8, u8, u8, u8)> for Color {
     }
 }
 
+/// Converts an opaque color.
+impl From<(u8, u8, u8)> for Color {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Self::new(r, g, b, 0xFF)
+    }
+}
+
+/// Converts a color packed as `0xRRGGBBAA`.
+impl From<u32> for Color {
+    fn from(value: u32) -> Self {
+        let [r, g, b, a] = value.to_be_bytes();
+        Self::new(r, g, b, a)
+    }
+}
+
 /// A structure that represents a color as `f32` RGBA components.
 ///
 /// The bits of this structure can be directly reinterpreted as a float-packed color which uses the
@@ -201,7 +216,7 @@ impl From<(f32, f32, f32, f32)> for ColorF32 {
 /// for the current platform. For example, `Rgba32` is an alias for `Abgr8888` on little-endian CPUs
 /// like x86, or an alias for `Rgba8888` on big-endian CPUs.
 #[repr(u32)]
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum PixelFormat {
     Unknown = sys::SDL_PixelFormat_SDL_PIXELFORMAT_UNKNOWN,
     Index1Lsb = sys::SDL_PixelFormat_SDL_PIXELFORMAT_INDEX1LSB,
@@ -288,11 +303,106 @@ impl PixelFormat {
         unsafe { core::mem::transmute(format_val) }
     }
 
+    /// Converts a raw `SDL_PixelFormat` into a `PixelFormat`.
+    ///
+    /// Returns an error if `format` isn't a format known to SDL.
+    pub fn try_from_ll(format: sys::SDL_PixelFormat) -> Result<Self, Error> {
+        Ok(match format {
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_UNKNOWN => Self::Unknown,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_INDEX1LSB => Self::Index1Lsb,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_INDEX1MSB => Self::Index1Msb,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_INDEX2LSB => Self::Index2Lsb,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_INDEX2MSB => Self::Index2Msb,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_INDEX4LSB => Self::Index4Lsb,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_INDEX4MSB => Self::Index4Msb,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_INDEX8 => Self::Index8,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_RGB332 => Self::Rgb332,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_XRGB4444 => Self::Xrgb4444,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_XBGR4444 => Self::Xbgr4444,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_XRGB1555 => Self::Xrgb1555,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_XBGR1555 => Self::Xbgr1555,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_ARGB4444 => Self::Argb4444,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_RGBA4444 => Self::Rgba4444,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_ABGR4444 => Self::Abgr4444,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_BGRA4444 => Self::Bgra4444,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_ARGB1555 => Self::Argb1555,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_RGBA5551 => Self::Rgba5551,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_ABGR1555 => Self::Abgr1555,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_BGRA5551 => Self::Bgra5551,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_RGB565 => Self::Rgb565,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_BGR565 => Self::Bgr565,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_RGB24 => Self::Rgb24,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_BGR24 => Self::Bgr24,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_XRGB8888 => Self::Xrgb8888,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_RGBX8888 => Self::Rgbx8888,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_XBGR8888 => Self::Xbgr8888,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_BGRX8888 => Self::Bgrx8888,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_ARGB8888 => Self::Argb8888,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_RGBA8888 => Self::Rgba8888,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_ABGR8888 => Self::Abgr8888,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_BGRA8888 => Self::Bgra8888,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_XRGB2101010 => Self::Xrgb2101010,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_XBGR2101010 => Self::Xbgr2101010,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_ARGB2101010 => Self::Argb2101010,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_ABGR2101010 => Self::Abgr2101010,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_RGB48 => Self::Rgb48,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_BGR48 => Self::Bgr48,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_RGBA64 => Self::Rgba64,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_ARGB64 => Self::Argb64,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_BGRA64 => Self::Bgra64,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_ABGR64 => Self::Abgr64,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_RGB48_FLOAT => Self::Rgb48Float,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_BGR48_FLOAT => Self::Bgr48Float,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_RGBA64_FLOAT => Self::Rgba64Float,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_ARGB64_FLOAT => Self::Argb64Float,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_BGRA64_FLOAT => Self::Bgra64Float,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_ABGR64_FLOAT => Self::Abgr64Float,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_RGB96_FLOAT => Self::Rgb96Float,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_BGR96_FLOAT => Self::Bgr96Float,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_RGBA128_FLOAT => Self::Rgba128Float,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_ARGB128_FLOAT => Self::Argb128Float,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_BGRA128_FLOAT => Self::Bgra128Float,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_ABGR128_FLOAT => Self::Abgr128Float,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_YV12 => Self::Yv12,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_IYUV => Self::Iyuv,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_YUY2 => Self::Yuy2,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_UYVY => Self::Uyvy,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_YVYU => Self::Yvyu,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_NV12 => Self::Nv12,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_NV21 => Self::Nv21,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_P010 => Self::P010,
+            sys::SDL_PixelFormat_SDL_PIXELFORMAT_EXTERNAL_OES => Self::ExternalOes,
+            _ => return Err(Error::register(c"Unknown pixel format.")),
+        })
+    }
+
     #[inline]
     pub fn to_ll(&self) -> sys::SDL_PixelFormat {
         *self as u32
     }
 
+    /// Returns the number of bytes of an image in this format with `height` rows of `pitch`
+    /// bytes, including every plane of planar YUV formats.
+    ///
+    /// `pitch` and `height` are those of the Y plane for YUV formats.
+    pub fn byte_size_from_pitch_and_height(&self, pitch: usize, height: u32) -> usize {
+        let height = height as usize;
+        match self {
+            // 4:2:0 with separate U and V planes of half the pitch and height, rounded up.
+            PixelFormat::Yv12 | PixelFormat::Iyuv => {
+                pitch * height + 2 * (pitch.div_ceil(2) * height.div_ceil(2))
+            }
+            // 4:2:0 with one interleaved UV plane of half the height, rounded up. Each row holds a
+            // U and a V sample for every two pixels, so odd widths are rounded up too.
+            PixelFormat::Nv12 | PixelFormat::Nv21 => {
+                pitch * height + 2 * pitch.div_ceil(2) * height.div_ceil(2)
+            }
+            // Same as NV12 with 2-byte samples.
+            PixelFormat::P010 => pitch * height + 4 * (pitch / 2).div_ceil(2) * height.div_ceil(2),
+            _ => pitch * height,
+        }
+    }
+
     pub fn details(&self) -> Result<&PixelFormatDetails, Error> {
         let details = unsafe { sys::SDL_GetPixelFormatDetails(self.to_ll()) };
         if details.is_null() {
@@ -467,19 +577,10 @@ impl PixelFormatDetails {
         unsafe { (*self.raw()).bytes_per_pixel }
     }
 
+    /// See [`PixelFormat::byte_size_from_pitch_and_height`].
     #[inline]
     pub fn byte_size_from_pitch_and_height(&self, pitch: usize, height: u32) -> usize {
-        let height = height as usize;
-        match self.format() {
-            PixelFormat::Yv12 | PixelFormat::Iyuv => {
-                // YUV is 4:2:0.
-                // `pitch` is the width of the Y component, and
-                // `height` is the height of the Y component.
-                // U and V have half the width and height of Y.
-                pitch * height + 2 * (pitch / 2 * height / 2)
-            }
-            _ => pitch * height,
-        }
+        self.format().byte_size_from_pitch_and_height(pitch, height)
     }
 
     #[inline]
@@ -675,3 +776,77 @@ impl Colorspace {
         self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{any_pixel_format, PIXEL_FORMATS};
+    use proptest::prelude::*;
+
+    #[test]
+    fn pixel_format_round_trips() {
+        for format in PIXEL_FORMATS {
+            assert_eq!(PixelFormat::try_from_ll(format.to_ll()).ok(), Some(format));
+        }
+    }
+
+    #[test]
+    fn yuv_byte_size_includes_every_plane() {
+        // 5x3 pixels: chroma planes round up to 3x2.
+        assert_eq!(
+            PixelFormat::Yv12.byte_size_from_pitch_and_height(5, 3),
+            15 + 2 * 6
+        );
+        assert_eq!(
+            PixelFormat::Iyuv.byte_size_from_pitch_and_height(5, 3),
+            15 + 2 * 6
+        );
+        assert_eq!(
+            PixelFormat::Nv12.byte_size_from_pitch_and_height(6, 3),
+            18 + 12
+        );
+        assert_eq!(
+            PixelFormat::Nv21.byte_size_from_pitch_and_height(6, 3),
+            18 + 12
+        );
+        assert_eq!(
+            PixelFormat::P010.byte_size_from_pitch_and_height(12, 3),
+            36 + 24
+        );
+        assert_eq!(PixelFormat::Yuy2.byte_size_from_pitch_and_height(8, 3), 24);
+    }
+
+    #[test]
+    fn odd_sized_yuv_byte_size() {
+        // 3x3 pixels: a 9-byte Y plane and 2x2 chroma samples for U and V.
+        for format in [
+            PixelFormat::Yv12,
+            PixelFormat::Iyuv,
+            PixelFormat::Nv12,
+            PixelFormat::Nv21,
+        ] {
+            assert_eq!(format.byte_size_from_pitch_and_height(3, 3), 17);
+        }
+        assert_eq!(PixelFormat::P010.byte_size_from_pitch_and_height(6, 3), 34);
+    }
+
+    proptest! {
+        #[test]
+        fn pixel_format_try_from_ll_is_exact(value: u32) {
+            if let Ok(format) = PixelFormat::try_from_ll(value) {
+                prop_assert_eq!(format.to_ll(), value);
+            }
+        }
+
+        #[test]
+        fn byte_size_covers_every_row(
+            format in any_pixel_format(),
+            pitch in 0usize..8192,
+            height in 0u32..8192,
+        ) {
+            let size = format.byte_size_from_pitch_and_height(pitch, height);
+            prop_assert!(size >= pitch * height as usize);
+            prop_assert!(format.byte_size_from_pitch_and_height(pitch, height + 1) >= size);
+        }
+    }
+}
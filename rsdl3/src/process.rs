@@ -0,0 +1,313 @@
+use crate::init::SdlDrop;
+use crate::iostream::IOStream;
+use crate::properties::{Properties, PropertiesRef};
+use crate::sys;
+use crate::{init::Sdl, Error};
+use alloc::ffi::CString;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::ffi::{c_char, c_void};
+use core::ptr::NonNull;
+use core::time::Duration;
+
+/// A child process.
+///
+/// Dropping a `Process` doesn't kill it, but closes its pipes and stops tracking it.
+pub struct Process {
+    _sdl: Rc<SdlDrop>,
+    ptr: NonNull<sys::SDL_Process>,
+}
+
+impl Process {
+    /// Spawns a process. `args[0]` is the path to the executable and the rest of `args` are
+    /// passed on its command line.
+    ///
+    /// If `pipe_stdio` is true, the process's standard input and output are piped to the
+    /// application, see [`Process::input`] and [`Process::output`]. Otherwise the process gets
+    /// no input and inherits the application's standard output.
+    pub fn new(sdl: &Sdl, args: &[&str], pipe_stdio: bool) -> Result<Self, Error> {
+        let args = ProcessArgs::new(args)?;
+        let ptr = unsafe { sys::SDL_CreateProcess(args.as_ptr(), pipe_stdio) };
+        Self::from_ptr(sdl, ptr)
+    }
+
+    fn from_ptr(sdl: &Sdl, ptr: *mut sys::SDL_Process) -> Result<Self, Error> {
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            _sdl: Rc::clone(&sdl.drop),
+            ptr,
+        })
+    }
+
+    /// Returns the process's properties, such as its `SDL.process.pid`.
+    pub fn properties(&self) -> Result<PropertiesRef<'_>, Error> {
+        let id = unsafe { sys::SDL_GetProcessProperties(self.raw()) };
+        unsafe { PropertiesRef::from_ll(id) }
+    }
+
+    /// Returns the process ID.
+    pub fn pid(&self) -> Result<i64, Error> {
+        self.properties()?.number("SDL.process.pid", 0)
+    }
+
+    /// Returns the stream piped to the process's standard input.
+    ///
+    /// Writes may be short if the process isn't reading its input, e.g. because it's waiting
+    /// for its output to be read.
+    pub fn input(&mut self) -> Result<IOStream<'_>, Error> {
+        let ptr = unsafe { sys::SDL_GetProcessInput(self.raw()) };
+        // SAFETY: the stream is owned by the process, which is borrowed for as long as it.
+        unsafe { IOStream::from_borrowed_ptr(&self._sdl, ptr) }
+    }
+
+    /// Closes the process's standard input, so it reads an end of file.
+    pub fn close_input(&mut self) -> Result<(), Error> {
+        let ptr = unsafe { sys::SDL_GetProcessInput(self.raw()) };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        let result = unsafe { sys::SDL_CloseIO(ptr) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns the stream piped from the process's standard output.
+    ///
+    /// Reads return 0 bytes with a status of [`crate::iostream::IOStatus::NotReady`] while no
+    /// output is available.
+    pub fn output(&mut self) -> Result<IOStream<'_>, Error> {
+        let ptr = unsafe { sys::SDL_GetProcessOutput(self.raw()) };
+        // SAFETY: the stream is owned by the process, which is borrowed for as long as it.
+        unsafe { IOStream::from_borrowed_ptr(&self._sdl, ptr) }
+    }
+
+    /// Returns the stream piped from the process's standard error, if it was spawned with
+    /// [`ProcessIO::App`] for it.
+    pub fn error_output(&mut self) -> Result<IOStream<'_>, Error> {
+        let ptr = self.properties()?.pointer("SDL.process.stderr")?;
+        if ptr.is_null() {
+            return Err(Error::register(
+                c"Standard error is not piped to the application.",
+            ));
+        }
+        // SAFETY: the stream is owned by the process, which is borrowed for as long as it.
+        unsafe { IOStream::from_borrowed_ptr(&self._sdl, ptr as *mut sys::SDL_IOStream) }
+    }
+
+    /// Blocks until the process exits, then returns everything it wrote to its standard output
+    /// along with its exit code.
+    pub fn read_output(&mut self) -> Result<(Vec<u8>, i32), Error> {
+        let mut len = 0;
+        let mut exit_code = 0;
+        unsafe {
+            let ptr = sys::SDL_ReadProcess(self.raw(), &raw mut len, &raw mut exit_code);
+            if ptr.is_null() {
+                return Err(Error::new());
+            }
+            let bytes = core::slice::from_raw_parts(ptr as *const u8, len).to_vec();
+            sys::SDL_free(ptr);
+            Ok((bytes, exit_code))
+        }
+    }
+
+    /// Stops the process. If `force` is false, the process is asked to exit, e.g. with `SIGTERM`,
+    /// and may ignore it.
+    pub fn kill(&mut self, force: bool) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_KillProcess(self.raw(), force) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns the exit code of the process if it has exited, without blocking.
+    ///
+    /// Exit codes are negative if the process was terminated by a signal.
+    pub fn try_wait(&mut self) -> Option<i32> {
+        let mut exit_code = 0;
+        let exited = unsafe { sys::SDL_WaitProcess(self.raw(), false, &raw mut exit_code) };
+        exited.then_some(exit_code)
+    }
+
+    /// Blocks until the process exits, or until `timeout` elapses if it's not `None`, and returns
+    /// its exit code if it has exited.
+    ///
+    /// Read all of the process's piped output first, or it may never exit. SDL can only wait
+    /// indefinitely, so a timeout is implemented by polling every millisecond.
+    pub fn wait(&mut self, timeout: Option<Duration>) -> Option<i32> {
+        let Some(timeout) = timeout else {
+            let mut exit_code = 0;
+            let exited = unsafe { sys::SDL_WaitProcess(self.raw(), true, &raw mut exit_code) };
+            return exited.then_some(exit_code);
+        };
+        let deadline = unsafe { sys::SDL_GetTicks() }.saturating_add(timeout.as_millis() as u64);
+        loop {
+            if let Some(exit_code) = self.try_wait() {
+                return Some(exit_code);
+            }
+            if unsafe { sys::SDL_GetTicks() } >= deadline {
+                return None;
+            }
+            crate::sleep(1);
+        }
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_Process {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for Process {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_DestroyProcess(self.raw()) };
+    }
+}
+
+/// Spawns a [`Process`] with control over its standard streams.
+#[derive(Clone, Debug)]
+pub struct ProcessBuilder<'a> {
+    args: &'a [&'a str],
+    stdin: ProcessIO,
+    stdout: ProcessIO,
+    stderr: ProcessIO,
+    stderr_to_stdout: bool,
+    background: bool,
+}
+
+impl<'a> ProcessBuilder<'a> {
+    /// `args[0]` is the path to the executable and the rest of `args` are passed on its command
+    /// line. By default the process gets no input and inherits the application's output streams.
+    pub fn new(args: &'a [&'a str]) -> Self {
+        Self {
+            args,
+            stdin: ProcessIO::Null,
+            stdout: ProcessIO::Inherited,
+            stderr: ProcessIO::Inherited,
+            stderr_to_stdout: false,
+            background: false,
+        }
+    }
+
+    pub fn stdin(&mut self, io: ProcessIO) -> &mut Self {
+        self.stdin = io;
+        self
+    }
+
+    pub fn stdout(&mut self, io: ProcessIO) -> &mut Self {
+        self.stdout = io;
+        self
+    }
+
+    /// Sets where the process's standard error goes. If it's [`ProcessIO::App`], it can be read
+    /// with [`Process::error_output`].
+    pub fn stderr(&mut self, io: ProcessIO) -> &mut Self {
+        self.stderr = io;
+        self
+    }
+
+    /// Sends the process's standard error to its standard output, overriding
+    /// [`ProcessBuilder::stderr`].
+    pub fn stderr_to_stdout(&mut self) -> &mut Self {
+        self.stderr_to_stdout = true;
+        self
+    }
+
+    /// Runs the process in the background. On Windows this doesn't create a console window.
+    ///
+    /// The exit code of a background process is not available and is always reported as 0.
+    pub fn background(&mut self) -> &mut Self {
+        self.background = true;
+        self
+    }
+
+    pub fn spawn(&self, sdl: &Sdl) -> Result<Process, Error> {
+        let args = ProcessArgs::new(self.args)?;
+        let mut props = Properties::new()?;
+        // SAFETY: the arguments outlive the process creation, which copies them.
+        unsafe { props.set_pointer("SDL.process.create.args", args.as_ptr() as *mut c_void)? };
+        props.set_number("SDL.process.create.stdin_option", self.stdin.to_ll() as i64)?;
+        props.set_number(
+            "SDL.process.create.stdout_option",
+            self.stdout.to_ll() as i64,
+        )?;
+        props.set_number(
+            "SDL.process.create.stderr_option",
+            self.stderr.to_ll() as i64,
+        )?;
+        props.set_boolean("SDL.process.create.stderr_to_stdout", self.stderr_to_stdout)?;
+        props.set_boolean("SDL.process.create.background", self.background)?;
+        let ptr = unsafe { sys::SDL_CreateProcessWithProperties(props.raw()) };
+        Process::from_ptr(sdl, ptr)
+    }
+}
+
+/// Where a standard stream of a [`Process`] goes.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProcessIO {
+    /// The stream is shared with the application.
+    Inherited = sys::SDL_ProcessIO_SDL_PROCESS_STDIO_INHERITED,
+    /// The stream is redirected to the null device.
+    Null = sys::SDL_ProcessIO_SDL_PROCESS_STDIO_NULL,
+    /// The stream is piped to the application, see [`Process::input`] and [`Process::output`].
+    App = sys::SDL_ProcessIO_SDL_PROCESS_STDIO_APP,
+}
+
+impl ProcessIO {
+    /// Converts a raw `SDL_ProcessIO` into a `ProcessIO`.
+    pub fn try_from_ll(ll: sys::SDL_ProcessIO) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_ProcessIO_SDL_PROCESS_STDIO_INHERITED => Self::Inherited,
+            sys::SDL_ProcessIO_SDL_PROCESS_STDIO_NULL => Self::Null,
+            sys::SDL_ProcessIO_SDL_PROCESS_STDIO_APP => Self::App,
+            _ => return Err(Error::register(c"Unknown process IO.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_ProcessIO {
+        *self as u32
+    }
+}
+
+/// A NULL-terminated array of C strings, as SDL expects for a process's arguments.
+struct ProcessArgs {
+    _strings: Vec<CString>,
+    ptrs: Vec<*const c_char>,
+}
+
+impl ProcessArgs {
+    fn new(args: &[&str]) -> Result<Self, Error> {
+        if args.is_empty() {
+            return Err(Error::register(c"A process needs at least an executable."));
+        }
+        let strings = args
+            .iter()
+            .map(|&arg| CString::new(arg))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut ptrs: Vec<*const c_char> = strings.iter().map(|arg| arg.as_ptr()).collect();
+        ptrs.push(core::ptr::null());
+        Ok(Self {
+            _strings: strings,
+            ptrs,
+        })
+    }
+
+    fn as_ptr(&self) -> *const *const c_char {
+        self.ptrs.as_ptr()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(ProcessIO: Inherited, Null, App);
+    }
+}
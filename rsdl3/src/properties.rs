@@ -0,0 +1,567 @@
+use crate::{sys, Error};
+use alloc::boxed::Box;
+use alloc::ffi::CString;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::{c_char, c_void, CStr};
+use core::fmt::Write;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ops::Deref;
+
+/// Id of a group of properties.
+pub type PropertiesId = sys::SDL_PropertiesID;
+
+/// A group of named properties.
+///
+/// Properties are used by many SDL APIs to pass optional or backend-specific parameters, such as
+/// [`crate::video::Window::with_properties`], and to expose internal handles, such as
+/// [`crate::video::WindowRef::properties`].
+///
+/// Property names are case-sensitive UTF-8 strings, like `"SDL.window.create.title"`.
+pub struct Properties {
+    id: PropertiesId,
+}
+
+impl Properties {
+    /// Creates a new, empty group of properties.
+    pub fn new() -> Result<Self, Error> {
+        let id = unsafe { sys::SDL_CreateProperties() };
+        if id == 0 {
+            return Err(Error::new());
+        }
+        Ok(Self { id })
+    }
+
+    /// Returns the global SDL properties.
+    pub fn global() -> Result<PropertiesRefMut<'static>, Error> {
+        unsafe { PropertiesRefMut::from_ll(sys::SDL_GetGlobalProperties()) }
+    }
+
+    /// Copies every property of this group into `dst`, replacing the ones with the same name.
+    ///
+    /// Pointer properties with a cleanup callback are not copied.
+    pub fn copy_to(&self, dst: &mut Properties) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_CopyProperties(self.id, dst.id) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns true if the group has a property called `name`.
+    pub fn has(&self, name: &str) -> Result<bool, Error> {
+        let name = CString::new(name)?;
+        Ok(unsafe { sys::SDL_HasProperty(self.id, name.as_ptr()) })
+    }
+
+    /// Returns the type of the property called `name`.
+    pub fn property_type(&self, name: &str) -> Result<PropertyType, Error> {
+        let name = CString::new(name)?;
+        let ll = unsafe { sys::SDL_GetPropertyType(self.id, name.as_ptr()) };
+        Ok(PropertyType::from_ll(ll))
+    }
+
+    /// Returns the names of every property in the group, in no particular order.
+    pub fn names(&self) -> Result<Vec<String>, Error> {
+        let mut names: Vec<String> = Vec::new();
+        let result = unsafe {
+            sys::SDL_EnumerateProperties(
+                self.id,
+                Some(property_name_marshall),
+                &raw mut names as *mut c_void,
+            )
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(names)
+    }
+
+    /// Returns the value of a string property, or `None` if it isn't set.
+    ///
+    /// Number, float and boolean properties are converted to strings.
+    pub fn string(&self, name: &str) -> Result<Option<String>, Error> {
+        let name = CString::new(name)?;
+        let ptr = unsafe { sys::SDL_GetStringProperty(self.id, name.as_ptr(), core::ptr::null()) };
+        if ptr.is_null() {
+            return Ok(None);
+        }
+        Ok(Some(
+            unsafe { CStr::from_ptr(ptr) }
+                .to_string_lossy()
+                .into_owned(),
+        ))
+    }
+
+    /// Returns the value of a number property, or `default` if it isn't set.
+    ///
+    /// Float and boolean properties are converted to numbers.
+    pub fn number(&self, name: &str, default: i64) -> Result<i64, Error> {
+        let name = CString::new(name)?;
+        Ok(unsafe { sys::SDL_GetNumberProperty(self.id, name.as_ptr(), default) })
+    }
+
+    /// Returns the value of a float property, or `default` if it isn't set.
+    ///
+    /// Number and boolean properties are converted to floats.
+    pub fn float(&self, name: &str, default: f32) -> Result<f32, Error> {
+        let name = CString::new(name)?;
+        Ok(unsafe { sys::SDL_GetFloatProperty(self.id, name.as_ptr(), default) })
+    }
+
+    /// Returns the value of a boolean property, or `default` if it isn't set.
+    ///
+    /// Number and float properties are converted to booleans.
+    pub fn boolean(&self, name: &str, default: bool) -> Result<bool, Error> {
+        let name = CString::new(name)?;
+        Ok(unsafe { sys::SDL_GetBooleanProperty(self.id, name.as_ptr(), default) })
+    }
+
+    /// Returns the value of a pointer property, or a null pointer if it isn't set.
+    pub fn pointer(&self, name: &str) -> Result<*mut c_void, Error> {
+        let name = CString::new(name)?;
+        Ok(unsafe { sys::SDL_GetPointerProperty(self.id, name.as_ptr(), core::ptr::null_mut()) })
+    }
+
+    /// Sets a string property. SDL keeps its own copy of `value`.
+    pub fn set_string(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        let name = CString::new(name)?;
+        let value = CString::new(value)?;
+        let result = unsafe { sys::SDL_SetStringProperty(self.id, name.as_ptr(), value.as_ptr()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Sets a number property.
+    pub fn set_number(&mut self, name: &str, value: i64) -> Result<(), Error> {
+        let name = CString::new(name)?;
+        let result = unsafe { sys::SDL_SetNumberProperty(self.id, name.as_ptr(), value) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Sets a float property.
+    pub fn set_float(&mut self, name: &str, value: f32) -> Result<(), Error> {
+        let name = CString::new(name)?;
+        let result = unsafe { sys::SDL_SetFloatProperty(self.id, name.as_ptr(), value) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Sets a boolean property.
+    pub fn set_boolean(&mut self, name: &str, value: bool) -> Result<(), Error> {
+        let name = CString::new(name)?;
+        let result = unsafe { sys::SDL_SetBooleanProperty(self.id, name.as_ptr(), value) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Sets a pointer property. Setting a null pointer deletes the property.
+    ///
+    /// SDL doesn't take ownership of `value`; use [`Properties::set_pointer_with_cleanup`] to
+    /// release it once the property is deleted.
+    ///
+    /// # Safety
+    ///
+    /// SDL APIs reading the property dereference `value`, so it must point to the type they
+    /// expect and stay valid for as long as the property is set.
+    pub unsafe fn set_pointer(&mut self, name: &str, value: *mut c_void) -> Result<(), Error> {
+        let name = CString::new(name)?;
+        let result = unsafe { sys::SDL_SetPointerProperty(self.id, name.as_ptr(), value) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Sets a pointer property, calling `cleanup` with `value` once the property is deleted,
+    /// replaced or the group is destroyed.
+    ///
+    /// `cleanup` is also called if setting the property fails.
+    ///
+    /// # Safety
+    ///
+    /// Same requirements as [`Properties::set_pointer`], until `cleanup` is called.
+    pub unsafe fn set_pointer_with_cleanup<C: PropertyCleanupCallback>(
+        &mut self,
+        name: &str,
+        value: *mut c_void,
+        cleanup: C,
+    ) -> Result<(), Error> {
+        let name = match CString::new(name) {
+            Ok(name) => name,
+            Err(err) => {
+                let mut cleanup = cleanup;
+                cleanup.cleanup(value);
+                return Err(err.into());
+            }
+        };
+        let user_data = Box::into_raw(Box::new(cleanup));
+        let result = unsafe {
+            sys::SDL_SetPointerPropertyWithCleanup(
+                self.id,
+                name.as_ptr(),
+                value,
+                Some(property_cleanup_marshall::<C>),
+                user_data as *mut c_void,
+            )
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Deletes the property called `name`, if it exists.
+    pub fn clear(&mut self, name: &str) -> Result<(), Error> {
+        let name = CString::new(name)?;
+        let result = unsafe { sys::SDL_ClearProperty(self.id, name.as_ptr()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Writes every property of the group into a `String`, one `name (type) = value` line per
+    /// property, sorted by name.
+    pub fn dump(&self) -> Result<String, Error> {
+        dump_properties(self.id)
+    }
+
+    #[inline]
+    pub fn raw(&self) -> PropertiesId {
+        self.id
+    }
+}
+
+impl Drop for Properties {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_DestroyProperties(self.id) };
+    }
+}
+
+/// A group of properties owned by SDL or by another object, such as a window.
+///
+/// The group is not destroyed when this reference is dropped. It can only be read; owners that
+/// allow changing their properties return a [`PropertiesRefMut`] from a method taking `&mut self`.
+pub struct PropertiesRef<'a> {
+    // Never dropped: the group belongs to someone else. Not exposed, so it can't be moved out.
+    properties: ManuallyDrop<Properties>,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl PropertiesRef<'_> {
+    /// SAFETY: `id` must be 0 or a group of properties that outlives the returned reference.
+    pub(crate) unsafe fn from_ll(id: PropertiesId) -> Result<Self, Error> {
+        if id == 0 {
+            return Err(Error::new());
+        }
+        Ok(Self {
+            properties: ManuallyDrop::new(Properties { id }),
+            _marker: PhantomData,
+        })
+    }
+
+    /// See [`Properties::copy_to`].
+    pub fn copy_to(&self, dst: &mut Properties) -> Result<(), Error> {
+        self.properties.copy_to(dst)
+    }
+
+    /// See [`Properties::has`].
+    pub fn has(&self, name: &str) -> Result<bool, Error> {
+        self.properties.has(name)
+    }
+
+    /// See [`Properties::property_type`].
+    pub fn property_type(&self, name: &str) -> Result<PropertyType, Error> {
+        self.properties.property_type(name)
+    }
+
+    /// See [`Properties::names`].
+    pub fn names(&self) -> Result<Vec<String>, Error> {
+        self.properties.names()
+    }
+
+    /// See [`Properties::string`].
+    pub fn string(&self, name: &str) -> Result<Option<String>, Error> {
+        self.properties.string(name)
+    }
+
+    /// See [`Properties::number`].
+    pub fn number(&self, name: &str, default: i64) -> Result<i64, Error> {
+        self.properties.number(name, default)
+    }
+
+    /// See [`Properties::float`].
+    pub fn float(&self, name: &str, default: f32) -> Result<f32, Error> {
+        self.properties.float(name, default)
+    }
+
+    /// See [`Properties::boolean`].
+    pub fn boolean(&self, name: &str, default: bool) -> Result<bool, Error> {
+        self.properties.boolean(name, default)
+    }
+
+    /// See [`Properties::pointer`].
+    pub fn pointer(&self, name: &str) -> Result<*mut c_void, Error> {
+        self.properties.pointer(name)
+    }
+
+    /// See [`Properties::dump`].
+    pub fn dump(&self) -> Result<String, Error> {
+        self.properties.dump()
+    }
+
+    #[inline]
+    pub fn raw(&self) -> PropertiesId {
+        self.properties.id
+    }
+}
+
+/// A group of properties owned by SDL or by another object, which can be changed.
+///
+/// Obtained from methods that borrow the owner mutably, such as
+/// [`crate::video::WindowRef::properties_mut`]. Dereferences to [`PropertiesRef`] for reading.
+pub struct PropertiesRefMut<'a> {
+    properties: PropertiesRef<'a>,
+    _marker: PhantomData<&'a mut ()>,
+}
+
+impl PropertiesRefMut<'_> {
+    /// SAFETY: `id` must be 0 or a group of properties that outlives the returned reference.
+    pub(crate) unsafe fn from_ll(id: PropertiesId) -> Result<Self, Error> {
+        Ok(Self {
+            properties: unsafe { PropertiesRef::from_ll(id)? },
+            _marker: PhantomData,
+        })
+    }
+
+    fn inner(&mut self) -> &mut Properties {
+        &mut self.properties.properties
+    }
+
+    /// See [`Properties::set_string`].
+    pub fn set_string(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        self.inner().set_string(name, value)
+    }
+
+    /// See [`Properties::set_number`].
+    pub fn set_number(&mut self, name: &str, value: i64) -> Result<(), Error> {
+        self.inner().set_number(name, value)
+    }
+
+    /// See [`Properties::set_float`].
+    pub fn set_float(&mut self, name: &str, value: f32) -> Result<(), Error> {
+        self.inner().set_float(name, value)
+    }
+
+    /// See [`Properties::set_boolean`].
+    pub fn set_boolean(&mut self, name: &str, value: bool) -> Result<(), Error> {
+        self.inner().set_boolean(name, value)
+    }
+
+    /// See [`Properties::set_pointer`].
+    ///
+    /// # Safety
+    ///
+    /// Same requirements as [`Properties::set_pointer`].
+    pub unsafe fn set_pointer(&mut self, name: &str, value: *mut c_void) -> Result<(), Error> {
+        unsafe { self.inner().set_pointer(name, value) }
+    }
+
+    /// See [`Properties::set_pointer_with_cleanup`].
+    ///
+    /// # Safety
+    ///
+    /// Same requirements as [`Properties::set_pointer`], until `cleanup` is called.
+    pub unsafe fn set_pointer_with_cleanup<C: PropertyCleanupCallback>(
+        &mut self,
+        name: &str,
+        value: *mut c_void,
+        cleanup: C,
+    ) -> Result<(), Error> {
+        unsafe { self.inner().set_pointer_with_cleanup(name, value, cleanup) }
+    }
+
+    /// See [`Properties::clear`].
+    pub fn clear(&mut self, name: &str) -> Result<(), Error> {
+        self.inner().clear(name)
+    }
+}
+
+impl<'a> Deref for PropertiesRefMut<'a> {
+    type Target = PropertiesRef<'a>;
+
+    fn deref(&self) -> &PropertiesRef<'a> {
+        &self.properties
+    }
+}
+
+/// The type of a property.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PropertyType {
+    Invalid = sys::SDL_PropertyType_SDL_PROPERTY_TYPE_INVALID,
+    Pointer = sys::SDL_PropertyType_SDL_PROPERTY_TYPE_POINTER,
+    String = sys::SDL_PropertyType_SDL_PROPERTY_TYPE_STRING,
+    Number = sys::SDL_PropertyType_SDL_PROPERTY_TYPE_NUMBER,
+    Float = sys::SDL_PropertyType_SDL_PROPERTY_TYPE_FLOAT,
+    Boolean = sys::SDL_PropertyType_SDL_PROPERTY_TYPE_BOOLEAN,
+}
+
+impl PropertyType {
+    /// Converts a raw `SDL_PropertyType` into a `PropertyType`.
+    pub fn try_from_ll(ll: sys::SDL_PropertyType) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_PropertyType_SDL_PROPERTY_TYPE_INVALID => Self::Invalid,
+            sys::SDL_PropertyType_SDL_PROPERTY_TYPE_POINTER => Self::Pointer,
+            sys::SDL_PropertyType_SDL_PROPERTY_TYPE_STRING => Self::String,
+            sys::SDL_PropertyType_SDL_PROPERTY_TYPE_NUMBER => Self::Number,
+            sys::SDL_PropertyType_SDL_PROPERTY_TYPE_FLOAT => Self::Float,
+            sys::SDL_PropertyType_SDL_PROPERTY_TYPE_BOOLEAN => Self::Boolean,
+            _ => return Err(Error::register(c"Unknown property type.")),
+        })
+    }
+
+    pub fn from_ll(ll: sys::SDL_PropertyType) -> Self {
+        match ll {
+            sys::SDL_PropertyType_SDL_PROPERTY_TYPE_POINTER => Self::Pointer,
+            sys::SDL_PropertyType_SDL_PROPERTY_TYPE_STRING => Self::String,
+            sys::SDL_PropertyType_SDL_PROPERTY_TYPE_NUMBER => Self::Number,
+            sys::SDL_PropertyType_SDL_PROPERTY_TYPE_FLOAT => Self::Float,
+            sys::SDL_PropertyType_SDL_PROPERTY_TYPE_BOOLEAN => Self::Boolean,
+            _ => Self::Invalid,
+        }
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_PropertyType {
+        *self as u32
+    }
+}
+
+/// Defines how the value of a pointer property is released.
+///
+/// The callback may run on any thread that deletes the property or destroys its group.
+pub trait PropertyCleanupCallback: Send {
+    fn cleanup(&mut self, value: *mut c_void);
+}
+
+unsafe extern "C" fn property_cleanup_marshall<C: PropertyCleanupCallback>(
+    user_data: *mut c_void,
+    value: *mut c_void,
+) {
+    let mut callback: Box<C> = unsafe { Box::from_raw(user_data as *mut C) };
+    callback.cleanup(value);
+}
+
+unsafe extern "C" fn property_name_marshall(
+    user_data: *mut c_void,
+    _props: sys::SDL_PropertiesID,
+    name: *const c_char,
+) {
+    let names: &mut Vec<String> = unsafe { &mut *(user_data as *mut Vec<String>) };
+    names.push(
+        unsafe { CStr::from_ptr(name) }
+            .to_string_lossy()
+            .into_owned(),
+    );
+}
+
+/// Writes every property of a property group into a `String`, one `name (type) = value` line
+/// per property, sorted by name.
+///
+/// Used by the `dump_properties` diagnostics of windows, renderers and textures.
+pub(crate) fn dump_properties(props: sys::SDL_PropertiesID) -> Result<String, Error> {
+    if props == 0 {
+        return Err(Error::new());
+    }
+    let mut lines: Vec<String> = Vec::new();
+    let result = unsafe {
+        sys::SDL_EnumerateProperties(
+            props,
+            Some(dump_property_marshall),
+            &raw mut lines as *mut c_void,
+        )
+    };
+    if !result {
+        return Err(Error::new());
+    }
+    lines.sort_unstable();
+    let mut dump = String::new();
+    for line in lines {
+        dump.push_str(&line);
+        dump.push('\n');
+    }
+    Ok(dump)
+}
+
+unsafe extern "C" fn dump_property_marshall(
+    user_data: *mut c_void,
+    props: sys::SDL_PropertiesID,
+    name: *const c_char,
+) {
+    let lines: &mut Vec<String> = unsafe { &mut *(user_data as *mut Vec<String>) };
+    let mut line = unsafe { CStr::from_ptr(name) }
+        .to_string_lossy()
+        .into_owned();
+    // Writing into a String can't fail.
+    let _ = unsafe {
+        match sys::SDL_GetPropertyType(props, name) {
+            sys::SDL_PropertyType_SDL_PROPERTY_TYPE_POINTER => write!(
+                line,
+                " (pointer) = {:p}",
+                sys::SDL_GetPointerProperty(props, name, core::ptr::null_mut())
+            ),
+            sys::SDL_PropertyType_SDL_PROPERTY_TYPE_STRING => {
+                let value = sys::SDL_GetStringProperty(props, name, core::ptr::null());
+                if value.is_null() {
+                    write!(line, " (string) = <null>")
+                } else {
+                    write!(
+                        line,
+                        " (string) = {:?}",
+                        CStr::from_ptr(value).to_string_lossy()
+                    )
+                }
+            }
+            sys::SDL_PropertyType_SDL_PROPERTY_TYPE_NUMBER => write!(
+                line,
+                " (number) = {}",
+                sys::SDL_GetNumberProperty(props, name, 0)
+            ),
+            sys::SDL_PropertyType_SDL_PROPERTY_TYPE_FLOAT => write!(
+                line,
+                " (float) = {}",
+                sys::SDL_GetFloatProperty(props, name, 0.0)
+            ),
+            sys::SDL_PropertyType_SDL_PROPERTY_TYPE_BOOLEAN => write!(
+                line,
+                " (boolean) = {}",
+                sys::SDL_GetBooleanProperty(props, name, false)
+            ),
+            _ => write!(line, " (invalid)"),
+        }
+    };
+    lines.push(line);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(PropertyType: Invalid, Pointer, String, Number, Float, Boolean);
+    }
+}
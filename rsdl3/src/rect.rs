@@ -125,6 +125,36 @@ impl Rect {
         }
     }
 
+    /// Returns true if the rectangle has no area.
+    ///
+    /// Rectangles built through [`Rect::new`] or [`Rect::from_ll`] are never empty, since their
+    /// dimensions are clamped to at least 1.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.w <= 0 || self.0.h <= 0
+    }
+
+    /// Returns true if `point` lies within the rectangle.
+    ///
+    /// The right and bottom edges are exclusive, so a point at `x() + w()` is outside.
+    #[inline]
+    pub fn contains_point(&self, point: Point) -> bool {
+        let (x, y) = (point.x() as i64, point.y() as i64);
+        x >= self.0.x as i64
+            && y >= self.0.y as i64
+            && x < self.0.x as i64 + self.0.w as i64
+            && y < self.0.y as i64 + self.0.h as i64
+    }
+
+    /// Returns true if `other` lies entirely within the rectangle.
+    #[inline]
+    pub fn contains_rect(&self, other: Rect) -> bool {
+        other.0.x as i64 >= self.0.x as i64
+            && other.0.y as i64 >= self.0.y as i64
+            && other.0.x as i64 + other.0.w as i64 <= self.0.x as i64 + self.0.w as i64
+            && other.0.y as i64 + other.0.h as i64 <= self.0.y as i64 + self.0.h as i64
+    }
+
     #[inline]
     pub fn to_ll(self) -> sys::SDL_Rect {
         self.0
@@ -230,6 +260,42 @@ impl RectF32 {
         }
     }
 
+    /// Returns true if the rectangle has no area.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        // Written this way so NaN dimensions are considered empty too.
+        !(self.0.w > 0.0 && self.0.h > 0.0)
+    }
+
+    /// Returns true if both rectangles are equal within `epsilon`, unlike `==`, which is exact.
+    #[inline]
+    pub fn approx_eq(&self, other: RectF32, epsilon: f32) -> bool {
+        (self.0.x - other.0.x).abs() <= epsilon
+            && (self.0.y - other.0.y).abs() <= epsilon
+            && (self.0.w - other.0.w).abs() <= epsilon
+            && (self.0.h - other.0.h).abs() <= epsilon
+    }
+
+    /// Returns true if `point` lies within the rectangle.
+    ///
+    /// Unlike [`Rect::contains_point`], the right and bottom edges are inclusive.
+    #[inline]
+    pub fn contains_point(&self, point: PointF32) -> bool {
+        point.x() >= self.0.x
+            && point.y() >= self.0.y
+            && point.x() <= self.0.x + self.0.w
+            && point.y() <= self.0.y + self.0.h
+    }
+
+    /// Returns true if `other` lies entirely within the rectangle.
+    #[inline]
+    pub fn contains_rect(&self, other: RectF32) -> bool {
+        other.0.x >= self.0.x
+            && other.0.y >= self.0.y
+            && other.0.x + other.0.w <= self.0.x + self.0.w
+            && other.0.y + other.0.h <= self.0.y + self.0.h
+    }
+
     #[inline]
     pub fn to_ll(self) -> sys::SDL_FRect {
         self.0
@@ -240,6 +306,26 @@ impl RectF32 {
     }
 }
 
+impl PartialEq for Rect {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.x == other.0.x
+            && self.0.y == other.0.y
+            && self.0.w == other.0.w
+            && self.0.h == other.0.h
+    }
+}
+
+impl Eq for Rect {}
+
+impl PartialEq for RectF32 {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.x == other.0.x
+            && self.0.y == other.0.y
+            && self.0.w == other.0.w
+            && self.0.h == other.0.h
+    }
+}
+
 impl From<Rect> for RectF32 {
     fn from(value: Rect) -> Self {
         RectF32::new(
@@ -348,6 +434,41 @@ impl Default for PointF32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn rect_corners_never_overflow(x: i32, y: i32, w: u32, h: u32) {
+            let rect = Rect::new(x, y, w, h);
+            prop_assert!(rect.w() >= 1 && rect.h() >= 1);
+            prop_assert!(rect.x().checked_add(rect.w() as i32).is_some());
+            prop_assert!(rect.y().checked_add(rect.h() as i32).is_some());
+            let raw = Rect::from_ll(sys::SDL_Rect { x, y, w: w as i32, h: h as i32 });
+            prop_assert!(raw.w() >= 1 && raw.h() >= 1);
+            prop_assert!(raw.x().checked_add(raw.w() as i32).is_some());
+        }
+
+        #[test]
+        fn rect_setters_clamp_like_new(x: i32, y: i32, w: u32, h: u32) {
+            let mut rect = Rect::new(0, 0, 1, 1);
+            rect.set_x(x);
+            rect.set_y(y);
+            rect.set_w(w);
+            rect.set_h(h);
+            prop_assert_eq!(rect, Rect::new(x, y, w, h));
+        }
+
+        #[test]
+        fn rect_contains_its_own_corners(x: i32, y: i32, w: u32, h: u32) {
+            let rect = Rect::new(x, y, w, h);
+            prop_assert!(rect.contains_point(Point::new(rect.x(), rect.y())));
+            let far_x = rect.x() + rect.w() as i32 - 1;
+            let far_y = rect.y() + rect.h() as i32 - 1;
+            prop_assert!(rect.contains_point(Point::new(far_x, far_y)));
+            prop_assert!(!rect.contains_point(Point::new(far_x + 1, far_y)));
+            prop_assert!(rect.contains_rect(rect));
+        }
+    }
 
     #[test]
     fn rect_bounds_dont_overflow() {
@@ -373,4 +494,38 @@ mod tests {
         assert_eq!(rect.h(), MAX_INT);
         assert!(!rect.x().overflowing_add(rect.w() as i32).1);
     }
+
+    #[test]
+    fn rect_contains_excludes_far_edges() {
+        let rect = Rect::new(10, 20, 30, 40);
+        assert!(!rect.is_empty());
+        assert!(rect.contains_point(Point::new(10, 20)));
+        assert!(rect.contains_point(Point::new(39, 59)));
+        assert!(!rect.contains_point(Point::new(40, 59)));
+        assert!(!rect.contains_point(Point::new(39, 60)));
+        assert!(rect.contains_rect(Rect::new(10, 20, 30, 40)));
+        assert!(rect.contains_rect(Rect::new(15, 25, 5, 5)));
+        assert!(!rect.contains_rect(Rect::new(15, 25, 30, 5)));
+        assert_eq!(rect, Rect::new(10, 20, 30, 40));
+    }
+
+    #[test]
+    fn rect_f32_comparisons() {
+        let rect = RectF32::new(0.0, 0.0, 10.0, 10.0);
+        assert!(rect.contains_point(PointF32::new(10.0, 10.0)));
+        assert!(!rect.contains_point(PointF32::new(10.5, 0.0)));
+        assert!(rect.contains_rect(RectF32::new(2.0, 2.0, 8.0, 8.0)));
+        assert!(!rect.contains_rect(RectF32::new(2.0, 2.0, 8.5, 8.0)));
+        assert!(rect.approx_eq(RectF32::new(0.05, 0.0, 10.0, 9.95), 0.1));
+        assert!(!rect.approx_eq(RectF32::new(0.2, 0.0, 10.0, 10.0), 0.1));
+        assert_eq!(rect, RectF32::new(0.0, 0.0, 10.0, 10.0));
+        assert_ne!(rect, RectF32::new(f32::EPSILON, 0.0, 10.0, 10.0));
+        assert!(RectF32::from_ll(sys::SDL_FRect {
+            x: 0.0,
+            y: 0.0,
+            w: 0.0,
+            h: 5.0
+        })
+        .is_empty());
+    }
 }
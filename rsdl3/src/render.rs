@@ -1,34 +1,75 @@
 use crate::blendmode::BlendMode;
-use crate::events::Event;
+use crate::events::{Event, EventPayload, RenderEventPayload};
+#[cfg(feature = "image")]
+use crate::iostream::IOStream;
 use crate::pixels::{Color, ColorF32, PixelFormat};
+use crate::properties::{PropertiesRef, PropertiesRefMut};
 use crate::rect::{Point, PointF32, Rect, RectF32};
-use crate::surface::{FlipMode, ScaleMode, Surface, SurfaceRef};
-use crate::video::{Window, WindowRef};
+use crate::surface::{FlipMode, ScaleMode, StagingBuffer, Surface, SurfaceRef};
+use crate::video::{VisibilityTracker, Window, WindowRef};
 use crate::{sys, Error, VideoSubsystem};
+use alloc::boxed::Box;
 use alloc::ffi::CString;
-use alloc::rc::Rc;
 use alloc::string::String;
-use core::cell::RefCell;
-use core::ffi::CStr;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
+use core::ffi::{c_void, CStr};
 use core::hint::unreachable_unchecked;
-use core::mem::{ManuallyDrop, MaybeUninit};
+use core::marker::PhantomData;
+use core::mem::{align_of, offset_of, size_of, ManuallyDrop, MaybeUninit};
 use core::ptr::NonNull;
+use core::time::Duration;
+
+mod capture;
+mod command_list;
+mod compositor;
+mod diagnostics;
+mod profiler;
+
+pub use capture::{CaptureFormat, CaptureThrottle, FrameCapture};
+pub use command_list::CommandList;
+pub use compositor::Compositor;
+pub use diagnostics::{DriverProbe, RendererDiagnostics};
+pub use profiler::{FrameReport, RenderProfiler, ScopeTiming};
+
+/// Number of points submitted per draw call by [`Renderer::render_points_iter`].
+const POINT_BATCH_SIZE: usize = 256;
+
+/// Reference count shared by a renderer and its textures. Textures can only be sent to other
+/// threads with the `send` feature, which needs an atomic count.
+#[cfg(not(feature = "send"))]
+type Shared<T> = alloc::rc::Rc<T>;
+#[cfg(feature = "send")]
+type Shared<T> = alloc::sync::Arc<T>;
+
+/// The width and height in pixels of a glyph drawn by [`Renderer::render_debug_text`].
+pub const DEBUG_TEXT_CHARACTER_SIZE: f32 = sys::SDL_DEBUG_TEXT_FONT_CHARACTER_SIZE as f32;
 
 /// A structure representing rendering state.
 ///
 /// Dropping the renderer does not necessarily mean the raw SDL renderer
 /// will be destroyed; same for its' backbuffer. Internally, the raw
 /// SDL renderer is shared by any [`Texture`]s created by this struct.
+///
+/// Rendering is only supported on the main thread, so the renderer can't be sent to other
+/// threads. Neither can its textures, unless the `send` feature is enabled; see [`Texture`].
+///
+#[cfg_attr(not(feature = "send"), doc = "```compile_fail")]
+#[cfg_attr(feature = "send", doc = "```")]
+/// fn assert_send<T: Send>() {}
+/// assert_send::<rsdl3::render::Texture>();
+/// ```
 pub struct Renderer<T = Window> {
     /// An internal renderer that takes care of destroying the raw renderer
     /// once it goes out of scope.
     /// Textures created by this renderer will also hold references to the
     /// internal renderer.
-    internal: Rc<RendererInternal<T>>,
+    internal: Shared<RendererInternal<T>>,
     /// The owner of the renderer can be a window, an owned surface or a
     /// borrowed surface. We need mutable access to the owner while this
     /// struct is alive. If this struct gets dropped and it's not the sole
-    /// owner of `internal` ([`Rc::strong_count`]), then we move the owner
+    /// owner of `internal` (its strong count), then we move the owner
     /// to the internal renderer so it can be dropped later.
     ///
     /// SAFETY: `owner` must be `Some` until this value gets dropped.
@@ -51,12 +92,9 @@ impl Renderer<Window> {
             };
             let driver = driver.map(|s| s.as_ptr()).unwrap_or(core::ptr::null());
             let ptr = sys::SDL_CreateRenderer(window.as_mut_ptr(), driver);
-            let ptr = NonNull::new(ptr).ok_or(Error::new())?;
+            let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
             Ok(Self {
-                internal: Rc::new(RendererInternal {
-                    ptr,
-                    owner: RefCell::new(None),
-                }),
+                internal: RendererInternal::new_shared(ptr),
                 owner: Some(window),
             })
         }
@@ -101,12 +139,9 @@ impl<'a> Renderer<Surface<'a>> {
     pub fn from_owned_surface(surface: Surface<'a>) -> Result<Self, Error> {
         unsafe {
             let ptr = sys::SDL_CreateSoftwareRenderer(surface.raw());
-            let ptr = NonNull::new(ptr).ok_or(Error::new())?;
+            let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
             Ok(Self {
-                internal: Rc::new(RendererInternal {
-                    ptr,
-                    owner: RefCell::new(None),
-                }),
+                internal: RendererInternal::new_shared(ptr),
                 owner: Some(surface),
             })
         }
@@ -150,12 +185,9 @@ impl<'a> Renderer<&'a mut SurfaceRef> {
     /// The surface can later be borrowed by calling `Renderer::as_surface_ref` or `Renderer::as_surface_mut`.
     pub fn from_surface(surface: &'a mut SurfaceRef) -> Result<Self, Error> {
         let ptr = unsafe { sys::SDL_CreateSoftwareRenderer(surface.raw()) };
-        let ptr = NonNull::new(ptr).ok_or(Error::new())?;
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
         Ok(Self {
-            internal: Rc::new(RendererInternal {
-                ptr,
-                owner: RefCell::new(None),
-            }),
+            internal: RendererInternal::new_shared(ptr),
             owner: Some(surface),
         })
     }
@@ -249,6 +281,108 @@ impl<T> Renderer<T> {
         Texture::from_surface(self, surface)
     }
 
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    /// Loads an image from a file directly into a texture.
+    ///
+    /// This method is equivalent to [`Texture::load`].
+    pub fn load_texture(&mut self, path: &str) -> Result<Texture<T>, Error> {
+        Texture::load(self, path)
+    }
+
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    /// Loads an image from a stream directly into a texture.
+    ///
+    /// This method is equivalent to [`Texture::load_from_io`].
+    pub fn load_texture_io(&mut self, io: IOStream) -> Result<Texture<T>, Error> {
+        Texture::load_from_io(self, io)
+    }
+
+    /// Copies the `src_rect` portion of `surface`, or the entire surface if `None`, into `texture`
+    /// with its top-left corner at `dst_point`, converting the pixels to the texture's format.
+    ///
+    /// Streaming textures are locked and written in place; other textures are updated through
+    /// [`Texture::update`]. This is meant for partial updates from a CPU-side cache, such as
+    /// newly rasterized glyphs in an atlas. Palettized and planar formats aren't supported.
+    pub fn upload_surface_region(
+        &mut self,
+        surface: &SurfaceRef,
+        src_rect: Option<Rect>,
+        texture: &mut Texture<T>,
+        dst_point: Point,
+    ) -> Result<(), Error> {
+        let (surface_w, surface_h, src_pitch, pixels) = unsafe {
+            let raw = surface.raw();
+            ((*raw).w, (*raw).h, (*raw).pitch, (*raw).pixels)
+        };
+        let src = src_rect.unwrap_or(Rect::new(
+            0,
+            0,
+            u32::try_from(surface_w)?,
+            u32::try_from(surface_h)?,
+        ));
+        let dst = Rect::new(dst_point.x(), dst_point.y(), src.w(), src.h());
+        let fits = |rect: &Rect, w: i32, h: i32| {
+            rect.x() >= 0
+                && rect.y() >= 0
+                && rect.x() as i64 + rect.w() as i64 <= w as i64
+                && rect.y() as i64 + rect.h() as i64 <= h as i64
+        };
+        if !fits(&src, surface_w, surface_h) || !fits(&dst, texture.w(), texture.h()) {
+            return Err(Error::register(
+                c"Region is outside the surface or the texture.",
+            ));
+        }
+        if src.w() == 0 || src.h() == 0 {
+            return Ok(());
+        }
+        if pixels.is_null() {
+            return Err(Error::register(
+                c"Surface pixels aren't accessible; disable RLE before uploading.",
+            ));
+        }
+        let src_format = surface.format();
+        let bytes_per_pixel = src_format.details()?.bytes_per_pixel() as usize;
+        if bytes_per_pixel == 0 {
+            return Err(Error::register(
+                c"Surface pixel format isn't byte-addressable.",
+            ));
+        }
+        let offset = src.y() as usize * src_pitch as usize + src.x() as usize * bytes_per_pixel;
+        let src_pixels = unsafe { (pixels as *const u8).add(offset) } as *const c_void;
+        let dst_format = texture.format();
+        let convert = |dst_pixels: &mut [u8], dst_pitch: usize| {
+            let result = unsafe {
+                sys::SDL_ConvertPixels(
+                    src.w() as i32,
+                    src.h() as i32,
+                    src_format.to_ll(),
+                    src_pixels,
+                    src_pitch,
+                    dst_format.to_ll(),
+                    dst_pixels.as_mut_ptr() as *mut c_void,
+                    i32::try_from(dst_pitch)?,
+                )
+            };
+            if !result {
+                return Err(Error::new());
+            }
+            Ok(())
+        };
+        let access = texture.properties()?.number("SDL.texture.access", 0)?;
+        if access == TextureAccess::Streaming.to_ll() as i64 {
+            let mut lock = texture.lock(Some(dst))?;
+            let pitch = lock.pitch();
+            convert(lock.pixels_mut(), pitch)
+        } else {
+            let mut staging = texture.staging(Some(dst))?;
+            let pitch = staging.pitch();
+            convert(staging.pixels_mut(), pitch)?;
+            texture.upload(Some(dst), &staging)
+        }
+    }
+
     /// Returns a pointer to the `CAMetalLayer` associated with the given Metal renderer.
     ///
     /// This function returns `*mut core::ffi::c_void`, so SDL doesn't have to include Metal's headers, but it can be
@@ -328,7 +462,8 @@ impl<T> Renderer<T> {
     /// Set the color used for drawing operations.
     ///
     /// Set the color for drawing or filling rectangles, lines, and points, and for [`Renderer::clear`].
-    pub fn set_draw_color(&mut self, color: Color) -> Result<(), Error> {
+    pub fn set_draw_color(&mut self, color: impl Into<Color>) -> Result<(), Error> {
+        let color = color.into();
         let result = unsafe {
             sys::SDL_SetRenderDrawColor(self.raw(), color.r(), color.g(), color.b(), color.a())
         };
@@ -496,6 +631,60 @@ impl<T> Renderer<T> {
         Ok(())
     }
 
+    /// Returns the properties associated with this renderer.
+    pub fn properties(&self) -> Result<PropertiesRef<'_>, Error> {
+        unsafe { PropertiesRef::from_ll(sys::SDL_GetRendererProperties(self.raw())) }
+    }
+
+    /// Returns the properties associated with this renderer, allowing them to be changed.
+    pub fn properties_mut(&mut self) -> Result<PropertiesRefMut<'_>, Error> {
+        unsafe { PropertiesRefMut::from_ll(sys::SDL_GetRendererProperties(self.raw())) }
+    }
+
+    /// Returns every property SDL associates with this renderer as a `String`.
+    ///
+    /// Useful when reporting driver-specific rendering bugs. See
+    /// [`WindowRef::dump_properties`] for the output format.
+    pub fn dump_properties(&self) -> Result<String, Error> {
+        let props = unsafe { sys::SDL_GetRendererProperties(self.raw()) };
+        crate::properties::dump_properties(props)
+    }
+
+    /// Returns the scale mode applied to textures created by this renderer, if one was set with
+    /// [`Renderer::set_default_texture_scale_mode`].
+    #[inline]
+    pub fn default_texture_scale_mode(&self) -> Option<ScaleMode> {
+        self.internal.default_scale_mode.get()
+    }
+
+    /// Sets the scale mode applied to textures created by this renderer from now on.
+    ///
+    /// Passing `None` keeps SDL's default, which is [`ScaleMode::Linear`]. Existing textures are
+    /// not affected.
+    #[inline]
+    pub fn set_default_texture_scale_mode(&mut self, scale_mode: Option<ScaleMode>) {
+        self.internal.default_scale_mode.set(scale_mode);
+    }
+
+    /// Configures the renderer to produce the same output on every machine.
+    ///
+    /// Intended for golden-image tests. The renderer must be a software renderer, i.e. created
+    /// from a surface or with the `"software"` driver (see
+    /// [`crate::VideoSubsystem::force_software_rendering`]). VSync gets disabled and textures
+    /// created from now on use [`ScaleMode::Nearest`], so no filtering differences creep in.
+    ///
+    /// Returns an error if the renderer isn't a software renderer.
+    pub fn make_deterministic(&mut self) -> Result<(), Error> {
+        if self.name()? != "software" {
+            return Err(Error::register(
+                c"Deterministic rendering requires the software renderer.",
+            ));
+        }
+        self.set_vsync(RendererVSync::Disabled)?;
+        self.set_default_texture_scale_mode(Some(ScaleMode::Nearest));
+        Ok(())
+    }
+
     /// Get device independent resolution and presentation mode for rendering.
     ///
     /// `RendererLogicalPresentationMode` contains the width and height of the logical rendering output,
@@ -745,6 +934,30 @@ impl<T> Renderer<T> {
         Ok(())
     }
 
+    /// Draw points yielded by an iterator on the current rendering target at subpixel precision.
+    ///
+    /// Points are copied into a fixed-size buffer on the stack and submitted in batches, so there's
+    /// no need to collect them into a `Vec` first.
+    pub fn render_points_iter<I>(&mut self, points: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = PointF32>,
+    {
+        let mut batch = [PointF32::default(); POINT_BATCH_SIZE];
+        let mut len = 0;
+        for point in points {
+            batch[len] = point;
+            len += 1;
+            if len == POINT_BATCH_SIZE {
+                self.render_points(&batch)?;
+                len = 0;
+            }
+        }
+        if len > 0 {
+            self.render_points(&batch[..len])?;
+        }
+        Ok(())
+    }
+
     /// Draw a rectangle on the current rendering target at subpixel precision.
     pub fn render_rect(&mut self, rect: RectF32) -> Result<(), Error> {
         let result = unsafe { sys::SDL_RenderRect(self.raw(), rect.as_raw()) };
@@ -788,6 +1001,52 @@ impl<T> Renderer<T> {
         Ok(())
     }
 
+    /// Fill a rectangle with rounded corners on the current rendering target with the drawing color.
+    ///
+    /// `radius` is clamped to half of the smaller side of `rect`. The shape is tessellated into a
+    /// triangle fan and drawn with [`Renderer::render_geometry`], so it respects the draw blend mode.
+    pub fn fill_rounded_rect(&mut self, rect: RectF32, radius: f32) -> Result<(), Error> {
+        let color = self.draw_color_float()?;
+        let outline = rounded_rect_outline(rect, radius);
+        let center = PointF32::new(rect.x() + rect.w() / 2.0, rect.y() + rect.h() / 2.0);
+        let tex_coord = PointF32::new(0.0, 0.0);
+        let mut vertices = Vec::with_capacity(outline.len() + 1);
+        vertices.push(Vertex::new(center, color, tex_coord));
+        vertices.extend(
+            outline
+                .iter()
+                .map(|point| Vertex::new(*point, color, tex_coord)),
+        );
+        let count = outline.len() as u16;
+        let indices: Vec<u16> = (0..count)
+            .flat_map(|i| [0, i + 1, (i + 1) % count + 1])
+            .collect();
+        self.render_geometry_indexed(None, &vertices, &indices)
+    }
+
+    /// Fill a capsule (a rectangle whose shorter sides are semicircles) on the current rendering
+    /// target with the drawing color.
+    pub fn fill_capsule(&mut self, rect: RectF32) -> Result<(), Error> {
+        self.fill_rounded_rect(rect, rect.w().min(rect.h()) / 2.0)
+    }
+
+    /// Draw the outline of a rectangle with rounded corners on the current rendering target with
+    /// the drawing color.
+    ///
+    /// `radius` is clamped to half of the smaller side of `rect`.
+    pub fn render_rounded_rect(&mut self, rect: RectF32, radius: f32) -> Result<(), Error> {
+        let mut outline = rounded_rect_outline(rect, radius);
+        outline.push(outline[0]);
+        let count = i32::try_from(outline.len())?;
+        // SAFETY: PointF32 has the same in-memory representation as sys::SDL_FPoint.
+        let points = outline.as_ptr() as *const sys::SDL_FPoint;
+        let result = unsafe { sys::SDL_RenderLines(self.raw(), points, count) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
     /// Draw debug text to a `Renderer`.
     ///
     /// This function will render a string of text to a `Renderer`. Note that this is a convenience function for
@@ -801,17 +1060,157 @@ impl<T> Renderer<T> {
     /// support truetype, for proper scaling.
     /// - It does no word-wrapping and does not treat newline characters as a line break. If the text goes out of
     /// the window, it's gone.
+    ///
+    /// Short strings are converted on the stack; see [`Renderer::render_debug_text_cstr`] to skip
+    /// the conversion entirely.
     pub fn render_debug_text(&mut self, x: f32, y: f32, text: &str) -> Result<(), Error> {
-        let string = CString::new(text).map_err(|_| {
+        crate::with_cstr(text, |text| self.render_debug_text_cstr(x, y, text)).map_err(|_| {
             Error::register(c"Invalid debug text. Interior null byte found (NulError)")
-        })?;
-        let result = unsafe { sys::SDL_RenderDebugText(self.raw(), x, y, string.as_ptr()) };
+        })?
+    }
+
+    /// Draw debug text to a `Renderer`, replacing any interior NUL bytes with U+FFFD instead of
+    /// failing.
+    ///
+    /// See [`Renderer::render_debug_text`] for the limitations of debug text.
+    pub fn render_debug_text_lossy(&mut self, x: f32, y: f32, text: &str) -> Result<(), Error> {
+        let string = crate::cstring_lossy(text);
+        self.render_debug_text_cstr(x, y, string.as_c_str())
+    }
+
+    /// Draw debug text to a `Renderer` from a C string, without any conversion or allocation.
+    ///
+    /// See [`Renderer::render_debug_text`] for the limitations of debug text.
+    pub fn render_debug_text_cstr(&mut self, x: f32, y: f32, text: &CStr) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_RenderDebugText(self.raw(), x, y, text.as_ptr()) };
         if !result {
             return Err(Error::new());
         }
         Ok(())
     }
 
+    /// Draw formatted debug text to a `Renderer`, usually created with [`core::format_args`].
+    ///
+    /// ```no_run
+    /// # fn frame(renderer: &mut rsdl3::render::Renderer, fps: f32) -> Result<(), rsdl3::Error> {
+    /// renderer.render_debug_text_fmt(4.0, 4.0, format_args!("{fps:.1} fps"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// See [`Renderer::render_debug_text`] for the limitations of debug text.
+    pub fn render_debug_text_fmt(
+        &mut self,
+        x: f32,
+        y: f32,
+        args: core::fmt::Arguments<'_>,
+    ) -> Result<(), Error> {
+        match args.as_str() {
+            Some(text) => self.render_debug_text(x, y, text),
+            None => self.render_debug_text(x, y, &alloc::fmt::format(args)),
+        }
+    }
+
+    /// Draw debug text to a `Renderer`, starting a new line below the previous one at every `\n`.
+    ///
+    /// Lines are [`DEBUG_TEXT_CHARACTER_SIZE`] pixels apart. See [`Renderer::render_debug_text`] for
+    /// the other limitations of debug text.
+    pub fn render_debug_text_lines(&mut self, x: f32, y: f32, text: &str) -> Result<(), Error> {
+        let mut line_y = y;
+        for line in text.lines() {
+            if !line.is_empty() {
+                self.render_debug_text(x, line_y, line)?;
+            }
+            line_y += DEBUG_TEXT_CHARACTER_SIZE;
+        }
+        Ok(())
+    }
+
+    /// Multiplies the color modulation of every texture drawn from now on by `color_mod`, until
+    /// the matching [`Renderer::pop_color_mod`].
+    ///
+    /// Pushed mods combine with each other and with each texture's own color mod, so a parent
+    /// can tint everything drawn by its children. Textures are modulated only for the duration
+    /// of each draw, and their own color mod is restored afterwards. Geometry and text drawn
+    /// without a texture copy, e.g. with [`Renderer::render_geometry`], isn't affected.
+    pub fn push_color_mod(&mut self, color_mod: (u8, u8, u8)) {
+        let mut color_mods = self.internal.color_mods.borrow_mut();
+        let (r, g, b) = color_mods.last().copied().unwrap_or((1.0, 1.0, 1.0));
+        let (mod_r, mod_g, mod_b) = color_mod;
+        color_mods.push((
+            r * f32::from(mod_r) / 255.0,
+            g * f32::from(mod_g) / 255.0,
+            b * f32::from(mod_b) / 255.0,
+        ));
+    }
+
+    /// Removes the color mod added by the last call to [`Renderer::push_color_mod`].
+    ///
+    /// Returns an error if no color mod was pushed.
+    pub fn pop_color_mod(&mut self) -> Result<(), Error> {
+        match self.internal.color_mods.borrow_mut().pop() {
+            Some(_) => Ok(()),
+            None => Err(Error::register(c"Color mod stack is empty.")),
+        }
+    }
+
+    /// Multiplies the alpha modulation of every texture drawn from now on by `alpha_mod`, until
+    /// the matching [`Renderer::pop_alpha_mod`], e.g. to fade out a whole group of textures.
+    ///
+    /// See [`Renderer::push_color_mod`].
+    pub fn push_alpha_mod(&mut self, alpha_mod: u8) {
+        let mut alpha_mods = self.internal.alpha_mods.borrow_mut();
+        let alpha = alpha_mods.last().copied().unwrap_or(1.0);
+        alpha_mods.push(alpha * f32::from(alpha_mod) / 255.0);
+    }
+
+    /// Removes the alpha mod added by the last call to [`Renderer::push_alpha_mod`].
+    ///
+    /// Returns an error if no alpha mod was pushed.
+    pub fn pop_alpha_mod(&mut self) -> Result<(), Error> {
+        match self.internal.alpha_mods.borrow_mut().pop() {
+            Some(_) => Ok(()),
+            None => Err(Error::register(c"Alpha mod stack is empty.")),
+        }
+    }
+
+    // Runs `draw` with the pushed color and alpha mods applied to `texture`, restoring its own
+    // mods afterwards.
+    fn modulated(&self, texture: &Texture<T>, draw: impl FnOnce() -> bool) -> Result<(), Error> {
+        let color_mod = self.internal.color_mods.borrow().last().copied();
+        let alpha_mod = self.internal.alpha_mods.borrow().last().copied();
+        if color_mod.is_none() && alpha_mod.is_none() {
+            if !draw() {
+                return Err(Error::new());
+            }
+            return Ok(());
+        }
+        let texture = texture.raw();
+        let (mut r, mut g, mut b, mut a) = (0.0, 0.0, 0.0, 0.0);
+        let saved = unsafe {
+            sys::SDL_GetTextureColorModFloat(texture, &raw mut r, &raw mut g, &raw mut b)
+                && sys::SDL_GetTextureAlphaModFloat(texture, &raw mut a)
+        };
+        if !saved {
+            return Err(Error::new());
+        }
+        let (mod_r, mod_g, mod_b) = color_mod.unwrap_or((1.0, 1.0, 1.0));
+        let mod_a = alpha_mod.unwrap_or(1.0);
+        let drawn = unsafe {
+            sys::SDL_SetTextureColorModFloat(texture, r * mod_r, g * mod_g, b * mod_b)
+                && sys::SDL_SetTextureAlphaModFloat(texture, a * mod_a)
+                && draw()
+        };
+        let restored = unsafe {
+            sys::SDL_SetTextureColorModFloat(texture, r, g, b)
+                & sys::SDL_SetTextureAlphaModFloat(texture, a)
+        };
+        if !drawn || !restored {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
     /// Copy a portion of the texture to the current rendering target at subpixel precision.
     ///
     /// * `texture` - the source texture
@@ -831,18 +1230,14 @@ impl<T> Renderer<T> {
             .as_ref()
             .map(RectF32::as_raw)
             .unwrap_or(core::ptr::null());
-        let result = unsafe {
+        self.modulated(texture, || unsafe {
             sys::SDL_RenderTexture(
                 self.raw(),
                 texture.ptr.as_ptr(),
                 src_rect_ptr,
                 dest_rect_ptr,
             )
-        };
-        if !result {
-            return Err(Error::new());
-        }
-        Ok(())
+        })
     }
 
     /// Perform a scaled copy using the 9-grid algorithm to the current rendering target at subpixel precision.
@@ -870,7 +1265,7 @@ impl<T> Renderer<T> {
             .as_ref()
             .map(RectF32::as_raw)
             .unwrap_or(core::ptr::null());
-        let result = unsafe {
+        self.modulated(texture, || unsafe {
             sys::SDL_RenderTexture9Grid(
                 self.raw(),
                 texture.raw(),
@@ -882,11 +1277,7 @@ impl<T> Renderer<T> {
                 scale,
                 dest_rect_ptr,
             )
-        };
-        if !result {
-            return Err(Error::new());
-        }
-        Ok(())
+        })
     }
 
     /// Tile a portion of the texture to the current rendering target at subpixel precision.
@@ -907,7 +1298,7 @@ impl<T> Renderer<T> {
             .as_ref()
             .map(RectF32::as_raw)
             .unwrap_or(core::ptr::null());
-        let result = unsafe {
+        self.modulated(texture, || unsafe {
             sys::SDL_RenderTextureTiled(
                 self.raw(),
                 texture.raw(),
@@ -915,11 +1306,39 @@ impl<T> Renderer<T> {
                 scale,
                 dest_rect_ptr,
             )
-        };
-        if !result {
-            return Err(Error::new());
-        }
-        Ok(())
+        })
+    }
+
+    /// Copy a region of a texture to the current rendering target at subpixel precision.
+    ///
+    /// `dest_rect` is the destination rectangle or `None` for the entire rendering target.
+    pub fn draw_region(
+        &mut self,
+        region: &AtlasRegion<'_, T>,
+        dest_rect: Option<RectF32>,
+    ) -> Result<(), Error> {
+        self.render_texture(region.texture, Some(region.rect), dest_rect)
+    }
+
+    /// Copy a region of a texture to the current rendering target, with rotation and flipping.
+    ///
+    /// See [`Renderer::render_texture_rotated`].
+    pub fn draw_region_rotated(
+        &mut self,
+        region: &AtlasRegion<'_, T>,
+        dest_rect: Option<RectF32>,
+        angle: f64,
+        center: Option<PointF32>,
+        flip: Option<FlipMode>,
+    ) -> Result<(), Error> {
+        self.render_texture_rotated(
+            region.texture,
+            Some(region.rect),
+            dest_rect,
+            angle,
+            center,
+            flip,
+        )
     }
 
     /// Copy a portion of the source texture to the current rendering target, with rotation and flipping, at subpixel precision.
@@ -947,7 +1366,7 @@ impl<T> Renderer<T> {
         let flip_mode = flip
             .map(|f| f.to_ll())
             .unwrap_or(sys::SDL_FlipMode_SDL_FLIP_NONE);
-        let result = unsafe {
+        self.modulated(texture, || unsafe {
             sys::SDL_RenderTextureRotated(
                 self.raw(),
                 texture.raw(),
@@ -957,11 +1376,38 @@ impl<T> Renderer<T> {
                 center_ptr,
                 flip_mode,
             )
-        };
-        if !result {
-            return Err(Error::new());
-        }
-        Ok(())
+        })
+    }
+
+    /// Copy a portion of the texture to the current rendering target, applying the overrides in
+    /// `params` for this draw only.
+    ///
+    /// Every texture property overridden by `params` is restored to its previous value once the
+    /// copy is done, even if the copy itself fails.
+    pub fn render_texture_with(
+        &mut self,
+        texture: &mut Texture<T>,
+        src_rect: Option<RectF32>,
+        dest_rect: Option<RectF32>,
+        params: &DrawParams,
+    ) -> Result<(), Error> {
+        let previous = TextureState::save(texture, params)?;
+        let result = TextureState::apply(texture, params).and_then(|_| {
+            if params.angle == 0.0 && params.flip.is_none() {
+                self.render_texture(texture, src_rect, dest_rect)
+            } else {
+                self.render_texture_rotated(
+                    texture,
+                    src_rect,
+                    dest_rect,
+                    params.angle,
+                    params.center,
+                    params.flip,
+                )
+            }
+        });
+        let restored = previous.restore(texture);
+        result.and(restored)
     }
 
     /// Copy a portion of the source texture to the current rendering target, with affine transform, at subpixel precision.
@@ -996,7 +1442,7 @@ impl<T> Renderer<T> {
             .as_ref()
             .map(PointF32::as_raw)
             .unwrap_or(core::ptr::null());
-        let result = unsafe {
+        self.modulated(texture, || unsafe {
             sys::SDL_RenderTextureAffine(
                 self.raw(),
                 texture.raw(),
@@ -1005,15 +1451,13 @@ impl<T> Renderer<T> {
                 right_ptr,
                 down_ptr,
             )
-        };
-        if !result {
-            return Err(Error::new());
-        }
-        Ok(())
+        })
     }
 
     /// Render a list of triangles, optionally using a texture and indices into the vertex array.
     /// Color and alpha modulation is done per vertex ([`Renderer::color_mod`] and [`Texture::alpha_mod`] are ignored).
+    ///
+    /// See [`Renderer::render_geometry_indexed`] for indices of other types.
     pub fn render_geometry(
         &mut self,
         texture: Option<&Texture<T>>,
@@ -1042,6 +1486,101 @@ impl<T> Renderer<T> {
         Ok(())
     }
 
+    /// Like [`Renderer::render_geometry`], but indices can be `u8`, `u16`, `i32` or `u32`.
+    ///
+    /// Smaller index types save memory and bandwidth for large batches such as sprites.
+    pub fn render_geometry_indexed<I: GeometryIndex>(
+        &mut self,
+        texture: Option<&Texture<T>>,
+        vertices: &[Vertex],
+        indices: &[I],
+    ) -> Result<(), Error> {
+        if vertices.is_empty() {
+            return Ok(());
+        }
+        let texture_ptr = texture.map(Texture::raw).unwrap_or(core::ptr::null_mut());
+        let indices_ptr = if indices.is_empty() {
+            core::ptr::null()
+        } else {
+            indices.as_ptr() as *const c_void
+        };
+        // SAFETY: Vertex has the same in-memory representation as sys::SDL_Vertex, and `vertices`
+        // isn't empty, so the attribute offsets stay within its first element.
+        let base = vertices.as_ptr() as *const sys::SDL_Vertex as *const u8;
+        let stride = size_of::<sys::SDL_Vertex>() as i32;
+        let result = unsafe {
+            sys::SDL_RenderGeometryRaw(
+                self.raw(),
+                texture_ptr,
+                base.add(offset_of!(sys::SDL_Vertex, position)) as *const f32,
+                stride,
+                base.add(offset_of!(sys::SDL_Vertex, color)) as *const sys::SDL_FColor,
+                stride,
+                base.add(offset_of!(sys::SDL_Vertex, tex_coord)) as *const f32,
+                stride,
+                i32::try_from(vertices.len())?,
+                indices_ptr,
+                i32::try_from(indices.len())?,
+                size_of::<I>() as i32,
+            )
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Render a list of triangles from vertex attributes stored in separate or interleaved buffers.
+    ///
+    /// This avoids copying vertex data that's already stored in another layout into [`Vertex`] structs.
+    /// Every attribute must hold at least as many elements as `positions`, which determines the number
+    /// of vertices. `tex_coords` is required when `texture` is given.
+    pub fn render_geometry_raw<I: GeometryIndex>(
+        &mut self,
+        texture: Option<&Texture<T>>,
+        positions: VertexAttribute<'_, PointF32>,
+        colors: VertexAttribute<'_, ColorF32>,
+        tex_coords: Option<VertexAttribute<'_, PointF32>>,
+        indices: &[I],
+    ) -> Result<(), Error> {
+        let num_vertices = positions.len();
+        if colors.len() < num_vertices || tex_coords.is_some_and(|uv| uv.len() < num_vertices) {
+            return Err(Error::register(
+                c"Vertex attributes have fewer elements than positions.",
+            ));
+        }
+        let texture_ptr = texture.map(Texture::raw).unwrap_or(core::ptr::null_mut());
+        let indices_ptr = if indices.is_empty() {
+            core::ptr::null()
+        } else {
+            indices.as_ptr() as *const c_void
+        };
+        let (uv_ptr, uv_stride) = match tex_coords {
+            Some(uv) => (uv.ptr as *const f32, uv.stride),
+            None => (core::ptr::null(), 0),
+        };
+        let result = unsafe {
+            sys::SDL_RenderGeometryRaw(
+                self.raw(),
+                texture_ptr,
+                positions.ptr as *const f32,
+                positions.stride,
+                colors.ptr as *const sys::SDL_FColor,
+                colors.stride,
+                uv_ptr,
+                uv_stride,
+                i32::try_from(num_vertices)?,
+                indices_ptr,
+                i32::try_from(indices.len())?,
+                size_of::<I>() as i32,
+            )
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
     /// Replaces the current rendering target with the given texture. Returns the previously used texture if there was one.
     ///
     /// The default render target is the window (or surface) for which the renderer was created.
@@ -1064,7 +1603,7 @@ impl<T> Renderer<T> {
             Some(texture) => {
                 // We're basically moving ownership of the texture to *the internal SDL Renderer*.
                 // This means the destructor cannot run safely otherwise it will destroy the texture.
-                // Still we need to drop the Rc from the texture.
+                // Still we need to drop the reference to the internal renderer from the texture.
                 let mut texture = ManuallyDrop::new(texture);
                 let result = unsafe { sys::SDL_SetRenderTarget(self.raw(), texture.ptr.as_ptr()) };
                 if !result {
@@ -1074,7 +1613,7 @@ impl<T> Renderer<T> {
                     return Err(Error::new());
                 }
                 unsafe {
-                    core::ptr::drop_in_place(&mut texture._renderer);
+                    ManuallyDrop::drop(&mut texture._renderer);
                 }
             }
             _ => {
@@ -1107,14 +1646,89 @@ impl<T> Renderer<T> {
     /// [`Renderer::replace_render_target`] afterwards, as textures by themselves do not have a concept of backbuffers.
     /// Calling [`Renderer::present`] while rendering to a texture will still update the screen with any current drawing that
     /// has been done _to the window itself_.
+    ///
+    /// Each successful call advances [`Renderer::frame_index`].
     pub fn present(&mut self) -> Result<(), Error> {
         let result = unsafe { sys::SDL_RenderPresent(self.raw()) };
         if !result {
             return Err(Error::new());
         }
+        let frame_index = &self.internal.frame_index;
+        frame_index.set(frame_index.get().next());
         Ok(())
     }
 
+    /// Like [`Renderer::present`], but tells a failure caused by a device reset or loss apart
+    /// from other errors, see [`Renderer::classify_error`].
+    pub fn try_present(&mut self) -> Result<(), RenderError> {
+        self.present().map_err(|error| self.classify_error(error))
+    }
+
+    /// Classifies an error returned by this renderer, such as a failed texture creation.
+    ///
+    /// If a device reset or loss is pending in the event queue for this renderer's window, the
+    /// error is reported as [`RenderError::Device`] and the hook set with
+    /// [`Renderer::set_device_reset_hook`] is called. The events are left in the queue.
+    pub fn classify_error(&self, error: Error) -> RenderError {
+        let Some(resync) = self.pending_device_reset() else {
+            return RenderError::Sdl(error);
+        };
+        if let Some(hook) = self.internal.device_reset_hook.borrow_mut().as_mut() {
+            hook(resync);
+        }
+        RenderError::Device(resync)
+    }
+
+    /// Sets a function that's called with the resources to rebuild whenever
+    /// [`Renderer::classify_error`] detects a device reset or loss, e.g. to recreate textures.
+    pub fn set_device_reset_hook(&mut self, hook: impl FnMut(RenderResync) + 'static) {
+        *self.internal.device_reset_hook.borrow_mut() = Some(Box::new(hook));
+    }
+
+    /// Removes the hook set with [`Renderer::set_device_reset_hook`].
+    pub fn clear_device_reset_hook(&mut self) {
+        self.internal.device_reset_hook.borrow_mut().take();
+    }
+
+    /// Returns the most severe device reset or loss queued for this renderer's window, if any.
+    fn pending_device_reset(&self) -> Option<RenderResync> {
+        const CAPACITY: usize = 16;
+        let window = unsafe { sys::SDL_GetRenderWindow(self.raw()) };
+        let window_id = if window.is_null() {
+            0
+        } else {
+            unsafe { sys::SDL_GetWindowID(window) }
+        };
+        let mut events: [MaybeUninit<sys::SDL_Event>; CAPACITY] =
+            [const { MaybeUninit::uninit() }; CAPACITY];
+        let count = unsafe {
+            sys::SDL_PeepEvents(
+                events.as_mut_ptr() as *mut sys::SDL_Event,
+                CAPACITY as i32,
+                sys::SDL_EventAction_SDL_PEEKEVENT,
+                sys::SDL_EventType_SDL_EVENT_RENDER_DEVICE_RESET,
+                sys::SDL_EventType_SDL_EVENT_RENDER_DEVICE_LOST,
+            )
+        };
+        events[..count.clamp(0, CAPACITY as i32) as usize]
+            .iter()
+            .map(|event| unsafe { event.assume_init_ref().render })
+            .filter(|event| event.windowID == window_id)
+            .map(|event| match event.type_ {
+                sys::SDL_EventType_SDL_EVENT_RENDER_DEVICE_LOST => RenderResync::Renderer,
+                _ => RenderResync::Textures,
+            })
+            .max()
+    }
+
+    /// Returns the index of the frame currently being drawn.
+    ///
+    /// This starts at 0 and is incremented every time [`Renderer::present`] succeeds.
+    #[inline]
+    pub fn frame_index(&self) -> FrameId {
+        self.internal.frame_index.get()
+    }
+
     /// Clear the current rendering target with the drawing color.
     ///
     /// This function clears the entire rendering target, ignoring the viewport and the clip rectangle. Note, that clearing will also
@@ -1163,12 +1777,42 @@ impl<T> Renderer<T> {
     }
 }
 
+/// Number of segments used to approximate each corner of a rounded rectangle.
+const ROUNDED_CORNER_SEGMENTS: u16 = 8;
+
+/// Returns the points along the outline of a rounded rectangle, clockwise from the top-left
+/// corner.
+fn rounded_rect_outline(rect: RectF32, radius: f32) -> Vec<PointF32> {
+    // Not `clamp`, which panics when a negative or NaN size makes the upper bound invalid.
+    let radius = radius.max(0.0).min((rect.w().min(rect.h()) / 2.0).max(0.0));
+    let (left, top) = (rect.x() + radius, rect.y() + radius);
+    let (right, bottom) = (rect.x() + rect.w() - radius, rect.y() + rect.h() - radius);
+    // Corner centers with the angle their arc starts at, as a multiple of pi. Angles grow
+    // clockwise since y points down.
+    let corners = [
+        (left, top, 1.0),
+        (right, top, 1.5),
+        (right, bottom, 0.0),
+        (left, bottom, 0.5),
+    ];
+    let mut points = Vec::with_capacity(corners.len() * (ROUNDED_CORNER_SEGMENTS as usize + 1));
+    for (cx, cy, start) in corners {
+        for i in 0..=ROUNDED_CORNER_SEGMENTS {
+            let step = 0.5 * f32::from(i) / f32::from(ROUNDED_CORNER_SEGMENTS);
+            let angle = (start + step) * sys::SDL_PI_F as f32;
+            let (sin, cos) = unsafe { (sys::SDL_sinf(angle), sys::SDL_cosf(angle)) };
+            points.push(PointF32::new(cx + radius * cos, cy + radius * sin));
+        }
+    }
+    points
+}
+
 impl<T> Drop for Renderer<T> {
     fn drop(&mut self) {
         // If there's still a reference to the internal renderer,
         // we move the owner to the internal renderer so destroying it
         // becomes the internal renderer's responsibility.
-        if Rc::strong_count(&self.internal) > 1 {
+        if Shared::strong_count(&self.internal) > 1 {
             let Some(owner) = self.owner.take() else {
                 return;
             };
@@ -1180,22 +1824,580 @@ impl<T> Drop for Renderer<T> {
     }
 }
 
-/// VSync behavior of a renderer.
+/// Skips presenting while a window can't be seen.
 ///
-/// When a renderer is created, vsync defaults to `RendererVSync::Disabled`.
-#[repr(i32)]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub enum RendererVSync {
-    EveryVerticalRefresh = 1,
-    EverySecondVerticalRefresh = 2,
-    Adaptive = sys::SDL_RENDERER_VSYNC_ADAPTIVE,
-    Disabled = sys::SDL_RENDERER_VSYNC_DISABLED as i32,
+/// Feed every event to [`PresentGate::handle_event`] and call [`PresentGate::present`] instead of
+/// [`Renderer::present`]. Presenting is paused while the window is minimized, hidden or
+/// occluded, and resumed once it's restored, shown or exposed again. This avoids wasted GPU work
+/// and issues some drivers have when presenting to a window that left exclusive fullscreen.
+pub struct PresentGate {
+    visibility: VisibilityTracker,
 }
 
-impl RendererVSync {
-    /// SAFETY: `value` must be a valid variant of the enum.
-    unsafe fn from_ll_unchecked(value: i32) -> Self {
-        unsafe { core::mem::transmute(value) }
+impl PresentGate {
+    /// Creates a `PresentGate` for the window with the given id.
+    ///
+    /// The window is assumed to be visible.
+    pub fn new(window_id: u32) -> Self {
+        Self {
+            visibility: VisibilityTracker::new(window_id),
+        }
+    }
+
+    /// Updates the window's visibility from an event.
+    ///
+    /// Events that don't belong to the tracked window are ignored.
+    pub fn handle_event(&mut self, event: &Event) {
+        self.visibility.handle_event(event);
+    }
+
+    /// Returns the underlying [`VisibilityTracker`].
+    #[inline]
+    pub fn visibility(&self) -> &VisibilityTracker {
+        &self.visibility
+    }
+
+    /// Returns true if presenting is currently paused.
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        !self.visibility.should_present()
+    }
+
+    /// Presents the renderer unless presenting is paused.
+    ///
+    /// Returns true if [`Renderer::present`] was called.
+    pub fn present<T>(&self, renderer: &mut Renderer<T>) -> Result<bool, Error> {
+        if self.is_paused() {
+            return Ok(false);
+        }
+        renderer.present()?;
+        Ok(true)
+    }
+}
+
+/// Limits the frame rate by waiting out the remainder of each frame.
+///
+/// Call [`FrameLimiter::wait`] once per frame, typically right before [`Renderer::present`].
+/// Deadlines are scheduled a fixed interval apart rather than relative to when `wait` returns, so
+/// small overshoots don't accumulate; if a frame runs late by more than a whole interval, the
+/// schedule restarts from the current time instead of rushing to catch up.
+#[derive(Clone, Debug)]
+pub struct FrameLimiter {
+    frequency: u64,
+    /// The frame interval in performance counter ticks.
+    interval: u64,
+    deadline: Option<u64>,
+    pacing: FramePacing,
+}
+
+impl FrameLimiter {
+    /// Time left before a deadline that [`FramePacing::Hybrid`] spends spinning, in nanoseconds.
+    const SPIN_THRESHOLD_NS: u64 = 1_000_000;
+
+    /// Creates a limiter targeting `fps` frames per second, with [`FramePacing::Hybrid`] pacing.
+    ///
+    /// Returns an error if `fps` is 0.
+    pub fn new(fps: u32) -> Result<Self, Error> {
+        if fps == 0 {
+            return Err(Error::register(c"The frame rate must be greater than 0."));
+        }
+        Ok(Self::from_frame_time(Duration::from_secs(1) / fps))
+    }
+
+    /// Creates a limiter that waits until `frame_time` has elapsed since the previous frame, with
+    /// [`FramePacing::Hybrid`] pacing.
+    pub fn from_frame_time(frame_time: Duration) -> Self {
+        let frequency = unsafe { sys::SDL_GetPerformanceFrequency() };
+        let interval = frame_time.as_nanos() * u128::from(frequency) / 1_000_000_000;
+        Self {
+            frequency,
+            interval: u64::try_from(interval).unwrap_or(u64::MAX),
+            deadline: None,
+            pacing: FramePacing::Hybrid,
+        }
+    }
+
+    /// Sets how the remainder of each frame is waited out.
+    pub fn set_pacing(&mut self, pacing: FramePacing) {
+        self.pacing = pacing;
+    }
+
+    #[inline]
+    pub fn pacing(&self) -> FramePacing {
+        self.pacing
+    }
+
+    /// Blocks until the current frame's deadline, then schedules the next one.
+    ///
+    /// The first call returns right away and starts the schedule.
+    pub fn wait(&mut self) {
+        let now = unsafe { sys::SDL_GetPerformanceCounter() };
+        let Some(deadline) = self.deadline else {
+            self.deadline = Some(now.saturating_add(self.interval));
+            return;
+        };
+        if now < deadline {
+            self.wait_until(deadline, now);
+        }
+        let now = unsafe { sys::SDL_GetPerformanceCounter() };
+        let next = deadline.saturating_add(self.interval);
+        self.deadline = Some(if next < now {
+            now.saturating_add(self.interval)
+        } else {
+            next
+        });
+    }
+
+    /// Forgets the schedule, e.g. after a loading screen, so the next [`FrameLimiter::wait`]
+    /// returns right away.
+    pub fn reset(&mut self) {
+        self.deadline = None;
+    }
+
+    fn wait_until(&self, deadline: u64, now: u64) {
+        let remaining_ns = u128::from(deadline - now) * 1_000_000_000 / u128::from(self.frequency);
+        let remaining_ns = u64::try_from(remaining_ns).unwrap_or(u64::MAX);
+        let sleep_ns = match self.pacing {
+            FramePacing::Sleep => remaining_ns,
+            FramePacing::Hybrid => remaining_ns.saturating_sub(Self::SPIN_THRESHOLD_NS),
+            FramePacing::Spin => 0,
+        };
+        if sleep_ns > 0 {
+            unsafe { sys::SDL_DelayNS(sleep_ns) };
+        }
+        if self.pacing != FramePacing::Sleep {
+            while unsafe { sys::SDL_GetPerformanceCounter() } < deadline {
+                core::hint::spin_loop();
+            }
+        }
+    }
+}
+
+/// How a [`FrameLimiter`] waits out the remainder of a frame.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FramePacing {
+    /// Sleeps for the whole remainder. Cheapest, but the OS scheduler may wake the thread late,
+    /// by up to a few milliseconds on some Windows systems.
+    Sleep,
+    /// Sleeps for all but the last millisecond, then spins until the deadline. Precise at the
+    /// cost of keeping a core busy for a short time each frame.
+    #[default]
+    Hybrid,
+    /// Spins on the performance counter for the whole remainder. Most precise, but keeps a core
+    /// fully busy.
+    Spin,
+}
+
+/// Tracks the application lifecycle on behalf of a renderer.
+///
+/// Mobile platforms, Android in particular, may destroy the graphics context while the
+/// application is in the background. Feed every event to [`RenderLifecycle::handle_event`]:
+/// pending commands are flushed before the application enters the background, and rendering
+/// should be skipped while [`RenderLifecycle::is_paused`] returns true. Once the application is
+/// back in the foreground, [`RenderLifecycle::take_resync`] reports which resources must be
+/// uploaded or drawn again.
+///
+/// On Android, `WillEnterBackground` must be handled before the event handler returns, so these
+/// events are best forwarded from an [`crate::events::EventWatch`] rather than the event queue.
+#[derive(Debug, Default)]
+pub struct RenderLifecycle {
+    paused: bool,
+    recreate_targets_on_resume: bool,
+    pending: Option<RenderResync>,
+}
+
+impl RenderLifecycle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assumes the contents of render targets are lost whenever the application returns to the
+    /// foreground, even if the renderer doesn't send [`RenderEventPayload::TargetsReset`].
+    pub fn recreate_targets_on_resume(mut self, value: bool) -> Self {
+        self.recreate_targets_on_resume = value;
+        self
+    }
+
+    /// Updates the lifecycle state from an event, flushing the renderer if the application is
+    /// about to enter the background.
+    pub fn handle_event<T>(
+        &mut self,
+        renderer: &mut Renderer<T>,
+        event: &Event,
+    ) -> Result<(), Error> {
+        match event.payload() {
+            EventPayload::WillEnterBackground => {
+                self.paused = true;
+                renderer.flush()?;
+            }
+            EventPayload::DidEnterBackground => self.paused = true,
+            EventPayload::DidEnterForeground => {
+                self.paused = false;
+                if self.recreate_targets_on_resume {
+                    self.request(RenderResync::Targets);
+                }
+            }
+            EventPayload::Render(event) => {
+                let window = unsafe { sys::SDL_GetRenderWindow(renderer.raw()) };
+                let window_id = if window.is_null() {
+                    0
+                } else {
+                    unsafe { sys::SDL_GetWindowID(window) }
+                };
+                if event.window_id != window_id {
+                    return Ok(());
+                }
+                self.request(match event.payload {
+                    RenderEventPayload::TargetsReset => RenderResync::Targets,
+                    RenderEventPayload::DeviceReset => RenderResync::Textures,
+                    RenderEventPayload::DeviceLost => RenderResync::Renderer,
+                });
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Returns true while the application is in the background and nothing should be rendered.
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Returns the resources that must be recreated before rendering again, and clears them.
+    ///
+    /// Always returns `None` while paused, so the request isn't lost before the application is
+    /// back in the foreground.
+    pub fn take_resync(&mut self) -> Option<RenderResync> {
+        if self.paused {
+            return None;
+        }
+        self.pending.take()
+    }
+
+    fn request(&mut self, resync: RenderResync) {
+        self.pending = self.pending.max(Some(resync));
+    }
+}
+
+/// Resources that must be recreated after a lifecycle change. See [`RenderLifecycle`].
+///
+/// Each variant implies the ones before it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RenderResync {
+    /// The contents of render target textures were lost and must be drawn again.
+    Targets,
+    /// Every texture was lost and must be recreated and uploaded again.
+    Textures,
+    /// The renderer itself is unusable and must be destroyed and created again.
+    Renderer,
+}
+
+/// An error from [`Renderer::try_present`] or [`Renderer::classify_error`].
+#[derive(Clone, Debug)]
+pub enum RenderError {
+    /// The graphics device was reset or lost. The renderer isn't necessarily broken: rebuild
+    /// the given resources and carry on.
+    Device(RenderResync),
+    /// Any other failure.
+    Sdl(Error),
+}
+
+impl core::fmt::Display for RenderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RenderError::Device(RenderResync::Renderer) => write!(f, "Render device lost"),
+            RenderError::Device(_) => write!(f, "Render device reset"),
+            RenderError::Sdl(error) => core::fmt::Display::fmt(error, f),
+        }
+    }
+}
+
+impl core::error::Error for RenderError {}
+
+impl From<Error> for RenderError {
+    fn from(error: Error) -> Self {
+        RenderError::Sdl(error)
+    }
+}
+
+/// A monotonic frame counter, as returned by [`Renderer::frame_index`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FrameId(u64);
+
+impl FrameId {
+    /// Returns the number of frames presented before this one.
+    #[inline]
+    pub fn get(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the id of the frame following this one.
+    #[inline]
+    pub fn next(&self) -> Self {
+        Self(self.0.wrapping_add(1))
+    }
+
+    /// Returns which of `count` cycled resources this frame should use, e.g. `slot(2)` for
+    /// double-buffering.
+    ///
+    /// Panics if `count` is 0.
+    #[inline]
+    pub fn slot(&self, count: usize) -> usize {
+        (self.0 % count as u64) as usize
+    }
+}
+
+/// A stack of nested clip rectangles.
+///
+/// Each call to [`ClipStack::push_clip`] intersects the given rectangle with the renderer's
+/// current clip rectangle, so content drawn by nested containers never escapes its parents.
+/// [`ClipStack::pop_clip`] restores the clip state that was active before the matching push,
+/// including disabling clipping altogether if it was disabled.
+///
+/// Rectangles are relative to the renderer's viewport, like [`Renderer::set_clip_rect`].
+#[derive(Default)]
+pub struct ClipStack {
+    saved: Vec<Option<sys::SDL_Rect>>,
+}
+
+impl ClipStack {
+    pub fn new() -> Self {
+        Self { saved: Vec::new() }
+    }
+
+    /// Intersects `rect` with the current clip rectangle and makes the result the new clip
+    /// rectangle.
+    ///
+    /// If the rectangles don't overlap, everything drawn until the next call to
+    /// [`ClipStack::pop_clip`] is clipped out.
+    pub fn push_clip<T>(&mut self, renderer: &mut Renderer<T>, rect: Rect) -> Result<(), Error> {
+        let current = if renderer.is_clip_enabled() {
+            let mut current: MaybeUninit<sys::SDL_Rect> = MaybeUninit::uninit();
+            let result =
+                unsafe { sys::SDL_GetRenderClipRect(renderer.raw(), current.as_mut_ptr()) };
+            if !result {
+                return Err(Error::new());
+            }
+            Some(unsafe { current.assume_init() })
+        } else {
+            None
+        };
+        let clip = match current {
+            Some(current) => {
+                let mut clip: MaybeUninit<sys::SDL_Rect> = MaybeUninit::uninit();
+                let intersects = unsafe {
+                    sys::SDL_GetRectIntersection(
+                        &raw const current,
+                        rect.as_raw(),
+                        clip.as_mut_ptr(),
+                    )
+                };
+                if intersects {
+                    unsafe { clip.assume_init() }
+                } else {
+                    sys::SDL_Rect {
+                        x: rect.x(),
+                        y: rect.y(),
+                        w: 0,
+                        h: 0,
+                    }
+                }
+            }
+            None => rect.to_ll(),
+        };
+        let result = unsafe { sys::SDL_SetRenderClipRect(renderer.raw(), &raw const clip) };
+        if !result {
+            return Err(Error::new());
+        }
+        self.saved.push(current);
+        Ok(())
+    }
+
+    /// Restores the clip state that was active before the last call to [`ClipStack::push_clip`].
+    ///
+    /// Returns an error if the stack is empty.
+    pub fn pop_clip<T>(&mut self, renderer: &mut Renderer<T>) -> Result<(), Error> {
+        let Some(previous) = self.saved.pop() else {
+            return Err(Error::register(c"Clip stack is empty."));
+        };
+        let previous = previous
+            .as_ref()
+            .map(|rect| rect as *const sys::SDL_Rect)
+            .unwrap_or(core::ptr::null());
+        let result = unsafe { sys::SDL_SetRenderClipRect(renderer.raw(), previous) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// The number of clip rectangles currently pushed.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.saved.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.saved.is_empty()
+    }
+}
+
+/// Splits the render output into one viewport per player, for local multiplayer.
+///
+/// [`SplitScreen::render`] sets the viewport and clip rectangle of each player in turn and runs
+/// a closure to draw their view, with `(0, 0)` at the top left corner of the player's area.
+/// The renderer's previous viewport and clip rectangle are restored afterwards.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SplitScreen {
+    players: usize,
+    layout: SplitLayout,
+}
+
+impl SplitScreen {
+    /// Creates a split screen for 1 to 4 players.
+    pub fn new(players: usize, layout: SplitLayout) -> Result<Self, Error> {
+        if !(1..=4).contains(&players) {
+            return Err(Error::register(c"Split screen supports 1 to 4 players."));
+        }
+        Ok(Self { players, layout })
+    }
+
+    #[inline]
+    pub fn players(&self) -> usize {
+        self.players
+    }
+
+    #[inline]
+    pub fn layout(&self) -> SplitLayout {
+        self.layout
+    }
+
+    /// Returns the area of each player within an output of `width` x `height` pixels.
+    ///
+    /// The areas cover the whole output without overlapping, except for three players in a
+    /// [`SplitLayout::Grid`], where the third player gets the whole bottom half.
+    pub fn viewports(&self, width: u32, height: u32) -> Vec<Rect> {
+        // Edges are computed from the full size so the areas add up exactly.
+        let edge = |size: u32, index: usize, count: usize| {
+            (u64::from(size) * index as u64 / count as u64) as i32
+        };
+        let span = |size: u32, index: usize, count: usize| {
+            (edge(size, index + 1, count) - edge(size, index, count)) as u32
+        };
+        let n = self.players;
+        match (self.layout, n) {
+            (_, 1) => vec![Rect::new(0, 0, width, height)],
+            (SplitLayout::Columns, _) => (0..n)
+                .map(|i| Rect::new(edge(width, i, n), 0, span(width, i, n), height))
+                .collect(),
+            (SplitLayout::Rows, _) => (0..n)
+                .map(|i| Rect::new(0, edge(height, i, n), width, span(height, i, n)))
+                .collect(),
+            (SplitLayout::Grid, 2) => (0..2)
+                .map(|i| Rect::new(edge(width, i, 2), 0, span(width, i, 2), height))
+                .collect(),
+            (SplitLayout::Grid, _) => (0..n)
+                .map(|i| {
+                    let (column, row) = (i % 2, i / 2);
+                    let (x, w) = if n == 3 && row == 1 {
+                        (0, width)
+                    } else {
+                        (edge(width, column, 2), span(width, column, 2))
+                    };
+                    Rect::new(x, edge(height, row, 2), w, span(height, row, 2))
+                })
+                .collect(),
+        }
+    }
+
+    /// Draws every player's view with `draw`, which receives the renderer, the player's index
+    /// and their area in render coordinates.
+    ///
+    /// Areas are computed from [`Renderer::current_output_size`]. The previous viewport and clip
+    /// rectangle are restored even if `draw` fails; drawing stops at the first error.
+    pub fn render<T>(
+        &self,
+        renderer: &mut Renderer<T>,
+        mut draw: impl FnMut(&mut Renderer<T>, usize, Rect) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let (width, height) = renderer.current_output_size()?;
+        let viewport = match renderer.is_viewport_set() {
+            true => Some(renderer.viewport()?),
+            false => None,
+        };
+        let clip = match renderer.is_clip_enabled() {
+            true => Some(renderer.clip_rect()?),
+            false => None,
+        };
+        let result = self
+            .viewports(width, height)
+            .into_iter()
+            .enumerate()
+            .try_for_each(|(player, area)| {
+                renderer.set_viewport(area)?;
+                renderer.set_clip_rect(Rect::new(0, 0, area.w(), area.h()))?;
+                draw(renderer, player, area)
+            });
+        let viewport = viewport
+            .as_ref()
+            .map(Rect::as_raw)
+            .unwrap_or(core::ptr::null());
+        let clip = clip.as_ref().map(Rect::as_raw).unwrap_or(core::ptr::null());
+        let restored = unsafe {
+            sys::SDL_SetRenderViewport(renderer.raw(), viewport)
+                & sys::SDL_SetRenderClipRect(renderer.raw(), clip)
+        };
+        result?;
+        if !restored {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+}
+
+/// How a [`SplitScreen`] arranges the players' areas.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SplitLayout {
+    /// Side by side, from left to right.
+    Columns,
+    /// Stacked, from top to bottom.
+    Rows,
+    /// Two columns, filled row by row. Two players are side by side.
+    Grid,
+}
+
+/// VSync behavior of a renderer.
+///
+/// When a renderer is created, vsync defaults to `RendererVSync::Disabled`.
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RendererVSync {
+    EveryVerticalRefresh = 1,
+    EverySecondVerticalRefresh = 2,
+    Adaptive = sys::SDL_RENDERER_VSYNC_ADAPTIVE,
+    Disabled = sys::SDL_RENDERER_VSYNC_DISABLED as i32,
+}
+
+impl RendererVSync {
+    /// Converts a raw vsync value into a `RendererVSync`.
+    ///
+    /// Returns an error for intervals SDL accepts but this enum doesn't represent, such as every
+    /// third refresh.
+    pub fn try_from_ll(value: i32) -> Result<Self, Error> {
+        Ok(match value {
+            1 => Self::EveryVerticalRefresh,
+            2 => Self::EverySecondVerticalRefresh,
+            sys::SDL_RENDERER_VSYNC_ADAPTIVE => Self::Adaptive,
+            v if v == sys::SDL_RENDERER_VSYNC_DISABLED as i32 => Self::Disabled,
+            _ => return Err(Error::register(c"Unsupported vsync interval.")),
+        })
+    }
+
+    /// SAFETY: `value` must be a valid variant of the enum.
+    unsafe fn from_ll_unchecked(value: i32) -> Self {
+        unsafe { core::mem::transmute(value) }
     }
 
     pub fn to_raw(&self) -> i32 {
@@ -1203,6 +2405,113 @@ impl RendererVSync {
     }
 }
 
+/// Checks that `bytes` holds consecutive planes of `(pitch, row_bytes, rows)`, where only
+/// `row_bytes` of the last row of the last plane need to be present.
+fn check_planes_len(
+    bytes: &[u8],
+    planes: impl IntoIterator<Item = (usize, usize, usize)>,
+) -> Result<(), Error> {
+    let mut required = 0usize;
+    let mut planes = planes.into_iter().peekable();
+    while let Some((pitch, row_bytes, rows)) = planes.next() {
+        if pitch < row_bytes {
+            return Err(Error::register(
+                c"Pitch is smaller than a row of the updated area.",
+            ));
+        }
+        let len = if planes.peek().is_some() {
+            pitch.checked_mul(rows)
+        } else if rows == 0 {
+            Some(0)
+        } else {
+            (rows - 1)
+                .checked_mul(pitch)
+                .and_then(|len| len.checked_add(row_bytes))
+        };
+        required = len
+            .and_then(|len| required.checked_add(len))
+            .ok_or(Error::IntConversion)?;
+    }
+    if bytes.len() < required {
+        return Err(Error::register(
+            c"Pixel buffer is too small for the updated area.",
+        ));
+    }
+    Ok(())
+}
+
+/// A 2D rendering driver, as listed by [`VideoSubsystem::render_drivers`].
+///
+/// Pass [`RenderDriver::name`] to [`Renderer::from_window`] to create a renderer with a specific
+/// driver.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RenderDriver {
+    /// Direct3D 9.
+    Direct3D,
+    Direct3D11,
+    Direct3D12,
+    OpenGL,
+    /// OpenGL ES 2.
+    OpenGLES2,
+    Vulkan,
+    Metal,
+    /// The renderer built on top of SDL's GPU API.
+    Gpu,
+    Software,
+    /// A driver that isn't known to this crate, such as those of game consoles.
+    Other(String),
+}
+
+impl RenderDriver {
+    /// Parses the name of a driver, as returned by [`VideoSubsystem::render_driver`].
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "direct3d" => Self::Direct3D,
+            "direct3d11" => Self::Direct3D11,
+            "direct3d12" => Self::Direct3D12,
+            "opengl" => Self::OpenGL,
+            "opengles2" => Self::OpenGLES2,
+            "vulkan" => Self::Vulkan,
+            "metal" => Self::Metal,
+            "gpu" => Self::Gpu,
+            "software" => Self::Software,
+            other => Self::Other(String::from(other)),
+        }
+    }
+
+    /// Returns the name SDL uses for the driver.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Direct3D => "direct3d",
+            Self::Direct3D11 => "direct3d11",
+            Self::Direct3D12 => "direct3d12",
+            Self::OpenGL => "opengl",
+            Self::OpenGLES2 => "opengles2",
+            Self::Vulkan => "vulkan",
+            Self::Metal => "metal",
+            Self::Gpu => "gpu",
+            Self::Software => "software",
+            Self::Other(name) => name,
+        }
+    }
+
+    /// Returns true if the driver renders on the GPU.
+    ///
+    /// Unknown drivers are assumed to be hardware accelerated.
+    pub fn is_hardware_accelerated(&self) -> bool {
+        !matches!(self, Self::Software)
+    }
+
+    /// Returns true if the driver can output in the `SRGB_LINEAR` colorspace, which is required
+    /// for HDR output.
+    pub fn supports_hdr_output(&self) -> bool {
+        matches!(
+            self,
+            Self::Direct3D11 | Self::Direct3D12 | Self::Metal | Self::Vulkan
+        )
+    }
+}
+
 // Describes how a renderer's logical size is mapped to its' output.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct RenderLogicalPresentation {
@@ -1228,6 +2537,26 @@ pub enum RenderLogicalPresentationMode {
 }
 
 impl RenderLogicalPresentationMode {
+    /// Converts a raw `SDL_RendererLogicalPresentation` into a `RenderLogicalPresentationMode`.
+    pub fn try_from_ll(value: sys::SDL_RendererLogicalPresentation) -> Result<Self, Error> {
+        Ok(match value {
+            sys::SDL_RendererLogicalPresentation_SDL_LOGICAL_PRESENTATION_DISABLED => {
+                Self::Disabled
+            }
+            sys::SDL_RendererLogicalPresentation_SDL_LOGICAL_PRESENTATION_STRETCH => Self::Stretch,
+            sys::SDL_RendererLogicalPresentation_SDL_LOGICAL_PRESENTATION_LETTERBOX => {
+                Self::Letterbox
+            }
+            sys::SDL_RendererLogicalPresentation_SDL_LOGICAL_PRESENTATION_OVERSCAN => {
+                Self::Overscan
+            }
+            sys::SDL_RendererLogicalPresentation_SDL_LOGICAL_PRESENTATION_INTEGER_SCALE => {
+                Self::IntegerScale
+            }
+            _ => return Err(Error::register(c"Unknown logical presentation mode.")),
+        })
+    }
+
     /// SAFETY: `value` must be a valid variant of the enum.
     unsafe fn from_ll_unchecked(value: u32) -> Self {
         unsafe { core::mem::transmute(value) }
@@ -1244,11 +2573,26 @@ impl RenderLogicalPresentationMode {
 /// via ref-count. A consequence of this is, to truly destroy the parent renderer
 /// (equivalent to `SDL_DestroyRenderer`) and its' backbuffer, all `Texture`s created
 /// by that renderer must be dropped.
+///
+/// With the `send` feature, textures can be sent to other threads, e.g. to be kept with the
+/// [`StagingBuffer`] a worker thread fills for them. They can still only be used on the main
+/// thread: every function that calls into SDL panics on other threads, except [`Texture::w`],
+/// [`Texture::h`], [`Texture::format`] and [`Texture::staging`]. A texture dropped on another
+/// thread is destroyed on the main thread the next time it pumps events.
 pub struct Texture<T = Window> {
-    _renderer: Rc<RendererInternal<T>>,
+    /// Only dropped by [`Texture`]'s `Drop` implementation, which may have to defer it to the main
+    /// thread.
+    _renderer: ManuallyDrop<Shared<RendererInternal<T>>>,
     ptr: NonNull<sys::SDL_Texture>,
 }
 
+// SAFETY: The renderer, and the owner it keeps alive, are only ever touched on the main thread:
+// `Texture::raw` asserts it and `Drop` defers to the main thread. `T: 'static` makes sure the
+// owner outlives a deferred drop.
+#[cfg(feature = "send")]
+#[cfg_attr(docsrs, doc(cfg(feature = "send")))]
+unsafe impl<T: 'static> Send for Texture<T> {}
+
 impl<T> Texture<T> {
     /// Creates a texture for a rendering context.
     ///
@@ -1271,26 +2615,24 @@ impl<T> Texture<T> {
                 height.try_into()?,
             )
         })
-        .ok_or(Error::new())?;
-        Ok(Self {
-            _renderer: Rc::clone(&renderer.internal),
-            ptr,
-        })
+        .ok_or_else(Error::new)?;
+        Self::with_default_scale_mode(renderer, ptr)
     }
 
     #[inline]
     pub fn w(&self) -> i32 {
-        unsafe { (*self.raw()).w }
+        unsafe { (*self.ptr.as_ptr()).w }
     }
 
     #[inline]
     pub fn h(&self) -> i32 {
-        unsafe { (*self.raw()).h }
+        unsafe { (*self.ptr.as_ptr()).h }
     }
 
     #[inline]
     pub fn format(&self) -> PixelFormat {
-        unsafe { PixelFormat::from_ll_unchecked((*self.raw()).format) }
+        // The size and format never change, so they're read directly on any thread.
+        unsafe { PixelFormat::from_ll_unchecked((*self.ptr.as_ptr()).format) }
     }
 
     /// Returns the size of a texture, as floating point values.
@@ -1315,11 +2657,96 @@ impl<T> Texture<T> {
         let ptr = NonNull::new(unsafe {
             sys::SDL_CreateTextureFromSurface(renderer.raw(), surface.raw() as *mut _)
         })
-        .ok_or(Error::new())?;
-        Ok(Texture {
-            _renderer: Rc::clone(&renderer.internal),
-            ptr,
+        .ok_or_else(Error::new)?;
+        Self::with_default_scale_mode(renderer, ptr)
+    }
+
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    /// Loads an image from a file directly into a texture.
+    ///
+    /// Unlike loading a [`Surface`] and calling [`Texture::from_surface`], the decoded image is
+    /// uploaded and freed right away, without keeping a second copy of it around.
+    pub fn load(renderer: &mut Renderer<T>, path: &str) -> Result<Self, Error> {
+        let path = CString::new(path)?;
+        let ptr =
+            NonNull::new(unsafe { sys::image::IMG_LoadTexture(renderer.raw(), path.as_ptr()) })
+                .ok_or_else(Error::new)?;
+        Self::with_default_scale_mode(renderer, ptr)
+    }
+
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    /// Loads an image from a stream directly into a texture.
+    pub fn load_from_io(renderer: &mut Renderer<T>, io: IOStream) -> Result<Self, Error> {
+        let ptr = NonNull::new(unsafe {
+            sys::image::IMG_LoadTexture_IO(renderer.raw(), io.raw(), false)
         })
+        .ok_or_else(Error::new)?;
+        Self::with_default_scale_mode(renderer, ptr)
+    }
+
+    /// Returns the properties associated with this texture.
+    pub fn properties(&self) -> Result<PropertiesRef<'_>, Error> {
+        unsafe { PropertiesRef::from_ll(sys::SDL_GetTextureProperties(self.raw())) }
+    }
+
+    /// Returns the properties associated with this texture, allowing them to be changed.
+    pub fn properties_mut(&mut self) -> Result<PropertiesRefMut<'_>, Error> {
+        unsafe { PropertiesRefMut::from_ll(sys::SDL_GetTextureProperties(self.raw())) }
+    }
+
+    /// Returns every property SDL associates with this texture as a `String`.
+    ///
+    /// See [`WindowRef::dump_properties`] for the output format.
+    pub fn dump_properties(&self) -> Result<String, Error> {
+        let props = unsafe { sys::SDL_GetTextureProperties(self.raw()) };
+        crate::properties::dump_properties(props)
+    }
+
+    /// Returns the object backing this texture in the renderer's graphics API, or `None` for
+    /// renderers that don't expose it, such as the software and Metal renderers.
+    ///
+    /// The handle stays owned by SDL and is only valid while the texture is alive. Using it is
+    /// inherently unsafe: the renderer batches commands, so call [`Renderer::flush`] before
+    /// accessing the texture natively, and restore any native state that was changed.
+    pub fn native_handle(&self) -> Result<Option<NativeTexture>, Error> {
+        let props = self.properties()?;
+        let handle = if props.has("SDL.texture.d3d11.texture")? {
+            NativeTexture::Direct3D11(props.pointer("SDL.texture.d3d11.texture")?)
+        } else if props.has("SDL.texture.d3d12.texture")? {
+            NativeTexture::Direct3D12(props.pointer("SDL.texture.d3d12.texture")?)
+        } else if props.has("SDL.texture.opengl.texture")? {
+            NativeTexture::OpenGl {
+                texture: props.number("SDL.texture.opengl.texture", 0)? as u32,
+                target: props.number("SDL.texture.opengl.target", 0)? as u32,
+            }
+        } else if props.has("SDL.texture.opengles2.texture")? {
+            NativeTexture::OpenGles2 {
+                texture: props.number("SDL.texture.opengles2.texture", 0)? as u32,
+                target: props.number("SDL.texture.opengles2.target", 0)? as u32,
+            }
+        } else if props.has("SDL.texture.vulkan.texture")? {
+            NativeTexture::Vulkan(props.number("SDL.texture.vulkan.texture", 0)? as u64)
+        } else {
+            return Ok(None);
+        };
+        Ok(Some(handle))
+    }
+
+    /// Wraps a newly created texture, applying the renderer's default scale mode.
+    fn with_default_scale_mode(
+        renderer: &Renderer<T>,
+        ptr: NonNull<sys::SDL_Texture>,
+    ) -> Result<Self, Error> {
+        let mut texture = Self {
+            _renderer: ManuallyDrop::new(Shared::clone(&renderer.internal)),
+            ptr,
+        };
+        if let Some(scale_mode) = renderer.internal.default_scale_mode.get() {
+            texture.set_scale_mode(scale_mode)?;
+        }
+        Ok(texture)
     }
 
     /// Returns the additional alpha value multiplied into render copy operations.
@@ -1486,26 +2913,390 @@ impl<T> Texture<T> {
         TextureLock::new(self, rect)
     }
 
+    /// Update a portion of the texture with new pixel data, or the entire texture if `rect` is `None`.
+    ///
+    /// `pixels` must be in the format of the texture, with `pitch` bytes per row. This is a fairly slow
+    /// function, intended for use with static textures that don't change often. For streaming textures that
+    /// are updated every frame, [`Texture::lock`] is usually faster.
+    ///
+    /// For planar YUV formats, [`Texture::update_yuv`] and [`Texture::update_nv`] accept each plane separately.
+    pub fn update(&mut self, rect: Option<Rect>, pixels: &[u8], pitch: usize) -> Result<(), Error> {
+        let (w, h) = self.update_size(rect)?;
+        let (w, h) = (w as usize, h as usize);
+        let format = self.format();
+        let bits = format.details()?.bits_per_pixel() as usize;
+        // For planar YUV formats this is the Y plane; SDL finds the other planes after it.
+        let row_bytes = w.checked_mul(bits).ok_or(Error::IntConversion)?.div_ceil(8);
+        let (chroma_w, chroma_h) = (w.div_ceil(2), h.div_ceil(2));
+        let planes = match format {
+            PixelFormat::Yv12 | PixelFormat::Iyuv => {
+                let chroma = (pitch.div_ceil(2), chroma_w, chroma_h);
+                [Some((pitch, row_bytes, h)), Some(chroma), Some(chroma)]
+            }
+            PixelFormat::Nv12 | PixelFormat::Nv21 | PixelFormat::P010 => {
+                let sample = bits.div_ceil(8);
+                let uv = (2 * pitch.div_ceil(2), 2 * chroma_w * sample, chroma_h);
+                [Some((pitch, row_bytes, h)), Some(uv), None]
+            }
+            _ => [Some((pitch, row_bytes, h)), None, None],
+        };
+        check_planes_len(pixels, planes.into_iter().flatten())?;
+        let rect = rect.as_ref().map(Rect::as_raw).unwrap_or(core::ptr::null());
+        let result = unsafe {
+            sys::SDL_UpdateTexture(
+                self.raw(),
+                rect,
+                pixels.as_ptr() as *const c_void,
+                i32::try_from(pitch)?,
+            )
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Update a portion of a planar YV12 or IYUV texture with new Y, U and V planes, or the entire texture
+    /// if `rect` is `None`.
+    ///
+    /// Each plane is given as its bytes and its pitch. The U and V planes have half the height of the Y plane,
+    /// rounded up. This is a fairly slow function, intended for use with static textures that don't change often.
+    pub fn update_yuv(
+        &mut self,
+        rect: Option<Rect>,
+        (y_plane, y_pitch): (&[u8], usize),
+        (u_plane, u_pitch): (&[u8], usize),
+        (v_plane, v_pitch): (&[u8], usize),
+    ) -> Result<(), Error> {
+        let (w, h) = self.update_size(rect)?;
+        let (w, h) = (w as usize, h as usize);
+        let (chroma_w, chroma_h) = (w.div_ceil(2), h.div_ceil(2));
+        check_planes_len(y_plane, [(y_pitch, w, h)])?;
+        check_planes_len(u_plane, [(u_pitch, chroma_w, chroma_h)])?;
+        check_planes_len(v_plane, [(v_pitch, chroma_w, chroma_h)])?;
+        let rect = rect.as_ref().map(Rect::as_raw).unwrap_or(core::ptr::null());
+        let result = unsafe {
+            sys::SDL_UpdateYUVTexture(
+                self.raw(),
+                rect,
+                y_plane.as_ptr(),
+                i32::try_from(y_pitch)?,
+                u_plane.as_ptr(),
+                i32::try_from(u_pitch)?,
+                v_plane.as_ptr(),
+                i32::try_from(v_pitch)?,
+            )
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Update a portion of a planar NV12 or NV21 texture with new Y and interleaved UV planes, or the
+    /// entire texture if `rect` is `None`.
+    ///
+    /// Each plane is given as its bytes and its pitch. The UV plane has half the height of the Y plane,
+    /// rounded up. This is a fairly slow function, intended for use with static textures that don't change often.
+    pub fn update_nv(
+        &mut self,
+        rect: Option<Rect>,
+        (y_plane, y_pitch): (&[u8], usize),
+        (uv_plane, uv_pitch): (&[u8], usize),
+    ) -> Result<(), Error> {
+        let (w, h) = self.update_size(rect)?;
+        let (w, h) = (w as usize, h as usize);
+        // P010 uses 2 bytes per sample.
+        let sample = (self.format().details()?.bits_per_pixel() as usize).div_ceil(8);
+        check_planes_len(y_plane, [(y_pitch, w * sample, h)])?;
+        check_planes_len(
+            uv_plane,
+            [(uv_pitch, 2 * w.div_ceil(2) * sample, h.div_ceil(2))],
+        )?;
+        let rect = rect.as_ref().map(Rect::as_raw).unwrap_or(core::ptr::null());
+        let result = unsafe {
+            sys::SDL_UpdateNVTexture(
+                self.raw(),
+                rect,
+                y_plane.as_ptr(),
+                i32::try_from(y_pitch)?,
+                uv_plane.as_ptr(),
+                i32::try_from(uv_pitch)?,
+            )
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Allocates a buffer for new pixels of a portion of the texture, or the entire texture if `rect` is `None`.
+    ///
+    /// The [`StagingBuffer`] can be filled on any thread, then passed back to [`Texture::upload`]
+    /// with the same `rect` on the main thread. Planar YUV formats aren't supported.
+    pub fn staging(&self, rect: Option<Rect>) -> Result<StagingBuffer, Error> {
+        let (w, h) = self.update_size(rect)?;
+        StagingBuffer::new(w, h, self.format(), Vec::new())
+    }
+
+    /// Copies the pixels of a buffer created by [`Texture::staging`] into a portion of the texture,
+    /// or the entire texture if `rect` is `None`.
+    ///
+    /// Returns an error if the buffer's format or size don't match the texture and `rect`.
+    pub fn upload(&mut self, rect: Option<Rect>, staging: &StagingBuffer) -> Result<(), Error> {
+        if staging.format() != self.format() {
+            return Err(Error::register(
+                c"Staging buffer format doesn't match the texture.",
+            ));
+        }
+        if (staging.width(), staging.height()) != self.update_size(rect)? {
+            return Err(Error::register(
+                c"Staging buffer size doesn't match the updated area.",
+            ));
+        }
+        self.update(rect, staging.pixels(), staging.pitch())
+    }
+
+    // The size of the area written by the `update` functions.
+    fn update_size(&self, rect: Option<Rect>) -> Result<(u32, u32), Error> {
+        match rect {
+            Some(rect) => Ok((rect.w(), rect.h())),
+            None => Ok((u32::try_from(self.w())?, u32::try_from(self.h())?)),
+        }
+    }
+
     /// SAFETY: texture must come directly from SDL and it *must* be owned by the caller.
     unsafe fn from_mut_ptr(renderer: &mut Renderer<T>, ptr: *mut sys::SDL_Texture) -> Self {
         Self {
-            _renderer: Rc::clone(&renderer.internal),
+            _renderer: ManuallyDrop::new(Shared::clone(&renderer.internal)),
             ptr: NonNull::new_unchecked(ptr),
         }
     }
 
     #[inline]
     fn raw(&self) -> *mut sys::SDL_Texture {
+        #[cfg(feature = "send")]
+        assert!(
+            crate::init::is_main_thread(),
+            "Textures can only be used on the main thread."
+        );
         self.ptr.as_ptr()
     }
 }
 
 impl<T> Drop for Texture<T> {
     fn drop(&mut self) {
-        unsafe { sys::SDL_DestroyTexture(self.ptr.as_ptr()) };
+        #[cfg(feature = "send")]
+        if !crate::init::is_main_thread() {
+            let deferred = Box::into_raw(Box::new(DeferredTextureDrop {
+                _renderer: unsafe { ManuallyDrop::take(&mut self._renderer) },
+                ptr: self.ptr,
+            }));
+            // If this fails, the texture and its renderer leak: they can't be destroyed here.
+            let _ = unsafe {
+                sys::SDL_RunOnMainThread(
+                    Some(drop_texture_on_main_thread::<T>),
+                    deferred.cast(),
+                    false,
+                )
+            };
+            return;
+        }
+        unsafe {
+            sys::SDL_DestroyTexture(self.ptr.as_ptr());
+            ManuallyDrop::drop(&mut self._renderer);
+        }
+    }
+}
+
+/// A texture dropped on another thread, destroyed by [`drop_texture_on_main_thread`].
+#[cfg(feature = "send")]
+struct DeferredTextureDrop<T> {
+    _renderer: Shared<RendererInternal<T>>,
+    ptr: NonNull<sys::SDL_Texture>,
+}
+
+#[cfg(feature = "send")]
+unsafe extern "C" fn drop_texture_on_main_thread<T>(user_data: *mut c_void) {
+    let deferred = unsafe { Box::from_raw(user_data as *mut DeferredTextureDrop<T>) };
+    unsafe { sys::SDL_DestroyTexture(deferred.ptr.as_ptr()) };
+}
+
+/// A rectangular region of a [`Texture`], such as a sprite in a texture atlas.
+///
+/// Keeping the source rectangle together with the texture it belongs to prevents drawing a
+/// rectangle meant for one atlas out of another.
+pub struct AtlasRegion<'a, T = Window> {
+    texture: &'a Texture<T>,
+    rect: RectF32,
+}
+
+impl<'a, T> AtlasRegion<'a, T> {
+    /// Creates a region covering `rect`, in pixels of `texture`.
+    pub fn new(texture: &'a Texture<T>, rect: RectF32) -> Self {
+        Self { texture, rect }
+    }
+
+    /// Creates a region covering the entire texture.
+    pub fn whole(texture: &'a Texture<T>) -> Self {
+        Self::new(
+            texture,
+            RectF32::new(0.0, 0.0, texture.w() as f32, texture.h() as f32),
+        )
+    }
+
+    /// Returns a region of the same texture, with `rect` relative to the top-left corner of this
+    /// region.
+    ///
+    /// This is useful to split a region into animation frames.
+    pub fn sub_region(&self, rect: RectF32) -> Self {
+        Self::new(
+            self.texture,
+            RectF32::new(
+                self.rect.x() + rect.x(),
+                self.rect.y() + rect.y(),
+                rect.w(),
+                rect.h(),
+            ),
+        )
+    }
+
+    #[inline]
+    pub fn texture(&self) -> &'a Texture<T> {
+        self.texture
+    }
+
+    #[inline]
+    pub fn rect(&self) -> RectF32 {
+        self.rect
     }
 }
 
+impl<T> Clone for AtlasRegion<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for AtlasRegion<'_, T> {}
+
+/// Per-draw overrides for [`Renderer::render_texture_with`].
+///
+/// Fields left as `None` keep the texture's current setting.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DrawParams {
+    pub blend: Option<BlendMode>,
+    pub scale_mode: Option<ScaleMode>,
+    pub color_mod: Option<(u8, u8, u8)>,
+    pub alpha_mod: Option<u8>,
+    pub flip: Option<FlipMode>,
+    /// Rotation in degrees, applied clockwise around `center`.
+    pub angle: f64,
+    /// Rotation center relative to the destination rectangle, or `None` for its center.
+    pub center: Option<PointF32>,
+}
+
+// Texture properties saved by `Renderer::render_texture_with`, kept in their raw form so that
+// they're restored exactly as they were.
+struct TextureState {
+    blend: Option<sys::SDL_BlendMode>,
+    scale_mode: Option<sys::SDL_ScaleMode>,
+    color_mod: Option<(u8, u8, u8)>,
+    alpha_mod: Option<u8>,
+}
+
+impl TextureState {
+    fn save<T>(texture: &Texture<T>, params: &DrawParams) -> Result<Self, Error> {
+        let blend = match params.blend {
+            Some(_) => {
+                let mut blend_mode: sys::SDL_BlendMode = 0;
+                let result =
+                    unsafe { sys::SDL_GetTextureBlendMode(texture.raw(), &raw mut blend_mode) };
+                if !result {
+                    return Err(Error::new());
+                }
+                Some(blend_mode)
+            }
+            None => None,
+        };
+        let scale_mode = match params.scale_mode {
+            Some(_) => Some(texture.scale_mode()?.to_ll()),
+            None => None,
+        };
+        let color_mod = match params.color_mod {
+            Some(_) => Some(texture.color_mod()?),
+            None => None,
+        };
+        let alpha_mod = match params.alpha_mod {
+            Some(_) => Some(texture.alpha_mod()?),
+            None => None,
+        };
+        Ok(Self {
+            blend,
+            scale_mode,
+            color_mod,
+            alpha_mod,
+        })
+    }
+
+    fn apply<T>(texture: &mut Texture<T>, params: &DrawParams) -> Result<(), Error> {
+        if let Some(blend) = params.blend {
+            texture.set_blend_mode(blend)?;
+        }
+        if let Some(scale_mode) = params.scale_mode {
+            texture.set_scale_mode(scale_mode)?;
+        }
+        if let Some(color_mod) = params.color_mod {
+            texture.set_color_mod(color_mod)?;
+        }
+        if let Some(alpha_mod) = params.alpha_mod {
+            texture.set_alpha_mod(alpha_mod)?;
+        }
+        Ok(())
+    }
+
+    // Restores every saved property, reporting the first failure.
+    fn restore<T>(self, texture: &mut Texture<T>) -> Result<(), Error> {
+        let mut result = Ok(());
+        if let Some(blend) = self.blend {
+            let restored = unsafe { sys::SDL_SetTextureBlendMode(texture.raw(), blend) };
+            if !restored && result.is_ok() {
+                result = Err(Error::new());
+            }
+        }
+        if let Some(scale_mode) = self.scale_mode {
+            let restored = unsafe { sys::SDL_SetTextureScaleMode(texture.raw(), scale_mode) };
+            if !restored && result.is_ok() {
+                result = Err(Error::new());
+            }
+        }
+        if let Some(color_mod) = self.color_mod {
+            result = result.and(texture.set_color_mod(color_mod));
+        }
+        if let Some(alpha_mod) = self.alpha_mod {
+            result = result.and(texture.set_alpha_mod(alpha_mod));
+        }
+        result
+    }
+}
+
+/// The native object backing a [`Texture`], returned by [`Texture::native_handle`].
+///
+/// Only the texture itself is exposed; the separate planes of YUV textures aren't.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NativeTexture {
+    /// An `ID3D11Texture2D`.
+    Direct3D11(*mut c_void),
+    /// An `ID3D12Resource`.
+    Direct3D12(*mut c_void),
+    /// A `GLuint` texture name and the `GLenum` it's bound to, such as `GL_TEXTURE_2D`.
+    OpenGl { texture: u32, target: u32 },
+    /// A `GLuint` texture name and the `GLenum` it's bound to, such as `GL_TEXTURE_EXTERNAL_OES`.
+    OpenGles2 { texture: u32, target: u32 },
+    /// A `VkImage`.
+    Vulkan(u64),
+}
+
 /// A texture that's locked for writing.
 pub struct TextureLock<'a, T> {
     /// A pointer to the pixels array, owned by SDL
@@ -1575,12 +3366,131 @@ pub enum TextureAccess {
 }
 
 impl TextureAccess {
+    /// Converts a raw `SDL_TextureAccess` into a `TextureAccess`.
+    pub fn try_from_ll(ll: sys::SDL_TextureAccess) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_TextureAccess_SDL_TEXTUREACCESS_STATIC => Self::Static,
+            sys::SDL_TextureAccess_SDL_TEXTUREACCESS_STREAMING => Self::Streaming,
+            sys::SDL_TextureAccess_SDL_TEXTUREACCESS_TARGET => Self::Target,
+            _ => return Err(Error::register(c"Unknown texture access.")),
+        })
+    }
+
     pub fn to_ll(self) -> sys::SDL_TextureAccess {
         self as sys::SDL_TextureAccess
     }
 }
 
+/// A vertex attribute read from a buffer with a fixed stride, for [`Renderer::render_geometry_raw`].
+///
+/// Attributes of interleaved vertices share the same buffer and stride, and start at different offsets.
+pub struct VertexAttribute<'a, A> {
+    ptr: *const A,
+    stride: i32,
+    len: usize,
+    _marker: PhantomData<&'a [A]>,
+}
+
+impl<'a, A> VertexAttribute<'a, A> {
+    /// An attribute stored in its own tightly packed buffer.
+    pub fn packed(data: &'a [A]) -> Self {
+        Self {
+            ptr: data.as_ptr(),
+            stride: size_of::<A>() as i32,
+            len: data.len(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// An attribute that starts `offset` bytes into `bytes`, with one element every `stride` bytes.
+    ///
+    /// Returns an error if `stride` is smaller than the attribute or if the attribute isn't properly
+    /// aligned. Trailing bytes that don't hold a complete element are ignored.
+    pub fn from_bytes(bytes: &'a [u8], offset: usize, stride: usize) -> Result<Self, Error> {
+        let size = size_of::<A>();
+        let align = align_of::<A>();
+        if stride < size || !stride.is_multiple_of(align) {
+            return Err(Error::register(c"Invalid vertex attribute stride."));
+        }
+        if !(bytes.as_ptr() as usize)
+            .wrapping_add(offset)
+            .is_multiple_of(align)
+        {
+            return Err(Error::register(c"Misaligned vertex attribute."));
+        }
+        let len = match bytes
+            .len()
+            .checked_sub(offset)
+            .and_then(|n| n.checked_sub(size))
+        {
+            Some(remaining) => remaining / stride + 1,
+            None => 0,
+        };
+        Ok(Self {
+            ptr: bytes.as_ptr().wrapping_add(offset) as *const A,
+            stride: i32::try_from(stride)?,
+            len,
+            _marker: PhantomData,
+        })
+    }
+
+    /// An attribute that's a field of each element of `vertices`, `offset` bytes from its start.
+    ///
+    /// # Safety
+    ///
+    /// Every element of `vertices` must hold a valid, initialized `A` at `offset`, such as a field
+    /// located with [`core::mem::offset_of`].
+    pub unsafe fn interleaved<V>(vertices: &'a [V], offset: usize) -> Self {
+        Self {
+            ptr: (vertices.as_ptr() as *const u8).wrapping_add(offset) as *const A,
+            stride: size_of::<V>() as i32,
+            len: vertices.len(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the attribute.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<A> Clone for VertexAttribute<'_, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A> Copy for VertexAttribute<'_, A> {}
+
+/// Types that can be used as indices by [`Renderer::render_geometry_indexed`] and
+/// [`Renderer::render_geometry_raw`].
+///
+/// This trait is sealed and implemented for `u8`, `u16`, `i32` and `u32`.
+pub trait GeometryIndex: Copy + private::Sealed {}
+
+impl GeometryIndex for u8 {}
+impl GeometryIndex for u16 {}
+impl GeometryIndex for i32 {}
+impl GeometryIndex for u32 {}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+    impl Sealed for i32 {}
+}
+
 #[repr(transparent)]
+#[derive(Copy, Clone)]
 pub struct Vertex(sys::SDL_Vertex);
 
 impl Vertex {
@@ -1629,6 +3539,35 @@ struct RendererInternal<T> {
     /// If the parent [`Renderer`] gets dropped before its' [`Texture`]s, then
     /// we move the owner to this struct. That's why we need the [`RefCell`].
     owner: RefCell<Option<T>>,
+    /// Scale mode applied to textures when they're created.
+    default_scale_mode: Cell<Option<ScaleMode>>,
+    /// Number of frames presented so far.
+    frame_index: Cell<FrameId>,
+    /// Accumulated color mods pushed with [`Renderer::push_color_mod`], innermost last.
+    color_mods: RefCell<Vec<(f32, f32, f32)>>,
+    /// Accumulated alpha mods pushed with [`Renderer::push_alpha_mod`], innermost last.
+    alpha_mods: RefCell<Vec<f32>>,
+    /// Called when a failure is classified as a device reset or loss.
+    device_reset_hook: RefCell<Option<DeviceResetHook>>,
+}
+
+type DeviceResetHook = Box<dyn FnMut(RenderResync)>;
+
+impl<T> RendererInternal<T> {
+    // With the `send` feature, the `Arc` is only shared with textures, which never touch it off
+    // the main thread.
+    #[cfg_attr(feature = "send", allow(clippy::arc_with_non_send_sync))]
+    fn new_shared(ptr: NonNull<sys::SDL_Renderer>) -> Shared<Self> {
+        Shared::new(Self {
+            ptr,
+            owner: RefCell::new(None),
+            default_scale_mode: Cell::new(None),
+            frame_index: Cell::new(FrameId(0)),
+            color_mods: RefCell::new(Vec::new()),
+            alpha_mods: RefCell::new(Vec::new()),
+            device_reset_hook: RefCell::new(None),
+        })
+    }
 }
 
 impl<T> Drop for RendererInternal<T> {
@@ -1637,3 +3576,86 @@ impl<T> Drop for RendererInternal<T> {
         // if the owner is Some it will get destroyed automatically :)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+    use proptest::prelude::*;
+
+    #[test]
+    fn renderer_vsync_round_trips() {
+        for vsync in [
+            RendererVSync::EveryVerticalRefresh,
+            RendererVSync::EverySecondVerticalRefresh,
+            RendererVSync::Adaptive,
+            RendererVSync::Disabled,
+        ] {
+            assert_eq!(RendererVSync::try_from_ll(vsync.to_raw()).ok(), Some(vsync));
+        }
+    }
+
+    #[test]
+    fn logical_presentation_round_trips() {
+        for mode in [
+            RenderLogicalPresentationMode::Disabled,
+            RenderLogicalPresentationMode::Stretch,
+            RenderLogicalPresentationMode::Letterbox,
+            RenderLogicalPresentationMode::Overscan,
+            RenderLogicalPresentationMode::IntegerScale,
+        ] {
+            assert_eq!(
+                RenderLogicalPresentationMode::try_from_ll(mode.to_ll()).ok(),
+                Some(mode)
+            );
+        }
+    }
+
+    #[test]
+    fn split_screen_viewports_tile_the_output() {
+        for layout in [SplitLayout::Columns, SplitLayout::Rows, SplitLayout::Grid] {
+            for players in 1..=4 {
+                let split = SplitScreen::new(players, layout).unwrap();
+                let viewports = split.viewports(1001, 601);
+                assert_eq!(viewports.len(), players);
+                let area: u32 = viewports.iter().map(|rect| rect.w() * rect.h()).sum();
+                assert_eq!(area, 1001 * 601, "{layout:?} with {players} players");
+            }
+        }
+        assert!(SplitScreen::new(0, SplitLayout::Grid).is_err());
+        assert!(SplitScreen::new(5, SplitLayout::Grid).is_err());
+    }
+
+    #[test]
+    fn rounded_rect_outline_accepts_degenerate_rects() {
+        for rect in [
+            RectF32::new(10.0, 10.0, -20.0, 5.0),
+            RectF32::new(10.0, 10.0, 20.0, -5.0),
+            RectF32::new(0.0, 0.0, f32::NAN, 5.0),
+        ] {
+            let outline = rounded_rect_outline(rect, 4.0);
+            assert_eq!(outline.len(), 4 * (ROUNDED_CORNER_SEGMENTS as usize + 1));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn renderer_vsync_try_from_ll_is_exact(value: i32) {
+            if let Ok(vsync) = RendererVSync::try_from_ll(value) {
+                prop_assert_eq!(vsync.to_raw(), value);
+            }
+        }
+
+        #[test]
+        fn logical_presentation_try_from_ll_is_exact(value: u32) {
+            if let Ok(mode) = RenderLogicalPresentationMode::try_from_ll(value) {
+                prop_assert_eq!(mode.to_ll(), value);
+            }
+        }
+    }
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(TextureAccess: Static, Streaming, Target);
+    }
+}
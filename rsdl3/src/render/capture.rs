@@ -0,0 +1,147 @@
+use super::Renderer;
+use crate::init::Sdl;
+use crate::iostream::IOStream;
+use crate::surface::SurfaceRef;
+use crate::video::Window;
+use crate::{sys, Error};
+use alloc::format;
+use alloc::string::String;
+use core::time::Duration;
+
+/// Dumps frames read back from a renderer to a numbered sequence of image files, e.g. to turn
+/// into a video afterwards.
+///
+/// Frames are written to `{prefix}{index}.{extension}`, where `index` is zero-padded to six
+/// digits and starts at 0. The prefix can contain a directory, which must already exist.
+///
+/// Reading pixels back from the GPU is slow; use a [`CaptureThrottle`] to skip frames if the
+/// application can't afford to capture every one of them.
+pub struct FrameCapture {
+    sdl: Sdl,
+    prefix: String,
+    format: CaptureFormat,
+    throttle: CaptureThrottle,
+    next_index: u32,
+    /// Frames offered to [`FrameCapture::capture`], captured or not.
+    offered: u64,
+    last_capture_ns: Option<u64>,
+}
+
+impl FrameCapture {
+    /// Creates a capture that writes every frame it's given.
+    pub fn new(sdl: &Sdl, prefix: &str, format: CaptureFormat) -> Self {
+        Self {
+            sdl: sdl.clone(),
+            prefix: String::from(prefix),
+            format,
+            throttle: CaptureThrottle::None,
+            next_index: 0,
+            offered: 0,
+            last_capture_ns: None,
+        }
+    }
+
+    pub fn set_throttle(&mut self, throttle: CaptureThrottle) {
+        self.throttle = throttle;
+    }
+
+    pub fn throttle(&self) -> CaptureThrottle {
+        self.throttle
+    }
+
+    pub fn format(&self) -> CaptureFormat {
+        self.format
+    }
+
+    /// Returns the number of frames written so far, which is also the index of the next one.
+    pub fn frames_written(&self) -> u32 {
+        self.next_index
+    }
+
+    /// Returns the path the next frame will be written to.
+    pub fn next_path(&self) -> String {
+        format!(
+            "{}{:06}.{}",
+            self.prefix,
+            self.next_index,
+            self.format.extension()
+        )
+    }
+
+    /// Reads the renderer's current target and writes it as the next frame, unless the throttle
+    /// skips this frame. Returns whether a frame was written.
+    ///
+    /// Call this after rendering and before [`Renderer::present`].
+    pub fn capture(&mut self, renderer: &Renderer<Window>) -> Result<bool, Error> {
+        if !self.should_capture() {
+            return Ok(false);
+        }
+        let surface = renderer.read_pixels(None)?;
+        self.write_frame(&surface)?;
+        Ok(true)
+    }
+
+    /// Writes `surface` as the next frame, bypassing the throttle.
+    pub fn write_frame(&mut self, surface: &SurfaceRef) -> Result<(), Error> {
+        let mut stream = IOStream::from_file(&self.sdl, &self.next_path(), "wb")?;
+        match self.format {
+            CaptureFormat::Bmp => surface.save_bmp_into_iostream(&mut stream)?,
+            #[cfg(feature = "image")]
+            CaptureFormat::Png => surface.save_png_into_iostream(&mut stream)?,
+        }
+        stream.flush()?;
+        self.next_index += 1;
+        Ok(())
+    }
+
+    fn should_capture(&mut self) -> bool {
+        let offered = self.offered;
+        self.offered += 1;
+        match self.throttle {
+            CaptureThrottle::None => true,
+            CaptureThrottle::EveryNth(n) => offered.is_multiple_of(u64::from(n.max(1))),
+            CaptureThrottle::Interval(interval) => {
+                let now = unsafe { sys::SDL_GetTicksNS() };
+                let due = self
+                    .last_capture_ns
+                    .is_none_or(|last| u128::from(now.saturating_sub(last)) >= interval.as_nanos());
+                if due {
+                    self.last_capture_ns = Some(now);
+                }
+                due
+            }
+        }
+    }
+}
+
+/// The image format [`FrameCapture`] writes frames in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CaptureFormat {
+    Bmp,
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    Png,
+}
+
+impl CaptureFormat {
+    /// Returns the file extension of the format, without the leading dot.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            CaptureFormat::Bmp => "bmp",
+            #[cfg(feature = "image")]
+            CaptureFormat::Png => "png",
+        }
+    }
+}
+
+/// Which of the frames given to [`FrameCapture::capture`] get written.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CaptureThrottle {
+    /// Every frame is written.
+    #[default]
+    None,
+    /// Only the first of every `n` frames is written. 0 behaves like 1.
+    EveryNth(u32),
+    /// A frame is written only if at least this much time has passed since the last one.
+    Interval(Duration),
+}
@@ -0,0 +1,230 @@
+use super::{Renderer, Texture, Vertex};
+use crate::pixels::{Color, ColorF32};
+use crate::rect::{PointF32, RectF32};
+use crate::Error;
+use alloc::vec::Vec;
+
+/// A list of draw calls recorded on the CPU and replayed into a [`Renderer`] in one pass.
+///
+/// Recording is cheap, and a list can be replayed any number of times, e.g. for static layers
+/// that don't change between frames. When replayed, consecutive textures drawn from the same
+/// texture are submitted as a single [`Renderer::render_geometry`] call, so sprite-heavy scenes
+/// benefit from [`CommandList::sort_by_texture`].
+///
+/// Commands are drawn in the order they were recorded, within each layer set by
+/// [`CommandList::set_layer`], and layers are drawn from lowest to highest once sorted.
+pub struct CommandList<'a, T> {
+    commands: Vec<Command<'a, T>>,
+    layer: i32,
+}
+
+struct Command<'a, T> {
+    layer: i32,
+    kind: CommandKind<'a, T>,
+}
+
+enum CommandKind<'a, T> {
+    FillRect(RectF32, Color),
+    Rect(RectF32, Color),
+    Texture {
+        texture: &'a Texture<T>,
+        src_rect: Option<RectF32>,
+        dest_rect: RectF32,
+    },
+    Geometry {
+        texture: Option<&'a Texture<T>>,
+        vertices: Vec<Vertex>,
+        indices: Vec<i32>,
+    },
+}
+
+impl<'a, T> CommandList<'a, T> {
+    pub fn new() -> Self {
+        Self {
+            commands: Vec::new(),
+            layer: 0,
+        }
+    }
+
+    /// Sets the layer of the commands recorded from now on. See [`CommandList::sort_by_texture`].
+    pub fn set_layer(&mut self, layer: i32) {
+        self.layer = layer;
+    }
+
+    /// Records filling `rect` with `color`.
+    pub fn fill_rect(&mut self, rect: RectF32, color: Color) {
+        self.push(CommandKind::FillRect(rect, color));
+    }
+
+    /// Records drawing the outline of `rect` with `color`.
+    pub fn render_rect(&mut self, rect: RectF32, color: Color) {
+        self.push(CommandKind::Rect(rect, color));
+    }
+
+    /// Records copying the `src_rect` portion of `texture`, or the entire texture if `None`, to
+    /// `dest_rect`.
+    ///
+    /// The texture's color and alpha modulation are read when the list is replayed.
+    pub fn render_texture(
+        &mut self,
+        texture: &'a Texture<T>,
+        src_rect: Option<RectF32>,
+        dest_rect: RectF32,
+    ) {
+        self.push(CommandKind::Texture {
+            texture,
+            src_rect,
+            dest_rect,
+        });
+    }
+
+    /// Records rendering a list of triangles. See [`Renderer::render_geometry`].
+    pub fn render_geometry(
+        &mut self,
+        texture: Option<&'a Texture<T>>,
+        vertices: &[Vertex],
+        indices: &[i32],
+    ) {
+        self.push(CommandKind::Geometry {
+            texture,
+            vertices: vertices.to_vec(),
+            indices: indices.to_vec(),
+        });
+    }
+
+    /// Orders the commands by layer, then groups the commands of each layer by texture.
+    ///
+    /// Grouping reduces the number of draw calls, but commands of the same layer that use
+    /// different textures may be drawn in a different order, so overlapping draws that must keep
+    /// their order should be put in different layers. Commands that don't use a texture are drawn
+    /// before the others of their layer.
+    pub fn sort_by_texture(&mut self) {
+        self.commands
+            .sort_by_key(|command| (command.layer, command.texture_key()));
+    }
+
+    /// Returns the number of recorded commands.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Removes every command, keeping the allocated memory for the next frame.
+    pub fn clear(&mut self) {
+        self.commands.clear();
+        self.layer = 0;
+    }
+
+    /// Draws every command into `renderer`.
+    ///
+    /// The draw color of the renderer is left set to the color of the last rectangle drawn.
+    pub fn replay(&self, renderer: &mut Renderer<T>) -> Result<(), Error> {
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+        let mut i = 0;
+        while i < self.commands.len() {
+            match &self.commands[i].kind {
+                CommandKind::FillRect(rect, color) => {
+                    renderer.set_draw_color(*color)?;
+                    renderer.fill_rect(*rect)?;
+                }
+                CommandKind::Rect(rect, color) => {
+                    renderer.set_draw_color(*color)?;
+                    renderer.render_rect(*rect)?;
+                }
+                CommandKind::Texture { texture, .. } => {
+                    // Merge the run of consecutive copies of this texture into one batch.
+                    let (w, h) = texture.size()?;
+                    let (r, g, b) = texture.color_mod()?;
+                    let a = texture.alpha_mod()?;
+                    let color = ColorF32::new(
+                        r as f32 / 255.0,
+                        g as f32 / 255.0,
+                        b as f32 / 255.0,
+                        a as f32 / 255.0,
+                    );
+                    vertices.clear();
+                    indices.clear();
+                    while let Some(CommandKind::Texture {
+                        texture: next,
+                        src_rect,
+                        dest_rect,
+                    }) = self.commands.get(i).map(|c| &c.kind)
+                    {
+                        if !core::ptr::eq(*next, *texture) {
+                            break;
+                        }
+                        let src = src_rect.unwrap_or(RectF32::new(0.0, 0.0, w, h));
+                        push_quad(&mut vertices, &mut indices, src, *dest_rect, (w, h), color)?;
+                        i += 1;
+                    }
+                    renderer.render_geometry(Some(texture), &vertices, &indices)?;
+                    continue;
+                }
+                CommandKind::Geometry {
+                    texture,
+                    vertices,
+                    indices,
+                } => {
+                    renderer.render_geometry(*texture, vertices, indices)?;
+                }
+            }
+            i += 1;
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, kind: CommandKind<'a, T>) {
+        self.commands.push(Command {
+            layer: self.layer,
+            kind,
+        });
+    }
+}
+
+impl<T> Default for CommandList<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Command<'_, T> {
+    fn texture_key(&self) -> usize {
+        match &self.kind {
+            CommandKind::Texture { texture, .. } => texture.raw() as usize,
+            CommandKind::Geometry {
+                texture: Some(texture),
+                ..
+            } => texture.raw() as usize,
+            _ => 0,
+        }
+    }
+}
+
+fn push_quad(
+    vertices: &mut Vec<Vertex>,
+    indices: &mut Vec<i32>,
+    src: RectF32,
+    dest: RectF32,
+    (w, h): (f32, f32),
+    color: ColorF32,
+) -> Result<(), Error> {
+    let base = i32::try_from(vertices.len())?;
+    let (u0, v0) = (src.x() / w, src.y() / h);
+    let (u1, v1) = ((src.x() + src.w()) / w, (src.y() + src.h()) / h);
+    let (x0, y0) = (dest.x(), dest.y());
+    let (x1, y1) = (dest.x() + dest.w(), dest.y() + dest.h());
+    vertices.extend([
+        Vertex::new(PointF32::new(x0, y0), color, PointF32::new(u0, v0)),
+        Vertex::new(PointF32::new(x1, y0), color, PointF32::new(u1, v0)),
+        Vertex::new(PointF32::new(x1, y1), color, PointF32::new(u1, v1)),
+        Vertex::new(PointF32::new(x0, y1), color, PointF32::new(u0, v1)),
+    ]);
+    indices.extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use super::{Renderer, Texture, TextureAccess};
+use crate::blendmode::BlendMode;
+use crate::pixels::{ColorF32, PixelFormat};
+use crate::Error;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// An ordered stack of named layers, each drawn into its own render target and blended onto the
+/// backbuffer by [`Compositor::composite`].
+///
+/// Layers are created at the renderer's output size and keep their contents between frames, so
+/// a layer that rarely changes, such as a UI, only needs to be drawn again when it does. Call
+/// [`Compositor::resize_to_output`] when the window is resized; the layers are recreated and
+/// their contents are lost.
+pub struct Compositor<T> {
+    layers: Vec<Layer<T>>,
+    size: (u32, u32),
+}
+
+struct Layer<T> {
+    name: String,
+    /// Only `None` while the layer is the renderer's target, see [`Compositor::draw_layer`].
+    texture: Option<Texture<T>>,
+    blend_mode: BlendMode,
+    opacity: f32,
+    visible: bool,
+}
+
+impl<T> Compositor<T> {
+    /// Creates a compositor with no layers, sized to the renderer's output.
+    pub fn new(renderer: &Renderer<T>) -> Result<Self, Error> {
+        Ok(Self {
+            layers: Vec::new(),
+            size: renderer.output_size()?,
+        })
+    }
+
+    /// Adds a layer on top of the others and clears it to transparent.
+    ///
+    /// Fails if a layer with the same name already exists.
+    pub fn push_layer(
+        &mut self,
+        renderer: &mut Renderer<T>,
+        name: &str,
+        blend_mode: BlendMode,
+    ) -> Result<(), Error> {
+        if self.position(name).is_some() {
+            return Err(Error::register(c"A layer with this name already exists."));
+        }
+        let texture = self.create_target(renderer)?;
+        self.layers.push(Layer {
+            name: String::from(name),
+            texture: Some(texture),
+            blend_mode,
+            opacity: 1.0,
+            visible: true,
+        });
+        self.draw_layer(renderer, name, clear_transparent)
+    }
+
+    /// Removes a layer. Returns false if there's no layer with that name.
+    pub fn remove_layer(&mut self, name: &str) -> bool {
+        let Some(index) = self.position(name) else {
+            return false;
+        };
+        self.layers.remove(index);
+        true
+    }
+
+    /// Moves a layer to `index` in the stack, where 0 is the bottom. Indices past the top move it
+    /// to the top.
+    pub fn move_layer(&mut self, name: &str, index: usize) -> Result<(), Error> {
+        let from = self.find(name)?;
+        let layer = self.layers.remove(from);
+        let index = index.min(self.layers.len());
+        self.layers.insert(index, layer);
+        Ok(())
+    }
+
+    /// Returns the names of the layers, from bottom to top.
+    pub fn layer_names(&self) -> impl Iterator<Item = &str> {
+        self.layers.iter().map(|layer| layer.name.as_str())
+    }
+
+    /// Returns the size of the layers, in pixels.
+    pub fn size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    pub fn set_blend_mode(&mut self, name: &str, blend_mode: BlendMode) -> Result<(), Error> {
+        let index = self.find(name)?;
+        self.layers[index].blend_mode = blend_mode;
+        Ok(())
+    }
+
+    /// Sets the opacity a layer is composited with, from 0.0 to 1.0.
+    pub fn set_opacity(&mut self, name: &str, opacity: f32) -> Result<(), Error> {
+        let index = self.find(name)?;
+        self.layers[index].opacity = opacity.clamp(0.0, 1.0);
+        Ok(())
+    }
+
+    /// Shows or hides a layer. Hidden layers keep their contents but aren't composited.
+    pub fn set_visible(&mut self, name: &str, visible: bool) -> Result<(), Error> {
+        let index = self.find(name)?;
+        self.layers[index].visible = visible;
+        Ok(())
+    }
+
+    /// Makes a layer the render target while `draw` runs, then restores the previous target.
+    ///
+    /// The layer isn't cleared first.
+    pub fn draw_layer(
+        &mut self,
+        renderer: &mut Renderer<T>,
+        name: &str,
+        draw: impl FnOnce(&mut Renderer<T>) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let index = self.find(name)?;
+        // The renderer owns its target, so the layer's texture is handed over and taken back.
+        let texture = self.layers[index].texture.take();
+        let previous = match renderer.replace_render_target(texture) {
+            Ok(previous) => previous,
+            Err(error) => {
+                // A failed replacement drops the texture, so the layer needs a new one.
+                self.layers[index].texture = Some(self.create_target(renderer)?);
+                return Err(error);
+            }
+        };
+        let result = draw(renderer);
+        self.layers[index].texture = renderer.replace_render_target(previous)?;
+        result
+    }
+
+    /// Recreates the layers if the renderer's output size changed. Returns true if it did.
+    ///
+    /// The recreated layers are cleared to transparent and have to be drawn again.
+    pub fn resize_to_output(&mut self, renderer: &mut Renderer<T>) -> Result<bool, Error> {
+        let size = renderer.output_size()?;
+        if size == self.size {
+            return Ok(false);
+        }
+        self.size = size;
+        for index in 0..self.layers.len() {
+            self.layers[index].texture = Some(self.create_target(renderer)?);
+            let name = self.layers[index].name.clone();
+            self.draw_layer(renderer, &name, clear_transparent)?;
+        }
+        Ok(true)
+    }
+
+    /// Draws the visible layers onto the current render target, from bottom to top.
+    pub fn composite(&mut self, renderer: &mut Renderer<T>) -> Result<(), Error> {
+        for layer in self.layers.iter_mut().filter(|layer| layer.visible) {
+            let Some(texture) = layer.texture.as_mut() else {
+                continue;
+            };
+            texture.set_blend_mode(layer.blend_mode)?;
+            texture.set_alpha_mod_f32(layer.opacity)?;
+            renderer.render_texture(texture, None, None)?;
+        }
+        Ok(())
+    }
+
+    fn create_target(&self, renderer: &mut Renderer<T>) -> Result<Texture<T>, Error> {
+        let (width, height) = self.size;
+        Texture::new(
+            renderer,
+            PixelFormat::Rgba8888,
+            TextureAccess::Target,
+            width.max(1),
+            height.max(1),
+        )
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.layers.iter().position(|layer| layer.name == name)
+    }
+
+    fn find(&self, name: &str) -> Result<usize, Error> {
+        self.position(name)
+            .ok_or_else(|| Error::register(c"No layer with this name."))
+    }
+}
+
+fn clear_transparent<T>(renderer: &mut Renderer<T>) -> Result<(), Error> {
+    let color = renderer.draw_color_float()?;
+    renderer.set_draw_color_float(ColorF32::new(0.0, 0.0, 0.0, 0.0))?;
+    let result = renderer.clear();
+    renderer.set_draw_color_float(color)?;
+    result
+}
@@ -0,0 +1,120 @@
+use super::{RenderDriver, Renderer};
+use crate::hints::{get_hint, Hint};
+use crate::init::VideoSubsystem;
+use crate::video::{Window, WindowFlags};
+use crate::Error;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// The hints that affect which render driver SDL picks.
+const RENDERER_HINTS: [Hint; 4] = [
+    Hint::RENDER_DRIVER,
+    Hint::VIDEO_DRIVER,
+    Hint::RENDER_VSYNC,
+    Hint::new(c"SDL_FRAMEBUFFER_ACCELERATION"),
+];
+
+impl VideoSubsystem {
+    /// Reports which render driver `renderer` uses, whether the other drivers would have worked
+    /// and the hints that influence the choice.
+    ///
+    /// Each of the other drivers is probed by creating a renderer for a hidden window, so this is
+    /// slow and meant for diagnostics, e.g. to log why an application ended up on the software
+    /// renderer. The [`Display`](fmt::Display) implementation of the report is human-readable.
+    pub fn explain_renderer_choice<T>(
+        &self,
+        renderer: &Renderer<T>,
+    ) -> Result<RendererDiagnostics, Error> {
+        let selected = RenderDriver::from_name(&renderer.name()?);
+        let alternatives = self
+            .render_drivers()?
+            .into_iter()
+            .filter(|driver| *driver != selected)
+            .map(|driver| {
+                let result = self.probe_render_driver(&driver);
+                DriverProbe { driver, result }
+            })
+            .collect();
+        Ok(RendererDiagnostics {
+            selected,
+            video_driver: self.current_driver()?,
+            alternatives,
+            hints: RENDERER_HINTS
+                .into_iter()
+                .map(|hint| (hint, get_hint(hint)))
+                .collect(),
+        })
+    }
+
+    fn probe_render_driver(&self, driver: &RenderDriver) -> Result<(), Error> {
+        let window = Window::new(self, "", 1, 1, Some(WindowFlags::HIDDEN))?;
+        Renderer::from_window(window, Some(driver.name())).map(|_| ())
+    }
+}
+
+/// Explains the render driver choice, see [`VideoSubsystem::explain_renderer_choice`].
+#[derive(Clone, Debug)]
+pub struct RendererDiagnostics {
+    /// The driver the renderer uses.
+    pub selected: RenderDriver,
+    /// The name of the current video driver, which limits the render drivers that can work.
+    pub video_driver: String,
+    /// The other builtin drivers, in the order SDL tries them.
+    pub alternatives: Vec<DriverProbe>,
+    /// The hints that affect the choice, and their values if they're set.
+    pub hints: Vec<(Hint, Option<String>)>,
+}
+
+impl RendererDiagnostics {
+    /// Returns the alternatives that could have been used instead of the selected driver.
+    pub fn available(&self) -> impl Iterator<Item = &RenderDriver> {
+        self.alternatives
+            .iter()
+            .filter(|probe| probe.result.is_ok())
+            .map(|probe| &probe.driver)
+    }
+
+    /// Returns true if [`Hint::RENDER_DRIVER`] is set, in which case SDL only tries the drivers
+    /// it lists.
+    pub fn restricted_by_hint(&self) -> bool {
+        self.hints
+            .iter()
+            .any(|(hint, value)| *hint == Hint::RENDER_DRIVER && value.is_some())
+    }
+}
+
+impl fmt::Display for RendererDiagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "render driver: {} (video driver: {})",
+            self.selected.name(),
+            self.video_driver
+        )?;
+        for probe in &self.alternatives {
+            match &probe.result {
+                Ok(()) => writeln!(f, "  {}: available", probe.driver.name())?,
+                Err(error) => writeln!(f, "  {}: unavailable: {error}", probe.driver.name())?,
+            }
+        }
+        writeln!(f, "hints:")?;
+        for (hint, value) in &self.hints {
+            let name = hint.name().to_string_lossy();
+            match value {
+                Some(value) => writeln!(f, "  {name} = {value:?}")?,
+                None => writeln!(f, "  {name} is not set")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether a render driver could be used, as found by
+/// [`VideoSubsystem::explain_renderer_choice`].
+#[derive(Clone, Debug)]
+pub struct DriverProbe {
+    pub driver: RenderDriver,
+    /// The error SDL reported when creating a renderer with the driver, if it failed.
+    pub result: Result<(), Error>,
+}
@@ -0,0 +1,124 @@
+use super::{FrameId, Renderer};
+use crate::{sys, Error};
+use alloc::vec::Vec;
+use core::time::Duration;
+
+/// Measures how long a frame spends in named scopes, flushing and presenting.
+///
+/// Wrap parts of the frame in [`RenderProfiler::begin_scope`] and [`RenderProfiler::end_scope`],
+/// then call [`RenderProfiler::present`] instead of [`Renderer::present`] to get a
+/// [`FrameReport`] for the frame. Scopes can be nested.
+///
+/// Times are measured on the CPU with the performance counter. Draw calls are queued and
+/// submitted in batches, so a scope measures how long it took to record its commands; the cost
+/// of executing them on the GPU shows up in the flush and present times instead. SDL's renderer
+/// doesn't expose GPU timestamp queries, so GPU durations can't be reported per scope.
+pub struct RenderProfiler {
+    frequency: u64,
+    frame_start: u64,
+    scopes: Vec<ScopeTiming>,
+    /// Indices into `scopes` of the open scopes, with their start counters.
+    open: Vec<(usize, u64)>,
+    flush_time: Duration,
+}
+
+impl RenderProfiler {
+    /// Creates a profiler whose first frame starts now.
+    pub fn new() -> Self {
+        Self {
+            frequency: unsafe { sys::SDL_GetPerformanceFrequency() },
+            frame_start: unsafe { sys::SDL_GetPerformanceCounter() },
+            scopes: Vec::new(),
+            open: Vec::new(),
+            flush_time: Duration::ZERO,
+        }
+    }
+
+    /// Starts timing a scope, nested in the scope that's currently open, if any.
+    pub fn begin_scope(&mut self, name: &'static str) {
+        self.scopes.push(ScopeTiming {
+            name,
+            depth: self.open.len(),
+            cpu_time: Duration::ZERO,
+        });
+        let start = unsafe { sys::SDL_GetPerformanceCounter() };
+        self.open.push((self.scopes.len() - 1, start));
+    }
+
+    /// Stops timing the innermost open scope and returns how long it took.
+    ///
+    /// Returns `None` if no scope is open.
+    pub fn end_scope(&mut self) -> Option<Duration> {
+        let end = unsafe { sys::SDL_GetPerformanceCounter() };
+        let (index, start) = self.open.pop()?;
+        let elapsed = self.elapsed(start, end);
+        self.scopes[index].cpu_time = elapsed;
+        Some(elapsed)
+    }
+
+    /// Calls [`Renderer::flush`], adding the time it took to the frame's flush time.
+    pub fn flush<T>(&mut self, renderer: &mut Renderer<T>) -> Result<(), Error> {
+        let start = unsafe { sys::SDL_GetPerformanceCounter() };
+        let result = renderer.flush();
+        let end = unsafe { sys::SDL_GetPerformanceCounter() };
+        self.flush_time += self.elapsed(start, end);
+        result
+    }
+
+    /// Calls [`Renderer::present`] and returns the report of the frame it ended.
+    ///
+    /// Scopes that are still open are closed first. The next frame starts once the renderer has
+    /// presented, whether or not presenting succeeded.
+    pub fn present<T>(&mut self, renderer: &mut Renderer<T>) -> Result<FrameReport, Error> {
+        while self.end_scope().is_some() {}
+        let frame = renderer.frame_index();
+        let start = unsafe { sys::SDL_GetPerformanceCounter() };
+        let result = renderer.present();
+        let end = unsafe { sys::SDL_GetPerformanceCounter() };
+        let report = FrameReport {
+            frame,
+            frame_time: self.elapsed(self.frame_start, end),
+            flush_time: core::mem::take(&mut self.flush_time),
+            present_time: self.elapsed(start, end),
+            scopes: core::mem::take(&mut self.scopes),
+        };
+        self.frame_start = end;
+        result.map(|()| report)
+    }
+
+    fn elapsed(&self, start: u64, end: u64) -> Duration {
+        let ticks = u128::from(end.saturating_sub(start));
+        let nanos = ticks * 1_000_000_000 / u128::from(self.frequency.max(1));
+        Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+    }
+}
+
+impl Default for RenderProfiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The timings of a frame, as returned by [`RenderProfiler::present`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FrameReport {
+    /// The frame that was presented.
+    pub frame: FrameId,
+    /// The time since the previous frame was presented, including presenting this one.
+    pub frame_time: Duration,
+    /// The total time spent in [`RenderProfiler::flush`].
+    pub flush_time: Duration,
+    /// The time spent in [`Renderer::present`], which includes waiting for vsync.
+    pub present_time: Duration,
+    /// The frame's scopes, in the order they were started.
+    pub scopes: Vec<ScopeTiming>,
+}
+
+/// The time spent in a scope started with [`RenderProfiler::begin_scope`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScopeTiming {
+    pub name: &'static str,
+    /// The number of scopes this one is nested in.
+    pub depth: usize,
+    pub cpu_time: Duration,
+}
@@ -0,0 +1,235 @@
+use crate::properties::PropertiesRef;
+use crate::sys;
+use crate::Error;
+use crate::SensorSubsystem;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::c_void;
+use core::ffi::CStr;
+use core::ptr::NonNull;
+
+/// Standard gravity, in meters per second squared.
+///
+/// An accelerometer at rest reports this value on the axis pointing away from the center of the
+/// earth.
+pub const STANDARD_GRAVITY: f32 = sys::SDL_STANDARD_GRAVITY as f32;
+
+impl SensorSubsystem {
+    /// Returns a list of currently connected sensors.
+    pub fn sensors(&self) -> Result<Vec<SensorId>, Error> {
+        let mut count = 0;
+        let ptr = unsafe { sys::SDL_GetSensors(&raw mut count) };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        let vec = match usize::try_from(count) {
+            Ok(len) => unsafe { core::slice::from_raw_parts(ptr, len) }.to_vec(),
+            Err(err) => {
+                unsafe { sys::SDL_free(ptr as *mut c_void) };
+                return Err(err.into());
+            }
+        };
+        unsafe { sys::SDL_free(ptr as *mut c_void) };
+        Ok(vec)
+    }
+
+    /// Returns the implementation dependent name of a sensor.
+    ///
+    /// This can be called before any sensors are opened.
+    pub fn sensor_name(&self, id: SensorId) -> Result<String, Error> {
+        let ptr = unsafe { sys::SDL_GetSensorNameForID(id) };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        Ok(unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned())
+    }
+
+    /// Returns the type of a sensor.
+    ///
+    /// This can be called before any sensors are opened.
+    pub fn sensor_type(&self, id: SensorId) -> SensorType {
+        SensorType::from_ll(unsafe { sys::SDL_GetSensorTypeForID(id) })
+    }
+
+    /// Returns the first connected sensor of the given type, if any.
+    pub fn find_sensor(&self, sensor_type: SensorType) -> Result<Option<SensorId>, Error> {
+        Ok(self
+            .sensors()?
+            .into_iter()
+            .find(|id| self.sensor_type(*id) == sensor_type))
+    }
+
+    /// Equivalent to [`Sensor::open`].
+    pub fn open_sensor(&self, id: SensorId) -> Result<Sensor, Error> {
+        Sensor::open(self, id)
+    }
+
+    /// Updates the state of all opened sensors.
+    ///
+    /// This is called automatically by the event loop unless the `SDL_AUTO_UPDATE_SENSORS` hint
+    /// is disabled.
+    pub fn update(&self) {
+        unsafe { sys::SDL_UpdateSensors() };
+    }
+}
+
+/// Instance id of a sensor.
+///
+/// The id is unique for as long as the device stays connected.
+pub type SensorId = sys::SDL_SensorID;
+
+/// An opened sensor.
+pub struct Sensor {
+    _subsystem: SensorSubsystem,
+    ptr: NonNull<sys::SDL_Sensor>,
+}
+
+impl Sensor {
+    /// Opens a sensor for use.
+    ///
+    /// Sensor events are delivered for opened sensors only.
+    pub fn open(subsystem: &SensorSubsystem, id: SensorId) -> Result<Self, Error> {
+        let ptr = NonNull::new(unsafe { sys::SDL_OpenSensor(id) }).ok_or_else(Error::new)?;
+        Ok(Self {
+            _subsystem: subsystem.clone(),
+            ptr,
+        })
+    }
+
+    /// Returns the instance id of the sensor.
+    pub fn id(&self) -> Result<SensorId, Error> {
+        let result = unsafe { sys::SDL_GetSensorID(self.raw()) };
+        if result == 0 {
+            return Err(Error::new());
+        }
+        Ok(result)
+    }
+
+    /// Returns the implementation dependent name of the sensor.
+    pub fn name(&self) -> Result<String, Error> {
+        let ptr = unsafe { sys::SDL_GetSensorName(self.raw()) };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        Ok(unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned())
+    }
+
+    /// Returns the type of the sensor.
+    pub fn sensor_type(&self) -> SensorType {
+        SensorType::from_ll(unsafe { sys::SDL_GetSensorType(self.raw()) })
+    }
+
+    /// Returns the platform dependent type of the sensor, or -1 if the sensor is invalid.
+    pub fn non_portable_type(&self) -> i32 {
+        unsafe { sys::SDL_GetSensorNonPortableType(self.raw()) }
+    }
+
+    /// Fills `data` with the current state of the sensor.
+    ///
+    /// The number of values and their meaning depend on the [`SensorType`].
+    pub fn data(&self, data: &mut [f32]) -> Result<(), Error> {
+        let len = i32::try_from(data.len())?;
+        let result = unsafe { sys::SDL_GetSensorData(self.raw(), data.as_mut_ptr(), len) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns the current state of a three-axis sensor, such as an accelerometer or gyroscope.
+    pub fn data3(&self) -> Result<[f32; 3], Error> {
+        let mut data = [0.0; 3];
+        self.data(&mut data)?;
+        Ok(data)
+    }
+
+    /// Returns the properties associated with the sensor.
+    pub fn properties(&self) -> Result<PropertiesRef<'_>, Error> {
+        unsafe { PropertiesRef::from_ll(sys::SDL_GetSensorProperties(self.raw())) }
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_Sensor {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for Sensor {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_CloseSensor(self.ptr.as_ptr()) };
+    }
+}
+
+/// The different sensors defined by SDL.
+///
+/// Accelerometers report the current acceleration in meters per second squared, including the
+/// force of gravity. Gyroscopes report the current rate of rotation in radians per second,
+/// positive in the counter-clockwise direction. In both cases the values are ordered x, y, z,
+/// where -X..+X is left..right, -Y..+Y is bottom..top and -Z..+Z is farther..closer, for devices
+/// held in their natural orientation.
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SensorType {
+    Invalid = sys::SDL_SensorType_SDL_SENSOR_INVALID,
+    Unknown = sys::SDL_SensorType_SDL_SENSOR_UNKNOWN,
+    Accelerometer = sys::SDL_SensorType_SDL_SENSOR_ACCEL,
+    Gyroscope = sys::SDL_SensorType_SDL_SENSOR_GYRO,
+    /// Accelerometer of a left Joy-Con controller or Wii nunchuk.
+    AccelerometerLeft = sys::SDL_SensorType_SDL_SENSOR_ACCEL_L,
+    /// Gyroscope of a left Joy-Con controller.
+    GyroscopeLeft = sys::SDL_SensorType_SDL_SENSOR_GYRO_L,
+    /// Accelerometer of a right Joy-Con controller.
+    AccelerometerRight = sys::SDL_SensorType_SDL_SENSOR_ACCEL_R,
+    /// Gyroscope of a right Joy-Con controller.
+    GyroscopeRight = sys::SDL_SensorType_SDL_SENSOR_GYRO_R,
+}
+
+impl SensorType {
+    /// Converts a raw `SDL_SensorType` into a `SensorType`.
+    pub fn try_from_ll(ll: sys::SDL_SensorType) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_SensorType_SDL_SENSOR_INVALID => Self::Invalid,
+            sys::SDL_SensorType_SDL_SENSOR_UNKNOWN => Self::Unknown,
+            sys::SDL_SensorType_SDL_SENSOR_ACCEL => Self::Accelerometer,
+            sys::SDL_SensorType_SDL_SENSOR_GYRO => Self::Gyroscope,
+            sys::SDL_SensorType_SDL_SENSOR_ACCEL_L => Self::AccelerometerLeft,
+            sys::SDL_SensorType_SDL_SENSOR_GYRO_L => Self::GyroscopeLeft,
+            sys::SDL_SensorType_SDL_SENSOR_ACCEL_R => Self::AccelerometerRight,
+            sys::SDL_SensorType_SDL_SENSOR_GYRO_R => Self::GyroscopeRight,
+            _ => return Err(Error::register(c"Unknown sensor type.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_SensorType {
+        *self as i32
+    }
+
+    pub(crate) fn from_ll(ll: sys::SDL_SensorType) -> Self {
+        match ll {
+            sys::SDL_SensorType_SDL_SENSOR_UNKNOWN => Self::Unknown,
+            sys::SDL_SensorType_SDL_SENSOR_ACCEL => Self::Accelerometer,
+            sys::SDL_SensorType_SDL_SENSOR_GYRO => Self::Gyroscope,
+            sys::SDL_SensorType_SDL_SENSOR_ACCEL_L => Self::AccelerometerLeft,
+            sys::SDL_SensorType_SDL_SENSOR_GYRO_L => Self::GyroscopeLeft,
+            sys::SDL_SensorType_SDL_SENSOR_ACCEL_R => Self::AccelerometerRight,
+            sys::SDL_SensorType_SDL_SENSOR_GYRO_R => Self::GyroscopeRight,
+            _ => Self::Invalid,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(SensorType: Invalid, Unknown, Accelerometer, Gyroscope, AccelerometerLeft, GyroscopeLeft, AccelerometerRight, GyroscopeRight);
+    }
+}
@@ -0,0 +1,269 @@
+use crate::filesystem::{enumerate_directory_marshall, PathInfo};
+use crate::init::SdlDrop;
+use crate::sys;
+use crate::{init::Sdl, Error};
+use alloc::ffi::CString;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ffi::{c_void, CStr};
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr::NonNull;
+
+/// A storage container, such as the game's read-only data or the user's save data.
+///
+/// Storage may not be available right after it's opened, e.g. while a console loads the user's
+/// save data; check [`Storage::is_ready`] before accessing it. Paths use `/` as the separator and
+/// are relative to the root of the container.
+///
+/// Writes may only be committed once the storage is closed; use [`Storage::close`] to find out
+/// whether they succeeded.
+pub struct Storage {
+    _sdl: Rc<SdlDrop>,
+    ptr: NonNull<sys::SDL_Storage>,
+}
+
+impl Storage {
+    /// Opens the read-only container with the application's bundled data.
+    ///
+    /// `override_path` replaces the platform's default location, e.g. to read assets from a
+    /// development directory.
+    pub fn open_title(sdl: &Sdl, override_path: Option<&str>) -> Result<Self, Error> {
+        let override_path = override_path.map(CString::new).transpose()?;
+        let override_ptr = override_path
+            .as_ref()
+            .map(|path| path.as_ptr())
+            .unwrap_or(core::ptr::null());
+        let ptr = unsafe { sys::SDL_OpenTitleStorage(override_ptr, 0) };
+        Self::from_ptr(sdl, ptr)
+    }
+
+    /// Opens the writable container with the user's data for an application, such as save
+    /// files.
+    pub fn open_user(sdl: &Sdl, org: &str, app: &str) -> Result<Self, Error> {
+        let org = CString::new(org)?;
+        let app = CString::new(app)?;
+        let ptr = unsafe { sys::SDL_OpenUserStorage(org.as_ptr(), app.as_ptr(), 0) };
+        Self::from_ptr(sdl, ptr)
+    }
+
+    /// Opens a directory of the filesystem as a container, for tools or platforms without a
+    /// dedicated storage implementation.
+    pub fn open_file(sdl: &Sdl, path: &str) -> Result<Self, Error> {
+        let path = CString::new(path)?;
+        let ptr = unsafe { sys::SDL_OpenFileStorage(path.as_ptr()) };
+        Self::from_ptr(sdl, ptr)
+    }
+
+    fn from_ptr(sdl: &Sdl, ptr: *mut sys::SDL_Storage) -> Result<Self, Error> {
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            _sdl: Rc::clone(&sdl.drop),
+            ptr,
+        })
+    }
+
+    /// Returns `true` once the container can be accessed.
+    pub fn is_ready(&self) -> bool {
+        unsafe { sys::SDL_StorageReady(self.raw()) }
+    }
+
+    /// Returns the size of a file in bytes.
+    pub fn file_size(&self, path: &str) -> Result<u64, Error> {
+        let path = CString::new(path)?;
+        let mut size = 0;
+        let result =
+            unsafe { sys::SDL_GetStorageFileSize(self.raw(), path.as_ptr(), &raw mut size) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(size)
+    }
+
+    /// Reads the entire contents of a file.
+    pub fn read_file(&self, path: &str) -> Result<Vec<u8>, Error> {
+        let size = self.file_size(path)?;
+        let path = CString::new(path)?;
+        let mut bytes = vec![0u8; usize::try_from(size)?];
+        let result = unsafe {
+            sys::SDL_ReadStorageFile(
+                self.raw(),
+                path.as_ptr(),
+                bytes.as_mut_ptr() as *mut c_void,
+                size,
+            )
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(bytes)
+    }
+
+    /// Writes `data` to a file, replacing its contents.
+    pub fn write_file(&mut self, path: &str, data: &[u8]) -> Result<(), Error> {
+        let path = CString::new(path)?;
+        let result = unsafe {
+            sys::SDL_WriteStorageFile(
+                self.raw(),
+                path.as_ptr(),
+                data.as_ptr() as *const c_void,
+                data.len() as u64,
+            )
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Creates a directory, along with any missing parent directories.
+    pub fn create_directory(&mut self, path: &str) -> Result<(), Error> {
+        let path = CString::new(path)?;
+        let result = unsafe { sys::SDL_CreateStorageDirectory(self.raw(), path.as_ptr()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns the names of the entries of a directory, or of the root if `path` is empty.
+    pub fn enumerate_directory(&self, path: &str) -> Result<Vec<String>, Error> {
+        let path = CString::new(path)?;
+        let mut entries: Vec<String> = Vec::new();
+        let result = unsafe {
+            sys::SDL_EnumerateStorageDirectory(
+                self.raw(),
+                path.as_ptr(),
+                Some(enumerate_directory_marshall),
+                &raw mut entries as *mut c_void,
+            )
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(entries)
+    }
+
+    /// Returns the paths of the files under a directory, or under the root if `path` is `None`,
+    /// whose paths match `pattern`.
+    ///
+    /// `pattern` may contain the wildcards `*` and `?`, which never match a `/`. Every file
+    /// matches if `pattern` is `None`.
+    pub fn glob_directory(
+        &self,
+        path: Option<&str>,
+        pattern: Option<&str>,
+        case_insensitive: bool,
+    ) -> Result<Vec<String>, Error> {
+        let path = path.map(CString::new).transpose()?;
+        let pattern = pattern.map(CString::new).transpose()?;
+        let flags = if case_insensitive {
+            sys::SDL_GLOB_CASEINSENSITIVE
+        } else {
+            0
+        };
+        let mut count = 0;
+        unsafe {
+            let paths = sys::SDL_GlobStorageDirectory(
+                self.raw(),
+                path.as_ref()
+                    .map(|p| p.as_ptr())
+                    .unwrap_or(core::ptr::null()),
+                pattern
+                    .as_ref()
+                    .map(|p| p.as_ptr())
+                    .unwrap_or(core::ptr::null()),
+                flags,
+                &raw mut count,
+            );
+            if paths.is_null() {
+                return Err(Error::new());
+            }
+            let array = core::slice::from_raw_parts(paths, usize::try_from(count)?);
+            let vec = array
+                .iter()
+                .map(|&ptr| CStr::from_ptr(ptr).to_string_lossy().into_owned())
+                .collect();
+            // The array and its strings are a single allocation.
+            sys::SDL_free(paths as *mut c_void);
+            Ok(vec)
+        }
+    }
+
+    /// Removes a file or an empty directory.
+    pub fn remove_path(&mut self, path: &str) -> Result<(), Error> {
+        let path = CString::new(path)?;
+        let result = unsafe { sys::SDL_RemoveStoragePath(self.raw(), path.as_ptr()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Renames a file or directory, replacing `new_path` if it exists.
+    pub fn rename_path(&mut self, old_path: &str, new_path: &str) -> Result<(), Error> {
+        let old_path = CString::new(old_path)?;
+        let new_path = CString::new(new_path)?;
+        let result =
+            unsafe { sys::SDL_RenameStoragePath(self.raw(), old_path.as_ptr(), new_path.as_ptr()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Copies a file, replacing `new_path` if it exists.
+    pub fn copy_file(&mut self, old_path: &str, new_path: &str) -> Result<(), Error> {
+        let old_path = CString::new(old_path)?;
+        let new_path = CString::new(new_path)?;
+        let result =
+            unsafe { sys::SDL_CopyStorageFile(self.raw(), old_path.as_ptr(), new_path.as_ptr()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns information about a file or directory.
+    pub fn path_info(&self, path: &str) -> Result<PathInfo, Error> {
+        let path = CString::new(path)?;
+        let mut info: MaybeUninit<sys::SDL_PathInfo> = MaybeUninit::uninit();
+        let result =
+            unsafe { sys::SDL_GetStoragePathInfo(self.raw(), path.as_ptr(), info.as_mut_ptr()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(PathInfo::from_ll(unsafe { info.assume_init() }))
+    }
+
+    /// Returns the number of bytes that can still be written to the container.
+    pub fn space_remaining(&self) -> u64 {
+        unsafe { sys::SDL_GetStorageSpaceRemaining(self.raw()) }
+    }
+
+    /// Closes the container, committing any pending writes.
+    ///
+    /// Dropping the storage closes it too, but ignores errors.
+    pub fn close(self) -> Result<(), Error> {
+        let this = ManuallyDrop::new(self);
+        let result = unsafe { sys::SDL_CloseStorage(this.raw()) };
+        // SAFETY: the container is closed and won't be used again, but SDL must outlive it.
+        drop(unsafe { core::ptr::read(&this._sdl) });
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_Storage {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for Storage {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_CloseStorage(self.raw()) };
+    }
+}
@@ -1,12 +1,15 @@
 use crate::blendmode::BlendMode;
 use crate::init::VideoSubsystem;
 use crate::iostream::IOStream;
-#[allow(unused)]
 use crate::pixels::PixelFormatDetails;
 use crate::pixels::{Color, ColorF32, Colorspace, Palette, PaletteRef, PixelFormat};
+use crate::properties::Properties;
 use crate::rect::Rect;
 use crate::render::{Renderer, Texture};
+use crate::video::WindowRef;
 use crate::{sys, Error};
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 use core::mem::MaybeUninit;
 use core::ops::{Deref, DerefMut};
@@ -38,7 +41,7 @@ impl Surface<'static> {
         let w = w.clamp(0, i32::MAX as u32) as i32;
         let h = h.clamp(0, i32::MAX as u32) as i32;
         let ptr = unsafe { sys::SDL_CreateSurface(w, h, format.to_ll()) };
-        let ptr = NonNull::new(ptr).ok_or(Error::new())?;
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
         Ok(Self {
             video: video.clone(),
             ptr,
@@ -105,22 +108,7 @@ impl<'a> Surface<'a> {
         width: u32,
         height: u32,
     ) -> Result<Surface<'a>, Error> {
-        // SDL_Surface's pixels are arranged in memory in rows.
-
-        // we need to make sure we won't overflow the byte buffer...
-        let details = format.details()?;
-        let bytes_per_pixel = details.bytes_per_pixel();
-        let total_bytes = usize::try_from(
-            width
-                .saturating_mul(height)
-                .saturating_mul(bytes_per_pixel as u32), // cast ok because we're going from u8 to i32
-        )?;
-        if total_bytes > pixels.len() {
-            return Err(Error::register(c"Invalid surface pixel parameters"));
-        }
-        let width = i32::try_from(width)?;
-        let height = i32::try_from(height)?;
-        let pitch = width.saturating_mul(bytes_per_pixel as i32);
+        let (width, height, pitch) = pixels_layout(format, pixels.len(), width, height)?;
         let ptr = unsafe {
             sys::SDL_CreateSurfaceFrom(
                 width,
@@ -136,6 +124,37 @@ impl<'a> Surface<'a> {
         Ok(unsafe { Surface::from_mut_ptr(video, ptr) })
     }
 
+    /// Wraps read-only pixel data in a surface without copying it, e.g. image data embedded with
+    /// `include_bytes!` that's turned into a texture right away.
+    ///
+    /// The returned [`ReadOnlySurface`] can be read, converted, saved or used as a blit source,
+    /// but not drawn to.
+    pub fn from_pixels_ref(
+        video: &VideoSubsystem,
+        format: PixelFormat,
+        pixels: &'a [u8],
+        width: u32,
+        height: u32,
+    ) -> Result<ReadOnlySurface<'a>, Error> {
+        let (width, height, pitch) = pixels_layout(format, pixels.len(), width, height)?;
+        // SDL takes a mutable pointer, but `ReadOnlySurface` never lets SDL write to the pixels.
+        let ptr = unsafe {
+            sys::SDL_CreateSurfaceFrom(
+                width,
+                height,
+                format.to_ll(),
+                pixels.as_ptr() as *mut _,
+                pitch,
+            )
+        };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        Ok(ReadOnlySurface {
+            surface: unsafe { Surface::from_mut_ptr(video, ptr) },
+        })
+    }
+
     /// Copy an existing surface to a new surface of the specified format.
     ///
     /// This function is used to optimize images for faster *repeat* blitting. This is accomplished by converting
@@ -143,7 +162,7 @@ impl<'a> Surface<'a> {
     /// source for future blits, making them faster.
     ///
     /// If you are converting to an indexed surface and want to map colors to a palette, you can use
-    /// [`Surface::convert_surface_and_colorspace`] instead.
+    /// [`Surface::convert_with_colorspace`] instead.
     pub fn convert(&self, format: PixelFormat) -> Result<Surface<'a>, Error> {
         let ptr = unsafe { sys::SDL_ConvertSurface(self.ptr.as_ptr(), format.to_ll()) };
         if ptr.is_null() {
@@ -152,6 +171,30 @@ impl<'a> Surface<'a> {
         Ok(unsafe { Surface::from_mut_ptr(&self.video, ptr) })
     }
 
+    /// Copy an existing surface to a new surface of the specified format and colorspace.
+    ///
+    /// This method is equivalent to [`SurfaceRef::convert_with_colorspace`].
+    pub fn convert_with_colorspace(
+        &self,
+        format: PixelFormat,
+        colorspace: Colorspace,
+        palette: Option<&PaletteRef>,
+        props: Option<&Properties>,
+    ) -> Result<Surface<'static>, Error> {
+        self.deref()
+            .convert_with_colorspace(&self.video, format, colorspace, palette, props)
+    }
+
+    /// Copy an existing surface to a new surface using the pixel format that's fastest to blit
+    /// onto `window`.
+    ///
+    /// The format is chosen by [`VideoSubsystem::optimal_surface_format_for`]. Converting once
+    /// and blitting the result many times avoids per-blit format conversions.
+    pub fn convert_for_window(&self, window: &WindowRef) -> Result<Surface<'a>, Error> {
+        let format = self.video.optimal_surface_format_for(window)?;
+        self.convert(format)
+    }
+
     /// Creates a new surface identical to the existing surface.
     ///
     /// If the original surface has alternate images, the new surface will have a reference to them as well.
@@ -206,6 +249,137 @@ impl<'a> DerefMut for Surface<'a> {
     }
 }
 
+/// A [`Surface`] over pixel data it may not write to, created with [`Surface::from_pixels_ref`].
+///
+/// Only operations that leave the pixels untouched are available: reading, converting, saving
+/// and using the surface as a blit source or for a texture. It doesn't give access to a
+/// [`SurfaceRef`], whose methods, such as [`SurfaceRef::set_rle`], could make SDL write to the
+/// pixels. In particular, RLE acceleration can't be enabled: SDL decodes RLE surfaces back into
+/// their pixels when they're locked.
+pub struct ReadOnlySurface<'a> {
+    surface: Surface<'a>,
+}
+
+impl<'a> ReadOnlySurface<'a> {
+    /// The format of the surface.
+    pub fn format(&self) -> PixelFormat {
+        self.surface.format()
+    }
+
+    /// See [`SurfaceRef::read_pixel`].
+    pub fn read_pixel(&self, x: u32, y: u32) -> Result<Color, Error> {
+        self.surface.read_pixel(x, y)
+    }
+
+    /// See [`SurfaceRef::read_pixel_float`].
+    pub fn read_pixel_float(&self, x: u32, y: u32) -> Result<ColorF32, Error> {
+        self.surface.read_pixel_float(x, y)
+    }
+
+    /// See [`SurfaceRef::to_rgba_bytes`].
+    pub fn to_rgba_bytes(&self) -> Result<Vec<u8>, Error> {
+        self.surface.to_rgba_bytes(&self.surface.video)
+    }
+
+    /// See [`Surface::convert`].
+    pub fn convert(&self, format: PixelFormat) -> Result<Surface<'a>, Error> {
+        self.surface.convert(format)
+    }
+
+    /// See [`Surface::duplicate`].
+    pub fn duplicate(&self) -> Result<Surface<'static>, Error> {
+        self.surface.duplicate()
+    }
+
+    /// Creates a texture with a copy of the pixels. See [`Texture::from_surface`].
+    pub fn to_texture<T>(&self, renderer: &mut Renderer<T>) -> Result<Texture<T>, Error> {
+        Texture::from_surface(renderer, &self.surface)
+    }
+
+    /// See [`SurfaceRef::blit`].
+    pub fn blit(
+        &self,
+        src_rect: Option<Rect>,
+        dest: &mut SurfaceRef,
+        dest_rect: Option<Rect>,
+    ) -> Result<(), Error> {
+        self.surface.blit(src_rect, dest, dest_rect)
+    }
+
+    /// See [`SurfaceRef::blit_scaled`].
+    pub fn blit_scaled(
+        &self,
+        src_rect: Option<Rect>,
+        dest: &mut SurfaceRef,
+        dest_rect: Option<Rect>,
+        scale_mode: ScaleMode,
+    ) -> Result<(), Error> {
+        self.surface
+            .blit_scaled(src_rect, dest, dest_rect, scale_mode)
+    }
+
+    /// See [`SurfaceRef::blit_tiled`].
+    pub fn blit_tiled(
+        &self,
+        src_rect: Option<Rect>,
+        dest: &mut SurfaceRef,
+        dest_rect: Option<Rect>,
+    ) -> Result<(), Error> {
+        self.surface.blit_tiled(src_rect, dest, dest_rect)
+    }
+
+    /// See [`SurfaceRef::save_bmp`].
+    pub fn save_bmp(&self, path: &str) -> Result<(), Error> {
+        self.surface.save_bmp(path)
+    }
+
+    /// See [`SurfaceRef::save_bmp_into_iostream`].
+    pub fn save_bmp_into_iostream(&self, stream: &mut IOStream) -> Result<(), Error> {
+        self.surface.save_bmp_into_iostream(stream)
+    }
+
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    /// See [`SurfaceRef::save_png`].
+    pub fn save_png(&self, path: &str) -> Result<(), Error> {
+        self.surface.save_png(path)
+    }
+
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    /// See [`SurfaceRef::save_png_into_iostream`].
+    pub fn save_png_into_iostream(&self, stream: &mut IOStream) -> Result<(), Error> {
+        self.surface.save_png_into_iostream(stream)
+    }
+}
+
+/// Returns the width, height and pitch of a `width` by `height` surface of the given format,
+/// checking that it fits in `len` bytes.
+fn pixels_layout(
+    format: PixelFormat,
+    len: usize,
+    width: u32,
+    height: u32,
+) -> Result<(i32, i32, i32), Error> {
+    // SDL_Surface's pixels are arranged in memory in rows.
+
+    // we need to make sure we won't overflow the byte buffer...
+    let details = format.details()?;
+    let bytes_per_pixel = details.bytes_per_pixel();
+    let total_bytes = usize::try_from(
+        width
+            .saturating_mul(height)
+            .saturating_mul(bytes_per_pixel as u32), // cast ok because we're going from u8 to i32
+    )?;
+    if total_bytes > len {
+        return Err(Error::register(c"Invalid surface pixel parameters"));
+    }
+    let width = i32::try_from(width)?;
+    let height = i32::try_from(height)?;
+    let pitch = width.saturating_mul(bytes_per_pixel as i32);
+    Ok((width, height, pitch))
+}
+
 /// A zero-sized type that functions as a reference to an SDL surface.
 ///
 /// This type is only exposed as a reference such that its' lifetime is bound to an owner.
@@ -263,6 +437,30 @@ impl SurfaceRef {
         Ok(())
     }
 
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    /// Save surface to a PNG file.
+    pub fn save_png(&self, path: &str) -> Result<(), Error> {
+        use alloc::ffi::CString;
+        let path = CString::new(path)?;
+        let result = unsafe { sys::image::IMG_SavePNG(self.raw(), path.as_ptr()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    /// Save a surface to an SDL data stream in PNG format.
+    pub fn save_png_into_iostream(&self, stream: &mut IOStream) -> Result<(), Error> {
+        let result = unsafe { sys::image::IMG_SavePNG_IO(self.raw(), stream.raw(), false) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
     /// Creates a new surface identical to the existing surface.
     /// If the original surface has alternate images, the new surface will have a reference to them as well.
     ///
@@ -298,6 +496,236 @@ impl SurfaceRef {
         Ok(unsafe { Surface::from_mut_ptr(video, ptr) })
     }
 
+    /// Copy an existing surface to a new surface of the specified format and colorspace.
+    ///
+    /// If `format` is indexed, colors are mapped to `palette`; without one, SDL creates a
+    /// palette for the new surface. `props` holds additional options, such as the
+    /// `SDL_PROP_SURFACE_SDR_WHITE_POINT_FLOAT` and `SDL_PROP_SURFACE_HDR_HEADROOM_FLOAT`
+    /// properties that control how HDR colors are mapped.
+    ///
+    /// This function takes a `VideoSubsystem` parameter due to lifetime requirements: the
+    /// returned surface cannot outlive the subsystem and `SurfaceRef` can't access it on
+    /// its' own.
+    pub fn convert_with_colorspace(
+        &self,
+        video: &VideoSubsystem,
+        format: PixelFormat,
+        colorspace: Colorspace,
+        palette: Option<&PaletteRef>,
+        props: Option<&Properties>,
+    ) -> Result<Surface<'static>, Error> {
+        let palette = palette.map_or(core::ptr::null_mut(), PaletteRef::raw);
+        let props = props.map_or(0, Properties::raw);
+        let ptr = unsafe {
+            sys::SDL_ConvertSurfaceAndColorspace(
+                self.raw(),
+                format.to_ll(),
+                palette,
+                colorspace.to_ll(),
+                props,
+            )
+        };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        Ok(unsafe { Surface::from_mut_ptr(video, ptr) })
+    }
+
+    /// Creates an 8-bit sRGB copy of an HDR surface, such as pixels read back from a renderer
+    /// using an HDR10 or scRGB output colorspace, e.g. to save a screenshot.
+    ///
+    /// Colors are converted to linear sRGB, where 1.0 is SDR white, then compressed into the SDR
+    /// range with `operator` and encoded with the sRGB transfer function. Writing HDR pixels to
+    /// an 8-bit format directly would clip or wash them out instead. The result uses
+    /// [`PixelFormat::Abgr8888`]. SDR surfaces can be passed too; they're only converted.
+    ///
+    /// This function takes a `VideoSubsystem` parameter due to lifetime requirements: the
+    /// returned surface cannot outlive the subsystem and `SurfaceRef` can't access it on
+    /// its' own.
+    pub fn tone_map_to_sdr(
+        &self,
+        video: &VideoSubsystem,
+        operator: ToneMapOperator,
+    ) -> Result<Surface<'static>, Error> {
+        let ptr = unsafe {
+            sys::SDL_ConvertSurfaceAndColorspace(
+                self.raw(),
+                PixelFormat::Rgba128Float.to_ll(),
+                core::ptr::null_mut(),
+                Colorspace::SRGB_LINEAR.to_ll(),
+                0,
+            )
+        };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        let mut linear = unsafe { Surface::from_mut_ptr(video, ptr) };
+        let (width, height) = unsafe { ((*ptr).w.max(0) as usize, (*ptr).h.max(0) as usize) };
+        let mut sdr = Surface::new(video, width as u32, height as u32, PixelFormat::Abgr8888)?;
+        let src_pitch = unsafe { (*ptr).pitch.max(0) as usize };
+        let dst_pitch = unsafe { (*sdr.raw()).pitch.max(0) as usize };
+        let src = linear.lock()?;
+        let mut dst = sdr.lock()?;
+        let (src, dst_bytes) = (src.as_bytes(), dst.as_bytes_mut());
+        for y in 0..height {
+            let src_row = &src[y * src_pitch..][..width * 16];
+            let dst_row = &mut dst_bytes[y * dst_pitch..][..width * 4];
+            for (src, dst) in src_row.chunks_exact(16).zip(dst_row.chunks_exact_mut(4)) {
+                let channel = |i: usize| {
+                    f32::from_ne_bytes([src[i * 4], src[i * 4 + 1], src[i * 4 + 2], src[i * 4 + 3]])
+                };
+                let r = srgb_encode(operator.apply(channel(0)));
+                let g = srgb_encode(operator.apply(channel(1)));
+                let b = srgb_encode(operator.apply(channel(2)));
+                let a = to_unorm8(channel(3));
+                let pixel =
+                    u32::from(a) << 24 | u32::from(b) << 16 | u32::from(g) << 8 | u32::from(r);
+                dst.copy_from_slice(&pixel.to_ne_bytes());
+            }
+        }
+        drop(dst);
+        Ok(sdr)
+    }
+
+    /// Creates a copy of this surface scaled to `width` x `height`, with alternate images for
+    /// each factor in `scales` attached to it.
+    ///
+    /// This is meant to be called on a single high resolution asset. For example, calling it on
+    /// a 128x128 image with a size of 32x32 and `scales` of `[1.5, 2.0, 4.0]` returns a 32x32
+    /// surface with 48x48, 64x64 and 128x128 alternate images. The result can be passed to
+    /// [`crate::video::WindowRef::set_icon`] or [`crate::mouse::Cursor::from_surface`] so SDL
+    /// picks the best image for the display scale;
+    /// [`crate::video::WindowRef::set_scaled_icon`] and [`crate::mouse::Cursor::from_scaled_image`]
+    /// do both steps at once.
+    ///
+    /// Scale factors smaller than or equal to `1.0` are skipped.
+    pub fn scaled_image_set(
+        &self,
+        video: &VideoSubsystem,
+        width: u32,
+        height: u32,
+        scales: &[f32],
+        scale_mode: ScaleMode,
+    ) -> Result<Surface<'static>, Error> {
+        let mut base = self.scale(video, width, height, scale_mode)?;
+        for &scale in scales {
+            if scale <= 1.0 {
+                continue;
+            }
+            let w = (width as f32 * scale + 0.5) as u32;
+            let h = (height as f32 * scale + 0.5) as u32;
+            let mut image = self.scale(video, w, h, scale_mode)?;
+            base.add_alternate_image(&mut image)?;
+        }
+        Ok(base)
+    }
+
+    /// Add an alternate version of a surface.
+    ///
+    /// This function adds an alternate version of this surface, usually used for content with
+    /// high DPI representations like cursors or icons. The size, format, and content do not need
+    /// to match the original surface, and these alternate versions will not be updated when the
+    /// original surface changes.
+    ///
+    /// This function adds a reference to the alternate version, so the `image` can be dropped
+    /// afterwards.
+    pub fn add_alternate_image(&mut self, image: &mut SurfaceRef) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_AddSurfaceAlternateImage(self.raw(), image.raw()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns whether a surface has alternate versions available.
+    pub fn has_alternate_images(&self) -> bool {
+        unsafe { sys::SDL_SurfaceHasAlternateImages(self.raw()) }
+    }
+
+    /// Remove all alternate versions of a surface.
+    pub fn remove_alternate_images(&mut self) {
+        unsafe { sys::SDL_RemoveSurfaceAlternateImages(self.raw()) };
+    }
+
+    /// Returns the surface's pixels as tightly packed RGBA bytes, one byte per channel in
+    /// `[r, g, b, a]` order.
+    ///
+    /// The surface is converted to [`PixelFormat::Abgr8888`] first and row padding is dropped,
+    /// so the result is the same regardless of the surface's format, pitch or the platform's
+    /// endianness.
+    pub fn to_rgba_bytes(&self, video: &VideoSubsystem) -> Result<Vec<u8>, Error> {
+        let ptr = unsafe { sys::SDL_ConvertSurface(self.raw(), PixelFormat::Abgr8888.to_ll()) };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        let mut converted = unsafe { Surface::from_mut_ptr(video, ptr) };
+        let (width, height, pitch) = unsafe {
+            let raw = converted.raw();
+            (
+                usize::try_from((*raw).w)?,
+                usize::try_from((*raw).h)?,
+                usize::try_from((*raw).pitch)?,
+            )
+        };
+        let row_len = width * 4;
+        let mut bytes = Vec::with_capacity(row_len * height);
+        let lock = converted.lock()?;
+        let pixels = lock.as_bytes();
+        for y in 0..height {
+            let row = &pixels[y * pitch..y * pitch + row_len];
+            for pixel in row.chunks_exact(4) {
+                let pixel = u32::from_ne_bytes([pixel[0], pixel[1], pixel[2], pixel[3]]);
+                // Abgr8888 is packed: red lives in the least significant bits.
+                bytes.extend_from_slice(&pixel.to_le_bytes());
+            }
+        }
+        Ok(bytes)
+    }
+
+    /// Computes a CRC-32 of the surface's pixels.
+    ///
+    /// The pixels are normalized with [`SurfaceRef::to_rgba_bytes`] first, so two surfaces with
+    /// the same visible contents produce the same hash even if their formats differ. This is
+    /// useful for golden-image tests.
+    pub fn pixel_crc32(&self, video: &VideoSubsystem) -> Result<u32, Error> {
+        let bytes = self.to_rgba_bytes(video)?;
+        Ok(unsafe { sys::SDL_crc32(0, bytes.as_ptr() as *const _, bytes.len()) })
+    }
+
+    /// Compares the pixels of two surfaces channel by channel.
+    ///
+    /// Both surfaces are normalized with [`SurfaceRef::to_rgba_bytes`] first, so surfaces with
+    /// different formats can be compared. Returns an error if their dimensions differ.
+    ///
+    /// Use [`ImageDiff::within`] for tolerance-based assertions in visual tests.
+    pub fn diff(&self, video: &VideoSubsystem, other: &SurfaceRef) -> Result<ImageDiff, Error> {
+        let (width, height) = unsafe { ((*self.raw()).w, (*self.raw()).h) };
+        let (other_width, other_height) = unsafe { ((*other.raw()).w, (*other.raw()).h) };
+        if width != other_width || height != other_height {
+            return Err(Error::register(c"Surfaces have different dimensions."));
+        }
+        let lhs = self.to_rgba_bytes(video)?;
+        let rhs = other.to_rgba_bytes(video)?;
+        let mut diff = ImageDiff {
+            max_channel_delta: 0,
+            differing_pixels: 0,
+            total_pixels: lhs.len() / 4,
+        };
+        for (a, b) in lhs.chunks_exact(4).zip(rhs.chunks_exact(4)) {
+            let delta = a
+                .iter()
+                .zip(b)
+                .map(|(a, b)| a.abs_diff(*b))
+                .max()
+                .unwrap_or(0);
+            if delta > 0 {
+                diff.differing_pixels += 1;
+                diff.max_channel_delta = diff.max_channel_delta.max(delta);
+            }
+        }
+        Ok(diff)
+    }
+
     /// Returns the additional alpha value used in blit operations.
     pub fn alpha_mod(&self) -> Result<u8, Error> {
         let mut alpha_mod: u8 = 0;
@@ -447,6 +875,18 @@ impl SurfaceRef {
         Colorspace::from_ll(result)
     }
 
+    /// Sets the colorspace used by the surface's pixels.
+    ///
+    /// This doesn't convert the pixels; it changes how they're interpreted by blits and
+    /// conversions. Use [`SurfaceRef::convert_with_colorspace`] to convert them.
+    pub fn set_colorspace(&mut self, colorspace: Colorspace) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_SetSurfaceColorspace(self.raw(), colorspace.to_ll()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
     /// Performs a fast blit from the source surface to the destination surface with clipping.
     ///
     /// If either `src_rect` or `dest_rect` are `None`, the entire surface (`self` or `dest`) is copied while
@@ -700,8 +1140,8 @@ impl SurfaceRef {
     ///
     /// If the surface is YUV, the color is assumed to be in the sRGB colorspace, otherwise the
     /// color is assumed to be in the colorspace of the suface.
-    pub fn clear(&mut self, color: Color) -> Result<(), Error> {
-        let color: ColorF32 = color.into();
+    pub fn clear(&mut self, color: impl Into<Color>) -> Result<(), Error> {
+        let color = ColorF32::from(color.into());
         let result = unsafe {
             sys::SDL_ClearSurface(self.raw(), color.r(), color.g(), color.b(), color.a())
         };
@@ -718,7 +1158,8 @@ impl SurfaceRef {
     ///
     /// Like [`PixelFormatDetails::map_rgba`], this uses the entire 0..255 range when converting color
     /// components from pixel formats with less than 8 bits per RGB component.
-    pub fn write_pixel(&mut self, x: u32, y: u32, color: Color) -> Result<(), Error> {
+    pub fn write_pixel(&mut self, x: u32, y: u32, color: impl Into<Color>) -> Result<(), Error> {
+        let color = color.into();
         let x = i32::try_from(x)?;
         let y = i32::try_from(y)?;
         let result = unsafe {
@@ -968,6 +1409,176 @@ impl<'a> SurfaceLock<'a> {
             core::slice::from_raw_parts_mut(pixels as *mut u8, length)
         }
     }
+
+    /// Returns an iterator over the rows of pixels, from top to bottom.
+    ///
+    /// Each row only holds the bytes of its pixels; the padding up to the pitch is skipped.
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[u8]> {
+        let (pitch, row_len) = self.row_layout();
+        self.as_bytes()
+            .chunks_exact(pitch.max(1))
+            .map(move |row| &row[..row_len])
+    }
+
+    /// Returns an iterator over the mutable rows of pixels, from top to bottom.
+    ///
+    /// Each row only holds the bytes of its pixels; the padding up to the pitch is skipped.
+    pub fn rows_mut(&mut self) -> impl ExactSizeIterator<Item = &mut [u8]> {
+        let (pitch, row_len) = self.row_layout();
+        self.as_bytes_mut()
+            .chunks_exact_mut(pitch.max(1))
+            .map(move |row| &mut row[..row_len])
+    }
+
+    /// Returns the pixels as a slice of `P`, e.g. `u32` for 32-bit formats or `[u8; 3]` for
+    /// [`PixelFormat::Rgb24`].
+    ///
+    /// The size of `P` must match the bytes per pixel of the surface's format, and the rows must
+    /// not be padded, i.e. the pitch must be the width times the bytes per pixel. Use
+    /// [`SurfaceLock::pixel_rows`] for surfaces with padded rows.
+    pub fn as_pixels<P: Pod>(&self) -> Result<&[P], Error> {
+        let (width, height) = self.check_pixel_type::<P>()?;
+        self.check_unpadded::<P>()?;
+        let bytes = self.as_bytes();
+        Ok(unsafe { core::slice::from_raw_parts(bytes.as_ptr() as *const P, width * height) })
+    }
+
+    /// Returns the pixels as a mutable slice of `P`. See [`SurfaceLock::as_pixels`].
+    pub fn as_pixels_mut<P: Pod>(&mut self) -> Result<&mut [P], Error> {
+        let (width, height) = self.check_pixel_type::<P>()?;
+        self.check_unpadded::<P>()?;
+        let bytes = self.as_bytes_mut();
+        Ok(
+            unsafe {
+                core::slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut P, width * height)
+            },
+        )
+    }
+
+    /// Returns an iterator over the rows of pixels as slices of `P`, from top to bottom.
+    ///
+    /// Unlike [`SurfaceLock::as_pixels`], this works with padded rows. The size of `P` must
+    /// match the bytes per pixel of the surface's format.
+    pub fn pixel_rows<P: Pod>(&self) -> Result<impl ExactSizeIterator<Item = &[P]>, Error> {
+        let (width, _) = self.check_pixel_type::<P>()?;
+        Ok(self.rows().map(move |row| unsafe {
+            core::slice::from_raw_parts(row.as_ptr() as *const P, width)
+        }))
+    }
+
+    /// Returns an iterator over the mutable rows of pixels as slices of `P`. See
+    /// [`SurfaceLock::pixel_rows`].
+    pub fn pixel_rows_mut<P: Pod>(
+        &mut self,
+    ) -> Result<impl ExactSizeIterator<Item = &mut [P]>, Error> {
+        let (width, _) = self.check_pixel_type::<P>()?;
+        Ok(self.rows_mut().map(move |row| unsafe {
+            core::slice::from_raw_parts_mut(row.as_mut_ptr() as *mut P, width)
+        }))
+    }
+
+    /// Returns the pitch and the number of bytes used by the pixels of a row.
+    fn row_layout(&self) -> (usize, usize) {
+        let (width, pitch) = unsafe {
+            let raw = self.0.raw();
+            ((*raw).w.max(0) as usize, (*raw).pitch.max(0) as usize)
+        };
+        let format = self.0.format();
+        let bits = format
+            .details()
+            .map_or(0, |details| details.bits_per_pixel()) as usize;
+        if bits == 0 {
+            // FourCC formats such as YUV don't have a fixed number of bits per pixel.
+            return (pitch, pitch);
+        }
+        // Sub-byte formats pack several pixels per byte, and the last byte may be partially used.
+        let row_len = width.saturating_mul(bits).div_ceil(8).min(pitch);
+        (pitch, row_len)
+    }
+
+    /// Checks that the pixels of every row can be read as `P`, and returns the width and height.
+    fn check_pixel_type<P: Pod>(&self) -> Result<(usize, usize), Error> {
+        let format = self.0.format();
+        let details = format.details()?;
+        if details.bytes_per_pixel() as usize != size_of::<P>() {
+            return Err(Error::register(
+                c"The pixel type doesn't match the bytes per pixel of the surface.",
+            ));
+        }
+        let (width, height, pitch, pixels) = unsafe {
+            let raw = self.0.raw();
+            (
+                (*raw).w.max(0) as usize,
+                (*raw).h.max(0) as usize,
+                (*raw).pitch.max(0) as usize,
+                (*raw).pixels,
+            )
+        };
+        if !(pixels as usize).is_multiple_of(align_of::<P>())
+            || !pitch.is_multiple_of(align_of::<P>())
+        {
+            return Err(Error::register(
+                c"The surface's rows aren't aligned for the pixel type.",
+            ));
+        }
+        Ok((width, height))
+    }
+
+    fn check_unpadded<P: Pod>(&self) -> Result<(), Error> {
+        let (width, pitch) = unsafe {
+            let raw = self.0.raw();
+            ((*raw).w.max(0) as usize, (*raw).pitch.max(0) as usize)
+        };
+        if width * size_of::<P>() != pitch {
+            return Err(Error::register(c"The surface's rows are padded."));
+        }
+        Ok(())
+    }
+
+    /// Returns an iterator over the surface's pixels, in row-major order starting at the top
+    /// left corner.
+    ///
+    /// Indexed formats are resolved through the surface's palette and packed formats through the
+    /// format's [`PixelFormatDetails`], using the entire 0..255 range like
+    /// [`PixelFormatDetails::rgba`]. Formats wider than 32 bits per pixel fall back to
+    /// [`SurfaceRef::read_pixel`]. Returns an error for FourCC formats such as YUV.
+    pub fn iter_pixels(&self) -> Result<SurfacePixels<'_>, Error> {
+        let format = self.0.format();
+        let details = unsafe { sys::SDL_GetPixelFormatDetails(format.to_ll()) };
+        if details.is_null() {
+            return Err(Error::new());
+        }
+        let details = unsafe { PixelFormatDetails::from_ptr(details) };
+        let format = format.to_ll();
+        let decoder = match (format >> 24) & 0xF {
+            sys::SDL_PixelType_SDL_PIXELTYPE_INDEX1
+            | sys::SDL_PixelType_SDL_PIXELTYPE_INDEX2
+            | sys::SDL_PixelType_SDL_PIXELTYPE_INDEX4 => PixelDecoder::Bits {
+                bits: details.bits_per_pixel(),
+                lsb_first: (format >> 20) & 0xF == sys::SDL_BitmapOrder_SDL_BITMAPORDER_1234,
+            },
+            _ => match details.bytes_per_pixel() {
+                0 => return Err(Error::register(c"Unsupported pixel format.")),
+                bytes @ 1..=4 => PixelDecoder::Packed(bytes),
+                _ => PixelDecoder::Fallback,
+            },
+        };
+        let (width, height, pitch) = unsafe {
+            let raw = self.0.raw();
+            ((*raw).w as u32, (*raw).h as u32, (*raw).pitch as usize)
+        };
+        Ok(SurfacePixels {
+            surface: self.0,
+            bytes: self.as_bytes(),
+            details,
+            palette: self.0.palette(),
+            decoder,
+            width,
+            height,
+            pitch,
+            index: 0,
+        })
+    }
 }
 
 impl<'a> Drop for SurfaceLock<'a> {
@@ -976,12 +1587,318 @@ impl<'a> Drop for SurfaceLock<'a> {
     }
 }
 
+/// A type whose values can be read from and written to raw pixel memory, for
+/// [`SurfaceLock::as_pixels`] and [`SurfaceLock::pixel_rows`].
+///
+/// # Safety
+///
+/// Every bit pattern must be a valid value of the type, and it must have no padding bytes.
+pub unsafe trait Pod: Copy + 'static {}
+
+unsafe impl Pod for u8 {}
+unsafe impl Pod for u16 {}
+unsafe impl Pod for u32 {}
+unsafe impl Pod for u64 {}
+unsafe impl Pod for f32 {}
+unsafe impl<P: Pod, const N: usize> Pod for [P; N] {}
+
+/// An iterator over the pixels of a locked surface.
+///
+/// Obtained from [`SurfaceLock::iter_pixels`].
+pub struct SurfacePixels<'a> {
+    surface: &'a SurfaceRef,
+    bytes: &'a [u8],
+    details: &'a PixelFormatDetails,
+    palette: Option<&'a PaletteRef>,
+    decoder: PixelDecoder,
+    width: u32,
+    height: u32,
+    pitch: usize,
+    index: usize,
+}
+
+#[derive(Copy, Clone)]
+enum PixelDecoder {
+    /// Indexed formats with several pixels per byte.
+    Bits { bits: u8, lsb_first: bool },
+    /// Formats with 1 to 4 bytes per pixel, stored in native byte order.
+    Packed(u8),
+    /// Anything wider, decoded by SDL one pixel at a time.
+    Fallback,
+}
+
+impl SurfacePixels<'_> {
+    fn pixel_value(&self, x: usize, y: usize) -> u32 {
+        let row = &self.bytes[y * self.pitch..];
+        match self.decoder {
+            PixelDecoder::Bits { bits, lsb_first } => {
+                let bits = bits as usize;
+                let per_byte = 8 / bits;
+                let byte = row[x / per_byte];
+                let slot = x % per_byte;
+                let shift = if lsb_first {
+                    slot * bits
+                } else {
+                    8 - bits - slot * bits
+                };
+                ((byte >> shift) & ((1 << bits) - 1) as u8) as u32
+            }
+            PixelDecoder::Packed(bytes) => {
+                let start = x * bytes as usize;
+                let p = &row[start..start + bytes as usize];
+                match bytes {
+                    1 => p[0] as u32,
+                    2 => u16::from_ne_bytes([p[0], p[1]]) as u32,
+                    #[cfg(target_endian = "little")]
+                    3 => p[0] as u32 | (p[1] as u32) << 8 | (p[2] as u32) << 16,
+                    #[cfg(target_endian = "big")]
+                    3 => (p[0] as u32) << 16 | (p[1] as u32) << 8 | p[2] as u32,
+                    _ => u32::from_ne_bytes([p[0], p[1], p[2], p[3]]),
+                }
+            }
+            PixelDecoder::Fallback => 0,
+        }
+    }
+}
+
+impl Iterator for SurfacePixels<'_> {
+    type Item = Color;
+
+    fn next(&mut self) -> Option<Color> {
+        if self.width == 0 || self.index >= self.width as usize * self.height as usize {
+            return None;
+        }
+        let x = self.index % self.width as usize;
+        let y = self.index / self.width as usize;
+        self.index += 1;
+        if let PixelDecoder::Fallback = self.decoder {
+            return Some(
+                self.surface
+                    .read_pixel(x as u32, y as u32)
+                    .unwrap_or(Color::new(0, 0, 0, 0)),
+            );
+        }
+        let (r, g, b, a) = self.details.rgba(self.pixel_value(x, y), self.palette);
+        Some(Color::new(r, g, b, a))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.width as usize * self.height as usize).saturating_sub(self.index);
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for SurfacePixels<'_> {}
+
+/// A pool of pixel buffers used to stage texture uploads produced on other threads.
+///
+/// SDL objects in this crate are `!Send`, so surfaces and textures can't be created on worker
+/// threads. The pattern supported by `SurfacePool` is the following:
+///
+/// 1. The main thread calls [`SurfacePool::acquire`] to obtain a [`StagingBuffer`] with the
+///    right size for a given pixel format.
+/// 2. The `StagingBuffer` is sent to a worker thread, which writes raw pixels into
+///    [`StagingBuffer::pixels_mut`].
+/// 3. The buffer is sent back to the main thread and handed to [`SurfacePool::submit`].
+/// 4. The main thread calls [`SurfacePool::upload_next`] until it returns `Ok(None)`. Each call
+///    wraps a staged buffer with [`Surface::from_pixels`], converts it into a [`Texture`] and
+///    returns the buffer's memory to the pool so it can be reused by later calls to `acquire`.
+pub struct SurfacePool {
+    video: VideoSubsystem,
+    free: Vec<Vec<u8>>,
+    ready: VecDeque<StagingBuffer>,
+}
+
+impl SurfacePool {
+    /// Creates an empty `SurfacePool`.
+    pub fn new(video: &VideoSubsystem) -> Self {
+        Self {
+            video: video.clone(),
+            free: Vec::new(),
+            ready: VecDeque::new(),
+        }
+    }
+
+    /// Returns a zeroed `StagingBuffer` that can hold a `width` x `height` image in `format`.
+    ///
+    /// Previously recycled memory is reused whenever possible.
+    pub fn acquire(
+        &mut self,
+        width: u32,
+        height: u32,
+        format: PixelFormat,
+    ) -> Result<StagingBuffer, Error> {
+        StagingBuffer::new(width, height, format, self.free.pop().unwrap_or_default())
+    }
+
+    /// Queues a filled `StagingBuffer` for upload on the next calls to
+    /// [`SurfacePool::upload_next`].
+    pub fn submit(&mut self, buffer: StagingBuffer) {
+        self.ready.push_back(buffer);
+    }
+
+    /// Returns a buffer's memory to the pool without uploading it.
+    pub fn recycle(&mut self, buffer: StagingBuffer) {
+        self.free.push(buffer.pixels);
+    }
+
+    /// The number of submitted buffers waiting to be uploaded.
+    #[inline]
+    pub fn pending(&self) -> usize {
+        self.ready.len()
+    }
+
+    /// Converts the oldest submitted buffer into a texture.
+    ///
+    /// Returns `Ok(None)` if there are no submitted buffers left. On error, the buffer stays at
+    /// the front of the queue.
+    pub fn upload_next<T>(
+        &mut self,
+        renderer: &mut Renderer<T>,
+    ) -> Result<Option<Texture<T>>, Error> {
+        let Some(mut buffer) = self.ready.pop_front() else {
+            return Ok(None);
+        };
+        let result = Surface::from_pixels(
+            &self.video,
+            buffer.format,
+            &mut buffer.pixels,
+            buffer.width,
+            buffer.height,
+        )
+        .and_then(|surface| Texture::from_surface(renderer, &surface));
+        match result {
+            Ok(texture) => {
+                self.free.push(buffer.pixels);
+                Ok(Some(texture))
+            }
+            Err(err) => {
+                self.ready.push_front(buffer);
+                Err(err)
+            }
+        }
+    }
+}
+
+/// A block of pixels that can be filled on any thread.
+///
+/// Obtained from [`SurfacePool::acquire`] or [`Texture::staging`]. Rows are tightly packed: the
+/// pitch is always `width * bytes_per_pixel`.
+pub struct StagingBuffer {
+    pixels: Vec<u8>,
+    width: u32,
+    height: u32,
+    pitch: usize,
+    format: PixelFormat,
+}
+
+impl StagingBuffer {
+    /// Creates a zeroed buffer, reusing the memory of `pixels`.
+    pub(crate) fn new(
+        width: u32,
+        height: u32,
+        format: PixelFormat,
+        mut pixels: Vec<u8>,
+    ) -> Result<Self, Error> {
+        let bytes_per_pixel = format.details()?.bytes_per_pixel();
+        if bytes_per_pixel == 0 {
+            return Err(Error::register(c"Unsupported staging pixel format."));
+        }
+        let pitch = usize::try_from(width)?
+            .checked_mul(bytes_per_pixel as usize)
+            .ok_or_else(|| Error::register(c"Invalid surface pixel parameters"))?;
+        let len = pitch
+            .checked_mul(usize::try_from(height)?)
+            .ok_or_else(|| Error::register(c"Invalid surface pixel parameters"))?;
+        pixels.clear();
+        pixels.resize(len, 0);
+        Ok(Self {
+            pixels,
+            width,
+            height,
+            pitch,
+            format,
+        })
+    }
+
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The length of a row of pixels in bytes.
+    #[inline]
+    pub fn pitch(&self) -> usize {
+        self.pitch
+    }
+
+    #[inline]
+    pub fn format(&self) -> PixelFormat {
+        self.format
+    }
+
+    /// Returns a slice with the buffer's bytes.
+    #[inline]
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Returns a mutable slice with the buffer's bytes.
+    #[inline]
+    pub fn pixels_mut(&mut self) -> &mut [u8] {
+        &mut self.pixels
+    }
+}
+
+/// How [`SurfaceRef::tone_map_to_sdr`] compresses HDR colors into the SDR range.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ToneMapOperator {
+    /// Clips every channel above SDR white. Highlights lose their detail, but SDR content keeps
+    /// its exact colors.
+    Clamp,
+    /// Maps each channel `c` to `c / (1 + c)` after multiplying it by `exposure`, so highlights
+    /// roll off smoothly. An exposure of about 2 keeps mid-tones close to their SDR brightness.
+    Reinhard { exposure: f32 },
+}
+
+impl ToneMapOperator {
+    fn apply(&self, value: f32) -> f32 {
+        let value = value.max(0.0);
+        match *self {
+            Self::Clamp => value.min(1.0),
+            Self::Reinhard { exposure } => {
+                let value = value * exposure;
+                value / (1.0 + value)
+            }
+        }
+    }
+}
+
+/// Encodes a linear value in 0..1 with the sRGB transfer function.
+fn srgb_encode(linear: f32) -> u8 {
+    let encoded = if linear <= 0.003_130_8 {
+        linear * 12.92
+    } else {
+        1.055 * unsafe { sys::SDL_powf(linear, 1.0 / 2.4) } - 0.055
+    };
+    to_unorm8(encoded)
+}
+
+fn to_unorm8(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0 + 0.5) as u8
+}
+
 /// The scaling mode.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 #[repr(u32)]
 pub enum ScaleMode {
-    Nearest = sys::SDL_ScaleMode_SDL_SCALEMODE_LINEAR,
-    Linear = sys::SDL_ScaleMode_SDL_SCALEMODE_NEAREST,
+    Nearest = sys::SDL_ScaleMode_SDL_SCALEMODE_NEAREST,
+    Linear = sys::SDL_ScaleMode_SDL_SCALEMODE_LINEAR,
 }
 
 impl ScaleMode {
@@ -1013,6 +1930,15 @@ pub enum FlipMode {
 }
 
 impl FlipMode {
+    /// Converts a raw `SDL_FlipMode` into a `FlipMode`.
+    pub fn try_from_ll(ll: sys::SDL_FlipMode) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_FlipMode_SDL_FLIP_HORIZONTAL => Self::Horizontal,
+            sys::SDL_FlipMode_SDL_FLIP_VERTICAL => Self::Vertical,
+            _ => return Err(Error::register(c"Unknown flip mode.")),
+        })
+    }
+
     /// Converts a raw `SDL_FlipMode` into a `FlipMode`.
     ///
     /// If the `SDL_FlipMode` is `SDL_FLIP_NONE`, this function will return `None`.
@@ -1030,3 +1956,56 @@ impl FlipMode {
         *self as u32
     }
 }
+
+/// Result of comparing two surfaces with [`SurfaceRef::diff`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ImageDiff {
+    max_channel_delta: u8,
+    differing_pixels: usize,
+    total_pixels: usize,
+}
+
+impl ImageDiff {
+    /// Returns the largest difference found in any single channel of any pixel.
+    #[inline]
+    pub fn max_channel_delta(&self) -> u8 {
+        self.max_channel_delta
+    }
+
+    /// Returns the number of pixels with at least one differing channel.
+    #[inline]
+    pub fn differing_pixels(&self) -> usize {
+        self.differing_pixels
+    }
+
+    /// Returns the number of pixels compared.
+    #[inline]
+    pub fn total_pixels(&self) -> usize {
+        self.total_pixels
+    }
+
+    /// Returns true if both surfaces are pixel-identical.
+    #[inline]
+    pub fn is_identical(&self) -> bool {
+        self.differing_pixels == 0
+    }
+
+    /// Returns true if no channel differs by more than `max_channel_delta` and at most
+    /// `max_differing_pixels` pixels differ at all.
+    #[inline]
+    pub fn within(&self, max_channel_delta: u8, max_differing_pixels: usize) -> bool {
+        self.max_channel_delta <= max_channel_delta && self.differing_pixels <= max_differing_pixels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(FlipMode: Horizontal, Vertical);
+        assert_round_trips!(ScaleMode: Nearest, Linear);
+    }
+}
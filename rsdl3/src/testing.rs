@@ -0,0 +1,91 @@
+//! Shared fixtures for the crate's unit tests.
+
+use crate::pixels::PixelFormat;
+use proptest::sample::select;
+use proptest::strategy::Strategy;
+
+/// Every [`PixelFormat`] variant, listed independently of its conversion functions.
+pub(crate) const PIXEL_FORMATS: [PixelFormat; 64] = [
+    PixelFormat::Unknown,
+    PixelFormat::Index1Lsb,
+    PixelFormat::Index1Msb,
+    PixelFormat::Index2Lsb,
+    PixelFormat::Index2Msb,
+    PixelFormat::Index4Lsb,
+    PixelFormat::Index4Msb,
+    PixelFormat::Index8,
+    PixelFormat::Rgb332,
+    PixelFormat::Xrgb4444,
+    PixelFormat::Xbgr4444,
+    PixelFormat::Xrgb1555,
+    PixelFormat::Xbgr1555,
+    PixelFormat::Argb4444,
+    PixelFormat::Rgba4444,
+    PixelFormat::Abgr4444,
+    PixelFormat::Bgra4444,
+    PixelFormat::Argb1555,
+    PixelFormat::Rgba5551,
+    PixelFormat::Abgr1555,
+    PixelFormat::Bgra5551,
+    PixelFormat::Rgb565,
+    PixelFormat::Bgr565,
+    PixelFormat::Rgb24,
+    PixelFormat::Bgr24,
+    PixelFormat::Xrgb8888,
+    PixelFormat::Rgbx8888,
+    PixelFormat::Xbgr8888,
+    PixelFormat::Bgrx8888,
+    PixelFormat::Argb8888,
+    PixelFormat::Rgba8888,
+    PixelFormat::Abgr8888,
+    PixelFormat::Bgra8888,
+    PixelFormat::Xrgb2101010,
+    PixelFormat::Xbgr2101010,
+    PixelFormat::Argb2101010,
+    PixelFormat::Abgr2101010,
+    PixelFormat::Rgb48,
+    PixelFormat::Bgr48,
+    PixelFormat::Rgba64,
+    PixelFormat::Argb64,
+    PixelFormat::Bgra64,
+    PixelFormat::Abgr64,
+    PixelFormat::Rgb48Float,
+    PixelFormat::Bgr48Float,
+    PixelFormat::Rgba64Float,
+    PixelFormat::Argb64Float,
+    PixelFormat::Bgra64Float,
+    PixelFormat::Abgr64Float,
+    PixelFormat::Rgb96Float,
+    PixelFormat::Bgr96Float,
+    PixelFormat::Rgba128Float,
+    PixelFormat::Argb128Float,
+    PixelFormat::Bgra128Float,
+    PixelFormat::Abgr128Float,
+    PixelFormat::Yv12,
+    PixelFormat::Iyuv,
+    PixelFormat::Yuy2,
+    PixelFormat::Uyvy,
+    PixelFormat::Yvyu,
+    PixelFormat::Nv12,
+    PixelFormat::Nv21,
+    PixelFormat::P010,
+    PixelFormat::ExternalOes,
+];
+
+pub(crate) fn any_pixel_format() -> impl Strategy<Value = PixelFormat> {
+    select(&PIXEL_FORMATS[..])
+}
+
+/// Asserts that `try_from_ll` turns the raw value of every listed variant back into it.
+macro_rules! assert_round_trips {
+    ($ty:ident: $($variant:ident),+ $(,)?) => {
+        $(
+            assert!(
+                matches!($ty::try_from_ll($ty::$variant as _), Ok($ty::$variant)),
+                concat!(stringify!($ty), "::", stringify!($variant)),
+            );
+        )+
+    };
+}
+
+pub(crate) use assert_round_trips;
@@ -0,0 +1,172 @@
+use crate::init::EventsSubsystem;
+use crate::sys;
+use crate::Error;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::c_void;
+use core::ffi::CStr;
+
+impl EventsSubsystem {
+    /// Returns a list of registered touch devices.
+    ///
+    /// On some platforms touch devices are only registered once they've been used, so this list
+    /// may be empty until the user touches the screen.
+    pub fn touch_devices(&self) -> Result<Vec<TouchDevice>, Error> {
+        let mut count = 0;
+        let ptr = unsafe { sys::SDL_GetTouchDevices(&raw mut count) };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        let vec = match usize::try_from(count) {
+            Ok(len) => unsafe { core::slice::from_raw_parts(ptr, len) }
+                .iter()
+                .map(|id| TouchDevice(*id))
+                .collect(),
+            Err(err) => {
+                unsafe { sys::SDL_free(ptr as *mut c_void) };
+                return Err(err.into());
+            }
+        };
+        unsafe { sys::SDL_free(ptr as *mut c_void) };
+        Ok(vec)
+    }
+}
+
+/// Instance id of a touch device.
+pub type TouchId = sys::SDL_TouchID;
+
+/// Id of a finger on a touch device.
+///
+/// The id is only unique while the finger touches the device.
+pub type FingerId = sys::SDL_FingerID;
+
+/// Touch id reported by touch events synthesized from mouse input.
+pub const MOUSE_TOUCH_ID: TouchId = u64::MAX;
+
+/// Touch id reported by touch events synthesized from pen input.
+pub const PEN_TOUCH_ID: TouchId = u64::MAX - 1;
+
+/// A touch device, such as a touchscreen or a trackpad.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TouchDevice(TouchId);
+
+impl TouchDevice {
+    /// Returns the touch device with the given id.
+    ///
+    /// The id can be obtained from `touch_id` of a [`crate::events::TouchFingerEvent`].
+    #[inline]
+    pub fn from_id(id: TouchId) -> Self {
+        Self(id)
+    }
+
+    #[inline]
+    pub fn id(&self) -> TouchId {
+        self.0
+    }
+
+    /// Returns the name of the touch device.
+    pub fn name(&self) -> Result<String, Error> {
+        let ptr = unsafe { sys::SDL_GetTouchDeviceName(self.0) };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        Ok(unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned())
+    }
+
+    /// Returns the type of the touch device.
+    pub fn device_type(&self) -> TouchDeviceType {
+        TouchDeviceType::from_ll(unsafe { sys::SDL_GetTouchDeviceType(self.0) })
+    }
+
+    /// Returns the fingers currently touching the device.
+    pub fn fingers(&self) -> Result<Vec<Finger>, Error> {
+        let mut count = 0;
+        let ptr = unsafe { sys::SDL_GetTouchFingers(self.0, &raw mut count) };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        let vec = match usize::try_from(count) {
+            Ok(len) => unsafe { core::slice::from_raw_parts(ptr, len) }
+                .iter()
+                .map(|finger| Finger::from_ll(unsafe { &**finger }))
+                .collect(),
+            Err(err) => {
+                unsafe { sys::SDL_free(ptr as *mut c_void) };
+                return Err(err.into());
+            }
+        };
+        unsafe { sys::SDL_free(ptr as *mut c_void) };
+        Ok(vec)
+    }
+}
+
+/// A finger touching a [`TouchDevice`].
+///
+/// Coordinates are normalized between 0 and 1, with the origin at the upper left.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Finger {
+    pub id: FingerId,
+    pub x: f32,
+    pub y: f32,
+    /// Normalized pressure, between 0 and 1.
+    pub pressure: f32,
+}
+
+impl Finger {
+    fn from_ll(ll: &sys::SDL_Finger) -> Self {
+        Self {
+            id: ll.id,
+            x: ll.x,
+            y: ll.y,
+            pressure: ll.pressure,
+        }
+    }
+}
+
+/// The type of a [`TouchDevice`].
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TouchDeviceType {
+    Invalid = sys::SDL_TouchDeviceType_SDL_TOUCH_DEVICE_INVALID,
+    /// A touchscreen, with window-relative coordinates.
+    Direct = sys::SDL_TouchDeviceType_SDL_TOUCH_DEVICE_DIRECT,
+    /// A trackpad, with absolute device coordinates.
+    IndirectAbsolute = sys::SDL_TouchDeviceType_SDL_TOUCH_DEVICE_INDIRECT_ABSOLUTE,
+    /// A trackpad, with screen cursor-relative coordinates.
+    IndirectRelative = sys::SDL_TouchDeviceType_SDL_TOUCH_DEVICE_INDIRECT_RELATIVE,
+}
+
+impl TouchDeviceType {
+    /// Converts a raw `SDL_TouchDeviceType` into a `TouchDeviceType`.
+    pub fn try_from_ll(ll: sys::SDL_TouchDeviceType) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_TouchDeviceType_SDL_TOUCH_DEVICE_INVALID => Self::Invalid,
+            sys::SDL_TouchDeviceType_SDL_TOUCH_DEVICE_DIRECT => Self::Direct,
+            sys::SDL_TouchDeviceType_SDL_TOUCH_DEVICE_INDIRECT_ABSOLUTE => Self::IndirectAbsolute,
+            sys::SDL_TouchDeviceType_SDL_TOUCH_DEVICE_INDIRECT_RELATIVE => Self::IndirectRelative,
+            _ => return Err(Error::register(c"Unknown touch device type.")),
+        })
+    }
+
+    pub(crate) fn from_ll(ll: sys::SDL_TouchDeviceType) -> Self {
+        match ll {
+            sys::SDL_TouchDeviceType_SDL_TOUCH_DEVICE_DIRECT => Self::Direct,
+            sys::SDL_TouchDeviceType_SDL_TOUCH_DEVICE_INDIRECT_ABSOLUTE => Self::IndirectAbsolute,
+            sys::SDL_TouchDeviceType_SDL_TOUCH_DEVICE_INDIRECT_RELATIVE => Self::IndirectRelative,
+            _ => Self::Invalid,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(TouchDeviceType: Invalid, Direct, IndirectAbsolute, IndirectRelative);
+    }
+}
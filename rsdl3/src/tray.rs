@@ -0,0 +1,346 @@
+use crate::init::VideoSubsystem;
+use crate::surface::SurfaceRef;
+use crate::{sys, Error};
+use alloc::boxed::Box;
+use alloc::ffi::CString;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::{c_void, CStr};
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+
+/// An icon in the system tray, or its equivalent on the platform, with an optional menu.
+///
+/// Trays should only be created and used on the main thread. Entry callbacks run while events
+/// are pumped.
+pub struct Tray {
+    _video: VideoSubsystem,
+    ptr: NonNull<sys::SDL_Tray>,
+    // Callbacks are only freed with the tray, since SDL may call them until it's destroyed.
+    callbacks: Vec<Box<dyn TrayCallback>>,
+}
+
+impl Tray {
+    /// Creates a tray icon. `tooltip` is shown when hovering the icon, on platforms that support
+    /// it.
+    ///
+    /// The surface can be dropped once the tray is created.
+    pub fn new(
+        video: &VideoSubsystem,
+        icon: Option<&SurfaceRef>,
+        tooltip: Option<&str>,
+    ) -> Result<Self, Error> {
+        let tooltip = tooltip.map(CString::new).transpose()?;
+        let icon = icon.map(SurfaceRef::raw).unwrap_or(core::ptr::null_mut());
+        let tooltip_ptr = tooltip
+            .as_ref()
+            .map(|tooltip| tooltip.as_ptr())
+            .unwrap_or(core::ptr::null());
+        let ptr = unsafe { sys::SDL_CreateTray(icon, tooltip_ptr) };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            _video: video.clone(),
+            ptr,
+            callbacks: Vec::new(),
+        })
+    }
+
+    pub fn set_icon(&mut self, icon: Option<&SurfaceRef>) {
+        let icon = icon.map(SurfaceRef::raw).unwrap_or(core::ptr::null_mut());
+        unsafe { sys::SDL_SetTrayIcon(self.raw(), icon) };
+    }
+
+    pub fn set_tooltip(&mut self, tooltip: Option<&str>) -> Result<(), Error> {
+        let tooltip = tooltip.map(CString::new).transpose()?;
+        let tooltip_ptr = tooltip
+            .as_ref()
+            .map(|tooltip| tooltip.as_ptr())
+            .unwrap_or(core::ptr::null());
+        unsafe { sys::SDL_SetTrayTooltip(self.raw(), tooltip_ptr) };
+        Ok(())
+    }
+
+    /// Returns the menu shown when the icon is clicked, creating it if needed.
+    pub fn menu(&mut self) -> Result<TrayMenu<'_>, Error> {
+        let mut ptr = unsafe { sys::SDL_GetTrayMenu(self.raw()) };
+        if ptr.is_null() {
+            ptr = unsafe { sys::SDL_CreateTrayMenu(self.raw()) };
+        }
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(TrayMenu {
+            ptr,
+            callbacks: &mut self.callbacks,
+        })
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_Tray {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for Tray {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_DestroyTray(self.raw()) };
+    }
+}
+
+/// A menu of a [`Tray`], or a submenu of one of its entries.
+pub struct TrayMenu<'a> {
+    ptr: NonNull<sys::SDL_TrayMenu>,
+    callbacks: &'a mut Vec<Box<dyn TrayCallback>>,
+}
+
+impl TrayMenu<'_> {
+    /// Inserts an entry at `index`, or appends it if `index` is `None`.
+    ///
+    /// The entry is a separator if `label` is `None`, in which case `kind` is ignored.
+    pub fn insert(
+        &mut self,
+        index: Option<usize>,
+        label: Option<&str>,
+        kind: TrayEntryKind,
+    ) -> Result<TrayEntry<'_>, Error> {
+        let index = match index {
+            Some(index) => i32::try_from(index)?,
+            None => -1,
+        };
+        let label = label.map(CString::new).transpose()?;
+        let label_ptr = label
+            .as_ref()
+            .map(|label| label.as_ptr())
+            .unwrap_or(core::ptr::null());
+        let ptr = unsafe {
+            sys::SDL_InsertTrayEntryAt(self.ptr.as_ptr(), index, label_ptr, kind.to_ll())
+        };
+        let Some(ptr) = NonNull::new(ptr) else {
+            return Err(Error::register(c"Tray entry index is out of bounds."));
+        };
+        Ok(TrayEntry {
+            ptr,
+            callbacks: self.callbacks,
+        })
+    }
+
+    /// Appends a button.
+    pub fn push_button(&mut self, label: &str) -> Result<TrayEntry<'_>, Error> {
+        self.insert(None, Some(label), TrayEntryKind::Button)
+    }
+
+    /// Appends a checkbox.
+    pub fn push_checkbox(&mut self, label: &str, checked: bool) -> Result<TrayEntry<'_>, Error> {
+        self.insert(None, Some(label), TrayEntryKind::Checkbox { checked })
+    }
+
+    /// Appends an entry that opens a submenu, see [`TrayEntry::submenu`].
+    pub fn push_submenu(&mut self, label: &str) -> Result<TrayEntry<'_>, Error> {
+        self.insert(None, Some(label), TrayEntryKind::Submenu)
+    }
+
+    /// Appends a separator.
+    pub fn push_separator(&mut self) -> Result<(), Error> {
+        self.insert(None, None, TrayEntryKind::Button)?;
+        Ok(())
+    }
+
+    /// Returns the number of entries in the menu, including separators.
+    pub fn len(&self) -> usize {
+        let mut count = 0;
+        unsafe { sys::SDL_GetTrayEntries(self.ptr.as_ptr(), &raw mut count) };
+        count.max(0) as usize
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the entry at `index`.
+    pub fn entry(&mut self, index: usize) -> Option<TrayEntry<'_>> {
+        let mut count = 0;
+        let entries = unsafe { sys::SDL_GetTrayEntries(self.ptr.as_ptr(), &raw mut count) };
+        if entries.is_null() || index >= count.max(0) as usize {
+            return None;
+        }
+        let ptr = unsafe { *entries.add(index) } as *mut sys::SDL_TrayEntry;
+        Some(TrayEntry {
+            ptr: NonNull::new(ptr)?,
+            callbacks: self.callbacks,
+        })
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_TrayMenu {
+        self.ptr.as_ptr()
+    }
+}
+
+/// An entry of a [`TrayMenu`].
+///
+/// Most accessors live in [`TrayEntryRef`], which this type dereferences to.
+pub struct TrayEntry<'a> {
+    ptr: NonNull<sys::SDL_TrayEntry>,
+    callbacks: &'a mut Vec<Box<dyn TrayCallback>>,
+}
+
+impl TrayEntry<'_> {
+    /// Sets the callback run when the entry is selected, replacing the previous one.
+    ///
+    /// Replaced callbacks are kept alive until the tray is dropped.
+    pub fn set_callback<C: TrayCallback>(&mut self, callback: C) {
+        let mut callback = Box::new(callback);
+        let userdata = &mut *callback as *mut C as *mut c_void;
+        // The callback's heap allocation doesn't move when the box is moved into the list.
+        self.callbacks.push(callback);
+        unsafe {
+            sys::SDL_SetTrayEntryCallback(
+                self.ptr.as_ptr(),
+                Some(tray_callback_marshall::<C>),
+                userdata,
+            )
+        };
+    }
+
+    /// Returns the submenu of an entry created with [`TrayEntryKind::Submenu`], creating it if
+    /// needed.
+    pub fn submenu(&mut self) -> Result<TrayMenu<'_>, Error> {
+        let mut ptr = unsafe { sys::SDL_GetTraySubmenu(self.ptr.as_ptr()) };
+        if ptr.is_null() {
+            ptr = unsafe { sys::SDL_CreateTraySubmenu(self.ptr.as_ptr()) };
+        }
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(TrayMenu {
+            ptr,
+            callbacks: self.callbacks,
+        })
+    }
+
+    /// Simulates a click on the entry, running its callback.
+    pub fn click(&mut self) {
+        unsafe { sys::SDL_ClickTrayEntry(self.ptr.as_ptr()) };
+    }
+
+    /// Removes the entry from its menu, along with its submenu, if any.
+    pub fn remove(self) {
+        unsafe { sys::SDL_RemoveTrayEntry(self.ptr.as_ptr()) };
+    }
+}
+
+impl Deref for TrayEntry<'_> {
+    type Target = TrayEntryRef;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { TrayEntryRef::from_ptr(self.ptr.as_ptr()) }
+    }
+}
+
+impl DerefMut for TrayEntry<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { TrayEntryRef::from_mut_ptr(self.ptr.as_ptr()) }
+    }
+}
+
+/// The state of a tray entry, shared by [`TrayEntry`] and the entry passed to a
+/// [`TrayCallback`].
+///
+/// This type is only exposed as a reference such that its' lifetime is bound to an owner.
+pub struct TrayEntryRef {
+    _inner: PhantomData<*const ()>, // !Send + !Sync
+}
+
+impl TrayEntryRef {
+    pub(crate) unsafe fn from_ptr<'a>(ptr: *const sys::SDL_TrayEntry) -> &'a Self {
+        &*(ptr as *const Self)
+    }
+
+    pub(crate) unsafe fn from_mut_ptr<'a>(ptr: *mut sys::SDL_TrayEntry) -> &'a mut Self {
+        &mut *(ptr as *mut Self)
+    }
+
+    /// Returns the label of the entry, or `None` for separators.
+    pub fn label(&self) -> Option<String> {
+        let ptr = unsafe { sys::SDL_GetTrayEntryLabel(self.raw()) };
+        (!ptr.is_null()).then(|| {
+            unsafe { CStr::from_ptr(ptr) }
+                .to_string_lossy()
+                .into_owned()
+        })
+    }
+
+    /// Sets the label of the entry. This has no effect on separators.
+    pub fn set_label(&mut self, label: &str) -> Result<(), Error> {
+        let label = CString::new(label)?;
+        unsafe { sys::SDL_SetTrayEntryLabel(self.raw(), label.as_ptr()) };
+        Ok(())
+    }
+
+    /// Returns true if a checkbox is checked. Always false for other entries.
+    pub fn checked(&self) -> bool {
+        unsafe { sys::SDL_GetTrayEntryChecked(self.raw()) }
+    }
+
+    /// Checks or unchecks a checkbox. This has no effect on other entries.
+    pub fn set_checked(&mut self, checked: bool) {
+        unsafe { sys::SDL_SetTrayEntryChecked(self.raw(), checked) };
+    }
+
+    pub fn enabled(&self) -> bool {
+        unsafe { sys::SDL_GetTrayEntryEnabled(self.raw()) }
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        unsafe { sys::SDL_SetTrayEntryEnabled(self.raw(), enabled) };
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::SDL_TrayEntry {
+        self as *const Self as *mut Self as *mut () as *mut sys::SDL_TrayEntry
+    }
+}
+
+/// The kind of a [`TrayEntry`], see [`TrayMenu::insert`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TrayEntryKind {
+    Button,
+    Checkbox {
+        checked: bool,
+    },
+    /// An entry that opens a submenu, see [`TrayEntry::submenu`].
+    Submenu,
+}
+
+impl TrayEntryKind {
+    fn to_ll(self) -> sys::SDL_TrayEntryFlags {
+        match self {
+            Self::Button => sys::SDL_TRAYENTRY_BUTTON,
+            Self::Checkbox { checked: false } => sys::SDL_TRAYENTRY_CHECKBOX,
+            Self::Checkbox { checked: true } => {
+                sys::SDL_TRAYENTRY_CHECKBOX | sys::SDL_TRAYENTRY_CHECKED
+            }
+            Self::Submenu => sys::SDL_TRAYENTRY_SUBMENU,
+        }
+    }
+}
+
+/// Runs when a [`TrayEntry`] is selected. See [`TrayEntry::set_callback`].
+///
+/// Implemented for closures taking a `&mut TrayEntryRef`.
+pub trait TrayCallback: 'static {
+    fn selected(&mut self, entry: &mut TrayEntryRef);
+}
+
+impl<F: FnMut(&mut TrayEntryRef) + 'static> TrayCallback for F {
+    fn selected(&mut self, entry: &mut TrayEntryRef) {
+        self(entry)
+    }
+}
+
+unsafe extern "C" fn tray_callback_marshall<C: TrayCallback>(
+    userdata: *mut c_void,
+    entry: *mut sys::SDL_TrayEntry,
+) {
+    let callback: &mut C = unsafe { &mut *(userdata as *mut C) };
+    let entry = unsafe { TrayEntryRef::from_mut_ptr(entry) };
+    callback.selected(entry);
+}
@@ -0,0 +1,340 @@
+use crate::iostream::IOStream;
+use crate::pixels::Color;
+use crate::render::{Renderer, Texture};
+use crate::surface::Surface;
+use crate::sys;
+use crate::Error;
+use crate::VideoSubsystem;
+use alloc::ffi::CString;
+use alloc::rc::Rc;
+use core::ptr::NonNull;
+
+impl VideoSubsystem {
+    /// Equivalent to [`TtfContext::new`].
+    pub fn ttf(&self) -> Result<TtfContext, Error> {
+        TtfContext::new(self)
+    }
+}
+
+/// Handle to an initialized SDL_ttf library.
+///
+/// SDL_ttf is initialized when the first context is created and deinitialized once the last
+/// context and every [`Font`] opened through it are dropped.
+#[derive(Clone)]
+pub struct TtfContext {
+    video: VideoSubsystem,
+    _drop: Rc<TtfDrop>,
+}
+
+impl TtfContext {
+    /// Initializes SDL_ttf.
+    pub fn new(video: &VideoSubsystem) -> Result<Self, Error> {
+        let result = unsafe { sys::ttf::TTF_Init() };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(Self {
+            video: video.clone(),
+            _drop: Rc::new(TtfDrop),
+        })
+    }
+
+    /// Returns the version of the dynamically linked SDL_ttf library.
+    pub fn version(&self) -> i32 {
+        unsafe { sys::ttf::TTF_Version() }
+    }
+
+    /// Equivalent to [`Font::open`].
+    pub fn open_font(&self, path: &str, point_size: f32) -> Result<Font<'static>, Error> {
+        Font::open(self, path, point_size)
+    }
+
+    /// Equivalent to [`Font::open_from_io`].
+    pub fn open_font_from_io<'a>(
+        &self,
+        io: IOStream<'a>,
+        point_size: f32,
+    ) -> Result<Font<'a>, Error> {
+        Font::open_from_io(self, io, point_size)
+    }
+}
+
+struct TtfDrop;
+
+impl Drop for TtfDrop {
+    fn drop(&mut self) {
+        unsafe { sys::ttf::TTF_Quit() };
+    }
+}
+
+/// A font loaded at a specific point size.
+///
+/// The `'a` lifetime is tied to the [`IOStream`] the font was loaded from, which SDL_ttf keeps
+/// reading from for as long as the font is open.
+pub struct Font<'a> {
+    ctx: TtfContext,
+    ptr: NonNull<sys::ttf::TTF_Font>,
+    // Must be dropped after the font is closed.
+    _io: Option<IOStream<'a>>,
+}
+
+impl Font<'static> {
+    /// Creates a font from a file, using a specified point size.
+    ///
+    /// Some .fon fonts will have several sizes embedded in the file, so the point size becomes the
+    /// index of choosing which size. If the value is too high, the last indexed size will be the
+    /// default.
+    pub fn open(ctx: &TtfContext, path: &str, point_size: f32) -> Result<Self, Error> {
+        let path = CString::new(path)?;
+        let ptr = unsafe { sys::ttf::TTF_OpenFont(path.as_ptr(), point_size) };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            ctx: ctx.clone(),
+            ptr,
+            _io: None,
+        })
+    }
+}
+
+impl<'a> Font<'a> {
+    /// Creates a font from an [`IOStream`], using a specified point size.
+    ///
+    /// The stream is kept open until the font is dropped.
+    pub fn open_from_io(
+        ctx: &TtfContext,
+        io: IOStream<'a>,
+        point_size: f32,
+    ) -> Result<Self, Error> {
+        let ptr = unsafe { sys::ttf::TTF_OpenFontIO(io.raw(), false, point_size) };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(Self {
+            ctx: ctx.clone(),
+            ptr,
+            _io: Some(io),
+        })
+    }
+
+    /// Returns the point size of the font.
+    pub fn size(&self) -> f32 {
+        unsafe { sys::ttf::TTF_GetFontSize(self.raw()) }
+    }
+
+    /// Sets the point size of the font, clearing any already-generated glyphs from the cache.
+    pub fn set_size(&mut self, point_size: f32) -> Result<(), Error> {
+        let result = unsafe { sys::ttf::TTF_SetFontSize(self.raw(), point_size) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns the current style of the font.
+    pub fn style(&self) -> FontStyle {
+        FontStyle(unsafe { sys::ttf::TTF_GetFontStyle(self.raw()) })
+    }
+
+    /// Sets the style of the font, clearing any already-generated glyphs from the cache.
+    pub fn set_style(&mut self, style: FontStyle) {
+        unsafe { sys::ttf::TTF_SetFontStyle(self.raw(), style.0) }
+    }
+
+    /// Returns the total height of the font, usually equal to its point size.
+    pub fn height(&self) -> i32 {
+        unsafe { sys::ttf::TTF_GetFontHeight(self.raw()) }
+    }
+
+    /// Returns the offset from the baseline to the top of the font.
+    ///
+    /// This is a positive value, relative to the baseline.
+    pub fn ascent(&self) -> i32 {
+        unsafe { sys::ttf::TTF_GetFontAscent(self.raw()) }
+    }
+
+    /// Returns the offset from the baseline to the bottom of the font.
+    ///
+    /// This is a negative value, relative to the baseline.
+    pub fn descent(&self) -> i32 {
+        unsafe { sys::ttf::TTF_GetFontDescent(self.raw()) }
+    }
+
+    /// Returns the recommended spacing between lines of text.
+    pub fn line_skip(&self) -> i32 {
+        unsafe { sys::ttf::TTF_GetFontLineSkip(self.raw()) }
+    }
+
+    /// Returns the width and height, in pixels, that `text` will take to fully render.
+    pub fn size_of(&self, text: &str) -> Result<(u32, u32), Error> {
+        let mut w = 0;
+        let mut h = 0;
+        let result = unsafe {
+            sys::ttf::TTF_GetStringSize(
+                self.raw(),
+                text_ptr(text),
+                text.len(),
+                &raw mut w,
+                &raw mut h,
+            )
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok((w as u32, h as u32))
+    }
+
+    /// Calculates how much of `text` fits in `max_width` pixels without rendering it.
+    ///
+    /// Returns the width, in pixels, and the length, in bytes, of the part that fits.
+    pub fn measure(&self, text: &str, max_width: u32) -> Result<(u32, usize), Error> {
+        let max_width = i32::try_from(max_width)?;
+        let mut measured_width = 0;
+        let mut measured_length = 0;
+        let result = unsafe {
+            sys::ttf::TTF_MeasureString(
+                self.raw(),
+                text_ptr(text),
+                text.len(),
+                max_width,
+                &raw mut measured_width,
+                &raw mut measured_length,
+            )
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok((measured_width as u32, measured_length))
+    }
+
+    /// Renders `text` at fast quality to a new 8-bit, palettized surface.
+    ///
+    /// The surface's 0 pixel is the colorkey, giving a transparent background, and the 1 pixel is
+    /// set to `fg`.
+    pub fn render_solid(
+        &self,
+        text: &str,
+        fg: impl Into<Color>,
+    ) -> Result<Surface<'static>, Error> {
+        let fg = fg.into().to_ll();
+        let ptr =
+            unsafe { sys::ttf::TTF_RenderText_Solid(self.raw(), text_ptr(text), text.len(), fg) };
+        self.surface_from_ptr(ptr)
+    }
+
+    /// Renders `text` at high quality to a new 8-bit, palettized surface filled with `bg`.
+    pub fn render_shaded(
+        &self,
+        text: &str,
+        fg: impl Into<Color>,
+        bg: impl Into<Color>,
+    ) -> Result<Surface<'static>, Error> {
+        let fg = fg.into().to_ll();
+        let bg = bg.into().to_ll();
+        let ptr = unsafe {
+            sys::ttf::TTF_RenderText_Shaded(self.raw(), text_ptr(text), text.len(), fg, bg)
+        };
+        self.surface_from_ptr(ptr)
+    }
+
+    /// Renders `text` at high quality to a new 32-bit ARGB surface, using alpha blending.
+    pub fn render_blended(
+        &self,
+        text: &str,
+        fg: impl Into<Color>,
+    ) -> Result<Surface<'static>, Error> {
+        let fg = fg.into().to_ll();
+        let ptr =
+            unsafe { sys::ttf::TTF_RenderText_Blended(self.raw(), text_ptr(text), text.len(), fg) };
+        self.surface_from_ptr(ptr)
+    }
+
+    /// Renders word-wrapped `text` at high quality to a new 32-bit ARGB surface.
+    ///
+    /// Text is wrapped on line endings and on word boundaries once it extends beyond `wrap_width`
+    /// pixels. A `wrap_width` of 0 only wraps on newline characters.
+    pub fn render_blended_wrapped(
+        &self,
+        text: &str,
+        fg: impl Into<Color>,
+        wrap_width: u32,
+    ) -> Result<Surface<'static>, Error> {
+        let fg = fg.into().to_ll();
+        let wrap_width = i32::try_from(wrap_width)?;
+        let ptr = unsafe {
+            sys::ttf::TTF_RenderText_Blended_Wrapped(
+                self.raw(),
+                text_ptr(text),
+                text.len(),
+                fg,
+                wrap_width,
+            )
+        };
+        self.surface_from_ptr(ptr)
+    }
+
+    /// Renders `text` with [`Font::render_blended`] and uploads the result to a new texture.
+    pub fn render_texture<T>(
+        &self,
+        renderer: &mut Renderer<T>,
+        text: &str,
+        fg: impl Into<Color>,
+    ) -> Result<Texture<T>, Error> {
+        let surface = self.render_blended(text, fg)?;
+        Texture::from_surface(renderer, &surface)
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::ttf::TTF_Font {
+        self.ptr.as_ptr()
+    }
+
+    fn surface_from_ptr(&self, ptr: *mut sys::SDL_Surface) -> Result<Surface<'static>, Error> {
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        Ok(unsafe { Surface::from_mut_ptr(&self.ctx.video, ptr) })
+    }
+}
+
+// SDL_ttf treats a length of 0 as a null-terminated string, so empty text must point at a NUL.
+fn text_ptr(text: &str) -> *const core::ffi::c_char {
+    if text.is_empty() {
+        c"".as_ptr()
+    } else {
+        text.as_ptr() as *const _
+    }
+}
+
+impl Drop for Font<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::ttf::TTF_CloseFont(self.raw()) };
+    }
+}
+
+/// Style flags for a [`Font`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct FontStyle(sys::ttf::TTF_FontStyleFlags);
+
+impl FontStyle {
+    pub const NORMAL: Self = Self(sys::ttf::TTF_STYLE_NORMAL);
+    pub const BOLD: Self = Self(sys::ttf::TTF_STYLE_BOLD);
+    pub const ITALIC: Self = Self(sys::ttf::TTF_STYLE_ITALIC);
+    pub const UNDERLINE: Self = Self(sys::ttf::TTF_STYLE_UNDERLINE);
+    pub const STRIKETHROUGH: Self = Self(sys::ttf::TTF_STYLE_STRIKETHROUGH);
+
+    #[inline]
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::ttf::TTF_FontStyleFlags {
+        self.0
+    }
+}
+
+impl core::ops::BitOr for FontStyle {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
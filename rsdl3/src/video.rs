@@ -1,9 +1,12 @@
+use crate::events::{DisplayEventPayload, Event, EventPayload, WindowEventPayload};
+use crate::hints::{self, Hint};
 use crate::init::VideoSubsystem;
 use crate::iostream::IOStream;
 use crate::pixels::{PixelFormat, PixelFormatRgbaMask};
+use crate::properties::{Properties, PropertiesRef, PropertiesRefMut};
 use crate::rect::{Point, Rect};
-use crate::render::Renderer;
-use crate::surface::{Surface, SurfaceRef};
+use crate::render::{RenderDriver, Renderer};
+use crate::surface::{ScaleMode, Surface, SurfaceRef};
 use crate::{sys, Error};
 use alloc::ffi::CString;
 use alloc::string::String;
@@ -13,6 +16,11 @@ use core::marker::PhantomData;
 use core::mem::MaybeUninit;
 use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Deref, DerefMut};
 
+pub mod gl;
+#[cfg(feature = "vulkan")]
+#[cfg_attr(docsrs, doc(cfg(feature = "vulkan")))]
+pub mod vulkan;
+
 impl VideoSubsystem {
     /// Creates a `Window`.
     /// This method is equivalent to [`Window::new`].
@@ -26,6 +34,24 @@ impl VideoSubsystem {
         Window::new(self, name, width, height, flags)
     }
 
+    /// Creates a `Window`.
+    /// This method is equivalent to [`Window::new_lossy`].
+    pub fn create_window_lossy(
+        &self,
+        name: &str,
+        width: u32,
+        height: u32,
+        flags: Option<WindowFlags>,
+    ) -> Result<Window, Error> {
+        Window::new_lossy(self, name, width, height, flags)
+    }
+
+    /// Creates a `Window`.
+    /// This method is equivalent to [`Window::with_properties`].
+    pub fn create_window_with_properties(&self, props: &Properties) -> Result<Window, Error> {
+        Window::with_properties(self, props)
+    }
+
     /// Creates a `Window`.
     /// This method is equivalent to [`Surface::new`].
     pub fn create_surface<'a>(
@@ -107,6 +133,13 @@ impl VideoSubsystem {
         }
     }
 
+    /// Returns the builtin render drivers, in the order SDL normally tries them.
+    pub fn render_drivers(&self) -> Result<Vec<RenderDriver>, Error> {
+        (0..self.num_render_drivers()?)
+            .map(|index| Ok(RenderDriver::from_name(&self.render_driver(index)?)))
+            .collect()
+    }
+
     /// Returns the name of the currently initialized video driver.
     pub fn current_driver(&self) -> Result<String, Error> {
         unsafe {
@@ -342,6 +375,90 @@ impl VideoSubsystem {
         Ok(())
     }
 
+    /// Returns the pixel format surfaces should use to be blitted onto `window` as fast as
+    /// possible.
+    ///
+    /// This is the window's pixel format. If SDL can't report it, the format of the desktop
+    /// display mode of the window's display is used instead.
+    pub fn optimal_surface_format_for(&self, window: &WindowRef) -> Result<PixelFormat, Error> {
+        if let Ok(format) = window.pixel_format() {
+            return Ok(format);
+        }
+        let display_mode = self.desktop_display_mode(window.display()?)?;
+        if display_mode.format == PixelFormat::Unknown {
+            return Err(Error::register(
+                c"Unable to determine the window's pixel format.",
+            ));
+        }
+        Ok(display_mode.format)
+    }
+
+    /// Makes renderers created without an explicit driver use SDL's software renderer.
+    ///
+    /// This sets the `SDL_RENDER_DRIVER` hint and must be called before creating renderers.
+    /// Combined with [`Renderer::make_deterministic`], it makes rendering output identical across
+    /// machines, which golden-image tests rely on.
+    pub fn force_software_rendering(&self) -> Result<(), Error> {
+        hints::set_hint(Hint::RENDER_DRIVER, "software")
+    }
+
+    /// Sets whether fullscreen windows should be minimized when they lose key focus.
+    ///
+    /// Minimizing avoids leaving an exclusive fullscreen mode active on a display while the user
+    /// works in another application, but it can be undesirable on multi-monitor setups. This sets
+    /// the `SDL_VIDEO_MINIMIZE_ON_FOCUS_LOSS` hint and should be called before windows are created.
+    pub fn set_minimize_on_focus_loss(&self, minimize: bool) -> Result<(), Error> {
+        let value = if minimize { "1" } else { "0" };
+        hints::set_hint(Hint::VIDEO_MINIMIZE_ON_FOCUS_LOSS, value)
+    }
+
+    /// Returns whether fullscreen windows are minimized when they lose key focus, or `None` if SDL
+    /// decides automatically, which is the default.
+    ///
+    /// See [`VideoSubsystem::set_minimize_on_focus_loss`]. Resetting the hint with
+    /// [`hints::reset_hint`] goes back to the automatic behavior.
+    pub fn minimize_on_focus_loss(&self) -> Option<bool> {
+        let value = hints::get_hint(Hint::VIDEO_MINIMIZE_ON_FOCUS_LOSS)?;
+        if value.eq_ignore_ascii_case("auto") {
+            return None;
+        }
+        Some(hints::get_hint_boolean(
+            Hint::VIDEO_MINIMIZE_ON_FOCUS_LOSS,
+            false,
+        ))
+    }
+
+    /// Returns the ID of the window that currently has an input grab enabled, if any.
+    ///
+    /// The ID can be matched against [`WindowRef::id`] or the window IDs in events.
+    pub fn grabbed_window(&self) -> Option<u32> {
+        let window = unsafe { sys::SDL_GetGrabbedWindow() };
+        if window.is_null() {
+            return None;
+        }
+        match unsafe { sys::SDL_GetWindowID(window) } {
+            0 => None,
+            id => Some(id),
+        }
+    }
+
+    /// Releases the mouse and keyboard grabs of whichever window holds them, e.g. before showing
+    /// a modal dialog. Does nothing if no window is grabbed.
+    pub fn release_grab(&self) -> Result<(), Error> {
+        let window = unsafe { sys::SDL_GetGrabbedWindow() };
+        if window.is_null() {
+            return Ok(());
+        }
+        let result = unsafe {
+            sys::SDL_SetWindowMouseGrab(window, false)
+                && sys::SDL_SetWindowKeyboardGrab(window, false)
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
     /// Returns the current `SystemTheme`.
     pub fn system_theme(&self) -> Result<SysthemTheme, Error> {
         SysthemTheme::try_from_ll(unsafe { sys::SDL_GetSystemTheme() })
@@ -367,6 +484,14 @@ impl VideoSubsystem {
 }
 
 /// Type used to identify a window.
+///
+/// Most window functions must be called from the main thread, so `Window` is neither `Send` nor
+/// `Sync`.
+///
+/// ```compile_fail
+/// fn assert_send<T: Send>() {}
+/// assert_send::<rsdl3::video::Window>();
+/// ```
 pub struct Window {
     pub(crate) video: VideoSubsystem,
     /// This pointer should be safe to dereference as long as the window is still alive.
@@ -383,11 +508,50 @@ impl Window {
         flags: Option<WindowFlags>,
     ) -> Result<Window, Error> {
         let c_string = CString::new(name)?;
-        let c_str = c_string.as_c_str();
+        Self::with_title(video, c_string.as_c_str(), width, height, flags)
+    }
+
+    /// Creates a new [`Window`], replacing any interior NUL bytes in `name` with U+FFFD instead of
+    /// failing.
+    ///
+    /// Useful when the title comes from user-generated content such as file names or chat messages.
+    pub fn new_lossy(
+        video: &VideoSubsystem,
+        name: &str,
+        width: u32,
+        height: u32,
+        flags: Option<WindowFlags>,
+    ) -> Result<Window, Error> {
+        let c_string = crate::cstring_lossy(name);
+        Self::with_title(video, c_string.as_c_str(), width, height, flags)
+    }
+
+    /// Creates a new [`Window`] from a group of `SDL.window.create.*` properties.
+    ///
+    /// This exposes window options that have no [`WindowFlags`] equivalent, such as the initial
+    /// position or a parent window for popups.
+    pub fn with_properties(video: &VideoSubsystem, props: &Properties) -> Result<Window, Error> {
+        let ptr = unsafe { sys::SDL_CreateWindowWithProperties(props.raw()) };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        Ok(Window {
+            video: video.clone(),
+            ptr,
+        })
+    }
+
+    fn with_title(
+        video: &VideoSubsystem,
+        title: &CStr,
+        width: u32,
+        height: u32,
+        flags: Option<WindowFlags>,
+    ) -> Result<Window, Error> {
         let width = c_int::try_from(width)?;
         let height = c_int::try_from(height)?;
         let flags = flags.map(|f| f.0).unwrap_or_default();
-        let ptr = unsafe { sys::SDL_CreateWindow(c_str.as_ptr(), width, height, flags) };
+        let ptr = unsafe { sys::SDL_CreateWindow(title.as_ptr(), width, height, flags) };
         if ptr.is_null() {
             return Err(Error::new());
         }
@@ -768,11 +932,22 @@ impl WindowRef {
     }
 
     /// Sets the title of the window.
+    ///
+    /// Short titles are converted on the stack; see [`WindowRef::set_title_cstr`] to skip the
+    /// conversion entirely.
     pub fn set_title<'a>(&self, title: impl Into<&'a str>) -> Result<(), Error> {
-        let s: &str = title.into();
-        let c_string = CString::new(s)?;
-        let c_str = c_string.as_c_str();
-        let result = unsafe { sys::SDL_SetWindowTitle(self.as_ptr() as *mut _, c_str.as_ptr()) };
+        crate::with_cstr(title.into(), |title| self.set_title_cstr(title))?
+    }
+
+    /// Sets the title of the window, replacing any interior NUL bytes with U+FFFD instead of
+    /// failing.
+    pub fn set_title_lossy(&self, title: &str) -> Result<(), Error> {
+        self.set_title_cstr(crate::cstring_lossy(title).as_c_str())
+    }
+
+    /// Sets the title of the window from a C string, without any conversion or allocation.
+    pub fn set_title_cstr(&self, title: &CStr) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_SetWindowTitle(self.as_ptr() as *mut _, title.as_ptr()) };
         if !result {
             return Err(Error::new());
         }
@@ -952,6 +1127,23 @@ impl WindowRef {
         Ok(())
     }
 
+    /// Sets the window's icon to `image` scaled to `width` x `height`, with alternate images for
+    /// each factor in `scales` so SDL picks the best one for the display scale.
+    ///
+    /// `image` should be a single high resolution asset. Images are scaled with
+    /// [`ScaleMode::Linear`]; see [`SurfaceRef::scaled_image_set`] to use another mode.
+    pub fn set_scaled_icon(
+        &mut self,
+        video: &VideoSubsystem,
+        image: &SurfaceRef,
+        width: u32,
+        height: u32,
+        scales: &[f32],
+    ) -> Result<(), Error> {
+        let icon = image.scaled_image_set(video, width, height, scales, ScaleMode::Linear)?;
+        self.set_icon(&icon)
+    }
+
     /// Set the window's mouse grab mode.
     ///
     /// Mouse grab confines the mouse cursor to the window.
@@ -968,6 +1160,32 @@ impl WindowRef {
         unsafe { sys::SDL_GetWindowMouseGrab(self.as_ptr() as *mut _) }
     }
 
+    /// Sets the window's relative mouse mode.
+    ///
+    /// While enabled, the cursor is hidden and confined to the window, and mouse motion events
+    /// keep reporting relative motion even when the cursor would hit the edge of the screen.
+    /// This is what first-person camera controls need.
+    pub fn set_relative_mouse_mode(&mut self, enabled: bool) -> Result<(), Error> {
+        let result =
+            unsafe { sys::SDL_SetWindowRelativeMouseMode(self.as_ptr() as *mut _, enabled) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns true if relative mouse mode is enabled for the window.
+    pub fn relative_mouse_mode(&self) -> bool {
+        unsafe { sys::SDL_GetWindowRelativeMouseMode(self.as_ptr() as *mut _) }
+    }
+
+    /// Moves the mouse cursor to the given position within the window.
+    ///
+    /// This generates a mouse motion event, unless relative mouse mode is enabled.
+    pub fn warp_mouse(&mut self, x: f32, y: f32) {
+        unsafe { sys::SDL_WarpMouseInWindow(self.as_ptr() as *mut _, x, y) };
+    }
+
     /// Sets a window's keyboard grab mode.
     ///
     /// Keyboard grab enables capture of system keyboard shortcuts like Alt+Tab or the Meta/Super key.
@@ -1028,6 +1246,29 @@ impl WindowRef {
         Ok(())
     }
 
+    /// Tries each VSync mode in `preferred`, in order, and returns the first one accepted by the
+    /// driver.
+    ///
+    /// This is useful since [`WindowSurfaceVSync::Adaptive`] is not supported by many drivers.
+    /// A typical fallback chain would be `[Adaptive, EveryVerticalRefresh, Disabled]`.
+    ///
+    /// Returns the error from the last attempted mode if none of them are accepted, or an error
+    /// if `preferred` is empty.
+    pub fn set_surface_vsync_or_fallback(
+        &mut self,
+        preferred: &[WindowSurfaceVSync],
+    ) -> Result<WindowSurfaceVSync, Error> {
+        if preferred.is_empty() {
+            return Err(Error::register(c"No VSync modes were provided."));
+        }
+        for &vsync in preferred {
+            if self.set_surface_vsync(vsync).is_ok() {
+                return Ok(vsync);
+            }
+        }
+        Err(Error::new())
+    }
+
     /// Sets the shape of a transparent window.
     ///
     /// This sets the alpha channel of a transparent window and any fully transparent areas are also transparent
@@ -1080,6 +1321,31 @@ impl WindowRef {
         Ok(())
     }
 
+    /// Notifies the user that the window needs their attention, such as when a chat message arrives.
+    ///
+    /// Nothing happens if the window already has input focus. Otherwise the window is flashed until it
+    /// gains focus, or only briefly if `persistent` is false. Returns true if the user was notified.
+    ///
+    /// SDL doesn't expose dock or taskbar badge counts, so flashing is the only notification available
+    /// on every platform.
+    pub fn request_attention(&mut self, persistent: bool) -> Result<bool, Error> {
+        if self.flags().contains(WindowFlags::INPUT_FOCUS) {
+            return Ok(false);
+        }
+        let operation = if persistent {
+            WindowFlashOperation::UNTIL_FOCUSED
+        } else {
+            WindowFlashOperation::BRIEFLY
+        };
+        self.flash(operation)?;
+        Ok(true)
+    }
+
+    /// Stops a flash started by [`WindowRef::request_attention`] or [`WindowRef::flash`].
+    pub fn cancel_attention_request(&mut self) -> Result<(), Error> {
+        self.flash(WindowFlashOperation::CANCEL)
+    }
+
     /// Request that the window be made as large as possible.
     ///
     /// Non-resizable windows can't be maximized. The window must have the [`WindowFlags::RESIZABLE`] flag set,
@@ -1230,6 +1496,26 @@ impl WindowRef {
         Ok(())
     }
 
+    /// Returns the properties associated with this window.
+    pub fn properties(&self) -> Result<PropertiesRef<'_>, Error> {
+        unsafe { PropertiesRef::from_ll(sys::SDL_GetWindowProperties(self.as_ptr() as *mut _)) }
+    }
+
+    /// Returns the properties associated with this window, allowing them to be changed.
+    pub fn properties_mut(&mut self) -> Result<PropertiesRefMut<'_>, Error> {
+        unsafe { PropertiesRefMut::from_ll(sys::SDL_GetWindowProperties(self.as_ptr() as *mut _)) }
+    }
+
+    /// Returns every property SDL associates with this window, formatted as one
+    /// `name (type) = value` line per property.
+    ///
+    /// This is meant for diagnostics: attaching the output to bug reports helps tracking down
+    /// driver-specific issues.
+    pub fn dump_properties(&self) -> Result<String, Error> {
+        let props = unsafe { sys::SDL_GetWindowProperties(self.as_ptr() as *mut _) };
+        crate::properties::dump_properties(props)
+    }
+
     #[inline]
     pub fn as_ptr(&self) -> *const sys::SDL_Window {
         self as *const Self as *const sys::SDL_Window
@@ -1247,6 +1533,700 @@ impl Drop for Window {
     }
 }
 
+/// Owns a set of windows and routes events to the window they're tied to.
+///
+/// Each window is stored with user data of type `T`, such as its renderer or UI state:
+///
+/// ```no_run
+/// use rsdl3::events::{EventPayload, WindowEventPayload};
+/// use rsdl3::video::WindowManager;
+///
+/// # fn f(events: &mut rsdl3::EventsSubsystem, video: &rsdl3::VideoSubsystem) -> Result<(), rsdl3::Error> {
+/// let mut windows = WindowManager::new();
+/// windows.insert(video.create_window("Main", 800, 600, None)?, "main")?;
+/// windows.insert(video.create_window("Tools", 300, 600, None)?, "tools")?;
+///
+/// let mut pump = events.event_pump()?;
+/// while !windows.is_empty() {
+///     for event in pump.poll_iter() {
+///         let payload = event.payload();
+///         if let EventPayload::Window(event) = payload {
+///             if let WindowEventPayload::CloseRequested = event.payload {
+///                 windows.remove(event.window_id);
+///                 continue;
+///             }
+///         }
+///         if let Some((_, window, name)) = windows.route_mut(&payload) {
+///             // Handle `payload` for this window.
+///         }
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct WindowManager<T = ()> {
+    windows: Vec<(u32, Window, T)>,
+}
+
+impl<T> WindowManager<T> {
+    pub fn new() -> Self {
+        Self {
+            windows: Vec::new(),
+        }
+    }
+
+    /// Adds a window and its data, and returns the id of the window.
+    pub fn insert(&mut self, window: Window, data: T) -> Result<u32, Error> {
+        let id = window.id()?;
+        self.windows.push((id, window, data));
+        Ok(id)
+    }
+
+    /// Removes the window with the given id and returns it along with its data.
+    pub fn remove(&mut self, id: u32) -> Option<(Window, T)> {
+        let index = self.windows.iter().position(|(i, _, _)| *i == id)?;
+        let (_, window, data) = self.windows.remove(index);
+        Some((window, data))
+    }
+
+    pub fn get(&self, id: u32) -> Option<(&Window, &T)> {
+        self.windows
+            .iter()
+            .find(|(i, _, _)| *i == id)
+            .map(|(_, window, data)| (window, data))
+    }
+
+    pub fn get_mut(&mut self, id: u32) -> Option<(&mut Window, &mut T)> {
+        self.windows
+            .iter_mut()
+            .find(|(i, _, _)| *i == id)
+            .map(|(_, window, data)| (window, data))
+    }
+
+    /// Returns the window an event is tied to, along with its id and data.
+    ///
+    /// Returns `None` for events that aren't tied to a window, or whose window isn't managed.
+    pub fn route(&self, payload: &EventPayload) -> Option<(u32, &Window, &T)> {
+        let id = payload.window_id()?;
+        self.get(id).map(|(window, data)| (id, window, data))
+    }
+
+    /// Returns the window an event is tied to, along with its id and data. See
+    /// [`WindowManager::route`].
+    pub fn route_mut(&mut self, payload: &EventPayload) -> Option<(u32, &mut Window, &mut T)> {
+        let id = payload.window_id()?;
+        self.get_mut(id).map(|(window, data)| (id, window, data))
+    }
+
+    /// Calls `f` with the window an event is tied to and its data. Returns true if `f` was called.
+    pub fn dispatch<F>(&mut self, event: &Event, f: F) -> bool
+    where
+        F: FnOnce(&mut Window, &mut T, &EventPayload),
+    {
+        let payload = event.payload();
+        match self.route_mut(&payload) {
+            Some((_, window, data)) => {
+                f(window, data, &payload);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns an iterator over the ids, windows and data, in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &Window, &T)> {
+        self.windows
+            .iter()
+            .map(|(id, window, data)| (*id, window, data))
+    }
+
+    /// Returns a mutable iterator over the ids, windows and data, in insertion order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (u32, &mut Window, &mut T)> {
+        self.windows
+            .iter_mut()
+            .map(|(id, window, data)| (*id, window, data))
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.windows.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.windows.is_empty()
+    }
+}
+
+impl<T> Default for WindowManager<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Creates a [`Window`] with options that [`Window::new`] doesn't cover.
+///
+/// ```no_run
+/// use rsdl3::video::{WindowBuilder, WindowFlags, WindowPosition};
+///
+/// # fn f(video: &rsdl3::VideoSubsystem, display: u32) -> Result<(), rsdl3::Error> {
+/// let window = WindowBuilder::new("Editor", 1280, 720)
+///     .flags(WindowFlags::RESIZABLE)
+///     .display(display)
+///     .position(WindowPosition::Centered, WindowPosition::Centered)
+///     .min_size(640, 360)
+///     .build(video)?;
+///
+/// let menu = WindowBuilder::new("", 200, 300)
+///     .position(WindowPosition::Absolute(40), WindowPosition::Absolute(20))
+///     .child_of(&window)
+///     .popup_menu()
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct WindowBuilder {
+    title: String,
+    w: u32,
+    h: u32,
+    flags: WindowFlags,
+    x: WindowPosition,
+    y: WindowPosition,
+    display: Option<u32>,
+    min_size: Option<(u32, u32)>,
+    max_size: Option<(u32, u32)>,
+}
+
+impl WindowBuilder {
+    /// Creates a builder for a window of the given size whose position is left to the system.
+    pub fn new(title: &str, w: u32, h: u32) -> Self {
+        Self {
+            title: String::from(title),
+            w,
+            h,
+            flags: WindowFlags::default(),
+            x: WindowPosition::Undefined,
+            y: WindowPosition::Undefined,
+            display: None,
+            min_size: None,
+            max_size: None,
+        }
+    }
+
+    pub fn flags(mut self, flags: WindowFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Sets the position of the window. Child windows are positioned relative to their parent.
+    pub fn position(mut self, x: WindowPosition, y: WindowPosition) -> Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    /// Places the window on the display with the given id, as returned by
+    /// [`VideoSubsystem::displays`]. Only applies to coordinates that aren't
+    /// [`WindowPosition::Absolute`].
+    pub fn display(mut self, display_id: u32) -> Self {
+        self.display = Some(display_id);
+        self
+    }
+
+    /// Sets the minimum size of the window's client area. See [`WindowRef::set_min_size`].
+    pub fn min_size(mut self, w: u32, h: u32) -> Self {
+        self.min_size = Some((w, h));
+        self
+    }
+
+    /// Sets the maximum size of the window's client area. See [`WindowRef::set_max_size`].
+    pub fn max_size(mut self, w: u32, h: u32) -> Self {
+        self.max_size = Some((w, h));
+        self
+    }
+
+    /// Turns this into a builder for a window that belongs to `parent`, such as a popup menu.
+    pub fn child_of(self, parent: &Window) -> ChildWindowBuilder<'_> {
+        ChildWindowBuilder {
+            builder: self,
+            parent,
+        }
+    }
+
+    pub fn build(&self, video: &VideoSubsystem) -> Result<Window, Error> {
+        self.create(video, None, self.flags)
+    }
+
+    fn create(
+        &self,
+        video: &VideoSubsystem,
+        parent: Option<&WindowRef>,
+        flags: WindowFlags,
+    ) -> Result<Window, Error> {
+        let mut props = Properties::new()?;
+        props.set_string("SDL.window.create.title", &self.title)?;
+        props.set_number("SDL.window.create.width", self.w as i64)?;
+        props.set_number("SDL.window.create.height", self.h as i64)?;
+        props.set_number("SDL.window.create.flags", flags.0 as i64)?;
+        props.set_number("SDL.window.create.x", self.x.to_ll(self.display) as i64)?;
+        props.set_number("SDL.window.create.y", self.y.to_ll(self.display) as i64)?;
+        if let Some(parent) = parent {
+            // SAFETY: SDL expects an `SDL_Window`, which stays alive while the window is created.
+            unsafe { props.set_pointer("SDL.window.create.parent", parent.as_ptr() as *mut _)? };
+        }
+        let mut window = Window::with_properties(video, &props)?;
+        if let Some((w, h)) = self.min_size {
+            window.set_min_size(w, h)?;
+        }
+        if let Some((w, h)) = self.max_size {
+            window.set_max_size(w, h)?;
+        }
+        Ok(window)
+    }
+}
+
+/// Builds a [`ChildWindow`]. Created by [`WindowBuilder::child_of`].
+#[derive(Clone)]
+pub struct ChildWindowBuilder<'a> {
+    builder: WindowBuilder,
+    parent: &'a Window,
+}
+
+impl<'a> ChildWindowBuilder<'a> {
+    /// Makes the window a popup menu, which takes keyboard focus from its parent.
+    pub fn popup_menu(mut self) -> Self {
+        self.builder.flags |= WindowFlags::POPUP_MENU;
+        self
+    }
+
+    /// Makes the window a tooltip, which never takes focus.
+    pub fn tooltip(mut self) -> Self {
+        self.builder.flags |= WindowFlags::TOOLTIP;
+        self
+    }
+
+    /// Makes the window modal to its parent, which then can't receive input.
+    pub fn modal(mut self) -> Self {
+        self.builder.flags |= WindowFlags::MODAL;
+        self
+    }
+
+    pub fn build(&self) -> Result<ChildWindow<'a>, Error> {
+        let window =
+            self.builder
+                .create(&self.parent.video, Some(self.parent), self.builder.flags)?;
+        Ok(ChildWindow {
+            window,
+            _parent: PhantomData,
+        })
+    }
+}
+
+/// A window that belongs to another window, created by [`ChildWindowBuilder::build`].
+///
+/// SDL destroys child windows along with their parent, so the parent is borrowed for as long as
+/// the child is alive.
+pub struct ChildWindow<'a> {
+    window: Window,
+    _parent: PhantomData<&'a Window>,
+}
+
+impl Deref for ChildWindow<'_> {
+    type Target = Window;
+
+    fn deref(&self) -> &Window {
+        &self.window
+    }
+}
+
+impl DerefMut for ChildWindow<'_> {
+    fn deref_mut(&mut self) -> &mut Window {
+        &mut self.window
+    }
+}
+
+/// A window coordinate, for [`WindowBuilder::position`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum WindowPosition {
+    /// Let the system choose.
+    #[default]
+    Undefined,
+    /// Center the window on its display.
+    Centered,
+    /// An exact coordinate, relative to the parent for child windows.
+    Absolute(i32),
+}
+
+impl WindowPosition {
+    pub fn to_ll(&self, display_id: Option<u32>) -> i32 {
+        let display_id = display_id.unwrap_or(0);
+        match self {
+            Self::Undefined => (sys::SDL_WINDOWPOS_UNDEFINED_MASK | display_id) as i32,
+            Self::Centered => (sys::SDL_WINDOWPOS_CENTERED_MASK | display_id) as i32,
+            Self::Absolute(value) => *value,
+        }
+    }
+}
+
+/// Tracks whether a window can currently be seen.
+///
+/// Feed every event to [`VisibilityTracker::handle_event`] and check
+/// [`VisibilityTracker::should_present`] before rendering a frame. A window stops being visible
+/// when it's minimized, hidden or fully occluded by other windows, and becomes visible again
+/// once it's restored, shown or exposed. Skipping frames meanwhile saves GPU time and battery.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VisibilityTracker {
+    window_id: u32,
+    minimized: bool,
+    hidden: bool,
+    occluded: bool,
+}
+
+impl VisibilityTracker {
+    /// Creates a `VisibilityTracker` for the window with the given id, assuming it's visible.
+    pub fn new(window_id: u32) -> Self {
+        Self {
+            window_id,
+            minimized: false,
+            hidden: false,
+            occluded: false,
+        }
+    }
+
+    /// Creates a `VisibilityTracker` for `window`, initialized from its current flags.
+    pub fn for_window(window: &WindowRef) -> Result<Self, Error> {
+        let flags = window.flags().0;
+        Ok(Self {
+            window_id: window.id()?,
+            minimized: flags & sys::SDL_WINDOW_MINIMIZED != 0,
+            hidden: flags & sys::SDL_WINDOW_HIDDEN != 0,
+            occluded: flags & sys::SDL_WINDOW_OCCLUDED != 0,
+        })
+    }
+
+    /// Returns the id of the tracked window.
+    #[inline]
+    pub fn window_id(&self) -> u32 {
+        self.window_id
+    }
+
+    /// Updates the window's visibility from an event.
+    ///
+    /// Events that don't belong to the tracked window are ignored. Returns true if the window's
+    /// visibility changed.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        let EventPayload::Window(event) = event.payload() else {
+            return false;
+        };
+        if event.window_id != self.window_id {
+            return false;
+        }
+        let was_visible = self.is_visible();
+        match event.payload {
+            WindowEventPayload::Minimized => self.minimized = true,
+            WindowEventPayload::Restored | WindowEventPayload::Maximized => {
+                self.minimized = false;
+                self.occluded = false;
+            }
+            WindowEventPayload::Hidden => self.hidden = true,
+            WindowEventPayload::Shown => self.hidden = false,
+            WindowEventPayload::Occluded => self.occluded = true,
+            WindowEventPayload::Exposed => self.occluded = false,
+            _ => {}
+        }
+        was_visible != self.is_visible()
+    }
+
+    /// Returns true if the window isn't minimized, hidden or occluded.
+    #[inline]
+    pub fn is_visible(&self) -> bool {
+        !(self.minimized || self.hidden || self.occluded)
+    }
+
+    /// Returns true if presenting a frame to the window is worthwhile.
+    ///
+    /// This is currently the same as [`VisibilityTracker::is_visible`].
+    #[inline]
+    pub fn should_present(&self) -> bool {
+        self.is_visible()
+    }
+
+    #[inline]
+    pub fn is_minimized(&self) -> bool {
+        self.minimized
+    }
+
+    #[inline]
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    #[inline]
+    pub fn is_occluded(&self) -> bool {
+        self.occluded
+    }
+}
+
+/// Follows the refresh rate of the display a window is on.
+///
+/// Feed every event to [`RefreshRateObserver::handle_event`]; `on_change` is called with the new
+/// refresh rate in Hz whenever the window moves to a display with a different rate, or the mode
+/// of its display changes. Use it to adapt frame pacing or fixed update rates, e.g. when the
+/// window is dragged from a 60 Hz to a 144 Hz monitor.
+///
+/// A rate of 0 means the refresh rate is unknown.
+pub struct RefreshRateObserver<F: FnMut(f32)> {
+    window_id: u32,
+    display_id: u32,
+    refresh_rate: f32,
+    on_change: F,
+}
+
+impl<F: FnMut(f32)> RefreshRateObserver<F> {
+    /// Creates an observer for `window`, initialized from the mode of its current display.
+    ///
+    /// `on_change` isn't called for the initial refresh rate, see
+    /// [`RefreshRateObserver::refresh_rate`].
+    pub fn new(window: &WindowRef, on_change: F) -> Result<Self, Error> {
+        let display_id = window.display()?;
+        Ok(Self {
+            window_id: window.id()?,
+            display_id,
+            refresh_rate: Self::query(display_id)?,
+            on_change,
+        })
+    }
+
+    /// Updates the refresh rate from an event, calling `on_change` if it changed.
+    ///
+    /// Events that don't concern the window or its display are ignored.
+    pub fn handle_event(&mut self, event: &Event) -> Result<(), Error> {
+        match event.payload() {
+            EventPayload::Window(event) if event.window_id == self.window_id => {
+                let WindowEventPayload::DisplayChanged { display_id } = event.payload else {
+                    return Ok(());
+                };
+                self.display_id = display_id;
+            }
+            EventPayload::Display(event) if event.display_id == self.display_id => {
+                if !matches!(
+                    event.payload,
+                    DisplayEventPayload::CurrentModeChanged
+                        | DisplayEventPayload::DesktopModeChanged
+                ) {
+                    return Ok(());
+                }
+            }
+            _ => return Ok(()),
+        }
+        let refresh_rate = Self::query(self.display_id)?;
+        if refresh_rate != self.refresh_rate {
+            self.refresh_rate = refresh_rate;
+            (self.on_change)(refresh_rate);
+        }
+        Ok(())
+    }
+
+    /// Returns the latest known refresh rate in Hz.
+    #[inline]
+    pub fn refresh_rate(&self) -> f32 {
+        self.refresh_rate
+    }
+
+    /// Returns the id of the display the window was last seen on.
+    #[inline]
+    pub fn display_id(&self) -> u32 {
+        self.display_id
+    }
+
+    fn query(display_id: u32) -> Result<f32, Error> {
+        let mode = unsafe { sys::SDL_GetCurrentDisplayMode(display_id) };
+        if mode.is_null() {
+            return Err(Error::new());
+        }
+        Ok(unsafe { (*mode).refresh_rate })
+    }
+}
+
+/// Keeps the latest safe area of a window for layout code.
+///
+/// Feed every event to [`SafeAreaTracker::handle_event`]; the safe area is fetched again the next
+/// time it's requested after the window's safe area, size or display changed. Both the safe area
+/// of the window and of a renderer drawing into it are cached.
+///
+/// The renderer's safe area also depends on its viewport and logical presentation, which don't
+/// send events: call [`SafeAreaTracker::invalidate`] after changing them.
+#[derive(Copy, Clone, Debug)]
+pub struct SafeAreaTracker {
+    window_id: u32,
+    window_area: Option<Rect>,
+    render_area: Option<Rect>,
+}
+
+impl SafeAreaTracker {
+    /// Creates a `SafeAreaTracker` for the window with the given id.
+    pub fn new(window_id: u32) -> Self {
+        Self {
+            window_id,
+            window_area: None,
+            render_area: None,
+        }
+    }
+
+    /// Returns the id of the tracked window.
+    #[inline]
+    pub fn window_id(&self) -> u32 {
+        self.window_id
+    }
+
+    /// Updates the tracker from an event.
+    ///
+    /// Events that don't belong to the tracked window are ignored. Returns true if the safe area
+    /// may have changed.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        let EventPayload::Window(event) = event.payload() else {
+            return false;
+        };
+        if event.window_id != self.window_id {
+            return false;
+        }
+        match event.payload {
+            WindowEventPayload::SafeAreaChanged
+            | WindowEventPayload::Resized { .. }
+            | WindowEventPayload::PixelSizeChanged { .. }
+            | WindowEventPayload::DisplayChanged { .. } => {
+                self.invalidate();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Forgets the cached safe areas, so they're fetched again when next requested.
+    pub fn invalidate(&mut self) {
+        self.window_area = None;
+        self.render_area = None;
+    }
+
+    /// Returns the safe area of `window`, which must be the tracked window. See
+    /// [`WindowRef::safe_area`].
+    pub fn safe_area(&mut self, window: &WindowRef) -> Result<Rect, Error> {
+        match self.window_area {
+            Some(area) => Ok(area),
+            None => Ok(*self.window_area.insert(window.safe_area()?)),
+        }
+    }
+
+    /// Returns the safe area of `renderer`, which must draw into the tracked window. See
+    /// [`Renderer::safe_area`].
+    pub fn render_safe_area<T>(&mut self, renderer: &Renderer<T>) -> Result<Rect, Error> {
+        match self.render_area {
+            Some(area) => Ok(area),
+            None => Ok(*self.render_area.insert(renderer.safe_area()?)),
+        }
+    }
+}
+
+/// Collects the areas of a window surface changed since the last update.
+///
+/// Call [`DirtyTracker::mark`] with every rectangle drawn into the surface returned by
+/// [`WindowRef::as_surface_mut`], then [`DirtyTracker::present`] instead of
+/// [`WindowRef::update_surface`]. Overlapping rectangles are merged, so only the changed areas of
+/// mostly static UIs are copied to the screen.
+#[derive(Clone, Debug, Default)]
+pub struct DirtyTracker {
+    rects: Vec<Rect>,
+    everything: bool,
+}
+
+impl DirtyTracker {
+    /// Past this many separate rectangles, they're merged into their bounding rectangle.
+    const MAX_RECTS: usize = 32;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that `rect` was changed.
+    pub fn mark(&mut self, rect: Rect) {
+        if self.everything || rect.is_empty() {
+            return;
+        }
+        let mut rect = rect;
+        // Merging two rectangles may make the result overlap others, so keep merging until no
+        // rectangle overlaps the new one.
+        while let Some(i) = self.rects.iter().position(|r| dirty_rects_touch(r, &rect)) {
+            rect = dirty_rects_union(&self.rects.swap_remove(i), &rect);
+        }
+        self.rects.push(rect);
+        if self.rects.len() > Self::MAX_RECTS {
+            let bounds = self
+                .rects
+                .iter()
+                .fold(rect, |bounds, r| dirty_rects_union(&bounds, r));
+            self.rects.clear();
+            self.rects.push(bounds);
+        }
+    }
+
+    /// Records that the whole surface was changed.
+    pub fn mark_all(&mut self) {
+        self.everything = true;
+        self.rects.clear();
+    }
+
+    /// Returns the changed rectangles, which never overlap.
+    ///
+    /// This is empty after [`DirtyTracker::mark_all`].
+    #[inline]
+    pub fn rects(&self) -> &[Rect] {
+        &self.rects
+    }
+
+    /// Returns true if nothing changed since the last update.
+    #[inline]
+    pub fn is_clean(&self) -> bool {
+        !self.everything && self.rects.is_empty()
+    }
+
+    /// Forgets every change without updating the window.
+    pub fn clear(&mut self) {
+        self.everything = false;
+        self.rects.clear();
+    }
+
+    /// Copies the changed areas of the window surface to the screen and forgets them.
+    ///
+    /// Nothing is copied if nothing changed.
+    pub fn present(&mut self, window: &mut WindowRef) -> Result<(), Error> {
+        if self.everything {
+            window.update_surface()?;
+        } else if !self.rects.is_empty() {
+            window.update_surface_rects(&self.rects)?;
+        }
+        self.clear();
+        Ok(())
+    }
+}
+
+// Returns true if the rectangles overlap or share an edge.
+fn dirty_rects_touch(a: &Rect, b: &Rect) -> bool {
+    let (ax, ay, bx, by) = (a.x() as i64, a.y() as i64, b.x() as i64, b.y() as i64);
+    ax <= bx + b.w() as i64
+        && bx <= ax + a.w() as i64
+        && ay <= by + b.h() as i64
+        && by <= ay + a.h() as i64
+}
+
+fn dirty_rects_union(a: &Rect, b: &Rect) -> Rect {
+    let x = a.x().min(b.x());
+    let y = a.y().min(b.y());
+    let right = (a.x() as i64 + a.w() as i64).max(b.x() as i64 + b.w() as i64);
+    let bottom = (a.y() as i64 + a.h() as i64).max(b.y() as i64 + b.h() as i64);
+    Rect::new(x, y, (right - x as i64) as u32, (bottom - y as i64) as u32)
+}
+
 #[repr(transparent)]
 #[derive(Copy, Clone, Debug)]
 pub struct WindowFlags(sys::SDL_WindowFlags);
@@ -1276,6 +2256,11 @@ impl WindowFlags {
     pub const METAL: WindowFlags = WindowFlags(sys::SDL_WINDOW_METAL);
     pub const TRANSPARENT: WindowFlags = WindowFlags(sys::SDL_WINDOW_TRANSPARENT);
     pub const NOT_FOCUSABLE: WindowFlags = WindowFlags(sys::SDL_WINDOW_NOT_FOCUSABLE);
+
+    #[inline]
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
 }
 
 impl BitOr for WindowFlags {
@@ -1375,7 +2360,7 @@ impl DisplayMode {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum DisplayOrientation {
     Unknown,
     Landscape,
@@ -0,0 +1,454 @@
+//! OpenGL context management for SDL windows.
+//!
+//! Context attributes must be set with [`VideoSubsystem::gl_set_attribute`] before the window is
+//! created with [`crate::video::WindowFlags::OPEN_GL`] and before its context is created. OpenGL
+//! functions themselves are loaded with [`VideoSubsystem::gl_get_proc_address`], for example by
+//! passing it to the loader of an OpenGL bindings crate.
+
+use crate::init::VideoSubsystem;
+use crate::video::{Window, WindowRef};
+use crate::{sys, Error};
+use alloc::ffi::CString;
+use core::ops::BitOr;
+use core::ptr::NonNull;
+
+/// An untyped OpenGL function pointer, returned by [`VideoSubsystem::gl_get_proc_address`].
+///
+/// Transmute it to the signature of the requested function before calling it.
+pub type GlFunction = unsafe extern "C" fn();
+
+impl VideoSubsystem {
+    /// Sets an OpenGL attribute, to be used by the next context that's created.
+    pub fn gl_set_attribute(&self, attr: GlAttr, value: i32) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_GL_SetAttribute(attr.to_ll(), value) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns the value of an OpenGL attribute for the current context.
+    pub fn gl_get_attribute(&self, attr: GlAttr) -> Result<i32, Error> {
+        let mut value = 0;
+        let result = unsafe { sys::SDL_GL_GetAttribute(attr.to_ll(), &raw mut value) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(value)
+    }
+
+    /// Resets all OpenGL attributes to their default values.
+    pub fn gl_reset_attributes(&self) {
+        unsafe { sys::SDL_GL_ResetAttributes() };
+    }
+
+    /// Requests a context of the given OpenGL version and profile.
+    ///
+    /// This sets [`GlAttr::ContextMajorVersion`], [`GlAttr::ContextMinorVersion`] and
+    /// [`GlAttr::ContextProfileMask`].
+    pub fn gl_set_context_version(
+        &self,
+        major: i32,
+        minor: i32,
+        profile: GlProfile,
+    ) -> Result<(), Error> {
+        self.gl_set_attribute(GlAttr::ContextMajorVersion, major)?;
+        self.gl_set_attribute(GlAttr::ContextMinorVersion, minor)?;
+        self.gl_set_attribute(GlAttr::ContextProfileMask, profile.to_ll() as i32)
+    }
+
+    /// Sets the flags of the next context that's created.
+    pub fn gl_set_context_flags(&self, flags: GlContextFlags) -> Result<(), Error> {
+        self.gl_set_attribute(GlAttr::ContextFlags, flags.0 as i32)
+    }
+
+    /// Loads an OpenGL library.
+    ///
+    /// `path` is the platform dependent name of the library, or `None` for the default one. This
+    /// is optional: the default library is loaded when the first OpenGL window is created.
+    pub fn gl_load_library(&self, path: Option<&str>) -> Result<(), Error> {
+        let path = path.map(CString::new).transpose()?;
+        let path_ptr = path
+            .as_ref()
+            .map(|p| p.as_ptr())
+            .unwrap_or(core::ptr::null());
+        let result = unsafe { sys::SDL_GL_LoadLibrary(path_ptr) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Unloads the library loaded by [`VideoSubsystem::gl_load_library`].
+    ///
+    /// # Safety
+    ///
+    /// Function pointers returned by [`VideoSubsystem::gl_get_proc_address`] must not be called
+    /// once the library is unloaded.
+    pub unsafe fn gl_unload_library(&self) {
+        unsafe { sys::SDL_GL_UnloadLibrary() };
+    }
+
+    /// Returns the address of an OpenGL function, or `None` if it can't be found.
+    ///
+    /// Some platforms return a non-null pointer for functions that aren't supported by the
+    /// current context, so check the context version or [`VideoSubsystem::gl_extension_supported`]
+    /// before calling the function.
+    pub fn gl_get_proc_address(&self, name: &str) -> Option<GlFunction> {
+        crate::with_cstr(name, |name| unsafe {
+            sys::SDL_GL_GetProcAddress(name.as_ptr())
+        })
+        .ok()
+        .flatten()
+    }
+
+    /// Returns true if an OpenGL extension is supported by the current context.
+    pub fn gl_extension_supported(&self, extension: &str) -> bool {
+        crate::with_cstr(extension, |extension| unsafe {
+            sys::SDL_GL_ExtensionSupported(extension.as_ptr())
+        })
+        .unwrap_or(false)
+    }
+
+    /// Sets the swap interval of the current context.
+    ///
+    /// Fails if the interval isn't supported, which is common for
+    /// [`GlSwapInterval::Adaptive`]; callers can fall back to [`GlSwapInterval::VSync`].
+    pub fn gl_set_swap_interval(&self, interval: GlSwapInterval) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_GL_SetSwapInterval(interval.to_ll()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Returns the swap interval of the current context.
+    pub fn gl_swap_interval(&self) -> Result<GlSwapInterval, Error> {
+        let mut interval = 0;
+        let result = unsafe { sys::SDL_GL_GetSwapInterval(&raw mut interval) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(GlSwapInterval::from_ll(interval))
+    }
+
+    /// Sets whether the next context that's created shares its objects, such as textures and
+    /// buffers, with the context that's current at that time.
+    ///
+    /// See also [`Window::gl_create_shared_context`].
+    pub fn gl_set_share_with_current_context(&self, share: bool) -> Result<(), Error> {
+        self.gl_set_attribute(GlAttr::ShareWithCurrentContext, share as i32)
+    }
+
+    /// Returns the ID of the window whose context is current on the calling thread, if any.
+    pub fn gl_current_window(&self) -> Result<Option<u32>, Error> {
+        let window = unsafe { sys::SDL_GL_GetCurrentWindow() };
+        if window.is_null() {
+            // SDL reports an error when no window is current, but not when the call failed.
+            return Ok(None);
+        }
+        let id = unsafe { sys::SDL_GetWindowID(window) };
+        if id == 0 {
+            return Err(Error::new());
+        }
+        Ok(Some(id))
+    }
+
+    /// Returns true if an OpenGL context is current on the calling thread.
+    pub fn gl_has_current_context(&self) -> bool {
+        !unsafe { sys::SDL_GL_GetCurrentContext() }.is_null()
+    }
+}
+
+impl Window {
+    /// Creates an OpenGL context for the window and makes it current.
+    ///
+    /// The window must have been created with [`crate::video::WindowFlags::OPEN_GL`].
+    pub fn gl_create_context(&self) -> Result<GlContext, Error> {
+        let ptr = unsafe { sys::SDL_GL_CreateContext(self.as_ptr() as *mut _) };
+        let ptr = NonNull::new(ptr).ok_or_else(Error::new)?;
+        Ok(GlContext {
+            _video: self.video.clone(),
+            ptr,
+        })
+    }
+
+    /// Creates an OpenGL context for the window that shares its objects with `share_with`, and
+    /// makes it current.
+    ///
+    /// `share_with` must be current on the calling thread. Textures, buffers and other objects
+    /// created in either context can be used in the other; switch between them with
+    /// [`WindowRef::gl_make_current`]. A `GlContext` can't be sent to other threads, so both
+    /// contexts are used on the thread that created them.
+    pub fn gl_create_shared_context(&self, share_with: &GlContext) -> Result<GlContext, Error> {
+        if !share_with.is_current() {
+            return Err(Error::register(
+                c"The context to share with must be current on the calling thread.",
+            ));
+        }
+        self.video.gl_set_share_with_current_context(true)?;
+        let context = self.gl_create_context();
+        self.video.gl_set_share_with_current_context(false)?;
+        context
+    }
+}
+
+impl WindowRef {
+    /// Makes `context` current for rendering into this window on the calling thread.
+    pub fn gl_make_current(&self, context: &GlContext) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_GL_MakeCurrent(self.as_ptr() as *mut _, context.raw()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Releases the context that's current on the calling thread, so it can be made current on
+    /// another thread.
+    pub fn gl_release_current(&self) -> Result<(), Error> {
+        let result =
+            unsafe { sys::SDL_GL_MakeCurrent(self.as_ptr() as *mut _, core::ptr::null_mut()) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Presents the back buffer of the window, when the window's context is current.
+    ///
+    /// With a swap interval other than [`GlSwapInterval::Immediate`], this waits for the vertical
+    /// refresh.
+    pub fn gl_swap_window(&mut self) -> Result<(), Error> {
+        let result = unsafe { sys::SDL_GL_SwapWindow(self.as_ptr() as *mut _) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+}
+
+/// An OpenGL context, destroyed when dropped.
+pub struct GlContext {
+    _video: VideoSubsystem,
+    ptr: NonNull<sys::SDL_GLContextState>,
+}
+
+impl GlContext {
+    /// Returns true if this is the current context of the calling thread.
+    pub fn is_current(&self) -> bool {
+        unsafe { sys::SDL_GL_GetCurrentContext() == self.raw() }
+    }
+
+    #[inline]
+    pub fn raw(&self) -> sys::SDL_GLContext {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Drop for GlContext {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_GL_DestroyContext(self.ptr.as_ptr()) };
+    }
+}
+
+/// OpenGL attributes, set with [`VideoSubsystem::gl_set_attribute`].
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GlAttr {
+    /// The minimum number of bits for the red channel of the color buffer; defaults to 8.
+    RedSize = sys::SDL_GLAttr_SDL_GL_RED_SIZE,
+    /// The minimum number of bits for the green channel of the color buffer; defaults to 8.
+    GreenSize = sys::SDL_GLAttr_SDL_GL_GREEN_SIZE,
+    /// The minimum number of bits for the blue channel of the color buffer; defaults to 8.
+    BlueSize = sys::SDL_GLAttr_SDL_GL_BLUE_SIZE,
+    /// The minimum number of bits for the alpha channel of the color buffer; defaults to 8.
+    AlphaSize = sys::SDL_GLAttr_SDL_GL_ALPHA_SIZE,
+    /// The minimum number of bits for the frame buffer size; defaults to 0.
+    BufferSize = sys::SDL_GLAttr_SDL_GL_BUFFER_SIZE,
+    /// Whether the output is single or double buffered; defaults to double buffering on.
+    DoubleBuffer = sys::SDL_GLAttr_SDL_GL_DOUBLEBUFFER,
+    /// The minimum number of bits in the depth buffer; defaults to 16.
+    DepthSize = sys::SDL_GLAttr_SDL_GL_DEPTH_SIZE,
+    /// The minimum number of bits in the stencil buffer; defaults to 0.
+    StencilSize = sys::SDL_GLAttr_SDL_GL_STENCIL_SIZE,
+    AccumRedSize = sys::SDL_GLAttr_SDL_GL_ACCUM_RED_SIZE,
+    AccumGreenSize = sys::SDL_GLAttr_SDL_GL_ACCUM_GREEN_SIZE,
+    AccumBlueSize = sys::SDL_GLAttr_SDL_GL_ACCUM_BLUE_SIZE,
+    AccumAlphaSize = sys::SDL_GLAttr_SDL_GL_ACCUM_ALPHA_SIZE,
+    /// Whether the output is stereo 3D; defaults to off.
+    Stereo = sys::SDL_GLAttr_SDL_GL_STEREO,
+    /// The number of buffers used for multisample anti-aliasing; defaults to 0.
+    MultisampleBuffers = sys::SDL_GLAttr_SDL_GL_MULTISAMPLEBUFFERS,
+    /// The number of samples used around the current pixel for multisample anti-aliasing.
+    MultisampleSamples = sys::SDL_GLAttr_SDL_GL_MULTISAMPLESAMPLES,
+    /// Set to 1 to require hardware acceleration, or 0 to force software rendering.
+    AcceleratedVisual = sys::SDL_GLAttr_SDL_GL_ACCELERATED_VISUAL,
+    RetainedBacking = sys::SDL_GLAttr_SDL_GL_RETAINED_BACKING,
+    ContextMajorVersion = sys::SDL_GLAttr_SDL_GL_CONTEXT_MAJOR_VERSION,
+    ContextMinorVersion = sys::SDL_GLAttr_SDL_GL_CONTEXT_MINOR_VERSION,
+    /// See [`GlContextFlags`].
+    ContextFlags = sys::SDL_GLAttr_SDL_GL_CONTEXT_FLAGS,
+    /// See [`GlProfile`].
+    ContextProfileMask = sys::SDL_GLAttr_SDL_GL_CONTEXT_PROFILE_MASK,
+    /// Set to 1 to share resources with the context that's current when the next one is created.
+    ShareWithCurrentContext = sys::SDL_GLAttr_SDL_GL_SHARE_WITH_CURRENT_CONTEXT,
+    /// Set to 1 to request an sRGB-capable framebuffer.
+    FramebufferSrgbCapable = sys::SDL_GLAttr_SDL_GL_FRAMEBUFFER_SRGB_CAPABLE,
+    ContextReleaseBehavior = sys::SDL_GLAttr_SDL_GL_CONTEXT_RELEASE_BEHAVIOR,
+    ContextResetNotification = sys::SDL_GLAttr_SDL_GL_CONTEXT_RESET_NOTIFICATION,
+    ContextNoError = sys::SDL_GLAttr_SDL_GL_CONTEXT_NO_ERROR,
+    FloatBuffers = sys::SDL_GLAttr_SDL_GL_FLOATBUFFERS,
+    EglPlatform = sys::SDL_GLAttr_SDL_GL_EGL_PLATFORM,
+}
+
+impl GlAttr {
+    /// Converts a raw `SDL_GLAttr` into a `GlAttr`.
+    pub fn try_from_ll(ll: sys::SDL_GLAttr) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GLAttr_SDL_GL_RED_SIZE => Self::RedSize,
+            sys::SDL_GLAttr_SDL_GL_GREEN_SIZE => Self::GreenSize,
+            sys::SDL_GLAttr_SDL_GL_BLUE_SIZE => Self::BlueSize,
+            sys::SDL_GLAttr_SDL_GL_ALPHA_SIZE => Self::AlphaSize,
+            sys::SDL_GLAttr_SDL_GL_BUFFER_SIZE => Self::BufferSize,
+            sys::SDL_GLAttr_SDL_GL_DOUBLEBUFFER => Self::DoubleBuffer,
+            sys::SDL_GLAttr_SDL_GL_DEPTH_SIZE => Self::DepthSize,
+            sys::SDL_GLAttr_SDL_GL_STENCIL_SIZE => Self::StencilSize,
+            sys::SDL_GLAttr_SDL_GL_ACCUM_RED_SIZE => Self::AccumRedSize,
+            sys::SDL_GLAttr_SDL_GL_ACCUM_GREEN_SIZE => Self::AccumGreenSize,
+            sys::SDL_GLAttr_SDL_GL_ACCUM_BLUE_SIZE => Self::AccumBlueSize,
+            sys::SDL_GLAttr_SDL_GL_ACCUM_ALPHA_SIZE => Self::AccumAlphaSize,
+            sys::SDL_GLAttr_SDL_GL_STEREO => Self::Stereo,
+            sys::SDL_GLAttr_SDL_GL_MULTISAMPLEBUFFERS => Self::MultisampleBuffers,
+            sys::SDL_GLAttr_SDL_GL_MULTISAMPLESAMPLES => Self::MultisampleSamples,
+            sys::SDL_GLAttr_SDL_GL_ACCELERATED_VISUAL => Self::AcceleratedVisual,
+            sys::SDL_GLAttr_SDL_GL_RETAINED_BACKING => Self::RetainedBacking,
+            sys::SDL_GLAttr_SDL_GL_CONTEXT_MAJOR_VERSION => Self::ContextMajorVersion,
+            sys::SDL_GLAttr_SDL_GL_CONTEXT_MINOR_VERSION => Self::ContextMinorVersion,
+            sys::SDL_GLAttr_SDL_GL_CONTEXT_FLAGS => Self::ContextFlags,
+            sys::SDL_GLAttr_SDL_GL_CONTEXT_PROFILE_MASK => Self::ContextProfileMask,
+            sys::SDL_GLAttr_SDL_GL_SHARE_WITH_CURRENT_CONTEXT => Self::ShareWithCurrentContext,
+            sys::SDL_GLAttr_SDL_GL_FRAMEBUFFER_SRGB_CAPABLE => Self::FramebufferSrgbCapable,
+            sys::SDL_GLAttr_SDL_GL_CONTEXT_RELEASE_BEHAVIOR => Self::ContextReleaseBehavior,
+            sys::SDL_GLAttr_SDL_GL_CONTEXT_RESET_NOTIFICATION => Self::ContextResetNotification,
+            sys::SDL_GLAttr_SDL_GL_CONTEXT_NO_ERROR => Self::ContextNoError,
+            sys::SDL_GLAttr_SDL_GL_FLOATBUFFERS => Self::FloatBuffers,
+            sys::SDL_GLAttr_SDL_GL_EGL_PLATFORM => Self::EglPlatform,
+            _ => return Err(Error::register(c"Unknown GL attribute.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GLAttr {
+        *self as u32
+    }
+}
+
+/// OpenGL context profiles, for [`GlAttr::ContextProfileMask`].
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GlProfile {
+    /// OpenGL core profile; deprecated functions are disabled.
+    Core = sys::SDL_GL_CONTEXT_PROFILE_CORE,
+    /// OpenGL compatibility profile; deprecated functions are allowed.
+    Compatibility = sys::SDL_GL_CONTEXT_PROFILE_COMPATIBILITY,
+    /// OpenGL ES profile.
+    Es = sys::SDL_GL_CONTEXT_PROFILE_ES,
+}
+
+impl GlProfile {
+    /// Converts a raw `SDL_GLProfile` into a `GlProfile`.
+    pub fn try_from_ll(ll: sys::SDL_GLProfile) -> Result<Self, Error> {
+        Ok(match ll {
+            sys::SDL_GL_CONTEXT_PROFILE_CORE => Self::Core,
+            sys::SDL_GL_CONTEXT_PROFILE_COMPATIBILITY => Self::Compatibility,
+            sys::SDL_GL_CONTEXT_PROFILE_ES => Self::Es,
+            _ => return Err(Error::register(c"Unknown GL profile.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GLProfile {
+        *self as u32
+    }
+}
+
+/// OpenGL context flags, for [`GlAttr::ContextFlags`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct GlContextFlags(sys::SDL_GLContextFlag);
+
+impl GlContextFlags {
+    pub const DEBUG: Self = Self(sys::SDL_GL_CONTEXT_DEBUG_FLAG);
+    pub const FORWARD_COMPATIBLE: Self = Self(sys::SDL_GL_CONTEXT_FORWARD_COMPATIBLE_FLAG);
+    pub const ROBUST_ACCESS: Self = Self(sys::SDL_GL_CONTEXT_ROBUST_ACCESS_FLAG);
+    pub const RESET_ISOLATION: Self = Self(sys::SDL_GL_CONTEXT_RESET_ISOLATION_FLAG);
+
+    #[inline]
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> sys::SDL_GLContextFlag {
+        self.0
+    }
+}
+
+impl BitOr for GlContextFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// How buffer swaps are synchronized with the vertical refresh of the display.
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GlSwapInterval {
+    /// Buffers are swapped immediately.
+    Immediate = 0,
+    /// Buffer swaps wait for the vertical refresh.
+    VSync = 1,
+    /// Like [`GlSwapInterval::VSync`], but late swaps happen immediately instead of waiting for
+    /// the next refresh.
+    Adaptive = -1,
+}
+
+impl GlSwapInterval {
+    /// Converts a raw value into a `GlSwapInterval`.
+    pub fn try_from_ll(ll: i32) -> Result<Self, Error> {
+        Ok(match ll {
+            0 => Self::Immediate,
+            1 => Self::VSync,
+            -1 => Self::Adaptive,
+            _ => return Err(Error::register(c"Unknown GL swap interval.")),
+        })
+    }
+
+    #[inline]
+    pub fn to_ll(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_ll(ll: i32) -> Self {
+        match ll {
+            0 => Self::Immediate,
+            ll if ll < 0 => Self::Adaptive,
+            _ => Self::VSync,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_round_trips;
+
+    #[test]
+    fn try_from_ll_round_trips() {
+        assert_round_trips!(GlAttr: RedSize, GreenSize, BlueSize, AlphaSize, BufferSize, DoubleBuffer, DepthSize, StencilSize, AccumRedSize, AccumGreenSize, AccumBlueSize, AccumAlphaSize, Stereo, MultisampleBuffers, MultisampleSamples, AcceleratedVisual, RetainedBacking, ContextMajorVersion, ContextMinorVersion, ContextFlags, ContextProfileMask, ShareWithCurrentContext, FramebufferSrgbCapable, ContextReleaseBehavior, ContextResetNotification, ContextNoError, FloatBuffers, EglPlatform);
+        assert_round_trips!(GlProfile: Core, Compatibility, Es);
+        assert_round_trips!(GlSwapInterval: Immediate, VSync, Adaptive);
+    }
+}
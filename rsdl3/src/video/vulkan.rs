@@ -0,0 +1,143 @@
+//! Helpers to render into SDL windows with Vulkan.
+//!
+//! SDL doesn't depend on any Vulkan bindings, so handles are exchanged as raw pointers; cast them
+//! to and from the types of your Vulkan crate of choice.
+
+use crate::init::VideoSubsystem;
+use crate::video::WindowRef;
+use crate::{sys, Error};
+use alloc::ffi::CString;
+use alloc::vec::Vec;
+use core::ffi::CStr;
+
+pub use sys::vulkan::{VkAllocationCallbacks, VkInstance, VkPhysicalDevice, VkSurfaceKHR};
+
+/// The untyped `vkGetInstanceProcAddr` function pointer.
+///
+/// Transmute it to `PFN_vkGetInstanceProcAddr` to load the rest of the Vulkan API.
+pub type VkGetInstanceProcAddr = unsafe extern "C" fn();
+
+impl VideoSubsystem {
+    /// Loads the Vulkan loader library.
+    ///
+    /// `path` is the platform dependent name of the library, or `None` for the default one. This
+    /// is optional: the default library is loaded when the first window with
+    /// [`crate::video::WindowFlags::VULKAN`] is created. Each successful call must be paired with
+    /// a call to [`VideoSubsystem::vulkan_unload_library`].
+    pub fn vulkan_load_library(&self, path: Option<&str>) -> Result<(), Error> {
+        let path = path.map(CString::new).transpose()?;
+        let path_ptr = path
+            .as_ref()
+            .map(|p| p.as_ptr())
+            .unwrap_or(core::ptr::null());
+        let result = unsafe { sys::vulkan::SDL_Vulkan_LoadLibrary(path_ptr) };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(())
+    }
+
+    /// Unloads the Vulkan library once it has been unloaded as many times as it was loaded.
+    ///
+    /// # Safety
+    ///
+    /// Every Vulkan object created through the library, including those owned by renderers and
+    /// GPU devices, must be destroyed before the library is actually unloaded.
+    pub unsafe fn vulkan_unload_library(&self) {
+        unsafe { sys::vulkan::SDL_Vulkan_UnloadLibrary() };
+    }
+
+    /// Returns the address of `vkGetInstanceProcAddr`.
+    ///
+    /// The Vulkan library must have been loaded with [`VideoSubsystem::vulkan_load_library`] or
+    /// by creating a Vulkan window.
+    pub fn vulkan_get_vk_get_instance_proc_addr(&self) -> Result<VkGetInstanceProcAddr, Error> {
+        unsafe { sys::vulkan::SDL_Vulkan_GetVkGetInstanceProcAddr() }.ok_or_else(Error::new)
+    }
+
+    /// Returns the instance extensions that must be enabled in `vkCreateInstance` for
+    /// [`WindowRef::vulkan_create_surface`] to work.
+    ///
+    /// The names are owned by SDL and stay valid until the Vulkan library is unloaded.
+    pub fn vulkan_get_instance_extensions(&self) -> Result<Vec<&CStr>, Error> {
+        let mut count = 0;
+        let ptr = unsafe { sys::vulkan::SDL_Vulkan_GetInstanceExtensions(&raw mut count) };
+        if ptr.is_null() {
+            return Err(Error::new());
+        }
+        let len = usize::try_from(count)?;
+        Ok(unsafe { core::slice::from_raw_parts(ptr, len) }
+            .iter()
+            .map(|name| unsafe { CStr::from_ptr(*name) })
+            .collect())
+    }
+
+    /// Returns true if the queue family of a physical device can present to windows.
+    ///
+    /// # Safety
+    ///
+    /// `instance` and `physical_device` must be valid handles, and `instance` must have been
+    /// created with the extensions from [`VideoSubsystem::vulkan_get_instance_extensions`].
+    pub unsafe fn vulkan_presentation_support(
+        &self,
+        instance: VkInstance,
+        physical_device: VkPhysicalDevice,
+        queue_family_index: u32,
+    ) -> bool {
+        unsafe {
+            sys::vulkan::SDL_Vulkan_GetPresentationSupport(
+                instance,
+                physical_device,
+                queue_family_index,
+            )
+        }
+    }
+
+    /// Destroys a surface created by [`WindowRef::vulkan_create_surface`].
+    ///
+    /// # Safety
+    ///
+    /// `surface` must have been created from `instance` with the same `allocator`, and must no
+    /// longer be in use. It should be destroyed before its window.
+    pub unsafe fn vulkan_destroy_surface(
+        &self,
+        instance: VkInstance,
+        surface: VkSurfaceKHR,
+        allocator: *const VkAllocationCallbacks,
+    ) {
+        unsafe { sys::vulkan::SDL_Vulkan_DestroySurface(instance, surface, allocator) };
+    }
+}
+
+impl WindowRef {
+    /// Creates a Vulkan surface for the window.
+    ///
+    /// The window must have been created with [`crate::video::WindowFlags::VULKAN`]. The surface
+    /// must be destroyed with [`VideoSubsystem::vulkan_destroy_surface`] before the window is
+    /// dropped.
+    ///
+    /// # Safety
+    ///
+    /// `instance` must be a valid handle created with the extensions from
+    /// [`VideoSubsystem::vulkan_get_instance_extensions`]. `allocator` must be null or point to
+    /// valid `VkAllocationCallbacks`.
+    pub unsafe fn vulkan_create_surface(
+        &self,
+        instance: VkInstance,
+        allocator: *const VkAllocationCallbacks,
+    ) -> Result<VkSurfaceKHR, Error> {
+        let mut surface = core::ptr::null_mut();
+        let result = unsafe {
+            sys::vulkan::SDL_Vulkan_CreateSurface(
+                self.as_ptr() as *mut _,
+                instance,
+                allocator,
+                &raw mut surface,
+            )
+        };
+        if !result {
+            return Err(Error::new());
+        }
+        Ok(surface)
+    }
+}